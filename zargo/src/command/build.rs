@@ -8,6 +8,8 @@ use std::path::PathBuf;
 use failure::Fail;
 use structopt::StructOpt;
 
+use zinc_bytecode::Curve;
+
 use crate::directory::build::Directory as BuildDirectory;
 use crate::directory::build::Error as BuildDirectoryError;
 use crate::directory::data::Directory as DataDirectory;
@@ -56,6 +58,14 @@ pub struct Command {
         default_value = "./data/public-data.json"
     )]
     public_data: PathBuf,
+
+    #[structopt(
+        long = "curve",
+        help = "The curve, whose scalar field the circuit is compiled for",
+        default_value = "bn256",
+        possible_values = &Curve::NAMES
+    )]
+    curve: Curve,
 }
 
 #[derive(Debug, Fail)]
@@ -92,6 +102,7 @@ impl Command {
             &self.witness,
             &self.public_data,
             &self.circuit,
+            self.curve,
             &source_file_paths,
         )
         .map_err(Error::Compiler)?;
@@ -6,6 +6,7 @@ use failure::Fail;
 
 use crate::command::build::Error as BuildCommandError;
 use crate::command::clean::Error as CleanCommandError;
+use crate::command::export_verifier::Error as ExportVerifierCommandError;
 use crate::command::init::Error as InitCommandError;
 use crate::command::new::Error as NewCommandError;
 use crate::command::proof_check::Error as ProofCheckCommandError;
@@ -34,6 +35,8 @@ pub enum Error {
     Verify(VerifyCommandError),
     #[fail(display = "{}", _0)]
     ProofCheck(ProofCheckCommandError),
+    #[fail(display = "{}", _0)]
+    ExportVerifier(ExportVerifierCommandError),
 }
 
 impl From<NewCommandError> for Error {
@@ -89,3 +92,9 @@ impl From<ProofCheckCommandError> for Error {
         Self::ProofCheck(inner)
     }
 }
+
+impl From<ExportVerifierCommandError> for Error {
+    fn from(inner: ExportVerifierCommandError) -> Self {
+        Self::ExportVerifier(inner)
+    }
+}
@@ -0,0 +1,51 @@
+//!
+//! The `export-verifier` command.
+//!
+
+use std::path::PathBuf;
+
+use failure::Fail;
+use structopt::StructOpt;
+
+use crate::executable::virtual_machine::Error as VirtualMachineError;
+use crate::executable::virtual_machine::VirtualMachine;
+
+#[derive(Debug, StructOpt)]
+#[structopt(about = "Generates the Solidity verifier contract from the verifying key")]
+pub struct Command {
+    #[structopt(
+        short = "v",
+        parse(from_occurrences),
+        help = "Shows verbose logs, use multiple times for more verbosity"
+    )]
+    verbosity: usize,
+
+    #[structopt(
+        long = "verifying-key",
+        help = "Path to the verifying key file",
+        default_value = "./data/verifying-key.txt"
+    )]
+    verifying_key: PathBuf,
+
+    #[structopt(
+        long = "output",
+        help = "Path to the Solidity contract file to generate",
+        default_value = "./build/Verifier.sol"
+    )]
+    output: PathBuf,
+}
+
+#[derive(Debug, Fail)]
+pub enum Error {
+    #[fail(display = "virtual machine {}", _0)]
+    VirtualMachine(VirtualMachineError),
+}
+
+impl Command {
+    pub fn execute(self) -> Result<(), Error> {
+        VirtualMachine::export_verifier(self.verbosity, &self.verifying_key, &self.output)
+            .map_err(Error::VirtualMachine)?;
+
+        Ok(())
+    }
+}
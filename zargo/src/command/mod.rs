@@ -5,6 +5,7 @@
 pub mod build;
 pub mod clean;
 pub mod error;
+pub mod export_verifier;
 pub mod init;
 pub mod new;
 pub mod proof_check;
@@ -18,6 +19,7 @@ use structopt::StructOpt;
 use self::build::Command as BuildCommand;
 use self::clean::Command as CleanCommand;
 use self::error::Error;
+use self::export_verifier::Command as ExportVerifierCommand;
 use self::init::Command as InitCommand;
 use self::new::Command as NewCommand;
 use self::proof_check::Command as ProofCheckCommand;
@@ -37,6 +39,7 @@ pub enum Command {
     Prove(ProveCommand),
     Verify(VerifyCommand),
     ProofCheck(ProofCheckCommand),
+    ExportVerifier(ExportVerifierCommand),
 }
 
 impl Command {
@@ -51,6 +54,7 @@ impl Command {
             Self::Prove(command) => command.execute()?,
             Self::Verify(command) => command.execute()?,
             Self::ProofCheck(command) => command.execute()?,
+            Self::ExportVerifier(command) => command.execute()?,
         }
         Ok(())
     }
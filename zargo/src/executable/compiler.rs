@@ -9,6 +9,8 @@ use std::process::ExitStatus;
 
 use failure::Fail;
 
+use zinc_bytecode::Curve;
+
 pub struct Compiler {}
 
 static BINARY_NAME_DEFAULT: &str = "znc";
@@ -29,6 +31,7 @@ impl Compiler {
         witness_path: &PathBuf,
         public_data_path: &PathBuf,
         circuit_path: &PathBuf,
+        curve: Curve,
         source_file_paths: &[PathBuf],
     ) -> Result<(), Error> {
         let mut child = process::Command::new(BINARY_NAME_DEFAULT)
@@ -39,6 +42,8 @@ impl Compiler {
             .arg(public_data_path)
             .arg("--output")
             .arg(circuit_path)
+            .arg("--curve")
+            .arg(curve.name())
             .args(source_file_paths)
             .spawn()
             .map_err(Error::Spawning)?;
@@ -141,6 +141,30 @@ impl VirtualMachine {
         Ok(())
     }
 
+    pub fn export_verifier(
+        verbosity: usize,
+        verifying_key_path: &PathBuf,
+        output_path: &PathBuf,
+    ) -> Result<(), Error> {
+        let mut child = process::Command::new(BINARY_NAME_DEFAULT)
+            .args(vec!["-v"; verbosity])
+            .arg("export-verifier")
+            .arg("--verifying-key")
+            .arg(&verifying_key_path)
+            .arg("--output")
+            .arg(&output_path)
+            .spawn()
+            .map_err(Error::Spawning)?;
+
+        let status = child.wait().map_err(Error::Waiting)?;
+
+        if !status.success() {
+            return Err(Error::Failure(status));
+        }
+
+        Ok(())
+    }
+
     pub fn prove_and_verify(
         verbosity: usize,
         circuit_path: &PathBuf,
@@ -0,0 +1,88 @@
+use serde_derive::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// The pairing-friendly curve, whose scalar field the circuits are built over.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Curve {
+    Bn256,
+    Bls12_381,
+}
+
+impl Curve {
+    pub const ALL: [Curve; 2] = [Curve::Bn256, Curve::Bls12_381];
+
+    pub const NAMES: [&'static str; 2] = ["bn256", "bls12-381"];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Curve::Bn256 => Self::NAMES[0],
+            Curve::Bls12_381 => Self::NAMES[1],
+        }
+    }
+
+    /// The number of bits required to represent any scalar field element.
+    pub fn bitlength_field(self) -> usize {
+        match self {
+            Curve::Bn256 => 254,
+            Curve::Bls12_381 => 255,
+        }
+    }
+
+    /// The largest integer bitlength, which is a multiple of the byte and fits into
+    /// the scalar field, so the integer overflow may be checked.
+    pub fn bitlength_max_int(self) -> usize {
+        (self.bitlength_field() - 1) / 8 * 8
+    }
+}
+
+impl Default for Curve {
+    fn default() -> Self {
+        Curve::Bn256
+    }
+}
+
+impl FromStr for Curve {
+    type Err = String;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .iter()
+            .find(|curve| curve.name() == name)
+            .copied()
+            .ok_or_else(|| {
+                format!(
+                    "unknown curve `{}`, expected one of: {}",
+                    name,
+                    Self::NAMES.join(", ")
+                )
+            })
+    }
+}
+
+impl fmt::Display for Curve {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_from_str() {
+        for curve in Curve::ALL.iter() {
+            assert_eq!(curve.name().parse::<Curve>(), Ok(*curve));
+        }
+        assert!("bls12_377".parse::<Curve>().is_err());
+    }
+
+    #[test]
+    fn test_bitlength() {
+        assert_eq!(Curve::Bn256.bitlength_field(), 254);
+        assert_eq!(Curve::Bn256.bitlength_max_int(), 248);
+        assert_eq!(Curve::Bls12_381.bitlength_field(), 255);
+        assert_eq!(Curve::Bls12_381.bitlength_max_int(), 248);
+    }
+}
@@ -10,6 +10,8 @@ pub enum DataType {
     Struct(Vec<(String, DataType)>),
     Tuple(Vec<DataType>),
     Array(Box<DataType>, usize),
+    // Tagged union is a field tag followed by the largest variant payload, all being fields
+    TaggedUnion(Vec<(String, DataType)>),
 }
 
 impl DataType {
@@ -21,6 +23,9 @@ impl DataType {
             DataType::Struct(fields) => fields.iter().map(|(_, f)| f.size()).sum(),
             DataType::Tuple(fields) => fields.iter().map(|f| f.size()).sum(),
             DataType::Array(element_type, array_size) => element_type.size() * *array_size,
+            DataType::TaggedUnion(variants) => {
+                1 + variants.iter().map(|(_, v)| v.size()).max().unwrap_or(0)
+            }
         }
     }
 }
@@ -35,6 +35,32 @@ pub struct StructField {
     pub value: Value,
 }
 
+/// The tagged union value, which is flattened to the field tag, the variant payload,
+/// and the zero padding up to the largest variant payload size.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaggedUnionValue {
+    pub variant: String,
+    pub tag: usize,
+    pub value: Value,
+    pub padding: usize,
+}
+
+impl TaggedUnionValue {
+    fn default_from_type(
+        data_type: &DataType,
+        tag: usize,
+        variant: String,
+        variant_type: &DataType,
+    ) -> Self {
+        Self {
+            variant,
+            tag,
+            value: Value::default_from_type(variant_type),
+            padding: data_type.size() - 1 - variant_type.size(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum ScalarValue {
     Field(BigInt),
@@ -64,6 +90,7 @@ pub enum Value {
     Scalar(ScalarValue),
     Struct(Vec<StructField>),
     Array(Vec<Value>),
+    TaggedUnion(Box<TaggedUnionValue>),
 }
 
 impl Value {
@@ -93,6 +120,15 @@ impl Value {
             DataType::Tuple(fields) => {
                 Value::Array(fields.iter().map(|t| Value::default_from_type(t)).collect())
             }
+            DataType::TaggedUnion(variants) => {
+                let (name, variant_type) = variants.first().expect("tagged union is not empty");
+                Value::TaggedUnion(Box::new(TaggedUnionValue::default_from_type(
+                    data_type,
+                    0,
+                    name.clone(),
+                    variant_type,
+                )))
+            }
         }
     }
 
@@ -116,13 +152,20 @@ impl Value {
                     value.to_flat_values_recursive(flat_array);
                 }
             }
+            Value::TaggedUnion(tagged_union) => {
+                flat_array.push(BigInt::from(tagged_union.tag));
+                tagged_union.value.to_flat_values_recursive(flat_array);
+                for _ in 0..tagged_union.padding {
+                    flat_array.push(BigInt::from(0));
+                }
+            }
         }
     }
 
     /// Creates value from flat array and data type.
     pub fn from_flat_values(data_type: &DataType, flat_values: &[BigInt]) -> Option<Self> {
         let mut value = Self::default_from_type(data_type);
-        let consumed = value.fill_from_flat_values(flat_values)?;
+        let consumed = value.fill_from_flat_values(data_type, flat_values)?;
         if consumed == flat_values.len() {
             Some(value)
         } else {
@@ -131,10 +174,14 @@ impl Value {
     }
 
     /// Fills values from slice, returns number of used values or None if there is not enough.
-    fn fill_from_flat_values(&mut self, flat_values: &[BigInt]) -> Option<usize> {
-        match self {
-            Value::Unit => Some(0),
-            Value::Scalar(scalar) => {
+    fn fill_from_flat_values(
+        &mut self,
+        data_type: &DataType,
+        flat_values: &[BigInt],
+    ) -> Option<usize> {
+        match (self, data_type) {
+            (Value::Unit, _) => Some(0),
+            (Value::Scalar(scalar), _) => {
                 match scalar {
                     ScalarValue::Field(value) | ScalarValue::Integer(value, _) => {
                         *value = flat_values.first()?.clone();
@@ -145,22 +192,49 @@ impl Value {
                 }
                 Some(1)
             }
-            Value::Struct(fields) => {
+            (Value::Struct(fields), DataType::Struct(field_types)) => {
                 let mut offset = 0;
-                for StructField { value, .. } in fields.iter_mut() {
-                    let slice = &flat_values[offset..];
-                    offset += value.fill_from_flat_values(slice)?;
+                for (StructField { value, .. }, (_, data_type)) in
+                    fields.iter_mut().zip(field_types.iter())
+                {
+                    let slice = flat_values.get(offset..)?;
+                    offset += value.fill_from_flat_values(data_type, slice)?;
                 }
                 Some(offset)
             }
-            Value::Array(values) => {
+            (Value::Array(values), DataType::Array(data_type, _)) => {
                 let mut offset = 0;
                 for value in values.iter_mut() {
-                    let slice = &flat_values[offset..];
-                    offset += value.fill_from_flat_values(slice)?;
+                    let slice = flat_values.get(offset..)?;
+                    offset += value.fill_from_flat_values(data_type, slice)?;
+                }
+                Some(offset)
+            }
+            (Value::Array(values), DataType::Tuple(types)) => {
+                let mut offset = 0;
+                for (value, data_type) in values.iter_mut().zip(types.iter()) {
+                    let slice = flat_values.get(offset..)?;
+                    offset += value.fill_from_flat_values(data_type, slice)?;
                 }
                 Some(offset)
             }
+            (Value::TaggedUnion(tagged_union), DataType::TaggedUnion(variants)) => {
+                let tag = flat_values.first()?;
+                let (tag, (name, variant_type)) = variants
+                    .iter()
+                    .enumerate()
+                    .find(|(index, _)| &BigInt::from(*index) == tag)?;
+                **tagged_union =
+                    TaggedUnionValue::default_from_type(data_type, tag, name.clone(), variant_type);
+                tagged_union
+                    .value
+                    .fill_from_flat_values(variant_type, flat_values.get(1..)?)?;
+
+                let size = data_type.size();
+                flat_values.get(..size)?;
+                Some(size)
+            }
+            _ => None,
         }
     }
 }
@@ -195,6 +269,14 @@ impl Value {
                 json::Value::Object(object)
             }
             Value::Array(values) => json::Value::Array(values.iter().map(Self::to_json).collect()),
+            Value::TaggedUnion(tagged_union) => match tagged_union.value {
+                Value::Unit => json::Value::String(tagged_union.variant.clone()),
+                ref value => {
+                    let mut object = json::Map::<String, serde_json::Value>::new();
+                    object.insert(tagged_union.variant.clone(), value.to_json());
+                    json::Value::Object(object)
+                }
+            },
         }
     }
 
@@ -206,6 +288,7 @@ impl Value {
             DataType::Struct(fields) => Self::struct_from_json(value, fields),
             DataType::Tuple(dtype) => Self::tuple_from_json(value, dtype),
             DataType::Array(dtype, size) => Self::array_from_json(value, dtype, *size),
+            DataType::TaggedUnion(variants) => Self::tagged_union_from_json(value, dtype, variants),
         }
     }
 
@@ -356,6 +439,57 @@ impl Value {
 
         Ok(Value::Array(values))
     }
+
+    fn tagged_union_from_json(
+        value: &json::Value,
+        dtype: &DataType,
+        variants: &[(String, DataType)],
+    ) -> Result<Self, JsonValueError> {
+        let (name, json_value) = match value {
+            json::Value::String(name) => (name.as_str(), None),
+            json::Value::Object(object) if object.len() == 1 => object
+                .iter()
+                .next()
+                .map(|(name, value)| (name.as_str(), Some(value)))
+                .expect("object length is checked above"),
+            value => {
+                return Err(JsonValueErrorType::type_error(
+                    "enumeration variant (string or single-field structure)",
+                    value,
+                )
+                .into())
+            }
+        };
+
+        let (tag, (name, variant_type)) = variants
+            .iter()
+            .enumerate()
+            .find(|(_, (variant, _))| variant == name)
+            .ok_or_else(|| JsonValueErrorType::UnknownVariant(name.into()))?;
+
+        let mut tagged_union =
+            TaggedUnionValue::default_from_type(dtype, tag, name.clone(), variant_type);
+        tagged_union.value = match (json_value, variant_type) {
+            (None, DataType::Unit) => Value::Unit,
+            (Some(json_value), DataType::Unit) => {
+                return Err(JsonValueErrorType::type_error("no payload", json_value).into())
+                    .in_struct(name.as_str());
+            }
+            (None, _) => {
+                return Err(JsonValueErrorType::TypeError {
+                    expected: "variant payload".into(),
+                    actual: "nothing".into(),
+                }
+                .into())
+                .in_struct(name.as_str());
+            }
+            (Some(json_value), variant_type) => {
+                Self::from_typed_json(json_value, variant_type).in_struct(name.as_str())?
+            }
+        };
+
+        Ok(Value::TaggedUnion(Box::new(tagged_union)))
+    }
 }
 
 #[derive(Debug, Fail)]
@@ -428,6 +562,9 @@ pub enum JsonValueErrorType {
     #[fail(display = "unexpected field \"{}\"", _0)]
     UnexpectedField(String),
 
+    #[fail(display = "unknown enumeration variant \"{}\"", _0)]
+    UnknownVariant(String),
+
     #[fail(
         display = "expected array/tuple of size {}, got {} elements",
         expected, actual
@@ -2,11 +2,13 @@ pub mod logger;
 pub mod scalar;
 
 pub mod builtins;
+pub mod curve;
 pub mod data;
 pub mod instructions;
 pub mod program;
 pub mod vlq;
 
+pub use curve::Curve;
 pub use instructions::*;
 pub use program::*;
 
@@ -1,18 +1,33 @@
 use crate::data::types::DataType;
-use crate::Instruction;
+use crate::data::values::{StructField, Value};
+use crate::{Curve, Instruction};
 use serde_derive::{Deserialize, Serialize};
 
+pub static PUBLIC_DATA_INPUT_FIELD: &str = "input";
+pub static PUBLIC_DATA_OUTPUT_FIELD: &str = "output";
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Program {
+    /// The curve, whose scalar field the program is compiled for.
+    pub curve: Curve,
     pub input: DataType,
+    pub public_input: DataType,
     pub output: DataType,
     pub bytecode: Vec<Instruction>,
 }
 
 impl Program {
-    pub fn new(input: DataType, output: DataType, bytecode: Vec<Instruction>) -> Self {
+    pub fn new(
+        curve: Curve,
+        input: DataType,
+        public_input: DataType,
+        output: DataType,
+        bytecode: Vec<Instruction>,
+    ) -> Self {
         Self {
+            curve,
             input,
+            public_input,
             output,
             bytecode,
         }
@@ -25,4 +40,88 @@ impl Program {
     pub fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
         bincode::deserialize(bytes).map_err(|e| format!("{:?}", e))
     }
+
+    /// Returns `true` if some of the `main` arguments are allocated as public inputs.
+    pub fn has_public_input(&self) -> bool {
+        Self::is_public_input_present(&self.public_input)
+    }
+
+    /// The type of the data seen by the verifier.
+    ///
+    /// If there are no public inputs, it is the output type. Otherwise, it is a structure
+    /// with the public inputs and the output, in the order they are allocated by the VM.
+    pub fn public_data_type(public_input: &DataType, output: &DataType) -> DataType {
+        if Self::is_public_input_present(public_input) {
+            DataType::Struct(vec![
+                (PUBLIC_DATA_INPUT_FIELD.to_owned(), public_input.to_owned()),
+                (PUBLIC_DATA_OUTPUT_FIELD.to_owned(), output.to_owned()),
+            ])
+        } else {
+            output.to_owned()
+        }
+    }
+
+    /// Builds the public data value from the program input and output.
+    ///
+    /// The input is expected to be a structure with all the `main` arguments,
+    /// out of which the public ones are taken.
+    pub fn public_data(&self, input: &Value, output: Value) -> Value {
+        if !self.has_public_input() {
+            return output;
+        }
+
+        let public_names: Vec<&str> = match self.public_input {
+            DataType::Struct(ref fields) => fields.iter().map(|(name, _)| name.as_str()).collect(),
+            _ => vec![],
+        };
+
+        let public_fields = match input {
+            Value::Struct(fields) => fields
+                .iter()
+                .filter(|field| public_names.contains(&field.field.as_str()))
+                .cloned()
+                .collect(),
+            _ => vec![],
+        };
+
+        Value::Struct(vec![
+            StructField {
+                field: PUBLIC_DATA_INPUT_FIELD.to_owned(),
+                value: Value::Struct(public_fields),
+            },
+            StructField {
+                field: PUBLIC_DATA_OUTPUT_FIELD.to_owned(),
+                value: output,
+            },
+        ])
+    }
+
+    fn is_public_input_present(public_input: &DataType) -> bool {
+        match public_input {
+            DataType::Struct(fields) => !fields.is_empty(),
+            DataType::Unit => false,
+            _ => true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_curve_roundtrip() {
+        for curve in Curve::ALL.iter() {
+            let program = Program::new(
+                *curve,
+                DataType::Unit,
+                DataType::Unit,
+                DataType::Unit,
+                vec![],
+            );
+
+            let decoded = Program::from_bytes(&program.to_bytes()).expect("decoded");
+            assert_eq!(decoded.curve, *curve);
+        }
+    }
 }
@@ -0,0 +1,77 @@
+//!
+//! The build context.
+//!
+
+use std::cell::RefCell;
+use std::path::PathBuf;
+use std::rc::Rc;
+
+use zinc_bytecode::Curve;
+
+use crate::semantic::scope::state::State as SemanticState;
+use crate::warning::Warning;
+
+///
+/// The state shared by the files of a single build, so the builds running at the same time
+/// do not affect each other.
+///
+#[derive(Debug, Default)]
+pub struct Context {
+    /// The semantic analyzer state with the curve, whose scalar field the program is
+    /// compiled for, the type IDs, and the constant evaluation counters.
+    state: Rc<SemanticState>,
+    /// The file table, whose indexes are referred to by the token locations.
+    files: Vec<PathBuf>,
+    /// The warnings emitted by the semantic analyzer, which are shared by all the files,
+    /// since the generic functions of a module may be analyzed along with the entry.
+    warnings: Rc<RefCell<Vec<Warning>>>,
+}
+
+impl Context {
+    ///
+    /// Initializes a context of the build for the `curve`.
+    ///
+    pub fn new(curve: Curve) -> Self {
+        Self {
+            state: Rc::new(SemanticState::new(curve)),
+            files: Vec::new(),
+            warnings: Rc::new(RefCell::new(Vec::new())),
+        }
+    }
+
+    ///
+    /// The curve, whose scalar field the program is compiled for.
+    ///
+    pub fn curve(&self) -> Curve {
+        self.state.curve()
+    }
+
+    ///
+    /// The semantic analyzer state shared by the global scopes of the build files.
+    ///
+    pub fn state(&self) -> Rc<SemanticState> {
+        self.state.clone()
+    }
+
+    ///
+    /// Adds the file to the file table and returns its index.
+    ///
+    pub fn register_file(&mut self, path: PathBuf) -> usize {
+        self.files.push(path);
+        self.files.len() - 1
+    }
+
+    ///
+    /// The path of the file with the `index` in the file table.
+    ///
+    pub fn file_path(&self, index: usize) -> Option<&PathBuf> {
+        self.files.get(index)
+    }
+
+    ///
+    /// The warning storage shared by the semantic analyzers of the build.
+    ///
+    pub fn warnings(&self) -> Rc<RefCell<Vec<Warning>>> {
+        self.warnings.clone()
+    }
+}
@@ -0,0 +1,241 @@
+//!
+//! The in-memory compilation.
+//!
+
+pub mod context;
+mod tests;
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::path::Component;
+use std::path::Path;
+use std::path::PathBuf;
+use std::rc::Rc;
+
+use serde_json::Value as JsonValue;
+
+use zinc_bytecode::Curve;
+use zinc_bytecode::Program;
+
+use crate::diagnostic::Diagnostic;
+use crate::file::File;
+use crate::generator::bytecode::Bytecode;
+use crate::semantic::scope::Scope;
+
+use self::context::Context;
+
+pub static ENTRY_MODULE_NAME: &str = "main";
+pub static MODULE_FILE_NAME: &str = "mod";
+pub static MODULE_PATH_SEPARATOR: &str = "::";
+pub static SOURCE_FILE_EXTENSION: &str = "zn";
+
+///
+/// The compiled program along with its input and output templates.
+///
+#[derive(Debug)]
+pub struct Build {
+    /// The Zinc VM program.
+    pub program: Program,
+    /// The witness template, that is, the `main` function arguments.
+    pub input_template: JsonValue,
+    /// The public data template, that is, the public arguments and the `main` function result.
+    pub output_template: JsonValue,
+    /// The warnings of all the compiled files.
+    pub warnings: Vec<Diagnostic>,
+}
+
+impl Build {
+    ///
+    /// Compiles the in-memory sources, which are the module paths mapped to their code.
+    /// The `main` module is the entry point, and the nested modules are specified by their
+    /// full paths, e.g. `crypto::merkle`.
+    ///
+    /// The files are not accessed, and the sources are referred to as `<module>.zn` in the
+    /// diagnostics, e.g. `crypto/merkle.zn`. On failure, the errors are returned along with
+    /// the warnings, which were produced before the compilation had stopped. If the warnings
+    /// are denied, they are returned as errors.
+    ///
+    /// The program is compiled for the `curve` scalar field.
+    ///
+    pub fn compile(
+        sources: HashMap<String, String>,
+        curve: Curve,
+        is_warning_denied: bool,
+    ) -> Result<Self, Vec<Diagnostic>> {
+        let mut entry = None;
+        let mut modules = Vec::with_capacity(sources.len());
+        for (name, code) in sources.into_iter() {
+            let path = PathBuf::from(format!(
+                "{}.{}",
+                name.replace(MODULE_PATH_SEPARATOR, "/"),
+                SOURCE_FILE_EXTENSION
+            ));
+            let file = File::new(path, code);
+            if name == ENTRY_MODULE_NAME {
+                entry = Some(file);
+            } else {
+                modules.push((name, file));
+            }
+        }
+
+        match entry {
+            Some(entry) => Self::compile_files(entry, modules, curve, is_warning_denied),
+            None => Err(vec![Diagnostic::message(
+                format!("the `{}` module is missing", ENTRY_MODULE_NAME).as_str(),
+                Some("the `main` module must contain the `main` function"),
+            )
+            .render(&[])]),
+        }
+    }
+
+    ///
+    /// Compiles the entry file with its modules, which are specified by their full paths,
+    /// e.g. `crypto::merkle`.
+    ///
+    /// The modules are analyzed first, the nested ones before their parents, since a module
+    /// depends on its submodules. The modules are written to the bytecode only after the entry
+    /// is analyzed, since the module generic functions may be instantiated by the entry.
+    ///
+    /// The file table, the warnings, and the semantic analyzer state with the `curve` and
+    /// the type IDs are kept in the build context, so several builds may run at the same time.
+    ///
+    pub fn compile_files(
+        entry: File,
+        mut modules: Vec<(String, File)>,
+        curve: Curve,
+        is_warning_denied: bool,
+    ) -> Result<Self, Vec<Diagnostic>> {
+        modules.sort_by(|(path_1, _), (path_2, _)| {
+            Self::depth(path_2)
+                .cmp(&Self::depth(path_1))
+                .then_with(|| path_1.cmp(path_2))
+        });
+        if let Some(duplicate) = modules
+            .windows(2)
+            .find(|pair| pair[0].0 == pair[1].0)
+            .map(|pair| pair[0].0.to_owned())
+        {
+            return Err(vec![Diagnostic::message(
+                format!("module `{}` is defined more than once", duplicate).as_str(),
+                Some("a module must be defined either in `<name>.zn` or in `<name>/mod.zn`"),
+            )
+            .render(&[])]);
+        }
+
+        let mut context = Context::new(curve);
+        let mut warnings = Vec::new();
+
+        let mut scopes = HashMap::<String, Rc<RefCell<Scope>>>::new();
+        let mut intermediates = Vec::with_capacity(modules.len() + 1);
+        for (module_path, file) in modules.into_iter() {
+            log::info!("Compiling {:?}", file.path());
+            let path = file.path().to_owned();
+            let dependencies = Self::take_submodules(&mut scopes, Some(module_path.as_str()));
+            let (scope, intermediate, module_warnings) = file
+                .try_into_module(&mut context, dependencies, is_warning_denied)
+                .map_err(|errors| Self::failure(&mut warnings, errors))?;
+            warnings.extend(module_warnings);
+
+            scopes.insert(module_path, scope);
+            intermediates.push((path, intermediate));
+        }
+
+        log::info!("Compiling {:?}", entry.path());
+        let path = entry.path().to_owned();
+        let dependencies = Self::take_submodules(&mut scopes, None);
+        let (intermediate, entry_warnings) = entry
+            .try_into_entry(&mut context, dependencies, is_warning_denied)
+            .map_err(|errors| Self::failure(&mut warnings, errors))?;
+        warnings.extend(entry_warnings);
+        intermediates.push((path, intermediate));
+
+        let bytecode = Rc::new(RefCell::new(Bytecode::new(curve)));
+        for (path, intermediate) in intermediates.into_iter() {
+            bytecode
+                .borrow_mut()
+                .start_new_file(path.to_string_lossy().as_ref());
+            intermediate.write_all_to_bytecode(bytecode.clone());
+        }
+        let bytecode = Rc::try_unwrap(bytecode)
+            .expect(crate::PANIC_LAST_SHARED_REFERENCE)
+            .into_inner();
+
+        Ok(Self {
+            input_template: bytecode.input_template(),
+            output_template: bytecode.output_template(),
+            program: bytecode.into_program(),
+            warnings,
+        })
+    }
+
+    ///
+    /// Converts the source file path relative to the `src` directory into the module path,
+    /// e.g. both `crypto/merkle.zn` and `crypto/merkle/mod.zn` into `crypto::merkle`.
+    ///
+    /// Returns `None` if the path is not a relative path to a Zinc source file.
+    ///
+    pub fn module_path(relative: &Path) -> Option<String> {
+        if relative.extension()? != SOURCE_FILE_EXTENSION {
+            return None;
+        }
+
+        let mut names = Vec::new();
+        for component in relative.with_extension("").components() {
+            match component {
+                Component::Normal(name) => names.push(name.to_str()?.to_owned()),
+                _ => return None,
+            }
+        }
+        if names.len() > 1 && names.last().map(String::as_str) == Some(MODULE_FILE_NAME) {
+            names.pop();
+        }
+
+        Some(names.join(MODULE_PATH_SEPARATOR))
+    }
+
+    ///
+    /// Removes the analyzed direct submodules of the `parent` module from `scopes`, and
+    /// returns them by their own names. If `parent` is `None`, the top-level modules declared
+    /// by the entry are taken.
+    ///
+    fn take_submodules(
+        scopes: &mut HashMap<String, Rc<RefCell<Scope>>>,
+        parent: Option<&str>,
+    ) -> HashMap<String, Rc<RefCell<Scope>>> {
+        let paths: Vec<String> = scopes
+            .keys()
+            .filter(|path| Self::parent(path) == parent)
+            .cloned()
+            .collect();
+
+        paths
+            .into_iter()
+            .filter_map(|path| {
+                let scope = scopes.remove(path.as_str())?;
+                let name = match path.rfind(MODULE_PATH_SEPARATOR) {
+                    Some(index) => path[index + MODULE_PATH_SEPARATOR.len()..].to_owned(),
+                    None => path,
+                };
+                Some((name, scope))
+            })
+            .collect()
+    }
+
+    fn parent(path: &str) -> Option<&str> {
+        path.rfind(MODULE_PATH_SEPARATOR)
+            .map(|index| &path[..index])
+    }
+
+    fn depth(path: &str) -> usize {
+        path.matches(MODULE_PATH_SEPARATOR).count()
+    }
+
+    ///
+    /// Appends the `errors` to the warnings produced before the failure.
+    ///
+    fn failure(warnings: &mut Vec<Diagnostic>, errors: Vec<Diagnostic>) -> Vec<Diagnostic> {
+        let mut diagnostics = std::mem::replace(warnings, Vec::new());
+        diagnostics.extend(errors);
+        diagnostics
+    }
+}
@@ -0,0 +1,482 @@
+//!
+//! The in-memory compilation tests.
+//!
+
+#![cfg(test)]
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::path::PathBuf;
+use std::thread;
+
+use num_bigint::BigInt;
+use num_traits::One;
+use serde_json::json;
+
+use zinc_bytecode::data::types::DataType;
+use zinc_bytecode::data::types::IntegerType;
+use zinc_bytecode::data::types::ScalarType;
+use zinc_bytecode::Curve;
+use zinc_bytecode::Instruction;
+use zinc_bytecode::PushConst;
+
+use crate::build::Build;
+use crate::diagnostic::Severity;
+use crate::file::File;
+
+static PANIC_SUCCESSFUL_BUILD: &str = "The build must be successful";
+static PANIC_FAILED_BUILD: &str = "The build must fail";
+static PANIC_THREAD: &str = "The build thread must not panic";
+
+fn sources(sources: &[(&str, &str)]) -> HashMap<String, String> {
+    sources
+        .iter()
+        .map(|(name, code)| ((*name).to_owned(), (*code).to_owned()))
+        .collect()
+}
+
+#[test]
+fn ok_entry() {
+    let main = r#"
+fn main(pub a: u8, b: u8) -> u8 {
+    a + b
+}
+"#;
+
+    let build = Build::compile(sources(&[("main", main)]), Curve::Bn256, false)
+        .expect(PANIC_SUCCESSFUL_BUILD);
+
+    assert_eq!(
+        build.program.output,
+        DataType::Scalar(ScalarType::Integer(IntegerType::U8))
+    );
+    assert_eq!(build.input_template, json!({ "a": "0", "b": "0" }));
+    assert_eq!(
+        build.output_template,
+        json!({ "input": { "a": "0" }, "output": "0" })
+    );
+    assert!(build.warnings.is_empty());
+}
+
+#[test]
+fn ok_modules() {
+    let module_1 = r#"
+pub const VALUE: u8 = 42;
+"#;
+
+    let module_2 = r#"
+pub fn double(value: u8) -> u8 {
+    value * 2
+}
+"#;
+
+    let main = r#"
+mod module_1;
+mod module_2;
+
+fn main() -> u8 {
+    module_2::double(module_1::VALUE)
+}
+"#;
+
+    let build = Build::compile(
+        sources(&[
+            ("main", main),
+            ("module_1", module_1),
+            ("module_2", module_2),
+        ]),
+        Curve::Bn256,
+        false,
+    )
+    .expect(PANIC_SUCCESSFUL_BUILD);
+
+    assert_eq!(build.output_template, json!("0"));
+}
+
+#[test]
+fn ok_warnings() {
+    let main = r#"
+fn main(value: u8) -> u8 {
+    let unused = 42;
+    value
+}
+"#;
+
+    let build = Build::compile(sources(&[("main", main)]), Curve::Bn256, false)
+        .expect(PANIC_SUCCESSFUL_BUILD);
+
+    assert_eq!(build.warnings.len(), 1);
+    assert_eq!(build.warnings[0].severity, Severity::Warning);
+}
+
+#[test]
+fn ok_warnings_module() {
+    let module_1 = r#"
+pub fn public_unused() -> u8 {
+    42
+}
+
+fn private_unused() -> u8 {
+    42
+}
+
+pub fn double(value: u8) -> u8 {
+    value * 2
+}
+"#;
+
+    let main = r#"
+mod module_1;
+
+fn main(value: u8) -> u8 {
+    module_1::double(value)
+}
+"#;
+
+    let build = Build::compile(
+        sources(&[("main", main), ("module_1", module_1)]),
+        Curve::Bn256,
+        false,
+    )
+    .expect(PANIC_SUCCESSFUL_BUILD);
+
+    assert_eq!(build.warnings.len(), 1);
+    assert_eq!(
+        build.warnings[0].message,
+        "function `private_unused` is never used"
+    );
+}
+
+#[test]
+fn error_entry_missing() {
+    let module_1 = r#"
+const VALUE: u8 = 42;
+"#;
+
+    let diagnostics = Build::compile(sources(&[("module_1", module_1)]), Curve::Bn256, false)
+        .expect_err(PANIC_FAILED_BUILD);
+
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].message, "the `main` module is missing");
+}
+
+#[test]
+fn error_module() {
+    let module_1 = r#"
+const VALUE: u8 = UNKNOWN;
+"#;
+
+    let main = r#"
+mod module_1;
+
+fn main() -> u8 {
+    module_1::VALUE
+}
+"#;
+
+    let diagnostics = Build::compile(
+        sources(&[("main", main), ("module_1", module_1)]),
+        Curve::Bn256,
+        false,
+    )
+    .expect_err(PANIC_FAILED_BUILD);
+
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].code, Some("Z0607"));
+    assert!(diagnostics[0]
+        .rendered
+        .as_ref()
+        .map(|rendered| rendered.contains("module_1.zn"))
+        .unwrap_or_default());
+}
+
+#[test]
+fn error_warnings_denied() {
+    let main = r#"
+fn main(value: u8) -> u8 {
+    let unused = 42;
+    value
+}
+"#;
+
+    let diagnostics = Build::compile(sources(&[("main", main)]), Curve::Bn256, true)
+        .expect_err(PANIC_FAILED_BUILD);
+
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].severity, Severity::Error);
+}
+
+#[test]
+fn ok_modules_nested() {
+    let crypto = r#"
+pub mod merkle;
+
+pub const ONE: u8 = 1;
+"#;
+
+    let crypto_merkle = r#"
+mod hash;
+
+pub fn restore_root(leaf: u8) -> u8 {
+    hash::double(leaf)
+}
+"#;
+
+    let crypto_merkle_hash = r#"
+pub fn double(value: u8) -> u8 {
+    value * 2
+}
+"#;
+
+    let main = r#"
+mod crypto;
+
+fn main(leaf: u8) -> u8 {
+    crypto::merkle::restore_root(leaf) + crypto::ONE
+}
+"#;
+
+    let build = Build::compile(
+        sources(&[
+            ("main", main),
+            ("crypto", crypto),
+            ("crypto::merkle", crypto_merkle),
+            ("crypto::merkle::hash", crypto_merkle_hash),
+        ]),
+        Curve::Bn256,
+        false,
+    )
+    .expect(PANIC_SUCCESSFUL_BUILD);
+
+    assert_eq!(build.input_template, json!({ "leaf": "0" }));
+}
+
+#[test]
+fn ok_module_path() {
+    assert_eq!(
+        Build::module_path(Path::new("crypto.zn")),
+        Some("crypto".to_owned())
+    );
+    assert_eq!(
+        Build::module_path(Path::new("crypto/merkle.zn")),
+        Some("crypto::merkle".to_owned())
+    );
+    assert_eq!(
+        Build::module_path(Path::new("crypto/merkle/mod.zn")),
+        Some("crypto::merkle".to_owned())
+    );
+    assert_eq!(
+        Build::module_path(Path::new("mod.zn")),
+        Some("mod".to_owned())
+    );
+}
+
+#[test]
+fn error_module_path() {
+    assert_eq!(Build::module_path(Path::new("crypto.rs")), None);
+    assert_eq!(Build::module_path(Path::new("../crypto.zn")), None);
+    assert_eq!(Build::module_path(Path::new("/src/crypto.zn")), None);
+}
+
+#[test]
+fn error_module_nested_not_found() {
+    let crypto = r#"
+mod merkle;
+"#;
+
+    let main = r#"
+mod crypto;
+
+fn main() {}
+"#;
+
+    let diagnostics = Build::compile(
+        sources(&[("main", main), ("crypto", crypto)]),
+        Curve::Bn256,
+        false,
+    )
+    .expect_err(PANIC_FAILED_BUILD);
+
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].code, Some("Z0727"));
+}
+
+#[test]
+fn error_module_duplicate() {
+    let main = r#"
+mod crypto;
+
+fn main() {}
+"#;
+
+    let diagnostics = Build::compile_files(
+        File::new(PathBuf::from("main.zn"), main.to_owned()),
+        vec![
+            (
+                "crypto".to_owned(),
+                File::new(PathBuf::from("crypto.zn"), String::new()),
+            ),
+            (
+                "crypto".to_owned(),
+                File::new(PathBuf::from("crypto/mod.zn"), String::new()),
+            ),
+        ],
+        Curve::Bn256,
+        false,
+    )
+    .expect_err(PANIC_FAILED_BUILD);
+
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(
+        diagnostics[0].message,
+        "module `crypto` is defined more than once"
+    );
+}
+
+#[test]
+fn error_module_item_private() {
+    let crypto = r#"
+fn helper() -> u8 {
+    42
+}
+
+pub fn hash() -> u8 {
+    helper()
+}
+"#;
+
+    let main = r#"
+mod crypto;
+
+fn main() -> u8 {
+    crypto::hash() + crypto::helper()
+}
+"#;
+
+    let diagnostics = Build::compile(
+        sources(&[("main", main), ("crypto", crypto)]),
+        Curve::Bn256,
+        false,
+    )
+    .expect_err(PANIC_FAILED_BUILD);
+
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].code, Some("Z0627"));
+}
+
+#[test]
+fn ok_parallel_builds_own_files() {
+    let builds: Vec<_> = (0..4)
+        .map(|index| {
+            thread::spawn(move || {
+                let module = format!("module_{}", index);
+                let main = format!(
+                    "mod {0};\n\nfn main() -> u8 {{\n    let unused_{0} = 42;\n    {0}::VALUE\n}}\n",
+                    module
+                );
+                let build = Build::compile(
+                    sources(&[
+                        ("main", main.as_str()),
+                        (module.as_str(), "pub const VALUE: u8 = 42;\n"),
+                    ]),
+                    Curve::Bn256,
+                    false,
+                )
+                .expect(PANIC_SUCCESSFUL_BUILD);
+                let diagnostics = Build::compile(
+                    sources(&[
+                        ("main", main.as_str()),
+                        (module.as_str(), "pub const VALUE: u8 = UNKNOWN;\n"),
+                    ]),
+                    Curve::Bn256,
+                    false,
+                )
+                .expect_err(PANIC_FAILED_BUILD);
+
+                (module, build.warnings, diagnostics)
+            })
+        })
+        .collect();
+
+    for build in builds.into_iter() {
+        let (module, warnings, diagnostics) = build.join().expect(PANIC_THREAD);
+
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(
+            warnings[0].message,
+            format!("unused variable `unused_{}`", module)
+        );
+        assert_eq!(warnings[0].to_json()["file"], json!("main.zn"));
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].to_json()["file"],
+            json!(format!("{}.zn", module))
+        );
+        assert!(diagnostics[0]
+            .rendered
+            .as_ref()
+            .map(|rendered| rendered.contains(format!("{}.zn", module).as_str()))
+            .unwrap_or_default());
+    }
+}
+
+#[test]
+fn ok_curve_bls12_381() {
+    let main = r#"
+const BIG: field = 0x4000000000000000000000000000000000000000000000000000000000000000;
+
+fn main(value: field) -> ([bool; 255], field) {
+    let bits = std::convert::to_bits(value + BIG);
+    (bits, std::convert::from_bits_field(bits))
+}
+"#;
+
+    let build = Build::compile(sources(&[("main", main)]), Curve::Bls12_381, false)
+        .expect(PANIC_SUCCESSFUL_BUILD);
+
+    assert_eq!(build.program.curve, Curve::Bls12_381);
+    assert_eq!(
+        build.program.output,
+        DataType::Tuple(vec![
+            DataType::Array(Box::new(DataType::Scalar(ScalarType::Boolean)), 255),
+            DataType::Scalar(ScalarType::Field),
+        ])
+    );
+    assert!(build
+        .program
+        .bytecode
+        .contains(&Instruction::PushConst(PushConst::new_field(
+            BigInt::one() << 254
+        ))));
+}
+
+#[test]
+fn error_curve_field_literal_too_large() {
+    let main = r#"
+const BIG: field = 0x4000000000000000000000000000000000000000000000000000000000000000;
+
+fn main() {}
+"#;
+
+    let diagnostics = Build::compile(sources(&[("main", main)]), Curve::Bn256, false)
+        .expect_err(PANIC_FAILED_BUILD);
+
+    assert_eq!(diagnostics.len(), 1);
+    assert!(diagnostics[0].message.contains("larger than `254` bits"));
+}
+
+#[test]
+fn error_curve_field_bitlength() {
+    let main = r#"
+fn main(value: field) -> [bool; 255] {
+    std::convert::to_bits(value)
+}
+"#;
+
+    let diagnostics = Build::compile(sources(&[("main", main)]), Curve::Bn256, false)
+        .expect_err(PANIC_FAILED_BUILD);
+
+    assert_eq!(diagnostics.len(), 1);
+    assert!(diagnostics[0].message.contains("[bool; 254]"));
+}
@@ -0,0 +1,226 @@
+//!
+//! The Zinc compiler diagnostic code explanations.
+//!
+
+mod tests;
+
+///
+/// The diagnostic codes with their explanations, printed by `znc --explain <code>`.
+///
+/// Each explanation starts with a one-line summary, followed by a minimal erroneous code
+/// example and its fixed version.
+///
+pub static EXPLANATIONS: [(&str, &str); 202] = [
+    ("Z0101", include_str!("Z0101.md")),
+    ("Z0102", include_str!("Z0102.md")),
+    ("Z0103", include_str!("Z0103.md")),
+    ("Z0104", include_str!("Z0104.md")),
+    ("Z0105", include_str!("Z0105.md")),
+    ("Z0106", include_str!("Z0106.md")),
+    ("Z0107", include_str!("Z0107.md")),
+    ("Z0108", include_str!("Z0108.md")),
+    ("Z0201", include_str!("Z0201.md")),
+    ("Z0202", include_str!("Z0202.md")),
+    ("Z0203", include_str!("Z0203.md")),
+    ("Z0204", include_str!("Z0204.md")),
+    ("Z0205", include_str!("Z0205.md")),
+    ("Z0206", include_str!("Z0206.md")),
+    ("Z0207", include_str!("Z0207.md")),
+    ("Z0208", include_str!("Z0208.md")),
+    ("Z0209", include_str!("Z0209.md")),
+    ("Z0210", include_str!("Z0210.md")),
+    ("Z0211", include_str!("Z0211.md")),
+    ("Z0212", include_str!("Z0212.md")),
+    ("Z0301", include_str!("Z0301.md")),
+    ("Z0302", include_str!("Z0302.md")),
+    ("Z0303", include_str!("Z0303.md")),
+    ("Z0304", include_str!("Z0304.md")),
+    ("Z0305", include_str!("Z0305.md")),
+    ("Z0306", include_str!("Z0306.md")),
+    ("Z0307", include_str!("Z0307.md")),
+    ("Z0308", include_str!("Z0308.md")),
+    ("Z0309", include_str!("Z0309.md")),
+    ("Z0310", include_str!("Z0310.md")),
+    ("Z0311", include_str!("Z0311.md")),
+    ("Z0312", include_str!("Z0312.md")),
+    ("Z0313", include_str!("Z0313.md")),
+    ("Z0314", include_str!("Z0314.md")),
+    ("Z0315", include_str!("Z0315.md")),
+    ("Z0316", include_str!("Z0316.md")),
+    ("Z0317", include_str!("Z0317.md")),
+    ("Z0318", include_str!("Z0318.md")),
+    ("Z0319", include_str!("Z0319.md")),
+    ("Z0320", include_str!("Z0320.md")),
+    ("Z0321", include_str!("Z0321.md")),
+    ("Z0322", include_str!("Z0322.md")),
+    ("Z0323", include_str!("Z0323.md")),
+    ("Z0324", include_str!("Z0324.md")),
+    ("Z0325", include_str!("Z0325.md")),
+    ("Z0326", include_str!("Z0326.md")),
+    ("Z0327", include_str!("Z0327.md")),
+    ("Z0328", include_str!("Z0328.md")),
+    ("Z0329", include_str!("Z0329.md")),
+    ("Z0330", include_str!("Z0330.md")),
+    ("Z0331", include_str!("Z0331.md")),
+    ("Z0332", include_str!("Z0332.md")),
+    ("Z0333", include_str!("Z0333.md")),
+    ("Z0334", include_str!("Z0334.md")),
+    ("Z0335", include_str!("Z0335.md")),
+    ("Z0336", include_str!("Z0336.md")),
+    ("Z0337", include_str!("Z0337.md")),
+    ("Z0338", include_str!("Z0338.md")),
+    ("Z0339", include_str!("Z0339.md")),
+    ("Z0340", include_str!("Z0340.md")),
+    ("Z0341", include_str!("Z0341.md")),
+    ("Z0342", include_str!("Z0342.md")),
+    ("Z0343", include_str!("Z0343.md")),
+    ("Z0344", include_str!("Z0344.md")),
+    ("Z0345", include_str!("Z0345.md")),
+    ("Z0346", include_str!("Z0346.md")),
+    ("Z0347", include_str!("Z0347.md")),
+    ("Z0348", include_str!("Z0348.md")),
+    ("Z0349", include_str!("Z0349.md")),
+    ("Z0350", include_str!("Z0350.md")),
+    ("Z0351", include_str!("Z0351.md")),
+    ("Z0352", include_str!("Z0352.md")),
+    ("Z0353", include_str!("Z0353.md")),
+    ("Z0354", include_str!("Z0354.md")),
+    ("Z0355", include_str!("Z0355.md")),
+    ("Z0356", include_str!("Z0356.md")),
+    ("Z0357", include_str!("Z0357.md")),
+    ("Z0358", include_str!("Z0358.md")),
+    ("Z0359", include_str!("Z0359.md")),
+    ("Z0360", include_str!("Z0360.md")),
+    ("Z0361", include_str!("Z0361.md")),
+    ("Z0362", include_str!("Z0362.md")),
+    ("Z0363", include_str!("Z0363.md")),
+    ("Z0364", include_str!("Z0364.md")),
+    ("Z0365", include_str!("Z0365.md")),
+    ("Z0366", include_str!("Z0366.md")),
+    ("Z0367", include_str!("Z0367.md")),
+    ("Z0368", include_str!("Z0368.md")),
+    ("Z0369", include_str!("Z0369.md")),
+    ("Z0370", include_str!("Z0370.md")),
+    ("Z0371", include_str!("Z0371.md")),
+    ("Z0372", include_str!("Z0372.md")),
+    ("Z0373", include_str!("Z0373.md")),
+    ("Z0374", include_str!("Z0374.md")),
+    ("Z0375", include_str!("Z0375.md")),
+    ("Z0376", include_str!("Z0376.md")),
+    ("Z0401", include_str!("Z0401.md")),
+    ("Z0402", include_str!("Z0402.md")),
+    ("Z0403", include_str!("Z0403.md")),
+    ("Z0404", include_str!("Z0404.md")),
+    ("Z0405", include_str!("Z0405.md")),
+    ("Z0406", include_str!("Z0406.md")),
+    ("Z0407", include_str!("Z0407.md")),
+    ("Z0408", include_str!("Z0408.md")),
+    ("Z0409", include_str!("Z0409.md")),
+    ("Z0410", include_str!("Z0410.md")),
+    ("Z0411", include_str!("Z0411.md")),
+    ("Z0412", include_str!("Z0412.md")),
+    ("Z0413", include_str!("Z0413.md")),
+    ("Z0501", include_str!("Z0501.md")),
+    ("Z0502", include_str!("Z0502.md")),
+    ("Z0503", include_str!("Z0503.md")),
+    ("Z0504", include_str!("Z0504.md")),
+    ("Z0505", include_str!("Z0505.md")),
+    ("Z0506", include_str!("Z0506.md")),
+    ("Z0507", include_str!("Z0507.md")),
+    ("Z0508", include_str!("Z0508.md")),
+    ("Z0509", include_str!("Z0509.md")),
+    ("Z0510", include_str!("Z0510.md")),
+    ("Z0511", include_str!("Z0511.md")),
+    ("Z0512", include_str!("Z0512.md")),
+    ("Z0513", include_str!("Z0513.md")),
+    ("Z0514", include_str!("Z0514.md")),
+    ("Z0515", include_str!("Z0515.md")),
+    ("Z0516", include_str!("Z0516.md")),
+    ("Z0517", include_str!("Z0517.md")),
+    ("Z0518", include_str!("Z0518.md")),
+    ("Z0519", include_str!("Z0519.md")),
+    ("Z0520", include_str!("Z0520.md")),
+    ("Z0521", include_str!("Z0521.md")),
+    ("Z0522", include_str!("Z0522.md")),
+    ("Z0523", include_str!("Z0523.md")),
+    ("Z0524", include_str!("Z0524.md")),
+    ("Z0525", include_str!("Z0525.md")),
+    ("Z0526", include_str!("Z0526.md")),
+    ("Z0527", include_str!("Z0527.md")),
+    ("Z0528", include_str!("Z0528.md")),
+    ("Z0529", include_str!("Z0529.md")),
+    ("Z0601", include_str!("Z0601.md")),
+    ("Z0602", include_str!("Z0602.md")),
+    ("Z0603", include_str!("Z0603.md")),
+    ("Z0604", include_str!("Z0604.md")),
+    ("Z0605", include_str!("Z0605.md")),
+    ("Z0606", include_str!("Z0606.md")),
+    ("Z0607", include_str!("Z0607.md")),
+    ("Z0608", include_str!("Z0608.md")),
+    ("Z0609", include_str!("Z0609.md")),
+    ("Z0610", include_str!("Z0610.md")),
+    ("Z0611", include_str!("Z0611.md")),
+    ("Z0612", include_str!("Z0612.md")),
+    ("Z0613", include_str!("Z0613.md")),
+    ("Z0614", include_str!("Z0614.md")),
+    ("Z0615", include_str!("Z0615.md")),
+    ("Z0616", include_str!("Z0616.md")),
+    ("Z0617", include_str!("Z0617.md")),
+    ("Z0618", include_str!("Z0618.md")),
+    ("Z0619", include_str!("Z0619.md")),
+    ("Z0620", include_str!("Z0620.md")),
+    ("Z0621", include_str!("Z0621.md")),
+    ("Z0622", include_str!("Z0622.md")),
+    ("Z0623", include_str!("Z0623.md")),
+    ("Z0624", include_str!("Z0624.md")),
+    ("Z0625", include_str!("Z0625.md")),
+    ("Z0626", include_str!("Z0626.md")),
+    ("Z0627", include_str!("Z0627.md")),
+    ("Z0628", include_str!("Z0628.md")),
+    ("Z0701", include_str!("Z0701.md")),
+    ("Z0702", include_str!("Z0702.md")),
+    ("Z0703", include_str!("Z0703.md")),
+    ("Z0704", include_str!("Z0704.md")),
+    ("Z0705", include_str!("Z0705.md")),
+    ("Z0706", include_str!("Z0706.md")),
+    ("Z0707", include_str!("Z0707.md")),
+    ("Z0708", include_str!("Z0708.md")),
+    ("Z0709", include_str!("Z0709.md")),
+    ("Z0710", include_str!("Z0710.md")),
+    ("Z0711", include_str!("Z0711.md")),
+    ("Z0712", include_str!("Z0712.md")),
+    ("Z0713", include_str!("Z0713.md")),
+    ("Z0714", include_str!("Z0714.md")),
+    ("Z0715", include_str!("Z0715.md")),
+    ("Z0716", include_str!("Z0716.md")),
+    ("Z0717", include_str!("Z0717.md")),
+    ("Z0718", include_str!("Z0718.md")),
+    ("Z0719", include_str!("Z0719.md")),
+    ("Z0720", include_str!("Z0720.md")),
+    ("Z0721", include_str!("Z0721.md")),
+    ("Z0722", include_str!("Z0722.md")),
+    ("Z0723", include_str!("Z0723.md")),
+    ("Z0724", include_str!("Z0724.md")),
+    ("Z0725", include_str!("Z0725.md")),
+    ("Z0726", include_str!("Z0726.md")),
+    ("Z0727", include_str!("Z0727.md")),
+    ("Z0728", include_str!("Z0728.md")),
+    ("Z0729", include_str!("Z0729.md")),
+    ("Z0730", include_str!("Z0730.md")),
+    ("Z0731", include_str!("Z0731.md")),
+    ("Z0732", include_str!("Z0732.md")),
+    ("Z0733", include_str!("Z0733.md")),
+    ("Z0734", include_str!("Z0734.md")),
+    ("Z0735", include_str!("Z0735.md")),
+    ("Z0736", include_str!("Z0736.md")),
+];
+
+///
+/// Returns the explanation of the diagnostic `code`, e.g. `Z0301`.
+///
+pub fn get(code: &str) -> Option<&'static str> {
+    EXPLANATIONS
+        .iter()
+        .find(|(candidate, _explanation)| candidate.eq_ignore_ascii_case(code))
+        .map(|(_code, explanation)| *explanation)
+}
@@ -0,0 +1,180 @@
+//!
+//! The Zinc compiler diagnostic code explanation tests.
+//!
+
+#![cfg(test)]
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::thread;
+
+use crate::diagnostic::explanation;
+use crate::error::Error;
+use crate::semantic::analyzer::entry::Analyzer as EntryAnalyzer;
+use crate::semantic::analyzer::module::Analyzer as ModuleAnalyzer;
+use crate::semantic::scope::state::State as SemanticState;
+use crate::Parser;
+
+static STACK_SIZE: usize = 64 * 1024 * 1024;
+
+static FENCE_COMPILE_FAIL: &str = "```zinc,compile_fail";
+static FENCE_MODULE: &str = "```zinc,module=";
+static FENCE_ZINC: &str = "```zinc";
+static FENCE_END: &str = "```";
+
+///
+/// An explanation code block, which is either expected to fail with the specified code,
+/// or to compile without errors and warnings.
+///
+/// The `modules` are the module blocks preceding the example, which are passed to it
+/// as dependencies.
+///
+struct Example {
+    expected: Option<String>,
+    code: String,
+    modules: HashMap<String, String>,
+}
+
+fn examples(code: &str, explanation: &str) -> Vec<Example> {
+    let mut examples = Vec::new();
+    let mut modules = HashMap::new();
+    let mut lines = explanation.lines();
+    while let Some(line) = lines.next() {
+        if line.starts_with(FENCE_MODULE) {
+            let block: Vec<&str> = lines
+                .by_ref()
+                .take_while(|line| *line != FENCE_END)
+                .collect();
+            modules.insert(line[FENCE_MODULE.len()..].to_owned(), block.join("\n"));
+            continue;
+        }
+
+        let expected = if line.starts_with(FENCE_COMPILE_FAIL) {
+            match line[FENCE_COMPILE_FAIL.len()..].strip_prefix(",") {
+                Some(overridden) => Some(overridden.to_owned()),
+                None => Some(code.to_owned()),
+            }
+        } else if line == FENCE_ZINC {
+            None
+        } else {
+            continue;
+        };
+
+        let block: Vec<&str> = lines
+            .by_ref()
+            .take_while(|line| *line != FENCE_END)
+            .collect();
+        examples.push(Example {
+            expected,
+            code: block.join("\n").trim_end().to_owned(),
+            modules: modules.clone(),
+        });
+    }
+    examples
+}
+
+fn compile(input: String, modules: HashMap<String, String>) -> Result<usize, Vec<Error>> {
+    thread::Builder::new()
+        .stack_size(STACK_SIZE)
+        .spawn(move || {
+            let state = Rc::new(SemanticState::default());
+
+            let mut dependencies = HashMap::with_capacity(modules.len());
+            for (name, module) in modules.into_iter() {
+                let tree = Parser::default().parse(module.as_str(), None)?;
+                let (scope, _intermediate, _warnings) =
+                    ModuleAnalyzer::new_in_build(state.clone(), Rc::new(RefCell::new(Vec::new())))
+                        .compile(tree, HashMap::new())?;
+                dependencies.insert(name, scope);
+            }
+
+            let tree = Parser::default().parse(input.as_str(), None)?;
+            let (_intermediate, warnings) =
+                EntryAnalyzer::new_in_build(state, Rc::new(RefCell::new(Vec::new())))
+                    .compile(tree, dependencies)?;
+            Ok(warnings.len())
+        })
+        .expect(crate::semantic::tests::PANIC_TEST_DATA)
+        .join()
+        .expect(crate::semantic::tests::PANIC_TEST_DATA)
+}
+
+#[test]
+fn ok_examples() {
+    for (code, explanation) in explanation::EXPLANATIONS.iter() {
+        let examples = examples(code, explanation);
+        assert!(
+            examples.iter().any(|example| example.expected.is_some()),
+            "{} has no erroneous code example",
+            code
+        );
+        assert!(
+            examples.iter().any(|example| example.expected.is_none()),
+            "{} has no fixed code example",
+            code
+        );
+
+        for example in examples.into_iter() {
+            match (example.expected, compile(example.code, example.modules)) {
+                (Some(expected), Err(errors)) => {
+                    let codes: Vec<&str> = errors
+                        .into_iter()
+                        .filter_map(|error| error.diagnostic().code)
+                        .collect();
+                    assert!(
+                        codes.contains(&expected.as_str()),
+                        "{} erroneous example failed with {:?} instead of {}",
+                        code,
+                        codes,
+                        expected
+                    );
+                }
+                (Some(expected), Ok(_warnings)) => {
+                    panic!("{} erroneous example did not fail with {}", code, expected)
+                }
+                (None, Ok(warnings)) => {
+                    assert_eq!(warnings, 0, "{} fixed example has warnings", code)
+                }
+                (None, Err(errors)) => panic!(
+                    "{} fixed example failed with {:?}",
+                    code,
+                    errors
+                        .into_iter()
+                        .map(|error| error.diagnostic().message)
+                        .collect::<Vec<String>>()
+                ),
+            }
+        }
+    }
+}
+
+#[test]
+fn ok_every_code_explained() {
+    let source = include_str!("../../error.rs");
+    let mut codes = source.split(".with_code(\"").skip(1).peekable();
+    assert!(codes.peek().is_some());
+
+    for code in codes {
+        let end = code
+            .find('"')
+            .expect(crate::semantic::tests::PANIC_TEST_DATA);
+        let code = &code[..end];
+        assert!(
+            explanation::get(code).is_some(),
+            "{} has no explanation",
+            code
+        );
+    }
+}
+
+#[test]
+fn ok_get_case_insensitive() {
+    assert_eq!(explanation::get("z0301"), explanation::get("Z0301"));
+    assert!(explanation::get("Z0301").is_some());
+}
+
+#[test]
+fn error_get_unknown() {
+    assert!(explanation::get("Z9999").is_none());
+}
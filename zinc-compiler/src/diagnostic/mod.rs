@@ -0,0 +1,320 @@
+//!
+//! The Zinc compiler diagnostic.
+//!
+
+mod explanation;
+mod tests;
+
+use colored::ColoredString;
+use colored::Colorize;
+use serde_json::json;
+use serde_json::Value as JsonValue;
+
+use crate::lexical::token::location::Location;
+
+///
+/// The diagnostic severity.
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+///
+/// A compiler error or warning, which can be either formatted in the Rust-like way for a human,
+/// or serialized into JSON for the editors and CI tools.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub code: Option<&'static str>,
+    pub message: String,
+    pub file: Option<String>,
+    pub location: Option<Location>,
+    pub end: Option<Location>,
+    pub reference: Option<Location>,
+    pub help: Option<String>,
+    pub rendered: Option<String>,
+}
+
+impl Diagnostic {
+    ///
+    /// Creates a diagnostic, which is not bound to any location.
+    ///
+    pub fn message(message: &str, help: Option<&str>) -> Self {
+        Self {
+            severity: Severity::Error,
+            code: None,
+            message: message.to_owned(),
+            file: None,
+            location: None,
+            end: None,
+            reference: None,
+            help: help.map(ToOwned::to_owned),
+            rendered: None,
+        }
+    }
+
+    ///
+    /// Creates a diagnostic pointing to a single location.
+    ///
+    pub fn line(message: &str, location: Location, help: Option<&str>) -> Self {
+        Self::line_with_reference(message, location, None, help)
+    }
+
+    ///
+    /// Creates a diagnostic pointing to a single location, with an optional reference, which
+    /// is usually the location of a related declaration.
+    ///
+    pub fn line_with_reference(
+        message: &str,
+        location: Location,
+        reference: Option<Location>,
+        help: Option<&str>,
+    ) -> Self {
+        Self {
+            location: Some(location),
+            reference,
+            ..Self::message(message, help)
+        }
+    }
+
+    ///
+    /// Creates a diagnostic spanning several lines from `start` to `end`.
+    ///
+    pub fn range(message: &str, start: Location, end: Location, help: Option<&str>) -> Self {
+        Self {
+            location: Some(start),
+            end: Some(end),
+            ..Self::message(message, help)
+        }
+    }
+
+    ///
+    /// Sets the path of the file, which the diagnostic location refers to.
+    ///
+    pub fn with_file(mut self, file: Option<String>) -> Self {
+        self.file = file;
+        self
+    }
+
+    ///
+    /// Sets the severity of the diagnostic.
+    ///
+    pub fn with_severity(mut self, severity: Severity) -> Self {
+        self.severity = severity;
+        self
+    }
+
+    ///
+    /// Returns the longer description of the diagnostic `code`, e.g. `Z0301`, with a minimal
+    /// erroneous code example and its fixed version.
+    ///
+    pub fn explain(code: &str) -> Option<&'static str> {
+        explanation::get(code)
+    }
+
+    ///
+    /// Sets the stable diagnostic code, which is explained by `znc --explain <code>`.
+    ///
+    pub fn with_code(mut self, code: &'static str) -> Self {
+        self.code = Some(code);
+        self
+    }
+
+    ///
+    /// Formats the diagnostic and stores the text in the `rendered` field, which is
+    /// required, since the source code `context` is not available to the diagnostic consumers.
+    ///
+    pub fn render(mut self, context: &[&str]) -> Self {
+        self.rendered = Some(self.format(context));
+        self
+    }
+
+    ///
+    /// Formats the diagnostic in the Rust-like way, with the source code `context` lines.
+    ///
+    pub fn format(&self, context: &[&str]) -> String {
+        match (self.location, self.end) {
+            (None, _) => self.format_message(),
+            (Some(start), Some(end)) => self.format_range(context, start, end),
+            (Some(location), None) => self.format_line(context, location),
+        }
+    }
+
+    ///
+    /// Serializes the diagnostic into a JSON record.
+    ///
+    /// The range `end` is omitted if the diagnostic points to a single location, since the
+    /// length of the token there is unknown.
+    ///
+    pub fn to_json(&self) -> JsonValue {
+        let range = self.location.map(|start| match self.end {
+            Some(end) => json!({
+                "start": Self::location_to_json(start),
+                "end": Self::location_to_json(end),
+            }),
+            None => json!({
+                "start": Self::location_to_json(start),
+            }),
+        });
+
+        json!({
+            "severity": match self.severity {
+                Severity::Error => "error",
+                Severity::Warning => "warning",
+            },
+            "code": self.code,
+            "message": self.message,
+            "file": self.file,
+            "range": range,
+            "reference": self.reference.map(Self::location_to_json),
+            "hints": self.help.iter().collect::<Vec<&String>>(),
+            "rendered": self.rendered,
+        })
+    }
+
+    fn location_to_json(location: Location) -> JsonValue {
+        json!({
+            "line": location.line,
+            "column": location.column,
+        })
+    }
+
+    fn label(&self) -> ColoredString {
+        let label = match self.severity {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        };
+        let label = match self.code {
+            Some(code) => format!("{}[{}]", label, code),
+            None => label.to_owned(),
+        };
+
+        match self.severity {
+            Severity::Error => label.bright_red(),
+            Severity::Warning => label.bright_yellow(),
+        }
+    }
+
+    fn format_message(&self) -> String {
+        let mut strings = Vec::with_capacity(8);
+        strings.push(String::new());
+        strings.push(format!("{}: {}", self.label(), self.message.bright_white()));
+        if let Some(ref help) = self.help {
+            strings.push(format!("{}: {}", "help".bright_white(), help.bright_blue()));
+        }
+        strings.push(String::new());
+        strings.join("\n")
+    }
+
+    fn format_location(&self, location: Location) -> String {
+        match self.file {
+            Some(ref file) => format!("{}:{}", file, location),
+            None => location.to_string(),
+        }
+    }
+
+    fn format_line(&self, context: &[&str], location: Location) -> String {
+        let line_number_length = location.line.to_string().len();
+
+        let mut strings = Vec::with_capacity(11);
+        strings.push(String::new());
+        strings.push(format!("{}: {}", self.label(), self.message.bright_white()));
+
+        if let Some(reference) = self.reference {
+            let line_number_length = reference.line.to_string().len();
+            strings.push(format!(
+                "{}{}",
+                " ".repeat(line_number_length + 1),
+                "|".bright_cyan()
+            ));
+            if let Some(line) = context.get(reference.line - 1) {
+                strings.push(format!(
+                    "{}{}",
+                    (reference.line.to_string() + " | ").bright_cyan(),
+                    line
+                ));
+            }
+            strings.push(format!(
+                "{}{} {}{}",
+                " ".repeat(line_number_length + 1),
+                "|".bright_cyan(),
+                "_".repeat(reference.column - 1).bright_red(),
+                "^".bright_red()
+            ));
+        }
+
+        strings.push(format!(
+            " {} {}",
+            "-->".bright_cyan(),
+            self.format_location(location)
+        ));
+
+        strings.push(format!(
+            "{}{}",
+            " ".repeat(line_number_length + 1),
+            "|".bright_cyan()
+        ));
+        if let Some(line) = context.get(location.line - 1) {
+            strings.push(format!(
+                "{}{}",
+                (location.line.to_string() + " | ").bright_cyan(),
+                line
+            ));
+        }
+        strings.push(format!(
+            "{}{} {}{}",
+            " ".repeat(line_number_length + 1),
+            "|".bright_cyan(),
+            "_".repeat(location.column - 1).bright_red(),
+            "^".bright_red()
+        ));
+
+        if let Some(ref help) = self.help {
+            strings.push(format!("{}: {}", "help".bright_white(), help.bright_blue()));
+        }
+        strings.push(String::new());
+        strings.join("\n")
+    }
+
+    fn format_range(&self, context: &[&str], start: Location, end: Location) -> String {
+        let line_number_length = end.line.to_string().len();
+
+        let mut strings = Vec::with_capacity(8 + end.line - start.line);
+        strings.push(String::new());
+        strings.push(format!("{}: {}", self.label(), self.message.bright_white()));
+        strings.push(format!(
+            " {} {}",
+            "-->".bright_cyan(),
+            self.format_location(start)
+        ));
+        strings.push(format!(
+            "{}{}",
+            " ".repeat(line_number_length + 1),
+            "|".bright_cyan()
+        ));
+        for line_number in start.line..=end.line {
+            if let Some(line) = context.get(line_number - 1) {
+                strings.push(format!(
+                    "{}{}",
+                    (line_number.to_string() + " | ").bright_cyan(),
+                    line
+                ));
+            }
+        }
+        strings.push(format!(
+            "{}{} {}{}",
+            " ".repeat(line_number_length + 1),
+            "|".bright_cyan(),
+            "_".repeat(end.column - 1).bright_red(),
+            "^".bright_red()
+        ));
+        if let Some(ref help) = self.help {
+            strings.push(format!("{}: {}", "help".bright_white(), help.bright_blue()));
+        }
+        strings.push(String::new());
+        strings.join("\n")
+    }
+}
@@ -0,0 +1,97 @@
+//!
+//! The Zinc compiler diagnostic tests.
+//!
+
+#![cfg(test)]
+
+use serde_json::json;
+
+use crate::diagnostic::Diagnostic;
+use crate::diagnostic::Severity;
+use crate::lexical::token::location::Location;
+use crate::warning::Warning;
+
+#[test]
+fn json_line() {
+    let diagnostic = Diagnostic::line(
+        "unexpected `;`",
+        Location::new(3, 13),
+        Some("remove the semicolon"),
+    );
+
+    let expected = json!({
+        "severity": "error",
+        "code": null,
+        "message": "unexpected `;`",
+        "file": null,
+        "range": {
+            "start": { "line": 3, "column": 13 },
+        },
+        "reference": null,
+        "hints": ["remove the semicolon"],
+        "rendered": null,
+    });
+
+    assert_eq!(diagnostic.to_json(), expected);
+}
+
+#[test]
+fn json_range() {
+    let diagnostic = Diagnostic::range(
+        "mismatched types",
+        Location::new(2, 5),
+        Location::new(4, 1),
+        None,
+    );
+
+    let result = diagnostic.to_json();
+
+    assert_eq!(
+        result["range"],
+        json!({
+            "start": { "line": 2, "column": 5 },
+            "end": { "line": 4, "column": 1 },
+        })
+    );
+    assert_eq!(result["hints"], json!([]));
+}
+
+#[test]
+fn json_message() {
+    let diagnostic = Diagnostic::message("the entry point is missing", None);
+
+    let result = diagnostic.to_json();
+
+    assert_eq!(result["range"], json!(null));
+    assert_eq!(result["file"], json!(null));
+}
+
+#[test]
+fn json_warning_rendered() {
+    let diagnostic = Warning::UnusedVariable {
+        location: Location::new(1, 5),
+        name: "value".to_owned(),
+    }
+    .diagnostic()
+    .render(&["let value = 42;"]);
+
+    let result = diagnostic.to_json();
+
+    assert_eq!(result["severity"], json!("warning"));
+    assert_eq!(result["message"], json!("unused variable `value`"));
+    assert!(result["rendered"]
+        .as_str()
+        .expect("The rendered text is set")
+        .contains("let value = 42;"));
+}
+
+#[test]
+fn severity_denied() {
+    let diagnostic = Warning::MatchBranchUnreachable {
+        location: Location::new(1, 1),
+    }
+    .diagnostic()
+    .with_severity(Severity::Error);
+
+    assert_eq!(diagnostic.to_json()["severity"], json!("error"));
+}
@@ -2,14 +2,16 @@
 //! The Zinc compiler error.
 //!
 
-use colored::Colorize;
-
+use crate::diagnostic::Diagnostic;
 use crate::file::error::Error as FileError;
 use crate::lexical::error::Error as LexicalError;
 use crate::lexical::token::location::Location;
 use crate::semantic::casting::error::Error as CastingError;
+use crate::semantic::element::constant::array::error::Error as ArrayConstantError;
 use crate::semantic::element::constant::error::Error as ConstantError;
 use crate::semantic::element::constant::integer::error::Error as IntegerConstantError;
+use crate::semantic::element::constant::structure::error::Error as StructureConstantError;
+use crate::semantic::element::constant::tuple::error::Error as TupleConstantError;
 use crate::semantic::element::error::Error as ElementError;
 use crate::semantic::element::place::error::Error as PlaceError;
 use crate::semantic::element::r#type::error::Error as TypeError;
@@ -35,28 +37,64 @@ pub enum Error {
 }
 
 impl Error {
+    ///
+    /// The error location. The file errors and some semantic ones are not bound to any.
+    ///
+    pub fn location(&self) -> Option<Location> {
+        match self {
+            Self::File(_) => None,
+            Self::Lexical(inner) => Some(inner.location()),
+            Self::Syntax(inner) => Some(inner.location()),
+            Self::Semantic(inner) => inner.location(),
+        }
+    }
+
+    ///
+    /// Formats the errors sorted by location. The errors without location go last.
+    ///
+    pub fn format_all(mut errors: Vec<Self>, context: &[&str]) -> String {
+        errors.sort_by_key(|error| {
+            let location = error.location();
+            (location.is_none(), location)
+        });
+
+        errors
+            .into_iter()
+            .map(|error| error.format(context))
+            .collect::<Vec<String>>()
+            .join("")
+    }
+
     pub fn format(self, context: &[&str]) -> String {
+        self.diagnostic().format(context)
+    }
+
+    ///
+    /// Converts the error into a diagnostic, which is either formatted for a human or
+    /// serialized for a tool.
+    ///
+    pub fn diagnostic(self) -> Diagnostic {
         match self {
-            Self::File(inner) => inner.to_string(),
+            Self::File(inner) => Diagnostic::message(inner.to_string().as_str(), None),
 
             Self::Lexical(LexicalError::UnterminatedBlockComment { start, end }) => {
-                Self::format_range(context, "unterminated block comment", start, end, None)
+                Diagnostic::range("unterminated block comment", start, end, None)
+                    .with_code("Z0101")
             }
             Self::Lexical(LexicalError::UnterminatedDoubleQuoteString { start, end }) => {
-                Self::format_range(
-                    context,
+                Diagnostic::range(
                     "unterminated double quote string",
                     start,
                     end,
                     None,
                 )
+                .with_code("Z0102")
             }
             Self::Lexical(LexicalError::ExpectedOneOfBinary {
                               location,
                               expected,
                               found,
-                          }) => Self::format_line(
-                context,
+                          }) => Diagnostic::line(
                 format!(
                     "expected one of binary symbols {} or '_', found `{}`",
                     expected, found
@@ -64,13 +102,13 @@ impl Error {
                     .as_str(),
                 location,
                 None,
-            ),
+            )
+            .with_code("Z0103"),
             Self::Lexical(LexicalError::ExpectedOneOfOctal {
                               location,
                               expected,
                               found,
-                          }) => Self::format_line(
-                context,
+                          }) => Diagnostic::line(
                 format!(
                     "expected one of octal symbols {} or '_', found `{}`",
                     expected, found
@@ -78,13 +116,13 @@ impl Error {
                     .as_str(),
                 location,
                 None,
-            ),
+            )
+            .with_code("Z0104"),
             Self::Lexical(LexicalError::ExpectedOneOfDecimal {
                 location,
                 expected,
                 found,
-            }) => Self::format_line(
-                context,
+            }) => Diagnostic::line(
                 format!(
                     "expected one of decimal symbols {} or '_', found `{}`",
                     expected, found
@@ -92,13 +130,13 @@ impl Error {
                 .as_str(),
                 location,
                 None,
-            ),
+            )
+            .with_code("Z0105"),
             Self::Lexical(LexicalError::ExpectedOneOfHexadecimal {
                 location,
                 expected,
                 found,
-            }) => Self::format_line(
-                context,
+            }) => Diagnostic::line(
                 format!(
                     "expected one of hexadecimal symbols {} or '_', found `{}`",
                     expected, found
@@ -106,15 +144,17 @@ impl Error {
                 .as_str(),
                 location,
                 None,
-            ),
-            Self::Lexical(LexicalError::InvalidCharacter { location, found }) => Self::format_line(
-                context,
+            )
+            .with_code("Z0106"),
+            Self::Lexical(LexicalError::InvalidCharacter { location, found }) => Diagnostic::line(
                 format!("invalid character `{}`", found).as_str(),
                 location,
                 None,
-            ),
+            )
+            .with_code("Z0107"),
             Self::Lexical(LexicalError::UnexpectedEnd { location }) => {
-                Self::format_line(context, "unexpected end of input", location, None)
+                Diagnostic::line("unexpected end of input", location, None)
+                    .with_code("Z0108")
             }
 
             Self::Syntax(SyntaxError::ExpectedOneOf {
@@ -122,19 +162,18 @@ impl Error {
                 expected,
                 found,
                 help,
-            }) => Self::format_line(
-                context,
+            }) => Diagnostic::line(
                 format!("expected one of {}, found `{}`", expected, found).as_str(),
                 location,
                 help,
-            ),
+            )
+            .with_code("Z0201"),
             Self::Syntax(SyntaxError::ExpectedOneOfOrOperator {
                 location,
                 expected,
                 found,
                 help,
-            }) => Self::format_line(
-                context,
+            }) => Diagnostic::line(
                 format!(
                     "expected one of {} or an operator, found `{}`",
                     expected, found
@@ -142,53 +181,53 @@ impl Error {
                 .as_str(),
                 location,
                 help,
-            ),
+            )
+            .with_code("Z0202"),
             Self::Syntax(SyntaxError::ExpectedIdentifier {
                 location,
                 found,
                 help,
-            }) => Self::format_line(
-                context,
+            }) => Diagnostic::line(
                 format!("expected identifier, found `{}`", found).as_str(),
                 location,
                 help,
-            ),
+            )
+            .with_code("Z0203"),
             Self::Syntax(SyntaxError::ExpectedMutOrIdentifier {
                 location,
                 found,
                 help,
-            }) => Self::format_line(
-                context,
+            }) => Diagnostic::line(
                 format!("expected `mut` or identifier, found `{}`", found).as_str(),
                 location,
                 help,
-            ),
+            )
+            .with_code("Z0204"),
             Self::Syntax(SyntaxError::ExpectedFieldIdentifier {
                 location,
                 found,
                 help,
-            }) => Self::format_line(
-                context,
+            }) => Diagnostic::line(
                 format!("expected field identifier, found `{}`", found).as_str(),
                 location,
                 help,
-            ),
+            )
+            .with_code("Z0205"),
             Self::Syntax(SyntaxError::ExpectedType {
                 location,
                 found,
                 help,
-            }) => Self::format_line(
-                context,
+            }) => Diagnostic::line(
                 format!("expected type, found `{}`", found).as_str(),
                 location,
                 help,
-            ),
+            )
+            .with_code("Z0206"),
             Self::Syntax(SyntaxError::ExpectedTypeOrValue {
                 location,
                 found,
                 help,
-            }) => Self::format_line(
-                context,
+            }) => Diagnostic::line(
                 format!(
                     "expected `:` with type or `=` with value, found `{}`",
                     found
@@ -196,44 +235,44 @@ impl Error {
                 .as_str(),
                 location,
                 help,
-            ),
+            )
+            .with_code("Z0207"),
             Self::Syntax(SyntaxError::ExpectedValue {
                 location,
                 found,
                 help,
-            }) => Self::format_line(
-                context,
+            }) => Diagnostic::line(
                 format!("expected `=` with value, found `{}`", found).as_str(),
                 location,
                 help,
-            ),
+            )
+            .with_code("Z0208"),
             Self::Syntax(SyntaxError::ExpectedExpressionOrOperand { location, found }) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!("expected expression or operand, found `{}`", found).as_str(),
                     location,
                     None,
                 )
+                .with_code("Z0209")
             }
             Self::Syntax(SyntaxError::ExpectedIntegerLiteral { location, found }) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!("expected integer literal, found `{}`", found).as_str(),
                     location,
                     None,
                 )
+                .with_code("Z0210")
             }
             Self::Syntax(SyntaxError::ExpectedBindingPattern { location, found }) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!("expected identifier or `_`, found `{}`", found).as_str(),
                     location,
                     None,
                 )
+                .with_code("Z0211")
             }
             Self::Syntax(SyntaxError::ExpectedMatchPattern { location, found }) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "expected identifier, boolean or integer literal, path, or `_`, found `{}`",
                         found
@@ -242,11 +281,11 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0212")
             }
 
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentFirstOperandExpectedPlace{ found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the assignment operator `=` expected a memory place as the first operand, found `{}`",
                         found,
@@ -255,10 +294,10 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0301")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentSecondOperandExpectedEvaluable{ found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the assignment operator `=` expected a value as the second operand, found `{}`",
                         found,
@@ -267,10 +306,10 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0302")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentBitwiseOrFirstOperandExpectedPlace{ found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the assignment bitwise OR operator `|=` expected a memory place as the first operand, found `{}`",
                         found,
@@ -279,22 +318,22 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0303")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentBitwiseOrSecondOperandExpectedEvaluable{ found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
-                        "the assignment bitwise OR operator `|=` expected a constant as the second operand, found `{}`", // TODO: constant -> value
+                        "the assignment bitwise OR operator `|=` expected a value as the second operand, found `{}`",
                         found,
                     )
                         .as_str(),
                     location,
                     None,
                 )
+                .with_code("Z0304")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentBitwiseXorFirstOperandExpectedPlace{ found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the assignment bitwise XOR operator `^=` expected a memory place as the first operand, found `{}`",
                         found,
@@ -303,22 +342,22 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0305")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentBitwiseXorSecondOperandExpectedEvaluable{ found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
-                        "the assignment bitwise XOR operator `^=` expected a constant as the second operand, found `{}`", // TODO: constant -> value
+                        "the assignment bitwise XOR operator `^=` expected a value as the second operand, found `{}`",
                         found,
                     )
                         .as_str(),
                     location,
                     None,
                 )
+                .with_code("Z0306")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentBitwiseAndFirstOperandExpectedPlace{ found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the assignment bitwise AND operator `&=` expected a memory place as the first operand, found `{}`",
                         found,
@@ -327,22 +366,22 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0307")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentBitwiseAndSecondOperandExpectedEvaluable{ found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
-                        "the assignment bitwise AND operator `&=` expected a constant as the second operand, found `{}`", // TODO: constant -> value
+                        "the assignment bitwise AND operator `&=` expected a value as the second operand, found `{}`",
                         found,
                     )
                         .as_str(),
                     location,
                     None,
                 )
+                .with_code("Z0308")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentBitwiseShiftLeftFirstOperandExpectedPlace{ found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the assignment bitwise shift left operator `<<=` expected a memory place as the first operand, found `{}`",
                         found,
@@ -351,22 +390,22 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0309")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentBitwiseShiftLeftSecondOperandExpectedEvaluable{ found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
-                        "the assignment bitwise shift left operator `<<=` expected a constant as the second operand, found `{}`", // TODO: constant -> value
+                        "the assignment bitwise shift left operator `<<=` expected a value as the second operand, found `{}`",
                         found,
                     )
                         .as_str(),
                     location,
                     None,
                 )
+                .with_code("Z0310")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentBitwiseShiftRightFirstOperandExpectedPlace{ found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the assignment bitwise shift right operator `>>=` expected a memory place as the first operand, found `{}`",
                         found,
@@ -375,22 +414,22 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0311")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentBitwiseShiftRightSecondOperandExpectedEvaluable{ found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
-                        "the assignment bitwise shift right operator `>>=` expected a constant as the second operand, found `{}`", // TODO: constant -> value
+                        "the assignment bitwise shift right operator `>>=` expected a value as the second operand, found `{}`",
                         found,
                     )
                         .as_str(),
                     location,
                     None,
                 )
+                .with_code("Z0312")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentAdditionFirstOperandExpectedPlace{ found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the assignment operator `+=` expected a memory place as the first operand, found `{}`",
                         found,
@@ -399,10 +438,10 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0313")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentAdditionSecondOperandExpectedEvaluable{ found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the assignment operator `+=` expected a value as the second operand, found `{}`",
                         found,
@@ -411,10 +450,10 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0314")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentSubtractionFirstOperandExpectedPlace{ found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the assignment operator `-=` expected a memory place as the first operand, found `{}`",
                         found,
@@ -423,10 +462,10 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0315")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentSubtractionSecondOperandExpectedEvaluable{ found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the assignment operator `-=` expected a value as the second operand, found `{}`",
                         found,
@@ -435,10 +474,10 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0316")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentMultiplicationFirstOperandExpectedPlace{ found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the assignment operator `*=` expected a memory place as the first operand, found `{}`",
                         found,
@@ -447,10 +486,10 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0317")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentMultiplicationSecondOperandExpectedEvaluable{ found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the assignment operator `*=` expected a value as the second operand, found `{}`",
                         found,
@@ -459,10 +498,10 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0318")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentDivisionFirstOperandExpectedPlace{ found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the assignment operator `/=` expected a memory place as the first operand, found `{}`",
                         found,
@@ -471,10 +510,10 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0319")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentDivisionSecondOperandExpectedEvaluable{ found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the assignment operator `/=` expected a value as the second operand, found `{}`",
                         found,
@@ -483,10 +522,10 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0320")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentRemainderFirstOperandExpectedPlace{ found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the assignment operator `%=` expected a memory place as the first operand, found `{}`",
                         found,
@@ -495,10 +534,10 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0321")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentRemainderSecondOperandExpectedEvaluable{ found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the assignment operator `%=` expected a value as the second operand, found `{}`",
                         found,
@@ -507,11 +546,11 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0322")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorRangeInclusiveFirstOperandExpectedConstant{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorRangeInclusiveFirstOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the inclusive range operator `..=` expected an integer constant as the first operand, found `{}`",
                         found,
@@ -520,11 +559,11 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0323")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorRangeInclusiveSecondOperandExpectedConstant{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorRangeInclusiveSecondOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the inclusive range operator `..=` expected an integer constant as the second operand, found `{}`",
                         found,
@@ -533,11 +572,11 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0324")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorRangeFirstOperandExpectedConstant{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorRangeFirstOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the range operator `..` expected an integer constant as the first operand, found `{}`",
                         found,
@@ -546,11 +585,11 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0325")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorRangeSecondOperandExpectedConstant{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorRangeSecondOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the range operator `..` expected an integer constant as the second operand, found `{}`",
                         found,
@@ -559,12 +598,12 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0326")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorOrFirstOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorOrFirstOperandExpectedBoolean{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorOrFirstOperandExpectedBoolean{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the OR operator `||` expected a boolean as the first operand, found `{}`",
                         found,
@@ -573,12 +612,12 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0327")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorOrSecondOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorOrSecondOperandExpectedBoolean{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorOrSecondOperandExpectedBoolean{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the OR operator `||` expected a boolean as the second operand, found `{}`",
                         found,
@@ -587,12 +626,12 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0328")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorXorFirstOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorXorFirstOperandExpectedBoolean{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorXorFirstOperandExpectedBoolean{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the XOR operator `^^` expected a boolean as the first operand, found `{}`",
                         found,
@@ -601,12 +640,12 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0329")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorXorSecondOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorXorSecondOperandExpectedBoolean{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorXorSecondOperandExpectedBoolean{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the XOR operator `^^` expected a boolean as the second operand, found `{}`",
                         found,
@@ -615,12 +654,12 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0330")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAndFirstOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorAndFirstOperandExpectedBoolean{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorAndFirstOperandExpectedBoolean{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the AND operator `&&` expected a boolean as the first operand, found `{}`",
                         found,
@@ -629,12 +668,12 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0331")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAndSecondOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorAndSecondOperandExpectedBoolean{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorAndSecondOperandExpectedBoolean{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the AND operator `&&` expected a boolean as the second operand, found `{}`",
                         found,
@@ -643,12 +682,12 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0332")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorEqualsFirstOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorEqualsFirstOperandExpectedPrimitiveType{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorEqualsFirstOperandExpectedPrimitiveType{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the equals operator `==` expected a unit, boolean or integer as the first operand, found `{}`",
                         found,
@@ -657,6 +696,7 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0333")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorEqualsSecondOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorEqualsSecondOperandExpectedUnit{ found }))) |
@@ -665,8 +705,7 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorEqualsSecondOperandExpectedUnit{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorEqualsSecondOperandExpectedBoolean{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorEqualsSecondOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the equals operator `==` expected a unit, boolean or integer as the second operand, found `{}`",
                         found,
@@ -675,12 +714,12 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0334")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorNotEqualsFirstOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorNotEqualsFirstOperandExpectedPrimitiveType{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorNotEqualsFirstOperandExpectedPrimitiveType{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the not equals operator `!=` expected a boolean or integer as the first operand, found `{}`",
                         found,
@@ -689,6 +728,7 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0335")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorNotEqualsSecondOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorNotEqualsSecondOperandExpectedUnit{ found }))) |
@@ -697,8 +737,7 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorNotEqualsSecondOperandExpectedUnit{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorNotEqualsSecondOperandExpectedBoolean{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorNotEqualsSecondOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the not equals operator `!=` expected a boolean or integer as the second operand, found `{}`",
                         found,
@@ -707,12 +746,12 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0336")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorGreaterEqualsFirstOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorGreaterEqualsFirstOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorGreaterEqualsFirstOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the greater equals operator `>=` expected an integer as the first operand, found `{}`",
                         found,
@@ -721,12 +760,12 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0337")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorGreaterEqualsSecondOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorGreaterEqualsSecondOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorGreaterEqualsSecondOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the greater equals operator `>=` expected an integer as the second operand, found `{}`",
                         found,
@@ -735,12 +774,12 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0338")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorLesserEqualsFirstOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorLesserEqualsFirstOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorLesserEqualsFirstOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the lesser equals operator `<=` expected an integer as the first operand, found `{}`",
                         found,
@@ -749,12 +788,12 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0339")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorLesserEqualsSecondOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorLesserEqualsSecondOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorLesserEqualsSecondOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the lesser equals operator `<=` expected an integer as the second operand, found `{}`",
                         found,
@@ -763,12 +802,12 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0340")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorGreaterFirstOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorGreaterFirstOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorGreaterFirstOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the greater operator `>` expected an integer as the first operand, found `{}`",
                         found,
@@ -777,12 +816,12 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0341")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorGreaterSecondOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorGreaterSecondOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorGreaterSecondOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the greater operator `>` expected an integer as the second operand, found `{}`",
                         found,
@@ -791,12 +830,12 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0342")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorLesserFirstOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorLesserFirstOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorLesserFirstOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the lesser operator `<` expected an integer as the first operand, found `{}`",
                         found,
@@ -805,12 +844,12 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0343")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorLesserSecondOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorLesserSecondOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorLesserSecondOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the lesser operator `<` expected an integer as the second operand, found `{}`",
                         found,
@@ -819,156 +858,156 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0344")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorBitwiseOrFirstOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorBitwiseOrFirstOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorBitwiseOrFirstOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
-                        "the bitwise OR operator `|` expected an integer constant as the first operand, found `{}`", // TODO: constant -> ''
+                        "the bitwise OR operator `|` expected an integer as the first operand, found `{}`",
                         found,
                     )
                         .as_str(),
                     location,
                     None,
                 )
+                .with_code("Z0345")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorBitwiseOrSecondOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorBitwiseOrSecondOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorBitwiseOrSecondOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
-                        "the bitwise OR operator `|` expected an integer constant as the second operand, found `{}`", // TODO: constant -> ''
+                        "the bitwise OR operator `|` expected an integer as the second operand, found `{}`",
                         found,
                     )
                         .as_str(),
                     location,
                     None,
                 )
+                .with_code("Z0346")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorBitwiseXorFirstOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorBitwiseXorFirstOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorBitwiseXorFirstOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
-                        "the bitwise XOR operator `^` expected an integer constant as the first operand, found `{}`", // TODO: constant -> ''
+                        "the bitwise XOR operator `^` expected an integer as the first operand, found `{}`",
                         found,
                     )
                         .as_str(),
                     location,
                     None,
                 )
+                .with_code("Z0347")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorBitwiseXorSecondOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorBitwiseXorSecondOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorBitwiseXorSecondOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
-                        "the bitwise XOR operator `^` expected an integer constant as the second operand, found `{}`", // TODO: constant -> ''
+                        "the bitwise XOR operator `^` expected an integer as the second operand, found `{}`",
                         found,
                     )
                         .as_str(),
                     location,
                     None,
                 )
+                .with_code("Z0348")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorBitwiseAndFirstOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorBitwiseAndFirstOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorBitwiseAndFirstOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
-                        "the bitwise AND operator `&` expected an integer constant as the first operand, found `{}`", // TODO: constant -> ''
+                        "the bitwise AND operator `&` expected an integer as the first operand, found `{}`",
                         found,
                     )
                         .as_str(),
                     location,
                     None,
                 )
+                .with_code("Z0349")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorBitwiseAndSecondOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorBitwiseAndSecondOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorBitwiseAndSecondOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
-                        "the bitwise AND operator `&` expected an integer constant as the second operand, found `{}`", // TODO: constant -> ''
+                        "the bitwise AND operator `&` expected an integer as the second operand, found `{}`",
                         found,
                     )
                         .as_str(),
                     location,
                     None,
                 )
+                .with_code("Z0350")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorBitwiseShiftLeftFirstOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorBitwiseShiftLeftFirstOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorBitwiseShiftLeftFirstOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
-                        "the bitwise shift left operator `<<` expected an integer constant as the first operand, found `{}`", // TODO: constant -> ''
+                        "the bitwise shift left operator `<<` expected an integer as the first operand, found `{}`",
                         found,
                     )
                         .as_str(),
                     location,
                     None,
                 )
+                .with_code("Z0351")
             }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorBitwiseShiftLeftSecondOperandExpectedConstant{ found })) |
+            Self::Semantic(SemanticError::Element(location, ElementError::OperatorBitwiseShiftLeftSecondOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorBitwiseShiftLeftSecondOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::OperatorBitwiseShiftLeftSecondOperatorExpectedUnsigned { found })))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorBitwiseShiftLeftSecondOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::OperatorBitwiseShiftLeftSecondOperatorExpectedUnsigned { found })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
-                        "the bitwise shift left operator `<<` expected an unsigned integer constant as the second operand, found `{}`",
+                        "the bitwise shift left operator `<<` expected an unsigned integer as the second operand, found `{}`",
                         found,
                     )
                         .as_str(),
                     location,
                     None,
                 )
+                .with_code("Z0352")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorBitwiseShiftRightFirstOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorBitwiseShiftRightFirstOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorBitwiseShiftRightFirstOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
-                        "the bitwise shift right operator `>>` expected an integer constant as the first operand, found `{}`", // TODO: constant -> ''
+                        "the bitwise shift right operator `>>` expected an integer as the first operand, found `{}`",
                         found,
                     )
                         .as_str(),
                     location,
                     None,
                 )
+                .with_code("Z0353")
             }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorBitwiseShiftRightSecondOperandExpectedConstant{ found })) |
+            Self::Semantic(SemanticError::Element(location, ElementError::OperatorBitwiseShiftRightSecondOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorBitwiseShiftRightSecondOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::OperatorBitwiseShiftRightSecondOperatorExpectedUnsigned { found })))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorBitwiseShiftRightSecondOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::OperatorBitwiseShiftRightSecondOperatorExpectedUnsigned { found })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
-                        "the bitwise shift right operator `>>` expected an unsigned integer constant as the second operand, found `{}`",
+                        "the bitwise shift right operator `>>` expected an unsigned integer as the second operand, found `{}`",
                         found,
                     )
                         .as_str(),
                     location,
                     None,
                 )
+                .with_code("Z0354")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAdditionFirstOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorAdditionFirstOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorAdditionFirstOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the addition operator `+` expected an integer as the first operand, found `{}`",
                         found,
@@ -977,12 +1016,12 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0355")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAdditionSecondOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorAdditionSecondOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorAdditionSecondOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the addition operator `+` expected an integer as the second operand, found `{}`",
                         found,
@@ -991,12 +1030,12 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0356")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorSubtractionFirstOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorSubtractionFirstOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorSubtractionFirstOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the subtraction operator `-` expected an integer as the first operand, found `{}`",
                         found,
@@ -1005,12 +1044,12 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0357")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorSubtractionSecondOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorSubtractionSecondOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorSubtractionSecondOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the subtraction operator `-` expected an integer as the second operand, found `{}`",
                         found,
@@ -1019,12 +1058,12 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0358")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorMultiplicationFirstOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorMultiplicationFirstOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorMultiplicationFirstOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the multiplication operator `*` expected an integer as the first operand, found `{}`",
                         found,
@@ -1033,12 +1072,12 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0359")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorMultiplicationSecondOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorMultiplicationSecondOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorMultiplicationSecondOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the multiplication operator `*` expected an integer as the second operand, found `{}`",
                         found,
@@ -1047,12 +1086,12 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0360")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorDivisionFirstOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorDivisionFirstOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorDivisionFirstOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the division operator `/` expected an integer as the first operand, found `{}`",
                         found,
@@ -1061,12 +1100,12 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0361")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorDivisionSecondOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorDivisionSecondOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorDivisionSecondOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the division operator `/` expected an integer as the second operand, found `{}`",
                         found,
@@ -1075,12 +1114,12 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0362")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorRemainderFirstOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorRemainderFirstOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorRemainderFirstOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the remainder operator `%` expected an integer as the first operand, found `{}`",
                         found,
@@ -1089,12 +1128,12 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0363")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorRemainderSecondOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorRemainderSecondOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorRemainderSecondOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the remainder operator `%` expected an integer as the second operand, found `{}`",
                         found,
@@ -1103,10 +1142,10 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0364")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorCastingFirstOperandExpectedEvaluable{ found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the casting operator `as` expected a value as the first operand, found `{}`",
                         found,
@@ -1115,10 +1154,10 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0365")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorCastingSecondOperandExpectedType{ found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the casting operator `as` expected a type as the second operand, found `{}`",
                         found,
@@ -1127,13 +1166,13 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0366")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Casting(CastingError::CastingFromInvalidType { from, to })))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Casting(CastingError::CastingToInvalidType { from, to })))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Casting(CastingError::CastingFromInvalidType { from, to })))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Casting(CastingError::CastingToInvalidType { from, to })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "cannot cast from `{}` to `{}`",
                         from, to,
@@ -1142,12 +1181,12 @@ impl Error {
                     location,
                     Some("only integer values can be casted to greater or equal bitlength"),
                 )
+                .with_code("Z0367")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorNotExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorNotExpectedBoolean{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorNotExpectedBoolean{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the NOT operator `!` expected a boolean, found `{}`",
                         found,
@@ -1156,12 +1195,12 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0368")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorBitwiseNotExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorBitwiseNotExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorBitwiseNotExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the bitwise NOT operator `~` expected an integer, found `{}`",
                         found,
@@ -1170,12 +1209,12 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0369")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorNegationExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorNegationExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorNegationExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the negation operator `-` expected an integer, found `{}`",
                         found,
@@ -1184,12 +1223,13 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0370")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorIndexFirstOperandExpectedPlaceOrEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Place(PlaceError::OperatorIndexFirstOperandExpectedArray{ found }))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorIndexFirstOperandExpectedArray{ found }))) => {
-                Self::format_line(
-                    context,
+            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorIndexFirstOperandExpectedArray{ found }))) |
+            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorIndexFirstOperandExpectedArray{ found }))) => {
+                Diagnostic::line(
                     format!(
                         "the index operator `[]` expected an array as the first operand, found `{}`",
                         found,
@@ -1198,12 +1238,13 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0371")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorIndexSecondOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Place(PlaceError::OperatorIndexSecondOperandExpectedIntegerOrRange{ found }))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorIndexSecondOperandExpectedIntegerOrRange{ found }))) => {
-                Self::format_line(
-                    context,
+            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorIndexSecondOperandExpectedIntegerOrRange{ found }))) |
+            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorIndexSecondOperandExpectedIntegerOrRange{ found }))) => {
+                Diagnostic::line(
                     format!(
                         "the index operator `[]` expected an integer or range as the second operand, found `{}`",
                         found,
@@ -1212,14 +1253,16 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0372")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorFieldFirstOperandExpectedPlaceOrEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Place(PlaceError::OperatorFieldFirstOperandExpectedTuple{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Place(PlaceError::OperatorFieldFirstOperandExpectedStructure{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorFieldFirstOperandExpectedTuple{ found }))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorFieldFirstOperandExpectedStructure{ found }))) => {
-                Self::format_line(
-                    context,
+            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorFieldFirstOperandExpectedStructure{ found }))) |
+            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorFieldFirstOperandExpectedTuple{ found }))) |
+            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorFieldFirstOperandExpectedStructure{ found }))) => {
+                Diagnostic::line(
                     format!(
                         "the field access operator `.` expected a tuple or structure as the first operand, found `{}`",
                         found,
@@ -1228,10 +1271,10 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0373")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorFieldSecondOperandExpectedIdentifier { found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the field access operator `.` expected a tuple or structure field identifier as the second operand, found `{}`",
                         found,
@@ -1240,10 +1283,10 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0374")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorPathFirstOperandExpectedPath{ found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the path resolution operator `::` expected an item identifier as the first operand, found `{}`",
                         found,
@@ -1252,10 +1295,10 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0375")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorPathSecondOperandExpectedIdentifier { found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the path resolution operator `::` expected an item identifier as the second operand, found `{}`",
                         found,
@@ -1264,11 +1307,11 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0376")
             }
 
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Array(ArrayValueError::PushingInvalidType { expected, found })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "expected `{}`, found `{}`",
                         expected, found,
@@ -1277,11 +1320,24 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0401")
+            }
+            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Array(ArrayConstantError::IndexOutOfRange { index, size })))) => {
+                Diagnostic::line(
+                    format!(
+                        "index `{}` is out of range of the array of size {}",
+                        index, size,
+                    )
+                        .as_str(),
+                    location,
+                    Some("array index must be within the array size"),
+                )
+                .with_code("Z0402")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Array(ArrayValueError::SliceStartOutOfRange { start })))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Place(PlaceError::ArraySliceStartOutOfRange { start }))) => {
-                Self::format_line(
-                    context,
+            Self::Semantic(SemanticError::Element(location, ElementError::Place(PlaceError::ArraySliceStartOutOfRange { start }))) |
+            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Array(ArrayConstantError::SliceStartOutOfRange { start })))) => {
+                Diagnostic::line(
                     format!(
                         "left slice bound `{}` is negative",
                         start,
@@ -1290,11 +1346,12 @@ impl Error {
                     location,
                     Some("slice range bounds must be within the array size"),
                 )
+                .with_code("Z0403")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Array(ArrayValueError::SliceEndOutOfRange { end, size })))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Place(PlaceError::ArraySliceEndOutOfRange { end, size }))) => {
-                Self::format_line(
-                    context,
+            Self::Semantic(SemanticError::Element(location, ElementError::Place(PlaceError::ArraySliceEndOutOfRange { end, size }))) |
+            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Array(ArrayConstantError::SliceEndOutOfRange { end, size })))) => {
+                Diagnostic::line(
                     format!(
                         "right slice bound `{}` is out of range of the array of size {}",
                         end, size,
@@ -1303,11 +1360,12 @@ impl Error {
                     location,
                     Some("slice range bounds must be within the array size"),
                 )
+                .with_code("Z0404")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Array(ArrayValueError::SliceEndLesserThanStart { start, end })))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Place(PlaceError::ArraySliceEndLesserThanStart { start, end }))) => {
-                Self::format_line(
-                    context,
+            Self::Semantic(SemanticError::Element(location, ElementError::Place(PlaceError::ArraySliceEndLesserThanStart { start, end }))) |
+            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Array(ArrayConstantError::SliceEndLesserThanStart { start, end })))) => {
+                Diagnostic::line(
                     format!(
                         "left slice bound `{}` is greater than right slice bound `{}`",
                         start, end,
@@ -1316,12 +1374,13 @@ impl Error {
                     location,
                     Some("left slice range bound must be lesser or equal to the right one"),
                 )
+                .with_code("Z0405")
             }
 
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Tuple(TupleValueError::FieldDoesNotExist { type_identifier, field_index })))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Place(PlaceError::TupleFieldDoesNotExist { type_identifier, field_index }))) => {
-                Self::format_line(
-                    context,
+            Self::Semantic(SemanticError::Element(location, ElementError::Place(PlaceError::TupleFieldDoesNotExist { type_identifier, field_index }))) |
+            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Tuple(TupleConstantError::FieldDoesNotExist { type_identifier, field_index })))) => {
+                Diagnostic::line(
                     format!(
                         "tuple `{}` has no field with index `{}`",
                         type_identifier, field_index,
@@ -1330,12 +1389,13 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0406")
             }
 
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Structure(StructureValueError::FieldDoesNotExist { type_identifier, field_name })))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Place(PlaceError::StructureFieldDoesNotExist { type_identifier, field_name }))) => {
-                Self::format_line(
-                    context,
+            Self::Semantic(SemanticError::Element(location, ElementError::Place(PlaceError::StructureFieldDoesNotExist { type_identifier, field_name }))) |
+            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Structure(StructureConstantError::FieldDoesNotExist { type_identifier, field_name })))) => {
+                Diagnostic::line(
                     format!(
                         "field `{}` does not exist in structure `{}`",
                         field_name, type_identifier,
@@ -1344,27 +1404,27 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0407")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Place(PlaceError::MutatingWithDifferentType { expected, found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!("expected `{}`, found `{}`", expected, found).as_str(),
                     location,
                     None,
                 )
+                .with_code("Z0408")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Place(PlaceError::MutatingImmutableMemory { name, reference }))) => {
-                Self::format_line_with_reference(
-                    context,
+                Diagnostic::line_with_reference(
                     format!("cannot assign twice to immutable variable `{}`", name).as_str(),
                     location,
                     reference,
                     Some(format!("make this variable mutable: `mut {}`", name).as_str()),
                 )
+                .with_code("Z0409")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Structure(StructureValueError::FieldExpected { type_identifier, position, expected, found })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "structure `{}` expected field `{}` at position {}, found `{}`",
                         type_identifier, expected, position, found,
@@ -1373,10 +1433,10 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0410")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Structure(StructureValueError::FieldInvalidType { type_identifier, field_name, expected, found })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "field `{}` of structure `{}` expected type `{}`, found `{}`",
                         field_name, type_identifier, expected, found,
@@ -1385,10 +1445,10 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0411")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Structure(StructureValueError::FieldOutOfRange { type_identifier, expected, found })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "structure `{}` expected {} fields, found {}",
                         type_identifier, expected, found,
@@ -1397,11 +1457,23 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0412")
+            }
+            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Array(ArrayConstantError::SizeLimitExceeded { size, limit })))) => {
+                Diagnostic::line(
+                    format!(
+                        "constant array of {} elements exceeds the limit of {}",
+                        size, limit,
+                    )
+                        .as_str(),
+                    location,
+                    Some("large arrays can only be declared as variables"),
+                )
+                .with_code("Z0413")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::TypesMismatchEquals{ first, second })))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::TypesMismatchEquals{ first, second })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the equals operator `==` expected two integers of the same type, found `{}` and `{}`",
                         first, second,
@@ -1410,11 +1482,11 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0501")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::TypesMismatchNotEquals{ first, second })))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::TypesMismatchNotEquals{ first, second })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the not equals operator `!=` expected two integers of the same type, found `{}` and `{}`",
                         first, second,
@@ -1423,11 +1495,11 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0502")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::TypesMismatchGreaterEquals{ first, second })))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::TypesMismatchGreaterEquals{ first, second })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the greater equals operator `>=` expected two integers of the same type, found `{}` and `{}`",
                         first, second,
@@ -1436,11 +1508,11 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0503")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::TypesMismatchLesserEquals{ first, second })))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::TypesMismatchLesserEquals{ first, second })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the lesser equals operator `<=` expected two integers of the same type, found `{}` and `{}`",
                         first, second,
@@ -1449,11 +1521,11 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0504")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::TypesMismatchGreater{ first, second })))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::TypesMismatchGreater{ first, second })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the greater operator `>` expected two integers of the same type, found `{}` and `{}`",
                         first, second,
@@ -1462,11 +1534,11 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0505")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::TypesMismatchLesser{ first, second })))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::TypesMismatchLesser{ first, second })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the lesser operator `<` expected two integers of the same type, found `{}` and `{}`",
                         first, second,
@@ -1475,11 +1547,11 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0506")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::TypesMismatchBitwiseOr{ first, second })))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::TypesMismatchBitwiseOr{ first, second })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the bitwise OR operator `|` expected two integers of the same type, found `{}` and `{}`",
                         first, second,
@@ -1488,11 +1560,11 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0507")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::TypesMismatchBitwiseXor{ first, second })))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::TypesMismatchBitwiseXor{ first, second })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the bitwise XOR operator `^` expected two integers of the same type, found `{}` and `{}`",
                         first, second,
@@ -1501,11 +1573,11 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0508")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::TypesMismatchBitwiseAnd{ first, second })))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::TypesMismatchBitwiseAnd{ first, second })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the bitwise AND operator `&` expected two integers of the same type, found `{}` and `{}`",
                         first, second,
@@ -1514,11 +1586,11 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0509")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::TypesMismatchAddition{ first, second })))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::TypesMismatchAddition{ first, second })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the addition operator `+` expected two integers of the same type, found `{}` and `{}`",
                         first, second,
@@ -1527,11 +1599,11 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0510")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::TypesMismatchSubtraction{ first, second })))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::TypesMismatchSubtraction{ first, second })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the subtraction operator `-` expected two integers of the same type, found `{}` and `{}`",
                         first, second,
@@ -1540,11 +1612,11 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0511")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::TypesMismatchMultiplication{ first, second })))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::TypesMismatchMultiplication{ first, second })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the multiplication operator `*` expected two integers of the same type, found `{}` and `{}`",
                         first, second,
@@ -1553,11 +1625,11 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0512")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::TypesMismatchDivision{ first, second })))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::TypesMismatchDivision{ first, second })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the division operator `/` expected two integers of the same type, found `{}` and `{}`",
                         first, second,
@@ -1566,11 +1638,11 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0513")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::TypesMismatchRemainder{ first, second })))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::TypesMismatchRemainder{ first, second })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the remainder operator `%` expected two integers of the same type, found `{}` and `{}`",
                         first, second,
@@ -1579,10 +1651,10 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0514")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::OverflowAddition { value, r#type })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the addition operator `+` overflow, as the value `{}` cannot be represeneted by type `{}`",
                         value, r#type,
@@ -1591,10 +1663,10 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0515")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::OverflowSubtraction { value, r#type })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the subtraction operator `-` overflow, as the value `{}` cannot be represeneted by type `{}`",
                         value, r#type,
@@ -1603,10 +1675,10 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0516")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::OverflowMultiplication { value, r#type })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the multiplication operator `*` overflow, as the value `{}` cannot be represeneted by type `{}`",
                         value, r#type,
@@ -1615,10 +1687,10 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0517")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::OverflowDivision { value, r#type })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the division operator `/` overflow, as the value `{}` cannot be represeneted by type `{}`",
                         value, r#type,
@@ -1627,10 +1699,10 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0518")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::OverflowRemainder { value, r#type })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the remainder operator `%` overflow, as the value `{}` cannot be represeneted by type `{}`",
                         value, r#type,
@@ -1639,10 +1711,10 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0519")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::OverflowCasting { value, r#type })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the casting operator `as` overflow, as the value `{}` cannot be represeneted by type `{}`",
                         value, r#type,
@@ -1651,10 +1723,10 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0520")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::OverflowNegation { value, r#type })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the negation operator `-` overflow, as the value `{}` cannot be represeneted by type `{}`",
                         value, r#type,
@@ -1663,78 +1735,78 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0521")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::ForbiddenFieldDivision)))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::ForbiddenFieldDivision)))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     "the division operator `/` is forbidden for the `field` type",
                     location,
                     Some("for inversion consider using `std::ff::invert`"),
                 )
+                .with_code("Z0522")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::ForbiddenFieldRemainder)))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::ForbiddenFieldRemainder)))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     "the remainder operator `%` is forbidden for the `field` type",
                     location,
                     Some("`field` type values cannot be used to get a remainder"),
                 )
+                .with_code("Z0523")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::ForbiddenFieldBitwise)))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::ForbiddenFieldBitwise)))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     "the bitwise operators are forbidden for the `field` type",
                     location,
                     None,
                 )
+                .with_code("Z0524")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::ForbiddenFieldNegation)))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::ForbiddenFieldNegation)))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     "the negation operator `-` is forbidden for the `field` type",
                     location,
                     Some("`field` type values cannot be negative"),
                 )
+                .with_code("Z0525")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::ZeroDivision)))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     "division by zero",
                     location,
                     None,
                 )
+                .with_code("Z0526")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::ZeroRemainder)))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     "remainder of division by zero",
                     location,
                     None,
                 )
+                .with_code("Z0527")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::IntegerTooLarge { value, bitlength })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!("integer `{}` is larger than `{}` bits", value, bitlength).as_str(),
                     location,
                     None,
                 )
+                .with_code("Z0528")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::UnsignedNegative { value, r#type })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!("found a negative value `{}` of unsigned type `{}`", value, r#type).as_str(),
                     location,
                     None,
                 )
+                .with_code("Z0529")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::AliasDoesNotPointToType { found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "expected type, found `{}`",
                         found
@@ -1743,10 +1815,10 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0601")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::AliasDoesNotPointToStructure { found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "expected structure type, found `{}`",
                         found
@@ -1755,11 +1827,48 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0602")
+            }
+
+            Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::AliasDoesNotPointToGenericType { found }))) => {
+                Diagnostic::line(
+                    format!(
+                        "expected generic type, found `{}`",
+                        found
+                    )
+                        .as_str(),
+                    location,
+                    Some("only the types declared with generic parameters accept generic arguments"),
+                )
+                .with_code("Z0603")
+            }
+            Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::GenericConstantExpectedInteger { found }))) => {
+                Diagnostic::line(
+                    format!(
+                        "expected an integer type of the constant generic parameter, found `{}`",
+                        found
+                    )
+                        .as_str(),
+                    location,
+                    Some("constant generic parameters must be integers, e.g. `const N: u64`"),
+                )
+                .with_code("Z0604")
+            }
+            Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::GenericArgumentExpectedIntegerConstant { found }))) => {
+                Diagnostic::line(
+                    format!(
+                        "expected an integer constant generic argument, found `{}`",
+                        found
+                    )
+                        .as_str(),
+                    location,
+                    Some("generic arguments must be constant expressions, e.g. `Path<10>`"),
+                )
+                .with_code("Z0605")
             }
 
             Self::Semantic(SemanticError::Scope(location, ScopeError::ItemRedeclared { name, reference })) => {
-                Self::format_line_with_reference(
-                    context,
+                Diagnostic::line_with_reference(
                     format!(
                         "item `{}` already declared here",
                         name
@@ -1769,10 +1878,10 @@ impl Error {
                     reference,
                     Some("consider giving the latter item another name"),
                 )
+                .with_code("Z0606")
             }
             Self::Semantic(SemanticError::Scope(location, ScopeError::ItemUndeclared { name })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "cannot find item `{}` in this scope",
                         name
@@ -1781,10 +1890,22 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0607")
+            }
+            Self::Semantic(SemanticError::Scope(location, ScopeError::ItemInvalid { name })) => {
+                Diagnostic::line(
+                    format!(
+                        "cannot use item `{}` whose declaration has failed",
+                        name
+                    )
+                        .as_str(),
+                    location,
+                    None,
+                )
+                .with_code("Z0607")
             }
             Self::Semantic(SemanticError::Scope(location, ScopeError::ItemIsNotNamespace { name })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "item `{}` is not a namespace",
                         name
@@ -1793,11 +1914,35 @@ impl Error {
                     location,
                     Some("only modules, structures, and enumerations can contain items within their namespaces"),
                 )
+                .with_code("Z0608")
+            }
+            Self::Semantic(SemanticError::Scope(location, ScopeError::ItemIsPrivate { name })) => {
+                Diagnostic::line(
+                    format!(
+                        "item `{}` is private",
+                        name
+                    )
+                        .as_str(),
+                    location,
+                    Some("declare the item with the `pub` keyword to make it accessible from other modules"),
+                )
+                .with_code("Z0627")
+            }
+            Self::Semantic(SemanticError::Scope(location, ScopeError::FieldIsPrivate { name, structure })) => {
+                Diagnostic::line(
+                    format!(
+                        "field `{}` of structure `{}` is private",
+                        name, structure,
+                    )
+                        .as_str(),
+                    location,
+                    Some("declare the field with the `pub` keyword to make it accessible from other modules"),
+                )
+                .with_code("Z0628")
             }
 
             Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::Function(FunctionTypeError::ArgumentCount { function, expected, found })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "function `{}` expected {} arguments, found {}",
                         function, expected, found
@@ -1806,10 +1951,10 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0609")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::Function(FunctionTypeError::ArgumentType { function, name, position, expected, found })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "function `{}` expected type `{}` as the argument `{}` (#{}), found `{}`",
                         function, expected, name, position, found
@@ -1818,10 +1963,10 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0610")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::Function(FunctionTypeError::ArgumentConstantness { function, name, position, found })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "function `{}` expected a constant as the argument `{}` (#{}), found a non-constant of type `{}`",
                         function, name, position, found
@@ -1830,10 +1975,10 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0611")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::Function(FunctionTypeError::ArgumentNotEvaluable { function, position, found })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "function `{}` expected a value as the argument #{}, found `{}`",
                         function, position, found
@@ -1842,10 +1987,10 @@ impl Error {
                     location,
                     None,
                 )
+                .with_code("Z0612")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::Function(FunctionTypeError::ReturnType { function, expected, found, reference })))) => {
-                Self::format_line_with_reference(
-                    context,
+                Diagnostic::line_with_reference(
                     format!(
                         "function `{}` must return a value of type `{}`, found `{}`",
                         function, expected, found
@@ -1855,10 +2000,10 @@ impl Error {
                     Some(reference),
                     None,
                 )
+                .with_code("Z0613")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::Function(FunctionTypeError::NonCallable { name })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "attempt to call a non-callable item `{}`",
                         name
@@ -1867,10 +2012,22 @@ impl Error {
                     location,
                     Some("only functions may be called"),
                 )
+                .with_code("Z0614")
+            }
+            Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::Function(FunctionTypeError::GenericNotInferred { function, name })))) => {
+                Diagnostic::line(
+                    format!(
+                        "function `{}` generic parameter `{}` cannot be inferred",
+                        function, name
+                    )
+                        .as_str(),
+                    location,
+                    Some("generic parameters must be used in the argument types, e.g. `fn max<T>(a: T, b: T) -> T`"),
+                )
+                .with_code("Z0615")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::Function(FunctionTypeError::BuiltIn(BuiltInFunctionTypeError::Unknown { function }))))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "attempt to call a non-builtin function `{}` with `!` specifier",
                         function
@@ -1879,10 +2036,10 @@ impl Error {
                     location,
                     Some("only built-in functions require the `!` symbol after the function name"),
                 )
+                .with_code("Z0616")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::Function(FunctionTypeError::BuiltIn(BuiltInFunctionTypeError::SpecifierMissing { function }))))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "attempt to call a builtin function `{}` without `!` specifier",
                         function
@@ -1891,10 +2048,10 @@ impl Error {
                     location,
                     Some("built-in functions require the `!` symbol after the function name"),
                 )
+                .with_code("Z0617")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::Function(FunctionTypeError::BuiltIn(BuiltInFunctionTypeError::DebugArgumentCount { expected, found }))))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the `dbg!` function expected {} arguments, but got {}",
                         expected, found,
@@ -1903,10 +2060,10 @@ impl Error {
                     location,
                     Some("the number of `dbg!` arguments after the format string must be equal to the number of placeholders, e.g. `dbg!(\"{}, {}\", a, b)`"),
                 )
+                .with_code("Z0618")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::Function(FunctionTypeError::StandardLibrary(StandardLibraryFunctionTypeError::ArrayTruncatingToBiggerSize { from, to }))))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "attempt to truncate an array from size `{}` to bigger size `{}`",
                         from, to,
@@ -1915,10 +2072,10 @@ impl Error {
                     location,
                     Some("consider truncating the array to a smaller size"),
                 )
+                .with_code("Z0619")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::Function(FunctionTypeError::StandardLibrary(StandardLibraryFunctionTypeError::ArrayPaddingToLesserSize { from, to }))))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "attempt to pad an array from size `{}` to lesser size `{}`",
                         from, to,
@@ -1927,10 +2084,10 @@ impl Error {
                     location,
                     Some("consider padding the array to a bigger size"),
                 )
+                .with_code("Z0620")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::Function(FunctionTypeError::StandardLibrary(StandardLibraryFunctionTypeError::ArrayNewLengthInvalid { value }))))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "new array length `{}` cannot act as an index",
                         value,
@@ -1939,10 +2096,10 @@ impl Error {
                     location,
                     Some("array indexes cannot be greater than maximum of `u64`"),
                 )
+                .with_code("Z0621")
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::Structure(StructureTypeError::DuplicateField { type_identifier, field_name })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "structure `{}` has a duplicate field `{}`",
                         type_identifier, field_name,
@@ -1951,131 +2108,332 @@ impl Error {
                     location,
                     Some("consider giving the field a unique name"),
                 )
+                .with_code("Z0622")
+            }
+            Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::Structure(StructureTypeError::DuplicateGeneric { type_identifier, name })))) => {
+                Diagnostic::line(
+                    format!(
+                        "structure `{}` has the generic parameter `{}` declared more than once",
+                        type_identifier, name,
+                    )
+                        .as_str(),
+                    location,
+                    Some("consider giving the generic parameters unique names"),
+                )
+                .with_code("Z0623")
+            }
+            Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::Structure(StructureTypeError::GenericExpectedConstant { type_identifier, name })))) => {
+                Diagnostic::line(
+                    format!(
+                        "structure `{}` generic parameter `{}` must be a constant",
+                        type_identifier, name,
+                    )
+                        .as_str(),
+                    location,
+                    Some("structures can only be parameterized by constants, e.g. `struct Path<const N: u64>`"),
+                )
+                .with_code("Z0624")
+            }
+            Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::Structure(StructureTypeError::GenericArgumentCount { type_identifier, expected, found })))) => {
+                Diagnostic::line(
+                    format!(
+                        "structure `{}` expected {} generic arguments, found {}",
+                        type_identifier, expected, found,
+                    )
+                        .as_str(),
+                    location,
+                    None,
+                )
+                .with_code("Z0625")
+            }
+            Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::Structure(StructureTypeError::GenericNotInferred { type_identifier, name })))) => {
+                Diagnostic::line(
+                    format!(
+                        "structure `{}` generic parameter `{}` cannot be inferred",
+                        type_identifier, name,
+                    )
+                        .as_str(),
+                    location,
+                    Some("constant generic parameters must be used as the field array sizes, e.g. `nodes: [u248; N]`"),
+                )
+                .with_code("Z0626")
             }
 
             Self::Semantic(SemanticError::MatchScrutineeInvalidType { location, found }) => {
-                Self::format_line(
-                    context,
-                    format!("match scrutinee expected a boolean or integer expression, found `{}`", found).as_str(),
+                Diagnostic::line(
+                    format!("match scrutinee expected a boolean, integer, tuple or structure expression, found `{}`", found).as_str(),
                     location,
                     None,
                 )
+                .with_code("Z0701")
             }
             Self::Semantic(SemanticError::MatchNotExhausted { location }) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     "match expression must be exhaustive",
                     location,
                     Some("ensure that all possible cases are being handled, possibly by adding wildcards or more match arms"),
                 )
+                .with_code("Z0702")
             }
             Self::Semantic(SemanticError::MatchLessThanTwoBranches { location }) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     "match expression must have at least two branches",
                     location,
                     Some("consider adding some branches to make the expression useful"),
                 )
-            }
-            Self::Semantic(SemanticError::MatchBranchUnreachable { location }) => {
-                Self::format_line(
-                    context,
-                    "match expression branch is unreachable",
-                    location,
-                    Some("consider removing the branch or moving it above the branch with a wildcard or irrefutable binding"),
-                )
+                .with_code("Z0703")
             }
             Self::Semantic(SemanticError::MatchBranchPatternPathExpectedConstant { location, found }) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!("expected path to a constant, found `{}`", found).as_str(),
                     location,
                     None,
                 )
+                .with_code("Z0704")
             }
             Self::Semantic(SemanticError::MatchBranchPatternInvalidType { location, expected, found, reference }) => {
-                Self::format_line_with_reference(
-                    context,
+                Diagnostic::line_with_reference(
                     format!("expected `{}`, found `{}`", expected, found).as_str(),
                     location,
                     Some(reference),
                     Some("all branch patterns must be compatible with the type of the expression being matched"),
                 )
+                .with_code("Z0705")
             }
             Self::Semantic(SemanticError::MatchBranchExpressionInvalidType { location, expected, found, reference }) => {
-                Self::format_line_with_reference(
-                    context,
+                Diagnostic::line_with_reference(
                     format!("expected `{}`, found `{}`", expected, found).as_str(),
                     location,
                     Some(reference),
                     Some("all branches must return the type returned by the first branch"),
                 )
+                .with_code("Z0706")
             }
             Self::Semantic(SemanticError::MatchBranchDuplicate { location, reference }) => {
-                Self::format_line_with_reference(
-                    context,
+                Diagnostic::line_with_reference(
                     "match expression contains a duplicate branch pattern",
                     location,
                     Some(reference),
                     Some("each pattern may occur only once"),
                 )
+                .with_code("Z0707")
+            }
+            Self::Semantic(SemanticError::MatchBranchPatternStructureFieldMissing { location, type_identifier, field_name }) => {
+                Diagnostic::line(
+                    format!("pattern does not mention field `{}` of structure `{}`", field_name, type_identifier).as_str(),
+                    location,
+                    Some("list all the structure fields or ignore the rest of them with `..`"),
+                )
+                .with_code("Z0708")
             }
 
             Self::Semantic(SemanticError::LoopWhileExpectedBooleanCondition { location, found }) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!("expected `bool`, found `{}`", found).as_str(),
                     location,
                     None,
                 )
+                .with_code("Z0709")
             }
             Self::Semantic(SemanticError::LoopBoundsExpectedConstantRangeExpression { location, found }) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!("expected a constant range expression, found `{}`", found).as_str(),
                     location,
                     Some("only constant ranges allowed, e.g. `for i in 0..42 { ... }`"),
                 )
+                .with_code("Z0710")
             }
 
             Self::Semantic(SemanticError::ConditionalExpectedBooleanCondition { location, found }) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!("expected `bool`, found `{}`", found).as_str(),
                     location,
                     None,
                 )
+                .with_code("Z0711")
             }
             Self::Semantic(SemanticError::ConditionalBranchTypesMismatch { location, expected, found, reference }) => {
-                Self::format_line_with_reference(
-                    context,
+                Diagnostic::line_with_reference(
                     format!("if and else branches return incompatible types `{}` and `{}`", expected, found).as_str(),
                     location,
                     Some(reference),
                     None,
                 )
+                .with_code("Z0712")
             }
             Self::Semantic(SemanticError::EntryPointMissing) => {
-                Self::format_message(
+                Diagnostic::message(
                     "function `main` is missing",
                     Some("create the `main` function in the entry point file `main.zn`"),
                 )
+                .with_code("Z0713")
+            }
+            Self::Semantic(SemanticError::FunctionPublicArgumentOutsideEntry { location, function }) => {
+                Diagnostic::line(
+                    format!(
+                        "function `{}` cannot have public arguments",
+                        function
+                    )
+                        .as_str(),
+                    location,
+                    Some("only the `main` function arguments can be declared as public inputs"),
+                )
+                .with_code("Z0714")
+            }
+            Self::Semantic(SemanticError::FunctionSelfArgumentNotFirst { location, function }) => {
+                Diagnostic::line(
+                    format!(
+                        "function `{}` has the `self` argument not in the first position",
+                        function
+                    )
+                        .as_str(),
+                    location,
+                    Some("the method receiver must be the first argument, e.g. `fn area(self, scale: u8) {}`"),
+                )
+                .with_code("Z0715")
+            }
+            Self::Semantic(SemanticError::FunctionGenericDuplicate { location, function, name }) => {
+                Diagnostic::line(
+                    format!(
+                        "function `{}` has the generic parameter `{}` declared more than once",
+                        function, name
+                    )
+                        .as_str(),
+                    location,
+                    Some("consider giving the generic parameters unique names"),
+                )
+                .with_code("Z0716")
+            }
+            Self::Semantic(SemanticError::FunctionGenericEntry { location }) => {
+                Diagnostic::line(
+                    "the entry function `main` cannot be generic",
+                    location,
+                    Some("the `main` function arguments must have concrete types"),
+                )
+                .with_code("Z0717")
+            }
+            Self::Semantic(SemanticError::FunctionGenericBoundExpectedTrait { location, found }) => {
+                Diagnostic::line(
+                    format!(
+                        "generic parameter bound expected a trait, found `{}`",
+                        found
+                    )
+                        .as_str(),
+                    location,
+                    None,
+                )
+                .with_code("Z0718")
+            }
+            Self::Semantic(SemanticError::FunctionGenericBoundNotSatisfied { location, function, r#type, r#trait }) => {
+                Diagnostic::line(
+                    format!(
+                        "function `{}` requires the trait `{}` to be implemented for `{}`",
+                        function, r#trait, r#type
+                    )
+                        .as_str(),
+                    location,
+                    Some(format!("consider implementing the trait, e.g. `impl {} for ... {{ ... }}`", r#trait).as_str()),
+                )
+                .with_code("Z0719")
+            }
+            Self::Semantic(SemanticError::FunctionWithoutBody { location, function }) => {
+                Diagnostic::line(
+                    format!(
+                        "function `{}` must have a body",
+                        function
+                    )
+                        .as_str(),
+                    location,
+                    Some("only trait methods may be declared without a body"),
+                )
+                .with_code("Z0720")
+            }
+            Self::Semantic(SemanticError::FunctionConstantGeneric { location, function }) => {
+                Diagnostic::line(
+                    format!(
+                        "constant function `{}` cannot be generic",
+                        function
+                    )
+                        .as_str(),
+                    location,
+                    Some("consider passing the generic values as constant arguments instead"),
+                )
+                .with_code("Z0721")
+            }
+            Self::Semantic(SemanticError::FunctionConstantUnsupportedStatement { location, function }) => {
+                Diagnostic::line(
+                    format!(
+                        "constant function `{}` cannot evaluate this at compile time",
+                        function
+                    )
+                        .as_str(),
+                    location,
+                    Some("constant functions may only declare and assign local variables, and contain loops, conditionals and `return` statements"),
+                )
+                .with_code("Z0722")
+            }
+            Self::Semantic(SemanticError::FunctionConstantRecursionLimit { location, function, limit }) => {
+                Diagnostic::line(
+                    format!(
+                        "constant function `{}` evaluation exceeded the recursion limit of {}",
+                        function, limit
+                    )
+                        .as_str(),
+                    location,
+                    Some("check whether the recursion has a terminating condition"),
+                )
+                .with_code("Z0723")
+            }
+            Self::Semantic(SemanticError::FunctionConstantStepLimit { location, function, limit }) => {
+                Diagnostic::line(
+                    format!(
+                        "constant function `{}` evaluation exceeded the limit of {} steps",
+                        function, limit
+                    )
+                        .as_str(),
+                    location,
+                    Some("each call and loop iteration of a constant function is a step"),
+                )
+                .with_code("Z0736")
+            }
+            Self::Semantic(SemanticError::ReturnOutsideFunction { location }) => {
+                Diagnostic::line(
+                    "`return` statement outside of a function body",
+                    location,
+                    None,
+                )
+                .with_code("Z0724")
+            }
+            Self::Semantic(SemanticError::BreakOutsideLoop { location }) => {
+                Diagnostic::line(
+                    "`break` statement outside of a loop body",
+                    location,
+                    None,
+                )
+                .with_code("Z0725")
+            }
+            Self::Semantic(SemanticError::ContinueOutsideLoop { location }) => {
+                Diagnostic::line(
+                    "`continue` statement outside of a loop body",
+                    location,
+                    None,
+                )
+                .with_code("Z0726")
             }
             Self::Semantic(SemanticError::ModuleNotFound { location, name }) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "file not found for module `{}`",
                         name
                     )
                         .as_str(),
                     location,
-                    Some(format!("create a file called `{}.zn` inside the `src` directory", name).as_str()),
+                    Some(format!("create a file called `{}.zn` or `{}/mod.zn` inside the module directory", name, name).as_str()),
                 )
+                .with_code("Z0727")
             }
             Self::Semantic(SemanticError::UseExpectedPath { location, found }) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "`use` expected an item path, but got `{}`",
                         found
@@ -2084,10 +2442,10 @@ impl Error {
                     location,
                     Some("consider specifying a valid path to an item to import"),
                 )
+                .with_code("Z0728")
             }
             Self::Semantic(SemanticError::ImplStatementExpectedStructureOrEnumeration { location, found }) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "`impl` expected a type with namespace, found `{}`",
                         found
@@ -2096,187 +2454,80 @@ impl Error {
                     location,
                     Some("only structures and enumerations can have an implementation"),
                 )
+                .with_code("Z0729")
             }
-            Self::Semantic(SemanticError::ConstantExpressionHasNonConstantElement { location, found }) => {
-                Self::format_line(
-                    context,
-                    format!("attempt to use a non-constant value `{}` in a constant expression", found).as_str(),
+
+            Self::Semantic(SemanticError::ImplStatementExpectedTrait { location, found }) => {
+                Diagnostic::line(
+                    format!(
+                        "`impl ... for` expected a trait, found `{}`",
+                        found
+                    )
+                        .as_str(),
                     location,
                     None,
                 )
+                .with_code("Z0730")
+            }
+            Self::Semantic(SemanticError::ImplStatementTraitMethodMissing { location, r#trait, method }) => {
+                Diagnostic::line(
+                    format!(
+                        "the trait `{}` method `{}` is not implemented",
+                        r#trait, method
+                    )
+                        .as_str(),
+                    location,
+                    Some("the trait methods without a default body must be implemented"),
+                )
+                .with_code("Z0731")
+            }
+            Self::Semantic(SemanticError::ImplStatementTraitMethodNotMember { location, r#trait, method }) => {
+                Diagnostic::line(
+                    format!(
+                        "method `{}` is not a member of the trait `{}`",
+                        method, r#trait
+                    )
+                        .as_str(),
+                    location,
+                    Some("consider moving the method to an inherent implementation, e.g. `impl Data { ... }`"),
+                )
+                .with_code("Z0732")
+            }
+            Self::Semantic(SemanticError::ImplStatementTraitMethodSignatureMismatch { location, r#trait, expected, found, reference }) => {
+                Diagnostic::line_with_reference(
+                    format!(
+                        "the trait `{}` method expected signature `{}`, found `{}`",
+                        r#trait, expected, found
+                    )
+                        .as_str(),
+                    location,
+                    Some(reference),
+                    None,
+                )
+                .with_code("Z0733")
             }
-        }
-    }
-
-    fn format_message(message: &str, help: Option<&str>) -> String {
-        let mut strings = Vec::with_capacity(8);
-        strings.push(String::new());
-        strings.push(format!(
-            "{}: {}",
-            "error".bright_red(),
-            message.bright_white()
-        ));
-        if let Some(help) = help {
-            strings.push(format!("{}: {}", "help".bright_white(), help.bright_blue()));
-        }
-        strings.push(String::new());
-        strings.join("\n")
-    }
-
-    fn format_line(
-        context: &[&str],
-        message: &str,
-        location: Location,
-        help: Option<&str>,
-    ) -> String {
-        let line_number_length = location.line.to_string().len();
-
-        let mut strings = Vec::with_capacity(8);
-        strings.push(String::new());
-        strings.push(format!(
-            "{}: {}",
-            "error".bright_red(),
-            message.bright_white()
-        ));
-        strings.push(format!(" {} {}", "-->".bright_cyan(), location));
-        strings.push(format!(
-            "{}{}",
-            " ".repeat(line_number_length + 1),
-            "|".bright_cyan()
-        ));
-        if let Some(line) = context.get(location.line - 1) {
-            strings.push(format!(
-                "{}{}",
-                (location.line.to_string() + " | ").bright_cyan(),
-                line
-            ));
-        }
-        strings.push(format!(
-            "{}{} {}{}",
-            " ".repeat(line_number_length + 1),
-            "|".bright_cyan(),
-            "_".repeat(location.column - 1).bright_red(),
-            "^".bright_red()
-        ));
-        if let Some(help) = help {
-            strings.push(format!("{}: {}", "help".bright_white(), help.bright_blue()));
-        }
-        strings.push(String::new());
-        strings.join("\n")
-    }
-
-    fn format_line_with_reference(
-        context: &[&str],
-        message: &str,
-        location: Location,
-        reference: Option<Location>,
-        help: Option<&str>,
-    ) -> String {
-        let line_number_length = location.line.to_string().len();
-
-        let mut strings = Vec::with_capacity(11);
-        strings.push(String::new());
-        strings.push(format!(
-            "{}: {}",
-            "error".bright_red(),
-            message.bright_white()
-        ));
-
-        if let Some(reference) = reference {
-            let line_number_length = reference.line.to_string().len();
-            strings.push(format!(
-                "{}{}",
-                " ".repeat(line_number_length + 1),
-                "|".bright_cyan()
-            ));
-            if let Some(line) = context.get(reference.line - 1) {
-                strings.push(format!(
-                    "{}{}",
-                    (reference.line.to_string() + " | ").bright_cyan(),
-                    line
-                ));
-            }
-            strings.push(format!(
-                "{}{} {}{}",
-                " ".repeat(line_number_length + 1),
-                "|".bright_cyan(),
-                "_".repeat(reference.column - 1).bright_red(),
-                "^".bright_red()
-            ));
-        }
-
-        strings.push(format!(" {} {}", "-->".bright_cyan(), location));
-
-        strings.push(format!(
-            "{}{}",
-            " ".repeat(line_number_length + 1),
-            "|".bright_cyan()
-        ));
-        if let Some(line) = context.get(location.line - 1) {
-            strings.push(format!(
-                "{}{}",
-                (location.line.to_string() + " | ").bright_cyan(),
-                line
-            ));
-        }
-        strings.push(format!(
-            "{}{} {}{}",
-            " ".repeat(line_number_length + 1),
-            "|".bright_cyan(),
-            "_".repeat(location.column - 1).bright_red(),
-            "^".bright_red()
-        ));
-
-        if let Some(help) = help {
-            strings.push(format!("{}: {}", "help".bright_white(), help.bright_blue()));
-        }
-        strings.push(String::new());
-        strings.join("\n")
-    }
-
-    fn format_range(
-        context: &[&str],
-        message: &'static str,
-        start: Location,
-        end: Location,
-        help: Option<&str>,
-    ) -> String {
-        let line_number_length = end.line.to_string().len();
 
-        let mut strings = Vec::with_capacity(8 + end.line - start.line);
-        strings.push(String::new());
-        strings.push(format!(
-            "{}: {}",
-            "error".bright_red(),
-            message.bright_white()
-        ));
-        strings.push(format!(" {} {}", "-->".bright_cyan(), start));
-        strings.push(format!(
-            "{}{}",
-            " ".repeat(line_number_length + 1),
-            "|".bright_cyan()
-        ));
-        for line_number in start.line..=end.line {
-            if let Some(line) = context.get(line_number - 1) {
-                strings.push(format!(
-                    "{}{}",
-                    (line_number.to_string() + " | ").bright_cyan(),
-                    line
-                ));
+            Self::Semantic(SemanticError::EnumerationVariantsMixed { location, type_identifier }) => {
+                Diagnostic::line(
+                    format!(
+                        "enumeration `{}` mixes variants with values and variants with data",
+                        type_identifier
+                    )
+                        .as_str(),
+                    location,
+                    Some("either assign a value to each variant, e.g. `A = 1`, or none of them"),
+                )
+                .with_code("Z0734")
+            }
+            Self::Semantic(SemanticError::ConstantExpressionHasNonConstantElement { location, found }) => {
+                Diagnostic::line(
+                    format!("attempt to use a non-constant value `{}` in a constant expression", found).as_str(),
+                    location,
+                    None,
+                )
+                .with_code("Z0735")
             }
         }
-        strings.push(format!(
-            "{}{} {}{}",
-            " ".repeat(line_number_length + 1),
-            "|".bright_cyan(),
-            "_".repeat(end.column - 1).bright_red(),
-            "^".bright_red()
-        ));
-        if let Some(help) = help {
-            strings.push(format!("{}: {}", "help".bright_white(), help.bright_blue()));
-        }
-        strings.push(String::new());
-        strings.join("\n")
     }
 }
 
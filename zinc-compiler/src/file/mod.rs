@@ -10,15 +10,17 @@ use std::convert::TryFrom;
 use std::io::Read;
 use std::path::PathBuf;
 use std::rc::Rc;
-use std::sync::RwLock;
 
-use lazy_static::lazy_static;
-
-use crate::generator::bytecode::Bytecode;
+use crate::build::context::Context as BuildContext;
+use crate::diagnostic::Diagnostic;
+use crate::diagnostic::Severity;
+use crate::error::Error as CompilerError;
+use crate::generator::Tree;
 use crate::semantic::analyzer::entry::Analyzer as EntryAnalyzer;
 use crate::semantic::analyzer::module::Analyzer as ModuleAnalyzer;
 use crate::semantic::scope::Scope;
 use crate::syntax::parser::Parser;
+use crate::warning::Warning;
 
 use self::error::Error;
 
@@ -27,59 +29,163 @@ pub struct File {
     code: String,
 }
 
-lazy_static! {
-    pub static ref INDEX: RwLock<Vec<PathBuf>> = RwLock::new(Vec::new());
-}
-
 impl File {
+    ///
+    /// Creates a file from the in-memory source code.
+    ///
+    /// The `path` is only used to refer to the file in the diagnostics and bytecode, so it
+    /// does not have to exist in the file system.
+    ///
+    pub fn new(path: PathBuf, code: String) -> Self {
+        Self { path, code }
+    }
+
+    ///
+    /// The file path, which the file is referred to by.
+    ///
+    pub fn path(&self) -> &PathBuf {
+        &self.path
+    }
+
+    ///
+    /// Analyzes the entry file.
+    ///
+    /// Yields the IR along with the warnings. If the warnings are denied, they are returned
+    /// as errors.
+    ///
+    /// The file is added to the file table of the `build_context`.
+    ///
     pub fn try_into_entry(
         self,
-        bytecode: Rc<RefCell<Bytecode>>,
+        build_context: &mut BuildContext,
         dependencies: HashMap<String, Rc<RefCell<Scope>>>,
-    ) -> Result<(), String> {
+        is_warning_denied: bool,
+    ) -> Result<(Tree, Vec<Diagnostic>), Vec<Diagnostic>> {
         let lines = self.code.lines().collect::<Vec<&str>>();
 
-        let next_file_id = INDEX.read().expect(crate::PANIC_MUTEX_SYNC).len();
-        INDEX
-            .write()
-            .expect(crate::PANIC_MUTEX_SYNC)
-            .push(self.path);
+        let file_index = build_context.register_file(self.path);
 
         let syntax_tree = Parser::default()
-            .parse(&self.code, Some(next_file_id))
-            .map_err(|error| error.format(&lines))?;
-
-        EntryAnalyzer::new()
-            .compile(syntax_tree, dependencies)
-            .map_err(|error| error.format(&lines))?
-            .write_all_to_bytecode(bytecode);
-
-        Ok(())
+            .parse(&self.code, Some(file_index))
+            .map_err(|errors| Self::errors(errors, &lines, build_context))?;
+
+        let (intermediate, warnings) =
+            EntryAnalyzer::new_in_build(build_context.state(), build_context.warnings())
+                .compile(syntax_tree, dependencies)
+                .map_err(|errors| Self::errors(errors, &lines, build_context))?;
+
+        Ok((
+            intermediate,
+            Self::warnings(warnings, &lines, build_context, is_warning_denied)?,
+        ))
     }
 
+    ///
+    /// Analyzes the module file.
+    ///
+    /// The module IR must be written to the bytecode after the entry is analyzed, since
+    /// the module generic functions may be instantiated by the entry.
+    ///
+    /// The `dependencies` are the module own submodules.
+    ///
+    /// The file is added to the file table of the `build_context`.
+    ///
     pub fn try_into_module(
         self,
-        bytecode: Rc<RefCell<Bytecode>>,
-    ) -> Result<Rc<RefCell<Scope>>, String> {
+        build_context: &mut BuildContext,
+        dependencies: HashMap<String, Rc<RefCell<Scope>>>,
+        is_warning_denied: bool,
+    ) -> Result<(Rc<RefCell<Scope>>, Tree, Vec<Diagnostic>), Vec<Diagnostic>> {
         let lines = self.code.lines().collect::<Vec<&str>>();
 
-        let next_file_id = INDEX.read().expect(crate::PANIC_MUTEX_SYNC).len();
-        INDEX
-            .write()
-            .expect(crate::PANIC_MUTEX_SYNC)
-            .push(self.path);
+        let file_index = build_context.register_file(self.path);
 
         let syntax_tree = Parser::default()
-            .parse(&self.code, Some(next_file_id))
-            .map_err(|error| error.format(&lines))?;
+            .parse(&self.code, Some(file_index))
+            .map_err(|errors| Self::errors(errors, &lines, build_context))?;
+
+        let (scope, intermediate, warnings) =
+            ModuleAnalyzer::new_in_build(build_context.state(), build_context.warnings())
+                .compile(syntax_tree, dependencies)
+                .map_err(|errors| Self::errors(errors, &lines, build_context))?;
+
+        Ok((
+            scope,
+            intermediate,
+            Self::warnings(warnings, &lines, build_context, is_warning_denied)?,
+        ))
+    }
 
-        let (scope, intermediate) = ModuleAnalyzer::new()
-            .compile(syntax_tree)
-            .map_err(|error| error.format(&lines))?;
+    ///
+    /// Converts the errors into diagnostics sorted by location, rendering them with the
+    /// source code `context`. The errors without location go last.
+    ///
+    fn errors(
+        errors: Vec<CompilerError>,
+        context: &[&str],
+        build_context: &BuildContext,
+    ) -> Vec<Diagnostic> {
+        let mut diagnostics = errors
+            .into_iter()
+            .map(CompilerError::diagnostic)
+            .collect::<Vec<Diagnostic>>();
+        diagnostics.sort_by_key(|diagnostic| (diagnostic.location.is_none(), diagnostic.location));
+
+        diagnostics
+            .into_iter()
+            .map(|diagnostic| Self::render(diagnostic, context, build_context))
+            .collect()
+    }
 
-        intermediate.write_all_to_bytecode(bytecode);
+    ///
+    /// Converts the warnings into diagnostics, rendering them with the source code `context`.
+    ///
+    /// If the warnings are denied, they are returned as errors.
+    ///
+    fn warnings(
+        warnings: Vec<Warning>,
+        context: &[&str],
+        build_context: &BuildContext,
+        is_warning_denied: bool,
+    ) -> Result<Vec<Diagnostic>, Vec<Diagnostic>> {
+        let is_denied = is_warning_denied && !warnings.is_empty();
+
+        let diagnostics = warnings
+            .into_iter()
+            .map(Warning::diagnostic)
+            .map(|diagnostic| {
+                if is_denied {
+                    diagnostic.with_severity(Severity::Error)
+                } else {
+                    diagnostic
+                }
+            })
+            .map(|diagnostic| Self::render(diagnostic, context, build_context))
+            .collect();
+
+        if is_denied {
+            Err(diagnostics)
+        } else {
+            Ok(diagnostics)
+        }
+    }
 
-        Ok(scope)
+    ///
+    /// Renders the diagnostic with the source code `context`, naming the file from the
+    /// `build_context` file table, which the diagnostic location refers to.
+    ///
+    fn render(
+        diagnostic: Diagnostic,
+        context: &[&str],
+        build_context: &BuildContext,
+    ) -> Diagnostic {
+        let file = diagnostic
+            .location
+            .and_then(|location| location.file_index)
+            .and_then(|file_index| build_context.file_path(file_index))
+            .map(|path| path.to_string_lossy().to_string());
+
+        diagnostic.with_file(file).render(context)
     }
 }
 
@@ -4,8 +4,11 @@
 
 use std::collections::HashMap;
 
+use serde_json::Value as JsonValue;
+
 use zinc_bytecode::data::types::DataType;
 use zinc_bytecode::data::values::Value as TemplateValue;
+use zinc_bytecode::Curve;
 use zinc_bytecode::Instruction;
 use zinc_bytecode::Program;
 
@@ -20,13 +23,18 @@ static PANIC_JSON_TEMPLATE_SERIALIZATION: &str =
 ///
 #[derive(Debug, PartialEq)]
 pub struct Bytecode {
+    curve: Curve,
     input_fields: Vec<(String, Type)>,
+    public_input_fields: Vec<(String, Type)>,
     output_type: Type,
     instructions: Vec<Instruction>,
 
     data_stack_pointer: usize,
     variable_addresses: HashMap<String, usize>,
     function_addresses: HashMap<usize, usize>,
+    unresolved_calls: Vec<(usize, usize)>,
+    return_addresses: Option<(usize, usize)>,
+    loop_addresses: Vec<(usize, usize)>,
 
     current_file: String,
     current_location: Location,
@@ -34,7 +42,7 @@ pub struct Bytecode {
 
 impl Default for Bytecode {
     fn default() -> Self {
-        Self::new()
+        Self::new(Curve::default())
     }
 }
 
@@ -43,13 +51,18 @@ impl Bytecode {
     const FUNCTION_ADDRESSES_HASHMAP_INITIAL_SIZE: usize = 16;
     const VARIABLE_ADDRESSES_HASHMAP_INITIAL_SIZE: usize = 16;
 
-    pub fn new() -> Self {
+    ///
+    /// Initializes the bytecode of a program compiled for the `curve` scalar field.
+    ///
+    pub fn new(curve: Curve) -> Self {
         let mut instructions = Vec::with_capacity(Self::INSTRUCTION_VECTOR_INITIAL_SIZE);
         instructions.push(Instruction::NoOperation(zinc_bytecode::NoOperation));
         instructions.push(Instruction::NoOperation(zinc_bytecode::NoOperation));
 
         Self {
+            curve,
             input_fields: vec![],
+            public_input_fields: vec![],
             output_type: Type::structure(vec![]),
             instructions,
 
@@ -60,6 +73,9 @@ impl Bytecode {
             function_addresses: HashMap::with_capacity(
                 Self::FUNCTION_ADDRESSES_HASHMAP_INITIAL_SIZE,
             ),
+            unresolved_calls: Vec::new(),
+            return_addresses: None,
+            loop_addresses: Vec::new(),
 
             current_file: String::new(),
             current_location: Location::new_beginning(None),
@@ -74,6 +90,7 @@ impl Bytecode {
         let address = self.instructions.len();
         self.function_addresses.insert(unique_id, address);
         self.data_stack_pointer = 0;
+        self.return_addresses = None;
 
         self.instructions.push(Instruction::FileMarker(
             zinc_bytecode::instructions::FileMarker::new(self.current_file.clone()),
@@ -83,10 +100,17 @@ impl Bytecode {
         ));
     }
 
+    ///
+    /// Starts the entry function.
+    ///
+    /// The arguments listed in `public_arguments` are allocated by the VM as public inputs
+    /// instead of the private witness.
+    ///
     pub fn start_main_function(
         &mut self,
         unique_id: usize,
         input_arguments: Vec<(String, Type)>,
+        public_arguments: Vec<String>,
         output_type: Option<Type>,
     ) {
         let input_size = input_arguments
@@ -98,6 +122,11 @@ impl Bytecode {
             .map(|r#type| r#type.size())
             .unwrap_or(0);
 
+        self.public_input_fields = input_arguments
+            .iter()
+            .filter(|(name, _type)| public_arguments.contains(name))
+            .cloned()
+            .collect();
         self.input_fields = input_arguments;
         self.output_type = output_type.unwrap_or_else(|| Type::structure(vec![]));
 
@@ -106,6 +135,7 @@ impl Bytecode {
         self.instructions[0] = Instruction::Call(zinc_bytecode::Call::new(address, input_size));
         self.instructions[1] = Instruction::Exit(zinc_bytecode::Exit::new(output_size));
         self.data_stack_pointer = 0;
+        self.return_addresses = None;
 
         self.instructions.push(Instruction::FileMarker(
             zinc_bytecode::instructions::FileMarker::new(self.current_file.clone()),
@@ -128,6 +158,46 @@ impl Bytecode {
         start_address
     }
 
+    ///
+    /// Binds the `identifier` to the already allocated `address`, e.g. to a part of
+    /// the match scrutinee destructured by a pattern.
+    ///
+    pub fn declare_variable_alias(&mut self, identifier: String, address: usize) {
+        self.variable_addresses.insert(identifier, address);
+    }
+
+    ///
+    /// Allocates the early return flag and value of the current function.
+    ///
+    /// Returns the addresses of the flag and the value.
+    ///
+    pub fn declare_return(&mut self, r#type: Type) -> (usize, usize) {
+        let flag_address = self.declare_variable(None, Type::boolean());
+        let value_address = self.declare_variable(None, r#type);
+        self.return_addresses = Some((flag_address, value_address));
+        (flag_address, value_address)
+    }
+
+    ///
+    /// Allocates the active and iteration flags of the loop being started.
+    ///
+    /// Returns the addresses of the active and iteration flags.
+    ///
+    pub fn declare_loop(&mut self) -> (usize, usize) {
+        let active_address = self.declare_variable(None, Type::boolean());
+        let iteration_address = self.declare_variable(None, Type::boolean());
+        self.loop_addresses
+            .push((active_address, iteration_address));
+        (active_address, iteration_address)
+    }
+
+    ///
+    /// Releases the flags of the innermost loop declared with `declare_loop`.
+    ///
+    pub fn end_loop(&mut self) {
+        self.loop_addresses.pop();
+    }
+
     pub fn push_instruction(&mut self, instruction: Instruction, location: Option<Location>) {
         if let Some(location) = location {
             if self.current_location != location {
@@ -148,6 +218,29 @@ impl Bytecode {
         self.instructions.push(instruction)
     }
 
+    ///
+    /// Pushes the function call instruction.
+    ///
+    /// If the function has not been written yet, e.g. a generic function instance or a function
+    /// called by one, its address is resolved when the bytecode is finalized.
+    ///
+    pub fn push_call(&mut self, unique_id: usize, input_size: usize, location: Option<Location>) {
+        let address = self.function_addresses.get(&unique_id).copied();
+
+        self.push_instruction(
+            Instruction::Call(zinc_bytecode::Call::new(
+                address.unwrap_or_default(),
+                input_size,
+            )),
+            location,
+        );
+
+        if address.is_none() {
+            self.unresolved_calls
+                .push((self.instructions.len() - 1, unique_id));
+        }
+    }
+
     pub fn get_function_address(&self, unique_id: usize) -> Option<usize> {
         self.function_addresses.get(&unique_id).copied()
     }
@@ -156,40 +249,84 @@ impl Bytecode {
         self.variable_addresses.get(name).copied()
     }
 
-    pub fn input_template_bytes(&self) -> Vec<u8> {
+    pub fn get_return_addresses(&self) -> Option<(usize, usize)> {
+        self.return_addresses
+    }
+
+    pub fn get_loop_addresses(&self) -> Option<(usize, usize)> {
+        self.loop_addresses.last().copied()
+    }
+
+    ///
+    /// The witness template, that is, the `main` function arguments with default values.
+    ///
+    pub fn input_template(&self) -> JsonValue {
         let input_type = self.input_types_as_struct();
-        let input_template_value = TemplateValue::default_from_type(&input_type);
-        match serde_json::to_string_pretty(&input_template_value.to_json()) {
-            Ok(json) => (json + "\n").into_bytes(),
-            Err(error) => {
-                panic!(PANIC_JSON_TEMPLATE_SERIALIZATION.to_owned() + error.to_string().as_str())
-            }
-        }
+        TemplateValue::default_from_type(&input_type).to_json()
     }
 
-    pub fn output_template_bytes(&self) -> Vec<u8> {
-        let output_bytecode_type = self.output_type.to_owned().into();
-        let output_value_template = TemplateValue::default_from_type(&output_bytecode_type);
-        match serde_json::to_string_pretty(&output_value_template.to_json()) {
-            Ok(json) => (json + "\n").into_bytes(),
-            Err(error) => {
-                panic!(PANIC_JSON_TEMPLATE_SERIALIZATION.to_owned() + error.to_string().as_str())
-            }
-        }
+    ///
+    /// The public data template, that is, the public inputs and the `main` function result
+    /// with default values.
+    ///
+    pub fn output_template(&self) -> JsonValue {
+        let output_bytecode_type = Program::public_data_type(
+            &self.public_input_types_as_struct(),
+            &self.output_type.to_owned().into(),
+        );
+        TemplateValue::default_from_type(&output_bytecode_type).to_json()
     }
 
-    pub fn into_bytes(self) -> Vec<u8> {
+    ///
+    /// Resolves the function calls and builds the VM program.
+    ///
+    pub fn into_program(mut self) -> Program {
+        self.resolve_calls();
+
         for (index, instruction) in self.instructions.iter().enumerate() {
             log::debug!("{:03} {:?}", index, instruction)
         }
 
-        let program = Program::new(
+        Program::new(
+            self.curve,
             self.input_types_as_struct(),
+            self.public_input_types_as_struct(),
             self.output_type.into(),
             self.instructions,
-        );
+        )
+    }
 
-        program.to_bytes()
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.into_program().to_bytes()
+    }
+
+    ///
+    /// Serializes the JSON template in the human-readable form, which is written to the
+    /// template files.
+    ///
+    pub fn template_bytes(template: JsonValue) -> Vec<u8> {
+        match serde_json::to_string_pretty(&template) {
+            Ok(json) => (json + "\n").into_bytes(),
+            Err(error) => {
+                panic!(PANIC_JSON_TEMPLATE_SERIALIZATION.to_owned() + error.to_string().as_str())
+            }
+        }
+    }
+
+    ///
+    /// Writes the addresses of the functions, which were called before being written.
+    ///
+    fn resolve_calls(&mut self) {
+        for (index, unique_id) in self.unresolved_calls.drain(..) {
+            let address = self
+                .function_addresses
+                .get(&unique_id)
+                .copied()
+                .expect(crate::PANIC_VALIDATED_DURING_SEMANTIC_ANALYSIS);
+            if let Instruction::Call(ref mut call) = self.instructions[index] {
+                call.address = address;
+            }
+        }
     }
 
     fn input_types_as_struct(&self) -> DataType {
@@ -200,10 +337,20 @@ impl Bytecode {
                 .collect(),
         )
     }
+
+    fn public_input_types_as_struct(&self) -> DataType {
+        DataType::Struct(
+            self.public_input_fields
+                .iter()
+                .map(|(name, r#type)| (name.to_owned(), r#type.to_owned().into()))
+                .collect(),
+        )
+    }
 }
 
 impl Into<Vec<Instruction>> for Bytecode {
-    fn into(self) -> Vec<Instruction> {
+    fn into(mut self) -> Vec<Instruction> {
+        self.resolve_calls();
         self.instructions
     }
 }
@@ -19,7 +19,9 @@ use zinc_bytecode::Instruction;
 use crate::generator::bytecode::Bytecode;
 use crate::generator::expression::operand::constant::Constant;
 use crate::generator::expression::operand::place::Place;
+use crate::generator::r#type::Type;
 use crate::lexical::token::location::Location;
+use crate::semantic::element::constant::Constant as SemanticConstant;
 
 use self::element::Element;
 use self::operand::Operand;
@@ -50,6 +52,42 @@ impl Expression {
         self.elements.push(Element::Operator { location, operator })
     }
 
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
+    ///
+    /// Removes the elements written after the first `length` ones.
+    ///
+    pub fn truncate(&mut self, length: usize) {
+        self.elements.truncate(length)
+    }
+
+    ///
+    /// Replaces the argument `index` of the argument list written last with the `constant`.
+    ///
+    pub fn replace_argument_constant(&mut self, index: usize, constant: &SemanticConstant) {
+        let list = self
+            .elements
+            .iter_mut()
+            .rev()
+            .find_map(|element| match element {
+                Element::Operand(Operand::List(list)) => Some(list),
+                _ => None,
+            })
+            .expect(crate::PANIC_VALIDATED_DURING_SEMANTIC_ANALYSIS);
+
+        let mut expression = Self::new();
+        if let Some(operand) = Operand::try_from_constant(constant) {
+            expression.push_operand(operand);
+        }
+        list.replace(index, expression);
+    }
+
     pub fn write_all_to_bytecode(self, bytecode: Rc<RefCell<Bytecode>>) {
         for element in self.elements.into_iter() {
             match element {
@@ -292,11 +330,7 @@ impl Expression {
                         );
                     }
                     Operator::Slice { access } => {
-                        Constant::new_integer(
-                            BigInt::from(access.offset),
-                            false,
-                            crate::BITLENGTH_FIELD,
-                        )
+                        Constant::new_field(BigInt::from(access.offset))
                         .write_all_to_bytecode(bytecode.clone());
                         bytecode.borrow_mut().push_instruction(
                             Instruction::Slice(zinc_bytecode::Slice::new(
@@ -337,6 +371,12 @@ impl Expression {
                         output_size,
                         location,
                     ),
+
+                    Operator::Variant {
+                        tag,
+                        payload_size,
+                        padding,
+                    } => Self::variant(bytecode.clone(), tag, payload_size, padding, location),
                 },
             }
         }
@@ -449,15 +489,9 @@ impl Expression {
         input_size: usize,
         location: Location,
     ) {
-        let address = bytecode
-            .borrow()
-            .get_function_address(unique_id)
-            .expect(crate::PANIC_VALIDATED_DURING_SEMANTIC_ANALYSIS);
-
-        bytecode.borrow_mut().push_instruction(
-            Instruction::Call(zinc_bytecode::Call::new(address, input_size)),
-            Some(location),
-        );
+        bytecode
+            .borrow_mut()
+            .push_call(unique_id, input_size, Some(location));
     }
 
     fn call_debug(
@@ -495,4 +529,48 @@ impl Expression {
             Some(location),
         );
     }
+
+    ///
+    /// Wraps the variant payload on the top of the stack into the tagged union value.
+    ///
+    /// The payload elements are casted to fields, so the values of different variants
+    /// can be used interchangeably, e.g. in conditional branches.
+    ///
+    fn variant(
+        bytecode: Rc<RefCell<Bytecode>>,
+        tag: usize,
+        payload_size: usize,
+        padding: usize,
+        location: Location,
+    ) {
+        let payload_address = bytecode
+            .borrow_mut()
+            .declare_variable(None, Type::array(Type::field(), payload_size));
+        bytecode.borrow_mut().push_instruction(
+            Instruction::StoreSequence(zinc_bytecode::StoreSequence::new(
+                payload_address,
+                payload_size,
+            )),
+            Some(location),
+        );
+
+        Constant::new_field(BigInt::from(tag))
+            .write_all_to_bytecode(bytecode.clone());
+
+        for index in 0..payload_size {
+            bytecode.borrow_mut().push_instruction(
+                Instruction::Load(zinc_bytecode::Load::new(payload_address + index)),
+                Some(location),
+            );
+            bytecode.borrow_mut().push_instruction(
+                Instruction::Cast(zinc_bytecode::Cast::new(ScalarType::Field)),
+                Some(location),
+            );
+        }
+
+        for _ in 0..padding {
+            Constant::new_field(BigInt::from(0))
+                .write_all_to_bytecode(bytecode.clone());
+        }
+    }
 }
@@ -3,13 +3,18 @@
 //!
 
 use crate::generator::expression::operand::block::Expression as BlockExpression;
+use crate::generator::expression::operand::block::Guard;
 use crate::generator::expression::Expression as GeneratorExpression;
+use crate::generator::r#type::Type;
 use crate::generator::statement::Statement;
+use crate::semantic::element::r#type::Type as SemanticType;
 
 #[derive(Debug, Default, Clone)]
 pub struct Builder {
     statements: Vec<Statement>,
     expression: Option<GeneratorExpression>,
+    guards: Vec<(usize, Guard)>,
+    expression_type: Option<Type>,
 }
 
 impl Builder {
@@ -17,11 +22,42 @@ impl Builder {
         self.statements.push(value);
     }
 
+    ///
+    /// Guards the rest of the block against execution after the last pushed statement
+    /// returns from the function.
+    ///
+    pub fn push_return_guard(&mut self) {
+        self.push_guard(Guard::Return);
+    }
+
+    ///
+    /// Guards the rest of the block against execution after the last pushed statement
+    /// breaks or continues the loop.
+    ///
+    pub fn push_loop_guard(&mut self) {
+        self.push_guard(Guard::LoopIteration);
+    }
+
     pub fn set_expression(&mut self, value: GeneratorExpression) {
         self.expression = Some(value);
     }
 
+    pub fn set_expression_type(&mut self, value: &SemanticType) {
+        self.expression_type = Type::try_from_semantic(value);
+    }
+
+    fn push_guard(&mut self, guard: Guard) {
+        if let Some(index) = self.statements.len().checked_sub(1) {
+            self.guards.push((index, guard));
+        }
+    }
+
     pub fn finish(self) -> BlockExpression {
-        BlockExpression::new(self.statements, self.expression)
+        BlockExpression::new(
+            self.statements,
+            self.expression,
+            self.guards,
+            self.expression_type,
+        )
     }
 }
@@ -7,10 +7,27 @@ pub mod builder;
 use std::cell::RefCell;
 use std::rc::Rc;
 
+use zinc_bytecode::Instruction;
+
 use crate::generator::bytecode::Bytecode;
+use crate::generator::expression::operand::constant::Constant;
 use crate::generator::expression::Expression as GeneratorExpression;
+use crate::generator::r#type::Type;
+use crate::generator::statement::loop_for::Statement as ForLoopStatement;
+use crate::generator::statement::r#return::Statement as ReturnStatement;
 use crate::generator::statement::Statement;
 
+///
+/// The guard, which skips the rest of the block after a control flow statement.
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Guard {
+    /// skips the rest of the block if the function has returned
+    Return,
+    /// skips the rest of the block if the loop iteration has been interrupted
+    LoopIteration,
+}
+
 ///
 /// The block expression which is translated to Zinc VM bytecode.
 ///
@@ -18,22 +35,77 @@ use crate::generator::statement::Statement;
 pub struct Expression {
     statements: Vec<Statement>,
     expression: Option<GeneratorExpression>,
+    guards: Vec<(usize, Guard)>,
+    expression_type: Option<Type>,
 }
 
 impl Expression {
-    pub fn new(statements: Vec<Statement>, expression: Option<GeneratorExpression>) -> Self {
+    pub fn new(
+        statements: Vec<Statement>,
+        expression: Option<GeneratorExpression>,
+        guards: Vec<(usize, Guard)>,
+        expression_type: Option<Type>,
+    ) -> Self {
         Self {
             statements,
             expression,
+            guards,
+            expression_type,
         }
     }
 
+    pub fn has_expression(&self) -> bool {
+        self.expression.is_some()
+    }
+
+    ///
+    /// The statements which may return from the function or interrupt the loop iteration are
+    /// followed by a guard, which skips the rest of the block. Since the guarded result is
+    /// discarded by the VM, it is replaced with zeros in the alternative branch.
+    ///
     pub fn write_all_to_bytecode(self, bytecode: Rc<RefCell<Bytecode>>) {
-        for statement in self.statements.into_iter() {
+        let mut guards_count = 0;
+        for (index, statement) in self.statements.into_iter().enumerate() {
             statement.write_all_to_bytecode(bytecode.clone());
+            for (_, guard) in self
+                .guards
+                .iter()
+                .filter(|(position, _)| *position == index)
+            {
+                match guard {
+                    Guard::Return => ReturnStatement::write_guard(bytecode.clone()),
+                    Guard::LoopIteration => {
+                        ForLoopStatement::write_iteration_guard(bytecode.clone())
+                    }
+                }
+                guards_count += 1;
+            }
         }
-        if let Some(expression) = self.expression {
-            expression.write_all_to_bytecode(bytecode);
+
+        let default_sequence = match (self.expression, self.expression_type) {
+            (Some(expression), Some(r#type)) => {
+                expression.write_all_to_bytecode(bytecode.clone());
+                Some(Constant::new_default_sequence(&r#type))
+            }
+            (Some(expression), None) => {
+                expression.write_all_to_bytecode(bytecode.clone());
+                None
+            }
+            (None, _) => None,
+        };
+
+        for _ in 0..guards_count {
+            if let Some(ref default_sequence) = default_sequence {
+                bytecode
+                    .borrow_mut()
+                    .push_instruction(Instruction::Else(zinc_bytecode::Else), None);
+                for constant in default_sequence.iter().cloned() {
+                    constant.write_all_to_bytecode(bytecode.clone());
+                }
+            }
+            bytecode
+                .borrow_mut()
+                .push_instruction(Instruction::EndIf(zinc_bytecode::EndIf), None);
         }
     }
 }
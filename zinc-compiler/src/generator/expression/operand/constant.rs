@@ -21,24 +21,35 @@ use crate::semantic::element::constant::Constant as SemanticConstant;
 #[derive(Debug, Clone)]
 pub struct Constant {
     pub value: BigInt,
-    pub is_signed: bool,
-    pub bitlength: usize,
+    pub r#type: ScalarType,
 }
 
 impl Constant {
     pub fn new_boolean(value: bool) -> Self {
         Self {
             value: if value { BigInt::one() } else { BigInt::zero() },
-            is_signed: false,
-            bitlength: crate::BITLENGTH_BOOLEAN,
+            r#type: ScalarType::Boolean,
         }
     }
 
     pub fn new_integer(value: BigInt, is_signed: bool, bitlength: usize) -> Self {
         Self {
             value,
-            is_signed,
-            bitlength,
+            r#type: ScalarType::Integer(IntegerType {
+                is_signed,
+                bitlength,
+            }),
+        }
+    }
+
+    ///
+    /// The field element bitlength is not stored, since it is defined by the curve
+    /// the bytecode is run on.
+    ///
+    pub fn new_field(value: BigInt) -> Self {
+        Self {
+            value,
+            r#type: ScalarType::Field,
         }
     }
 
@@ -48,11 +59,7 @@ impl Constant {
             (true, bitlength) => -(BigInt::one() << (bitlength - 1)),
         };
 
-        Self {
-            value,
-            is_signed,
-            bitlength,
-        }
+        Self::new_integer(value, is_signed, bitlength)
     }
 
     pub fn new_max(is_signed: bool, bitlength: usize) -> Self {
@@ -61,16 +68,44 @@ impl Constant {
             (true, bitlength) => (BigInt::one() << (bitlength - 1)) - BigInt::one(),
         };
 
-        Self {
-            value,
-            is_signed,
-            bitlength,
+        Self::new_integer(value, is_signed, bitlength)
+    }
+
+    ///
+    /// Creates the sequence of zero constants, which fills a value of `r#type`.
+    ///
+    pub fn new_default_sequence(r#type: &Type) -> Vec<Self> {
+        match r#type {
+            Type::Unit => vec![],
+            Type::Boolean => vec![Self::new_boolean(false)],
+            Type::IntegerUnsigned { bitlength } => {
+                vec![Self::new_integer(BigInt::zero(), false, *bitlength)]
+            }
+            Type::IntegerSigned { bitlength } => {
+                vec![Self::new_integer(BigInt::zero(), true, *bitlength)]
+            }
+            Type::Field => vec![Self::new_field(BigInt::zero())],
+            Type::Array { r#type, size } => {
+                let element = Self::new_default_sequence(r#type);
+                (0..*size).flat_map(|_| element.clone()).collect()
+            }
+            Type::Tuple { types } => types.iter().flat_map(Self::new_default_sequence).collect(),
+            Type::Structure { fields } => fields
+                .iter()
+                .flat_map(|(_name, r#type)| Self::new_default_sequence(r#type))
+                .collect(),
+            Type::TaggedUnion { .. } => (0..r#type.size())
+                .map(|_| Self::new_field(BigInt::zero()))
+                .collect(),
         }
     }
 
     pub fn try_from_semantic(constant: &SemanticConstant) -> Option<Self> {
         match constant {
             SemanticConstant::Boolean(boolean) => Some(Self::new_boolean(boolean.inner)),
+            SemanticConstant::Integer(integer) if integer.is_field() => {
+                Some(Self::new_field(integer.value.to_owned()))
+            }
             SemanticConstant::Integer(integer) => Some(Self::new_integer(
                 integer.value.to_owned(),
                 integer.is_signed,
@@ -80,26 +115,46 @@ impl Constant {
         }
     }
 
-    pub fn r#type(&self) -> Type {
-        match (self.is_signed, self.bitlength) {
-            (false, crate::BITLENGTH_BOOLEAN) => Type::boolean(),
-            (false, crate::BITLENGTH_FIELD) => Type::field(),
-            (is_signed, bitlength) => Type::integer(is_signed, bitlength),
+    ///
+    /// Flattens the semantic constant, which can be an array, tuple or structure,
+    /// into the sequence of scalar constants.
+    ///
+    /// Returns `None` if some part of the constant cannot be represented at runtime.
+    ///
+    pub fn try_from_semantic_sequence(constant: &SemanticConstant) -> Option<Vec<Self>> {
+        let values: Vec<&SemanticConstant> = match constant {
+            SemanticConstant::Unit => return Some(vec![]),
+            SemanticConstant::Array(array) => array.values.iter().collect(),
+            SemanticConstant::Tuple(tuple) => tuple.values.iter().collect(),
+            SemanticConstant::Structure(structure) => structure
+                .values
+                .iter()
+                .map(|(_name, value)| value)
+                .collect(),
+            constant => return Self::try_from_semantic(constant).map(|constant| vec![constant]),
+        };
+
+        let mut sequence = Vec::with_capacity(values.len());
+        for value in values.into_iter() {
+            sequence.extend(Self::try_from_semantic_sequence(value)?);
         }
+        Some(sequence)
     }
 
-    pub fn write_all_to_bytecode(self, bytecode: Rc<RefCell<Bytecode>>) {
-        let scalar_type = match (self.is_signed, self.bitlength) {
-            (false, crate::BITLENGTH_BOOLEAN) => ScalarType::Boolean,
-            (false, crate::BITLENGTH_FIELD) => ScalarType::Field,
-            (is_signed, bitlength) => ScalarType::Integer(IntegerType {
+    pub fn r#type(&self) -> Type {
+        match self.r#type {
+            ScalarType::Boolean => Type::boolean(),
+            ScalarType::Field => Type::field(),
+            ScalarType::Integer(IntegerType {
                 is_signed,
                 bitlength,
-            }),
-        };
+            }) => Type::integer(is_signed, bitlength),
+        }
+    }
 
+    pub fn write_all_to_bytecode(self, bytecode: Rc<RefCell<Bytecode>>) {
         bytecode.borrow_mut().push_instruction(
-            Instruction::PushConst(PushConst::new(self.value, scalar_type)),
+            Instruction::PushConst(PushConst::new(self.value, self.r#type)),
             None,
         );
     }
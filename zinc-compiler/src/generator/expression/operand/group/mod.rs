@@ -8,6 +8,8 @@ use std::cell::RefCell;
 use std::rc::Rc;
 
 use crate::generator::bytecode::Bytecode;
+use crate::generator::expression::operand::constant::Constant;
+use crate::generator::expression::operand::Operand;
 use crate::generator::expression::Expression as GeneratorExpression;
 use crate::generator::r#type::Type;
 
@@ -24,6 +26,23 @@ impl Expression {
         Self { expressions }
     }
 
+    ///
+    /// Creates a group of scalar constants, e.g. a tagged union variant without data.
+    ///
+    pub fn new_constants(constants: Vec<Constant>) -> Self {
+        Self {
+            expressions: constants
+                .into_iter()
+                .map(|constant| {
+                    let r#type = constant.r#type();
+                    let mut expression = GeneratorExpression::new();
+                    expression.push_operand(Operand::Constant(constant));
+                    (r#type, expression)
+                })
+                .collect(),
+        }
+    }
+
     pub fn write_all_to_bytecode(self, bytecode: Rc<RefCell<Bytecode>>) {
         for (_type, expression) in self.expressions.into_iter() {
             expression.write_all_to_bytecode(bytecode.clone());
@@ -23,6 +23,10 @@ impl Expression {
         Self { expressions }
     }
 
+    pub fn replace(&mut self, index: usize, expression: GeneratorExpression) {
+        self.expressions[index] = expression;
+    }
+
     pub fn write_all_to_bytecode(self, bytecode: Rc<RefCell<Bytecode>>) {
         for expression in self.expressions.into_iter() {
             expression.write_all_to_bytecode(bytecode.clone());
@@ -3,6 +3,7 @@
 //!
 
 use crate::generator::expression::operand::constant::Constant;
+use crate::generator::expression::operand::r#match::Branch;
 use crate::generator::expression::operand::r#match::Expression as MatchExpression;
 use crate::generator::expression::Expression as GeneratorExpression;
 use crate::generator::r#type::Type;
@@ -13,9 +14,7 @@ pub struct Builder {
     location: Option<Location>,
     scrutinee: Option<GeneratorExpression>,
     scrutinee_type: Option<Type>,
-    branches: Vec<(Constant, GeneratorExpression)>,
-    binding_branch: Option<(GeneratorExpression, String)>,
-    wildcard_branch: Option<GeneratorExpression>,
+    branches: Vec<Branch>,
 }
 
 impl Builder {
@@ -28,16 +27,13 @@ impl Builder {
         self.scrutinee_type = Some(r#type);
     }
 
-    pub fn push_branch(&mut self, pattern: Constant, expression: GeneratorExpression) {
-        self.branches.push((pattern, expression));
-    }
-
-    pub fn set_binding_branch(&mut self, expression: GeneratorExpression, name: String) {
-        self.binding_branch = Some((expression, name));
-    }
-
-    pub fn set_wildcard_branch(&mut self, value: GeneratorExpression) {
-        self.wildcard_branch = Some(value);
+    pub fn push_branch(
+        &mut self,
+        tests: Vec<(usize, Constant)>,
+        bindings: Vec<(String, usize, Option<Type>)>,
+        expression: GeneratorExpression,
+    ) {
+        self.branches.push(Branch::new(tests, bindings, expression));
     }
 
     pub fn finish(mut self) -> MatchExpression {
@@ -58,32 +54,17 @@ impl Builder {
             )
         });
 
-        match self.binding_branch.take() {
-            Some(binding_branch) => MatchExpression::new(
-                location,
-                scrutinee,
-                scrutinee_type,
-                self.branches,
-                Some(binding_branch),
-                None,
-            ),
-            None => {
-                let wildcard_branch = self.wildcard_branch.take().unwrap_or_else(|| {
-                    panic!(
-                        "{}{}",
-                        crate::PANIC_BUILDER_REQUIRES_VALUE,
-                        "wildcard_branch"
-                    )
-                });
-                MatchExpression::new(
-                    location,
-                    scrutinee,
-                    scrutinee_type,
-                    self.branches,
-                    None,
-                    Some(wildcard_branch),
-                )
-            }
-        }
+        let default_branch = self
+            .branches
+            .pop()
+            .unwrap_or_else(|| panic!("{}{}", crate::PANIC_BUILDER_REQUIRES_VALUE, "branches"));
+
+        MatchExpression::new(
+            location,
+            scrutinee,
+            scrutinee_type,
+            self.branches,
+            default_branch,
+        )
     }
 }
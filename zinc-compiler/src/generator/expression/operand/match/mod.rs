@@ -18,14 +18,47 @@ use crate::lexical::token::location::Location;
 ///
 /// The match expression which is translated to Zinc VM conditional series.
 ///
+/// The last branch is written as the final `else` block, since the match is checked to be
+/// exhaustive during the semantic analysis.
+///
 #[derive(Debug, Clone)]
 pub struct Expression {
     location: Location,
     scrutinee: GeneratorExpression,
     scrutinee_type: Type,
-    branches: Vec<(Constant, GeneratorExpression)>,
-    binding_branch: Option<(GeneratorExpression, String)>,
-    wildcard_branch: Option<GeneratorExpression>,
+    branches: Vec<Branch>,
+    default_branch: Branch,
+}
+
+///
+/// The match branch.
+///
+/// The `tests` are the scalar constants the scrutinee elements at the specified offsets
+/// are compared with, and the `bindings` are the variables aliasing the scrutinee elements
+/// at the specified offsets.
+///
+/// If a binding has a type, it is copied to a new variable with its elements casted
+/// to the type, since the tagged union payloads are stored as fields.
+///
+#[derive(Debug, Clone)]
+pub struct Branch {
+    tests: Vec<(usize, Constant)>,
+    bindings: Vec<(String, usize, Option<Type>)>,
+    expression: GeneratorExpression,
+}
+
+impl Branch {
+    pub fn new(
+        tests: Vec<(usize, Constant)>,
+        bindings: Vec<(String, usize, Option<Type>)>,
+        expression: GeneratorExpression,
+    ) -> Self {
+        Self {
+            tests,
+            bindings,
+            expression,
+        }
+    }
 }
 
 impl Expression {
@@ -33,17 +66,15 @@ impl Expression {
         location: Location,
         scrutinee: GeneratorExpression,
         scrutinee_type: Type,
-        branches: Vec<(Constant, GeneratorExpression)>,
-        binding_branch: Option<(GeneratorExpression, String)>,
-        wildcard_branch: Option<GeneratorExpression>,
+        branches: Vec<Branch>,
+        default_branch: Branch,
     ) -> Self {
         Self {
             location,
             scrutinee,
             scrutinee_type,
             branches,
-            binding_branch,
-            wildcard_branch,
+            default_branch,
         }
     }
 
@@ -51,14 +82,9 @@ impl Expression {
         let branch_count = self.branches.len();
         let scrutinee_size = self.scrutinee_type.size();
 
-        let (binding_branch, binding_name) = match self.binding_branch {
-            Some((binding_branch, binding_name)) => (Some(binding_branch), Some(binding_name)),
-            None => (None, None),
-        };
-
         let scrutinee_address = bytecode
             .borrow_mut()
-            .declare_variable(binding_name, self.scrutinee_type);
+            .declare_variable(None, self.scrutinee_type);
 
         self.scrutinee.write_all_to_bytecode(bytecode.clone());
         bytecode.borrow_mut().push_instruction(
@@ -69,42 +95,90 @@ impl Expression {
             Some(self.location),
         );
 
-        for (branch_pattern, branch_expression) in self.branches.into_iter() {
-            bytecode.borrow_mut().push_instruction(
-                Instruction::LoadSequence(zinc_bytecode::LoadSequence::new(
-                    scrutinee_address,
-                    scrutinee_size,
-                )),
-                Some(self.location),
-            );
-            branch_pattern.write_all_to_bytecode(bytecode.clone());
-            bytecode
-                .borrow_mut()
-                .push_instruction(Instruction::Eq(zinc_bytecode::Eq), Some(self.location));
+        for branch in self.branches.into_iter() {
+            for (index, (offset, constant)) in branch.tests.into_iter().enumerate() {
+                bytecode.borrow_mut().push_instruction(
+                    Instruction::Load(zinc_bytecode::Load::new(scrutinee_address + offset)),
+                    Some(self.location),
+                );
+                constant.write_all_to_bytecode(bytecode.clone());
+                bytecode
+                    .borrow_mut()
+                    .push_instruction(Instruction::Eq(zinc_bytecode::Eq), Some(self.location));
+                if index > 0 {
+                    bytecode.borrow_mut().push_instruction(
+                        Instruction::And(zinc_bytecode::And),
+                        Some(self.location),
+                    );
+                }
+            }
             bytecode
                 .borrow_mut()
                 .push_instruction(Instruction::If(zinc_bytecode::If), Some(self.location));
-            branch_expression.write_all_to_bytecode(bytecode.clone());
+            Self::write_bindings(
+                bytecode.clone(),
+                branch.bindings,
+                scrutinee_address,
+                self.location,
+            );
+            branch.expression.write_all_to_bytecode(bytecode.clone());
             bytecode
                 .borrow_mut()
                 .push_instruction(Instruction::Else(zinc_bytecode::Else), Some(self.location));
         }
 
-        if let Some(binding_branch) = binding_branch {
-            binding_branch.write_all_to_bytecode(bytecode.clone());
-        } else if let Some(wildcard_branch) = self.wildcard_branch {
-            wildcard_branch.write_all_to_bytecode(bytecode.clone());
-        }
-
-        bytecode.borrow_mut().push_instruction(
-            Instruction::EndIf(zinc_bytecode::EndIf),
-            Some(self.location),
+        Self::write_bindings(
+            bytecode.clone(),
+            self.default_branch.bindings,
+            scrutinee_address,
+            self.location,
         );
-        for _ in 0..branch_count - 1 {
+        self.default_branch
+            .expression
+            .write_all_to_bytecode(bytecode.clone());
+
+        for _ in 0..branch_count {
             bytecode.borrow_mut().push_instruction(
                 Instruction::EndIf(zinc_bytecode::EndIf),
                 Some(self.location),
             );
         }
     }
+
+    fn write_bindings(
+        bytecode: Rc<RefCell<Bytecode>>,
+        bindings: Vec<(String, usize, Option<Type>)>,
+        scrutinee_address: usize,
+        location: Location,
+    ) {
+        for (name, offset, r#type) in bindings.into_iter() {
+            let r#type = match r#type {
+                Some(r#type) => r#type,
+                None => {
+                    bytecode
+                        .borrow_mut()
+                        .declare_variable_alias(name, scrutinee_address + offset);
+                    continue;
+                }
+            };
+
+            let address = bytecode
+                .borrow_mut()
+                .declare_variable(Some(name), r#type.clone());
+            for (index, scalar_type) in r#type.into_flat_scalar_types().into_iter().enumerate() {
+                bytecode.borrow_mut().push_instruction(
+                    Instruction::Load(zinc_bytecode::Load::new(scrutinee_address + offset + index)),
+                    Some(location),
+                );
+                bytecode.borrow_mut().push_instruction(
+                    Instruction::Cast(zinc_bytecode::Cast::new(scalar_type)),
+                    Some(location),
+                );
+                bytecode.borrow_mut().push_instruction(
+                    Instruction::Store(zinc_bytecode::Store::new(address + index)),
+                    Some(location),
+                );
+            }
+        }
+    }
 }
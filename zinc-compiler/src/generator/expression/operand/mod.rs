@@ -17,6 +17,7 @@ use std::rc::Rc;
 use zinc_bytecode::Instruction;
 
 use crate::generator::bytecode::Bytecode;
+use crate::semantic::element::constant::Constant as SemanticConstant;
 
 use self::array::Expression as ArrayExpression;
 use self::block::Expression as BlockExpression;
@@ -43,6 +44,21 @@ pub enum Operand {
 }
 
 impl Operand {
+    ///
+    /// Translates the semantic constant to a scalar constant operand, or to a group of
+    /// scalar constants if the constant is an array, tuple or structure.
+    ///
+    pub fn try_from_constant(constant: &SemanticConstant) -> Option<Self> {
+        match constant {
+            SemanticConstant::Array(_)
+            | SemanticConstant::Tuple(_)
+            | SemanticConstant::Structure(_) => Constant::try_from_semantic_sequence(constant)
+                .map(GroupExpression::new_constants)
+                .map(Self::Group),
+            constant => Constant::try_from_semantic(constant).map(Self::Constant),
+        }
+    }
+
     pub fn write_all_to_bytecode(self, bytecode: Rc<RefCell<Bytecode>>) {
         match self {
             Self::Constant(inner) => inner.write_all_to_bytecode(bytecode),
@@ -30,7 +30,7 @@ pub struct Place {
 impl Place {
     pub fn write_all_to_bytecode(self, bytecode: Rc<RefCell<Bytecode>>) {
         if !self.elements.is_empty() {
-            Constant::new_integer(BigInt::zero(), false, crate::BITLENGTH_FIELD)
+            Constant::new_field(BigInt::zero())
                 .write_all_to_bytecode(bytecode.clone());
         }
         for element in self.elements.into_iter() {
@@ -43,11 +43,7 @@ impl Place {
                         Instruction::Cast(zinc_bytecode::Cast::new(ScalarType::Field)),
                         Some(self.location),
                     );
-                    Constant::new_integer(
-                        BigInt::from(access.element_size),
-                        false,
-                        crate::BITLENGTH_FIELD,
-                    )
+                    Constant::new_field(BigInt::from(access.element_size))
                     .write_all_to_bytecode(bytecode.clone());
                     bytecode.borrow_mut().push_instruction(
                         Instruction::Mul(zinc_bytecode::Mul),
@@ -64,11 +60,7 @@ impl Place {
                         Instruction::Cast(zinc_bytecode::Cast::new(ScalarType::Field)),
                         Some(self.location),
                     );
-                    Constant::new_integer(
-                        BigInt::from(access.element_size),
-                        false,
-                        crate::BITLENGTH_FIELD,
-                    )
+                    Constant::new_field(BigInt::from(access.element_size))
                     .write_all_to_bytecode(bytecode.clone());
                     bytecode.borrow_mut().push_instruction(
                         Instruction::Mul(zinc_bytecode::Mul),
@@ -80,11 +72,7 @@ impl Place {
                     );
                 }
                 SemanticPlaceElement::IndexRange { start, access, .. } => {
-                    Constant::new_integer(
-                        start * BigInt::from(access.element_size),
-                        false,
-                        crate::BITLENGTH_FIELD,
-                    )
+                    Constant::new_field(start * BigInt::from(access.element_size))
                     .write_all_to_bytecode(bytecode.clone());
                     bytecode.borrow_mut().push_instruction(
                         Instruction::Add(zinc_bytecode::Add),
@@ -92,11 +80,7 @@ impl Place {
                     );
                 }
                 SemanticPlaceElement::IndexRangeInclusive { start, access, .. } => {
-                    Constant::new_integer(
-                        start * BigInt::from(access.element_size),
-                        false,
-                        crate::BITLENGTH_FIELD,
-                    )
+                    Constant::new_field(start * BigInt::from(access.element_size))
                     .write_all_to_bytecode(bytecode.clone());
                     bytecode.borrow_mut().push_instruction(
                         Instruction::Add(zinc_bytecode::Add),
@@ -104,11 +88,7 @@ impl Place {
                     );
                 }
                 SemanticPlaceElement::Field { access } => {
-                    Constant::new_integer(
-                        BigInt::from(access.offset),
-                        false,
-                        crate::BITLENGTH_FIELD,
-                    )
+                    Constant::new_field(BigInt::from(access.offset))
                     .write_all_to_bytecode(bytecode.clone());
                     bytecode.borrow_mut().push_instruction(
                         Instruction::Add(zinc_bytecode::Add),
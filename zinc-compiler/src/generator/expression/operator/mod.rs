@@ -131,6 +131,13 @@ pub enum Operator {
         input_size: usize,
         output_size: usize,
     },
+
+    // tagged union variant construction
+    Variant {
+        tag: usize,
+        payload_size: usize,
+        padding: usize,
+    },
 }
 
 impl Operator {
@@ -175,4 +182,12 @@ impl Operator {
             output_size,
         }
     }
+
+    pub fn variant(tag: usize, payload_size: usize, size: usize) -> Self {
+        Self::Variant {
+            tag,
+            payload_size,
+            padding: size - 1 - payload_size,
+        }
+    }
 }
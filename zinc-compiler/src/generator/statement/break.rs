@@ -0,0 +1,45 @@
+//!
+//! The generator break statement.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use zinc_bytecode::Instruction;
+
+use crate::generator::bytecode::Bytecode;
+use crate::generator::expression::operand::constant::Constant;
+use crate::lexical::token::location::Location;
+
+///
+/// The Zinc VM loop break statement.
+///
+/// Since the VM executes every iteration of a loop, the break cannot jump out of the loop.
+/// Instead, it resets the loop active flag, which suppresses the remaining iterations, and
+/// the iteration flag, which guards the rest of the current iteration.
+///
+#[derive(Debug, Clone)]
+pub struct Statement {
+    pub location: Location,
+}
+
+impl Statement {
+    pub fn new(location: Location) -> Self {
+        Self { location }
+    }
+
+    pub fn write_all_to_bytecode(self, bytecode: Rc<RefCell<Bytecode>>) {
+        let (active_address, iteration_address) = bytecode
+            .borrow()
+            .get_loop_addresses()
+            .expect(crate::PANIC_VALIDATED_DURING_SEMANTIC_ANALYSIS);
+
+        for address in [active_address, iteration_address].iter() {
+            Constant::new_boolean(false).write_all_to_bytecode(bytecode.clone());
+            bytecode.borrow_mut().push_instruction(
+                Instruction::Store(zinc_bytecode::Store::new(*address)),
+                Some(self.location),
+            );
+        }
+    }
+}
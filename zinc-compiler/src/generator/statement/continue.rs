@@ -0,0 +1,41 @@
+//!
+//! The generator continue statement.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use zinc_bytecode::Instruction;
+
+use crate::generator::bytecode::Bytecode;
+use crate::generator::expression::operand::constant::Constant;
+use crate::lexical::token::location::Location;
+
+///
+/// The Zinc VM loop continue statement.
+///
+/// Resets the iteration flag, which guards the rest of the current iteration.
+///
+#[derive(Debug, Clone)]
+pub struct Statement {
+    pub location: Location,
+}
+
+impl Statement {
+    pub fn new(location: Location) -> Self {
+        Self { location }
+    }
+
+    pub fn write_all_to_bytecode(self, bytecode: Rc<RefCell<Bytecode>>) {
+        let (_active_address, iteration_address) = bytecode
+            .borrow()
+            .get_loop_addresses()
+            .expect(crate::PANIC_VALIDATED_DURING_SEMANTIC_ANALYSIS);
+
+        Constant::new_boolean(false).write_all_to_bytecode(bytecode.clone());
+        bytecode.borrow_mut().push_instruction(
+            Instruction::Store(zinc_bytecode::Store::new(iteration_address)),
+            Some(self.location),
+        );
+    }
+}
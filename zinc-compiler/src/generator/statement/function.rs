@@ -9,6 +9,7 @@ use zinc_bytecode::Instruction;
 
 use crate::generator::bytecode::Bytecode;
 use crate::generator::expression::operand::block::Expression;
+use crate::generator::expression::operand::constant::Constant;
 use crate::generator::r#type::Type;
 use crate::lexical::token::location::Location;
 use crate::semantic::element::r#type::Type as SemanticType;
@@ -21,10 +22,13 @@ pub struct Statement {
     pub location: Location,
     pub identifier: String,
     pub input_arguments: Vec<(String, Type)>,
+    pub public_arguments: Vec<String>,
     pub body: Expression,
     pub output_type: Option<Type>,
     pub unique_id: usize,
     pub is_main: bool,
+    pub has_return: bool,
+    pub is_terminated_by_return: bool,
 }
 
 impl Statement {
@@ -32,10 +36,13 @@ impl Statement {
         location: Location,
         identifier: String,
         input_arguments: Vec<(String, SemanticType)>,
+        public_arguments: Vec<String>,
         body: Expression,
         output_type: SemanticType,
         unique_id: usize,
         is_main: bool,
+        has_return: bool,
+        is_terminated_by_return: bool,
     ) -> Self {
         let input_arguments = input_arguments
             .into_iter()
@@ -51,10 +58,13 @@ impl Statement {
             location,
             identifier,
             input_arguments,
+            public_arguments,
             body,
             output_type,
             unique_id,
             is_main,
+            has_return,
+            is_terminated_by_return,
         }
     }
 
@@ -69,7 +79,8 @@ impl Statement {
             bytecode.borrow_mut().start_main_function(
                 self.unique_id,
                 self.input_arguments.clone(),
-                self.output_type,
+                self.public_arguments,
+                self.output_type.clone(),
             );
         } else {
             bytecode
@@ -83,11 +94,138 @@ impl Statement {
                 .declare_variable(Some(argument_name), argument_type);
         }
 
+        let return_addresses = if self.has_return {
+            Some(Self::write_return_initialization(
+                bytecode.clone(),
+                self.output_type.clone().unwrap_or_else(Type::unit),
+                self.location,
+            ))
+        } else {
+            None
+        };
+
+        let has_expression = self.body.has_expression() && !self.is_terminated_by_return;
         self.body.write_all_to_bytecode(bytecode.clone());
 
+        if let Some((flag_address, value_address)) = return_addresses {
+            Self::write_return_result(
+                bytecode.clone(),
+                flag_address,
+                value_address,
+                self.output_type.unwrap_or_else(Type::unit),
+                has_expression,
+                self.location,
+            );
+        }
+
         bytecode.borrow_mut().push_instruction(
             Instruction::Return(zinc_bytecode::Return::new(output_size)),
             Some(self.location),
         );
     }
+
+    ///
+    /// Allocates and initializes the early return flag and value.
+    ///
+    /// The value is initialized with zeros, since the VM discards the values written in a
+    /// conditional block to uninitialized memory.
+    ///
+    fn write_return_initialization(
+        bytecode: Rc<RefCell<Bytecode>>,
+        output_type: Type,
+        location: Location,
+    ) -> (usize, usize) {
+        let output_size = output_type.size();
+        let default_sequence = Constant::new_default_sequence(&output_type);
+        let (flag_address, value_address) = bytecode.borrow_mut().declare_return(output_type);
+
+        Constant::new_boolean(false).write_all_to_bytecode(bytecode.clone());
+        bytecode.borrow_mut().push_instruction(
+            Instruction::Store(zinc_bytecode::Store::new(flag_address)),
+            Some(location),
+        );
+
+        if output_size > 0 {
+            for constant in default_sequence.into_iter() {
+                constant.write_all_to_bytecode(bytecode.clone());
+            }
+            bytecode.borrow_mut().push_instruction(
+                Instruction::StoreSequence(zinc_bytecode::StoreSequence::new(
+                    value_address,
+                    output_size,
+                )),
+                Some(location),
+            );
+        }
+
+        (flag_address, value_address)
+    }
+
+    ///
+    /// Leaves the function result on the evaluation stack.
+    ///
+    /// If the function has returned early, the early return value is taken instead of
+    /// the body result.
+    ///
+    fn write_return_result(
+        bytecode: Rc<RefCell<Bytecode>>,
+        flag_address: usize,
+        value_address: usize,
+        output_type: Type,
+        has_expression: bool,
+        location: Location,
+    ) {
+        let output_size = output_type.size();
+        if output_size == 0 {
+            return;
+        }
+
+        if !has_expression {
+            bytecode.borrow_mut().push_instruction(
+                Instruction::LoadSequence(zinc_bytecode::LoadSequence::new(
+                    value_address,
+                    output_size,
+                )),
+                Some(location),
+            );
+            return;
+        }
+
+        let result_address = bytecode.borrow_mut().declare_variable(None, output_type);
+        bytecode.borrow_mut().push_instruction(
+            Instruction::StoreSequence(zinc_bytecode::StoreSequence::new(
+                result_address,
+                output_size,
+            )),
+            Some(location),
+        );
+        bytecode.borrow_mut().push_instruction(
+            Instruction::Load(zinc_bytecode::Load::new(flag_address)),
+            Some(location),
+        );
+        bytecode
+            .borrow_mut()
+            .push_instruction(Instruction::If(zinc_bytecode::If), Some(location));
+        bytecode.borrow_mut().push_instruction(
+            Instruction::LoadSequence(zinc_bytecode::LoadSequence::new(value_address, output_size)),
+            Some(location),
+        );
+        bytecode.borrow_mut().push_instruction(
+            Instruction::StoreSequence(zinc_bytecode::StoreSequence::new(
+                result_address,
+                output_size,
+            )),
+            Some(location),
+        );
+        bytecode
+            .borrow_mut()
+            .push_instruction(Instruction::EndIf(zinc_bytecode::EndIf), Some(location));
+        bytecode.borrow_mut().push_instruction(
+            Instruction::LoadSequence(zinc_bytecode::LoadSequence::new(
+                result_address,
+                output_size,
+            )),
+            Some(location),
+        );
+    }
 }
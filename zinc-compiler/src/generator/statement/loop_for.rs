@@ -12,6 +12,7 @@ use crate::generator::expression::operand::block::Expression as BlockExpression;
 use crate::generator::expression::operand::constant::Constant;
 use crate::generator::expression::Expression as GeneratorExpression;
 use crate::generator::r#type::Type;
+use crate::generator::statement::r#return::Statement as ReturnStatement;
 use crate::lexical::token::location::Location;
 
 use num_bigint::BigInt;
@@ -31,6 +32,9 @@ pub struct Statement {
     pub index_variable_bitlength: usize,
     pub while_condition: Option<GeneratorExpression>,
     pub body: BlockExpression,
+    pub has_return: bool,
+    pub has_break: bool,
+    pub has_continue: bool,
 }
 
 impl Statement {
@@ -44,6 +48,9 @@ impl Statement {
         index_variable_bitlength: usize,
         while_condition: Option<GeneratorExpression>,
         body: BlockExpression,
+        has_return: bool,
+        has_break: bool,
+        has_continue: bool,
     ) -> Self {
         Self {
             location,
@@ -55,6 +62,9 @@ impl Statement {
             index_variable_bitlength,
             while_condition,
             body,
+            has_return,
+            has_break,
+            has_continue,
         }
     }
 
@@ -79,28 +89,53 @@ impl Statement {
             Some(self.location),
         );
 
-        let while_allowed_address = if self.while_condition.is_some() {
-            let while_allowed = Constant::new_boolean(true);
-            let while_allowed_address = bytecode
-                .borrow_mut()
-                .declare_variable(None, while_allowed.r#type());
-            while_allowed.write_all_to_bytecode(bytecode.clone());
-            bytecode.borrow_mut().push_instruction(
-                Instruction::Store(zinc_bytecode::Store::new(while_allowed_address)),
-                Some(self.location),
-            );
-            Some(while_allowed_address)
+        let control_addresses = if self.has_break || self.has_continue {
+            Some(bytecode.borrow_mut().declare_loop())
         } else {
             None
         };
 
+        let active_address = match control_addresses {
+            Some((active_address, iteration_address)) => {
+                for address in [active_address, iteration_address].iter() {
+                    Constant::new_boolean(true).write_all_to_bytecode(bytecode.clone());
+                    bytecode.borrow_mut().push_instruction(
+                        Instruction::Store(zinc_bytecode::Store::new(*address)),
+                        Some(self.location),
+                    );
+                }
+                Some(active_address)
+            }
+            None if self.while_condition.is_some() => {
+                let while_allowed = Constant::new_boolean(true);
+                let while_allowed_address = bytecode
+                    .borrow_mut()
+                    .declare_variable(None, while_allowed.r#type());
+                while_allowed.write_all_to_bytecode(bytecode.clone());
+                bytecode.borrow_mut().push_instruction(
+                    Instruction::Store(zinc_bytecode::Store::new(while_allowed_address)),
+                    Some(self.location),
+                );
+                Some(while_allowed_address)
+            }
+            None => None,
+        };
+
         bytecode.borrow_mut().push_instruction(
             Instruction::LoopBegin(zinc_bytecode::LoopBegin::new(self.iterations_count)),
             Some(self.location),
         );
 
-        if let (Some(while_condition), Some(while_allowed_address)) =
-            (self.while_condition, while_allowed_address)
+        if let Some((_active_address, iteration_address)) = control_addresses {
+            Constant::new_boolean(true).write_all_to_bytecode(bytecode.clone());
+            bytecode.borrow_mut().push_instruction(
+                Instruction::Store(zinc_bytecode::Store::new(iteration_address)),
+                Some(self.location),
+            );
+        }
+
+        if let (Some(while_condition), Some(active_address)) =
+            (self.while_condition, active_address)
         {
             while_condition.write_all_to_bytecode(bytecode.clone());
             bytecode
@@ -112,7 +147,7 @@ impl Statement {
             Constant::new_boolean(false).write_all_to_bytecode(bytecode.clone());
             bytecode.borrow_mut().push_instruction(
                 Instruction::StoreSequence(zinc_bytecode::StoreSequence::new(
-                    while_allowed_address,
+                    active_address,
                     Type::boolean().size(),
                 )),
                 Some(self.location),
@@ -121,10 +156,12 @@ impl Statement {
                 Instruction::EndIf(zinc_bytecode::EndIf),
                 Some(self.location),
             );
+        }
 
+        if let Some(active_address) = active_address {
             bytecode.borrow_mut().push_instruction(
                 Instruction::LoadSequence(zinc_bytecode::LoadSequence::new(
-                    while_allowed_address,
+                    active_address,
                     Type::boolean().size(),
                 )),
                 Some(self.location),
@@ -132,13 +169,13 @@ impl Statement {
             bytecode
                 .borrow_mut()
                 .push_instruction(Instruction::If(zinc_bytecode::If), Some(self.location));
-            self.body.write_all_to_bytecode(bytecode.clone());
+            Self::write_body(bytecode.clone(), self.body, self.has_return);
             bytecode.borrow_mut().push_instruction(
                 Instruction::EndIf(zinc_bytecode::EndIf),
                 Some(self.location),
             );
         } else {
-            self.body.write_all_to_bytecode(bytecode.clone());
+            Self::write_body(bytecode.clone(), self.body, self.has_return);
         }
 
         if self.is_reversed {
@@ -214,5 +251,46 @@ impl Statement {
             Instruction::LoopEnd(zinc_bytecode::LoopEnd),
             Some(self.location),
         );
+
+        if control_addresses.is_some() {
+            bytecode.borrow_mut().end_loop();
+        }
+    }
+
+    ///
+    /// Opens a conditional block, which is only executed if the current iteration of the
+    /// innermost loop has not been interrupted with `break` or `continue`.
+    ///
+    /// The block must be closed with the `EndIf` instruction.
+    ///
+    pub fn write_iteration_guard(bytecode: Rc<RefCell<Bytecode>>) {
+        let (_active_address, iteration_address) = bytecode
+            .borrow()
+            .get_loop_addresses()
+            .expect(crate::PANIC_VALIDATED_DURING_SEMANTIC_ANALYSIS);
+
+        bytecode.borrow_mut().push_instruction(
+            Instruction::Load(zinc_bytecode::Load::new(iteration_address)),
+            None,
+        );
+        bytecode
+            .borrow_mut()
+            .push_instruction(Instruction::If(zinc_bytecode::If), None);
+    }
+
+    ///
+    /// Writes the loop body, which is skipped in the remaining iterations
+    /// if the function has returned early.
+    ///
+    fn write_body(bytecode: Rc<RefCell<Bytecode>>, body: BlockExpression, has_return: bool) {
+        if has_return {
+            ReturnStatement::write_guard(bytecode.clone());
+            body.write_all_to_bytecode(bytecode.clone());
+            bytecode
+                .borrow_mut()
+                .push_instruction(Instruction::EndIf(zinc_bytecode::EndIf), None);
+        } else {
+            body.write_all_to_bytecode(bytecode);
+        }
     }
 }
@@ -2,9 +2,12 @@
 //! The generator statement.
 //!
 
+pub mod r#break;
+pub mod r#continue;
 pub mod declaration;
 pub mod function;
 pub mod loop_for;
+pub mod r#return;
 
 use std::cell::RefCell;
 use std::rc::Rc;
@@ -14,6 +17,9 @@ use crate::generator::expression::Expression;
 use crate::generator::statement::declaration::Statement as DeclarationStatement;
 use crate::generator::statement::function::Statement as FunctionStatement;
 use crate::generator::statement::loop_for::Statement as ForLoopStatement;
+use crate::generator::statement::r#break::Statement as BreakStatement;
+use crate::generator::statement::r#continue::Statement as ContinueStatement;
+use crate::generator::statement::r#return::Statement as ReturnStatement;
 
 ///
 /// Statements translated to the target Zinc VM bytecode.
@@ -23,7 +29,12 @@ pub enum Statement {
     Expression(Expression),
     Declaration(DeclarationStatement),
     Loop(ForLoopStatement),
+    Return(ReturnStatement),
+    Break(BreakStatement),
+    Continue(ContinueStatement),
     Function(FunctionStatement),
+    /// the generic function instances, which are added as the function is called with new types
+    GenericFunction(Rc<RefCell<Vec<FunctionStatement>>>),
     Implementation(Vec<Self>),
 }
 
@@ -33,7 +44,15 @@ impl Statement {
             Self::Expression(inner) => inner.write_all_to_bytecode(bytecode),
             Self::Declaration(inner) => inner.write_all_to_bytecode(bytecode),
             Self::Loop(inner) => inner.write_all_to_bytecode(bytecode),
+            Self::Return(inner) => inner.write_all_to_bytecode(bytecode),
+            Self::Break(inner) => inner.write_all_to_bytecode(bytecode),
+            Self::Continue(inner) => inner.write_all_to_bytecode(bytecode),
             Self::Function(inner) => inner.write_all_to_bytecode(bytecode),
+            Self::GenericFunction(inner) => {
+                for instance in inner.borrow_mut().drain(..) {
+                    instance.write_all_to_bytecode(bytecode.clone());
+                }
+            }
             Self::Implementation(inner) => {
                 for element in inner.into_iter() {
                     element.write_all_to_bytecode(bytecode.clone());
@@ -0,0 +1,90 @@
+//!
+//! The generator return statement.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use zinc_bytecode::Instruction;
+
+use crate::generator::bytecode::Bytecode;
+use crate::generator::expression::operand::constant::Constant;
+use crate::generator::expression::Expression;
+use crate::generator::r#type::Type;
+use crate::lexical::token::location::Location;
+use crate::semantic::element::r#type::Type as SemanticType;
+
+///
+/// The Zinc VM early return statement.
+///
+/// Since the VM executes every branch of a circuit, the early return cannot jump out of the
+/// function. Instead, it stores the value into the function return slot and sets the return
+/// flag, which guards the rest of the function from being executed.
+///
+#[derive(Debug, Clone)]
+pub struct Statement {
+    pub location: Location,
+    pub expression: Option<Expression>,
+    pub size: usize,
+}
+
+impl Statement {
+    pub fn new(location: Location, expression: Option<Expression>, r#type: SemanticType) -> Self {
+        let size = Type::try_from_semantic(&r#type)
+            .map(|r#type| r#type.size())
+            .unwrap_or(0);
+
+        Self {
+            location,
+            expression,
+            size,
+        }
+    }
+
+    pub fn write_all_to_bytecode(self, bytecode: Rc<RefCell<Bytecode>>) {
+        let (flag_address, value_address) = bytecode
+            .borrow()
+            .get_return_addresses()
+            .expect(crate::PANIC_VALIDATED_DURING_SEMANTIC_ANALYSIS);
+
+        if let Some(expression) = self.expression {
+            expression.write_all_to_bytecode(bytecode.clone());
+            bytecode.borrow_mut().push_instruction(
+                Instruction::StoreSequence(zinc_bytecode::StoreSequence::new(
+                    value_address,
+                    self.size,
+                )),
+                Some(self.location),
+            );
+        }
+
+        Constant::new_boolean(true).write_all_to_bytecode(bytecode.clone());
+        bytecode.borrow_mut().push_instruction(
+            Instruction::Store(zinc_bytecode::Store::new(flag_address)),
+            Some(self.location),
+        );
+    }
+
+    ///
+    /// Opens a conditional block, which is only executed if the function has not returned yet.
+    ///
+    /// The block must be closed with the `EndIf` instruction.
+    ///
+    pub fn write_guard(bytecode: Rc<RefCell<Bytecode>>) {
+        let (flag_address, _value_address) = bytecode
+            .borrow()
+            .get_return_addresses()
+            .expect(crate::PANIC_VALIDATED_DURING_SEMANTIC_ANALYSIS);
+
+        bytecode.borrow_mut().push_instruction(
+            Instruction::Load(zinc_bytecode::Load::new(flag_address)),
+            None,
+        );
+        bytecode
+            .borrow_mut()
+            .push_instruction(Instruction::Not(zinc_bytecode::Not), None);
+        bytecode
+            .borrow_mut()
+            .push_instruction(Instruction::If(zinc_bytecode::If), None);
+    }
+}
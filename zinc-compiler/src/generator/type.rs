@@ -21,6 +21,7 @@ pub enum Type {
     Array { r#type: Box<Self>, size: usize },
     Tuple { types: Vec<Self> },
     Structure { fields: Vec<(String, Self)> },
+    TaggedUnion { variants: Vec<(String, Self)> },
 }
 
 impl Type {
@@ -67,6 +68,10 @@ impl Type {
         Self::Structure { fields }
     }
 
+    pub fn tagged_union(variants: Vec<(String, Self)>) -> Self {
+        Self::TaggedUnion { variants }
+    }
+
     pub fn size(&self) -> usize {
         match self {
             Self::Unit => 0,
@@ -77,6 +82,42 @@ impl Type {
             Self::Array { r#type, size } => r#type.size() * size,
             Self::Tuple { types } => types.iter().map(|r#type| r#type.size()).sum(),
             Self::Structure { fields } => fields.iter().map(|(_name, r#type)| r#type.size()).sum(),
+            Self::TaggedUnion { variants } => {
+                1 + variants
+                    .iter()
+                    .map(|(_name, r#type)| r#type.size())
+                    .max()
+                    .unwrap_or_default()
+            }
+        }
+    }
+
+    ///
+    /// Flattens the type into the scalar types of the elements it consists of.
+    ///
+    /// The tagged union elements are always fields, since the tag and payloads of different
+    /// variants share the same memory.
+    ///
+    pub fn into_flat_scalar_types(self) -> Vec<ScalarType> {
+        match self {
+            Self::Unit => vec![],
+            Self::Array { r#type, size } => {
+                let element = r#type.into_flat_scalar_types();
+                (0..size).flat_map(|_| element.clone()).collect()
+            }
+            Self::Tuple { types } => types
+                .into_iter()
+                .flat_map(Self::into_flat_scalar_types)
+                .collect(),
+            Self::Structure { fields } => fields
+                .into_iter()
+                .flat_map(|(_name, r#type)| r#type.into_flat_scalar_types())
+                .collect(),
+            r#type @ Self::TaggedUnion { .. } => vec![ScalarType::Field; r#type.size()],
+            r#type => {
+                let scalar_type: Option<ScalarType> = r#type.into();
+                scalar_type.into_iter().collect()
+            }
         }
     }
 
@@ -86,7 +127,7 @@ impl Type {
             SemanticType::Boolean => Some(Self::boolean()),
             SemanticType::IntegerUnsigned { bitlength } => Some(Self::integer_unsigned(*bitlength)),
             SemanticType::IntegerSigned { bitlength } => Some(Self::integer_signed(*bitlength)),
-            SemanticType::Field => Some(Self::field()),
+            SemanticType::Field { .. } => Some(Self::field()),
             SemanticType::Array { r#type, size } => {
                 Self::try_from_semantic(r#type).map(|r#type| Self::array(r#type, *size))
             }
@@ -116,6 +157,18 @@ impl Type {
             SemanticType::Enumeration(enumeration) => {
                 Some(Self::integer_unsigned(enumeration.bitlength))
             }
+            SemanticType::TaggedUnion(tagged_union) => Some(Self::tagged_union(
+                tagged_union
+                    .variants
+                    .iter()
+                    .map(|(name, r#type)| {
+                        (
+                            name.to_owned(),
+                            Self::try_from_semantic(r#type).unwrap_or_else(Self::unit),
+                        )
+                    })
+                    .collect(),
+            )),
             _ => None,
         }
     }
@@ -152,6 +205,12 @@ impl Into<DataType> for Type {
                     .map(|(name, r#type)| (name, r#type.into()))
                     .collect(),
             ),
+            Self::TaggedUnion { variants } => DataType::TaggedUnion(
+                variants
+                    .into_iter()
+                    .map(|(name, r#type)| (name, r#type.into()))
+                    .collect(),
+            ),
         }
     }
 }
@@ -93,6 +93,22 @@ impl Error {
         Self::UnexpectedEnd { location }
     }
 
+    ///
+    /// The error location, which is used to sort the errors before reporting them.
+    ///
+    pub fn location(&self) -> Location {
+        match self {
+            Self::UnterminatedBlockComment { start, .. }
+            | Self::UnterminatedDoubleQuoteString { start, .. } => *start,
+            Self::ExpectedOneOfBinary { location, .. }
+            | Self::ExpectedOneOfOctal { location, .. }
+            | Self::ExpectedOneOfDecimal { location, .. }
+            | Self::ExpectedOneOfHexadecimal { location, .. }
+            | Self::InvalidCharacter { location, .. }
+            | Self::UnexpectedEnd { location } => *location,
+        }
+    }
+
     fn join_expected(chars: Vec<char>) -> String {
         chars
             .into_iter()
@@ -14,9 +14,11 @@ use crate::lexical::error::Error;
 use crate::lexical::token::lexeme::identifier::Identifier;
 use crate::lexical::token::lexeme::literal::string::String as StringLiteral;
 use crate::lexical::token::lexeme::literal::Literal;
+use crate::lexical::token::lexeme::symbol::Symbol;
 use crate::lexical::token::lexeme::Lexeme;
 use crate::lexical::token::location::Location;
 use crate::lexical::token::Token;
+use crate::syntax::error::Error as SyntaxError;
 
 use self::comment::Error as CommentParserError;
 use self::integer::Error as IntegerParserError;
@@ -28,6 +30,9 @@ pub struct TokenStream<'a> {
     offset: usize,
     location: Location,
     look_ahead: VecDeque<Token>,
+    depth: usize,
+    terminated_depth: Option<usize>,
+    recovered_errors: Vec<SyntaxError>,
 }
 
 impl<'a> TokenStream<'a> {
@@ -43,12 +48,15 @@ impl<'a> TokenStream<'a> {
             offset: 0,
             location: Location::new_beginning(None),
             look_ahead: VecDeque::with_capacity(Self::DEQUE_LOOK_AHEAD_INITIAL_CAPACITY),
+            depth: 0,
+            terminated_depth: Some(0),
+            recovered_errors: Vec::new(),
         }
     }
 
     ///
     /// Initializes a stream with a file identifier.
-    /// The file identifier can be used to get its path from the global type index.
+    /// The file identifier can be used to get its path from the build file table.
     ///
     pub fn new_with_file(input: &'a str, file: usize) -> Self {
         Self {
@@ -56,6 +64,9 @@ impl<'a> TokenStream<'a> {
             offset: 0,
             location: Location::new_beginning(Some(file)),
             look_ahead: VecDeque::with_capacity(Self::DEQUE_LOOK_AHEAD_INITIAL_CAPACITY),
+            depth: 0,
+            terminated_depth: Some(0),
+            recovered_errors: Vec::new(),
         }
     }
 
@@ -69,9 +80,84 @@ impl<'a> TokenStream<'a> {
             None => self.advance()?,
         };
         log::debug!("{:?}", token);
+
+        match token.lexeme {
+            Lexeme::Symbol(Symbol::BracketCurlyLeft) => {
+                self.depth += 1;
+                self.terminated_depth = None;
+            }
+            Lexeme::Symbol(Symbol::BracketCurlyRight) => {
+                self.depth = self.depth.saturating_sub(1);
+                self.terminated_depth = Some(self.depth);
+            }
+            Lexeme::Symbol(Symbol::Semicolon) => self.terminated_depth = Some(self.depth),
+            _ => self.terminated_depth = None,
+        }
+
         Ok(token)
     }
 
+    ///
+    /// Skips the rest of an erroneous module level statement, that is, the tokens until
+    /// the `;` or the `}` at the top nesting level, which terminates the statement.
+    ///
+    /// The curly brackets are counted as the tokens are taken from the stream, so the tokens
+    /// already consumed by the parser which has failed are taken into account.
+    ///
+    pub fn skip_statement(&mut self) -> Result<(), Error> {
+        while self.terminated_depth != Some(0) {
+            if let Lexeme::Eof = self.next()?.lexeme {
+                break;
+            }
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Skips the rest of an erroneous statement of a block, whose statements are at the `depth`
+    /// nesting level, that is, the tokens until the `;` or the `}` at that level, which
+    /// terminates the statement, or the `}` which closes the block.
+    ///
+    /// Returns whether the block has been closed. If the input ends before that, an
+    /// 'unexpected end' error is returned.
+    ///
+    pub fn skip_block_statement(&mut self, depth: usize) -> Result<bool, Error> {
+        loop {
+            if self.depth < depth {
+                return Ok(true);
+            }
+            if self.terminated_depth == Some(depth) {
+                return Ok(false);
+            }
+            if let Lexeme::Eof = self.next()?.lexeme {
+                return Err(Error::unexpected_end(self.location));
+            }
+        }
+    }
+
+    ///
+    /// The nesting level of the curly brackets taken from the stream so far.
+    ///
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    ///
+    /// Stores the error of a statement, which the parser has recovered from, to be reported
+    /// along with the other errors when the parsing is finished.
+    ///
+    pub fn push_recovered_error(&mut self, error: SyntaxError) {
+        self.recovered_errors.push(error);
+    }
+
+    ///
+    /// Takes the errors of the statements, which the parser has recovered from.
+    ///
+    pub fn take_recovered_errors(&mut self) -> Vec<SyntaxError> {
+        std::mem::replace(&mut self.recovered_errors, Vec::new())
+    }
+
     ///
     /// Advances the iterator until there are `distance` elements in the look-ahead queue.
     /// Is used where there is a need to resolve an ambiguity like `if value {}`,
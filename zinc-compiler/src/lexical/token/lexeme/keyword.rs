@@ -20,6 +20,7 @@ pub enum Keyword {
     Mod,
     Use,
     Impl,
+    Trait,
 
     // controls
     For,
@@ -28,6 +29,9 @@ pub enum Keyword {
     If,
     Else,
     Match,
+    Return,
+    Break,
+    Continue,
 
     // types
     Bool,
@@ -44,16 +48,13 @@ pub enum Keyword {
 
     // special
     SelfUppercase,
+    SelfLowercase,
 
     // reserved
-    SelfLowercase,
     Pub,
     Ref,
     Extern,
-    Return,
     Loop,
-    Break,
-    Continue,
 }
 
 impl Keyword {
@@ -95,6 +96,7 @@ impl TryFrom<&str> for Keyword {
             "mod" => return Ok(Self::Mod),
             "use" => return Ok(Self::Use),
             "impl" => return Ok(Self::Impl),
+            "trait" => return Ok(Self::Trait),
 
             "for" => return Ok(Self::For),
             "in" => return Ok(Self::In),
@@ -102,6 +104,9 @@ impl TryFrom<&str> for Keyword {
             "if" => return Ok(Self::If),
             "else" => return Ok(Self::Else),
             "match" => return Ok(Self::Match),
+            "return" => return Ok(Self::Return),
+            "break" => return Ok(Self::Break),
+            "continue" => return Ok(Self::Continue),
 
             "bool" => return Ok(Self::Bool),
             "field" => return Ok(Self::Field),
@@ -112,15 +117,12 @@ impl TryFrom<&str> for Keyword {
             "as" => return Ok(Self::As),
 
             "Self" => return Ok(Self::SelfUppercase),
-
             "self" => return Ok(Self::SelfLowercase),
+
             "pub" => return Ok(Self::Pub),
             "ref" => return Ok(Self::Ref),
             "extern" => return Ok(Self::Extern),
-            "return" => return Ok(Self::Return),
             "loop" => return Ok(Self::Loop),
-            "break" => return Ok(Self::Break),
-            "continue" => return Ok(Self::Continue),
 
             _ => {}
         }
@@ -191,6 +193,7 @@ impl fmt::Display for Keyword {
             Self::Mod => write!(f, "mod"),
             Self::Use => write!(f, "use"),
             Self::Impl => write!(f, "impl"),
+            Self::Trait => write!(f, "trait"),
 
             Self::For => write!(f, "for"),
             Self::In => write!(f, "in"),
@@ -198,6 +201,9 @@ impl fmt::Display for Keyword {
             Self::If => write!(f, "if"),
             Self::Else => write!(f, "else"),
             Self::Match => write!(f, "match"),
+            Self::Return => write!(f, "return"),
+            Self::Break => write!(f, "break"),
+            Self::Continue => write!(f, "continue"),
 
             Self::Bool => write!(f, "bool"),
             Self::IntegerUnsigned { bitlength } => write!(f, "u{}", bitlength),
@@ -210,15 +216,12 @@ impl fmt::Display for Keyword {
             Self::As => write!(f, "as"),
 
             Self::SelfUppercase => write!(f, "Self"),
-
             Self::SelfLowercase => write!(f, "self"),
+
             Self::Pub => write!(f, "pub"),
             Self::Ref => write!(f, "ref"),
             Self::Extern => write!(f, "extern"),
-            Self::Return => write!(f, "return"),
             Self::Loop => write!(f, "loop"),
-            Self::Break => write!(f, "break"),
-            Self::Continue => write!(f, "continue"),
         }
     }
 }
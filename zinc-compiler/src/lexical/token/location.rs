@@ -4,7 +4,7 @@
 
 use std::fmt;
 
-#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Location {
     pub file_index: Option<usize>,
     pub line: usize,
@@ -26,7 +26,7 @@ impl Location {
 
     ///
     /// Creates a location with a file identifier.
-    /// The file identifier can be used to get its path from the global type index.
+    /// The file identifier can be used to get its path from the build file table.
     ///
     pub fn new_beginning(file_index: Option<usize>) -> Self {
         Self {
@@ -64,21 +64,7 @@ impl fmt::Display for Location {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match (self.line, self.column) {
             (0, 0) => write!(f, "<unavailable>"),
-            (line, column) => match self.file_index {
-                Some(file_index) => write!(
-                    f,
-                    "{}:{}:{}",
-                    crate::file::INDEX
-                        .read()
-                        .expect(crate::PANIC_MUTEX_SYNC)
-                        .get(file_index)
-                        .expect(crate::PANIC_FILE_INDEX)
-                        .to_string_lossy(),
-                    line,
-                    column,
-                ),
-                None => write!(f, "{}:{}", line, column),
-            },
+            (line, column) => write!(f, "{}:{}", line, column),
         }
     }
 }
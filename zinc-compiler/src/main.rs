@@ -2,24 +2,33 @@
 //! The Zinc compiler binary.
 //!
 
-use std::cell::RefCell;
-use std::collections::HashMap;
 use std::convert::TryFrom;
+use std::ffi::OsStr;
 use std::ffi::OsString;
 use std::fs::File;
 use std::io::Write;
+use std::path::Path;
 use std::path::PathBuf;
 use std::process;
-use std::rc::Rc;
 
 use failure::Fail;
 use structopt::StructOpt;
 
+use zinc_compiler::Build;
 use zinc_compiler::Bytecode;
+use zinc_compiler::Diagnostic;
 use zinc_compiler::File as ZincFile;
-use zinc_compiler::Scope;
+
+use zinc_bytecode::Curve;
 
 static ZINC_SOURCE_FILE_EXTENSION: &str = "zn";
+static ZINC_ENTRY_FILE_STEM: &str = "main";
+static LINT_WARNINGS: &str = "warnings";
+static MESSAGE_FORMAT_HUMAN: &str = "human";
+static MESSAGE_FORMAT_JSON: &str = "json";
+
+static PANIC_REQUIRED_UNLESS_EXPLAIN: &str =
+    "The output paths are required unless `--explain` is specified";
 
 const EXIT_CODE_SUCCESS: i32 = 0;
 const EXIT_CODE_FAILURE: i32 = 1;
@@ -36,22 +45,51 @@ struct Arguments {
     #[structopt(
         long = "witness",
         parse(from_os_str),
+        required_unless = "explain",
         help = "The witness template output path"
     )]
-    witness_template_path: PathBuf,
+    witness_template_path: Option<PathBuf>,
     #[structopt(
         long = "public-data",
         parse(from_os_str),
+        required_unless = "explain",
         help = "The public data template output path"
     )]
-    public_data_template_path: PathBuf,
+    public_data_template_path: Option<PathBuf>,
     #[structopt(
         short = "o",
         long = "output",
         parse(from_os_str),
+        required_unless = "explain",
         help = "The *.znb bytecode output path"
     )]
-    bytecode_output_path: PathBuf,
+    bytecode_output_path: Option<PathBuf>,
+    #[structopt(
+        short = "D",
+        number_of_values = 1,
+        possible_values = &[LINT_WARNINGS],
+        help = "Denies the lint, e.g. `-D warnings` turns the warnings into errors"
+    )]
+    deny: Vec<String>,
+    #[structopt(
+        long = "message-format",
+        default_value = "human",
+        possible_values = &[MESSAGE_FORMAT_HUMAN, MESSAGE_FORMAT_JSON],
+        help = "The diagnostic output format, `json` prints one JSON record per line to stdout"
+    )]
+    message_format: String,
+    #[structopt(
+        long = "curve",
+        default_value = "bn256",
+        possible_values = &Curve::NAMES,
+        help = "The curve, whose scalar field the circuit is compiled for"
+    )]
+    curve: Curve,
+    #[structopt(
+        long = "explain",
+        help = "Prints the detailed explanation of the error code, e.g. `--explain Z0301`"
+    )]
+    explain: Option<String>,
     #[structopt(parse(from_os_str), help = "The *.zn source file names")]
     source_files: Vec<PathBuf>,
 }
@@ -62,6 +100,8 @@ enum Error {
     SourceFile(FileError),
     #[fail(display = "{}", _0)]
     Compiler(String),
+    #[fail(display = "could not compile due to previous errors")]
+    Compilation,
     #[fail(display = "witness template output: {}", _0)]
     WitnessTemplateOutput(OutputError),
     #[fail(display = "public data template output: {}", _0)]
@@ -70,6 +110,8 @@ enum Error {
     BytecodeOutput(OutputError),
     #[fail(display = "the 'main.zn' source file is missing")]
     EntrySourceFileNotFound,
+    #[fail(display = "error code `{}` is unknown", _0)]
+    UnknownErrorCode(String),
 }
 
 #[derive(Debug, Fail)]
@@ -78,8 +120,8 @@ enum FileError {
     ExtensionNotFound,
     #[fail(display = "file extension is invalid")]
     ExtensionInvalid(OsString),
-    #[fail(display = "file name not found")]
-    StemNotFound,
+    #[fail(display = "file is not located in the 'main.zn' directory")]
+    OutsideSourceDirectory,
 }
 
 #[derive(Debug, Fail)]
@@ -105,12 +147,30 @@ fn main() {
 fn main_inner(args: Arguments) -> Result<(), Error> {
     zinc_bytecode::logger::init_logger("znc", args.verbosity);
 
-    let bytecode = Rc::new(RefCell::new(Bytecode::new()));
+    if let Some(code) = args.explain {
+        let explanation =
+            Diagnostic::explain(code.as_str()).ok_or(Error::UnknownErrorCode(code))?;
+        print!("{}", explanation);
+        return Ok(());
+    }
 
-    let mut modules = HashMap::<String, Rc<RefCell<Scope>>>::new();
-    let mut entry_file_path = None;
+    let witness_template_path = args
+        .witness_template_path
+        .expect(PANIC_REQUIRED_UNLESS_EXPLAIN);
+    let public_data_template_path = args
+        .public_data_template_path
+        .expect(PANIC_REQUIRED_UNLESS_EXPLAIN);
+    let bytecode_output_path = args
+        .bytecode_output_path
+        .expect(PANIC_REQUIRED_UNLESS_EXPLAIN);
 
-    for source_file_path in args.source_files.into_iter() {
+    let is_warning_denied = args.deny.iter().any(|lint| lint == LINT_WARNINGS);
+    let is_message_format_json = args.message_format == MESSAGE_FORMAT_JSON;
+    if is_message_format_json {
+        colored::control::set_override(false);
+    }
+
+    for source_file_path in args.source_files.iter() {
         let source_file_extension = source_file_path
             .extension()
             .ok_or(FileError::ExtensionNotFound)
@@ -121,80 +181,91 @@ fn main_inner(args: Arguments) -> Result<(), Error> {
             ))
             .map_err(Error::SourceFile);
         }
+    }
 
-        let source_file_stem = source_file_path
-            .file_stem()
-            .ok_or(FileError::StemNotFound)
-            .map_err(Error::SourceFile)?;
-        if source_file_stem == "main" {
-            entry_file_path = Some(source_file_path);
+    let entry_file_path = args
+        .source_files
+        .iter()
+        .filter(|path| path.file_stem() == Some(OsStr::new(ZINC_ENTRY_FILE_STEM)))
+        .min_by_key(|path| path.components().count())
+        .cloned()
+        .ok_or(Error::EntrySourceFileNotFound)?;
+    let source_directory_path = entry_file_path
+        .parent()
+        .map(Path::to_path_buf)
+        .unwrap_or_default();
+
+    let mut modules = Vec::with_capacity(args.source_files.len());
+    let mut entry = None;
+
+    for source_file_path in args.source_files.into_iter() {
+        if source_file_path == entry_file_path {
+            entry = Some(ZincFile::try_from(source_file_path).map_err(Error::Compiler)?);
             continue;
         }
 
-        let module_name = source_file_stem.to_string_lossy().to_string();
-        bytecode
-            .borrow_mut()
-            .start_new_file(source_file_path.to_string_lossy().as_ref());
-
-        log::info!("Compiling {:?}", source_file_path);
-        let module = ZincFile::try_from(source_file_path)
-            .map_err(Error::Compiler)?
-            .try_into_module(bytecode.clone())
-            .map_err(Error::Compiler)?;
+        let module_path = source_file_path
+            .strip_prefix(&source_directory_path)
+            .ok()
+            .and_then(Build::module_path)
+            .ok_or(FileError::OutsideSourceDirectory)
+            .map_err(Error::SourceFile)?;
 
-        modules.insert(module_name, module);
+        let file = ZincFile::try_from(source_file_path).map_err(Error::Compiler)?;
+        modules.push((module_path, file));
     }
 
-    match entry_file_path.take() {
-        Some(entry_file_path) => {
-            bytecode
-                .borrow_mut()
-                .start_new_file(entry_file_path.to_string_lossy().as_ref());
-
-            log::info!("Compiling {:?}", entry_file_path);
-            ZincFile::try_from(entry_file_path)
-                .map_err(Error::Compiler)?
-                .try_into_entry(bytecode.clone(), modules)
-                .map_err(Error::Compiler)?;
-        }
-        None => return Err(Error::EntrySourceFileNotFound),
-    }
+    let entry = entry.ok_or(Error::EntrySourceFileNotFound)?;
+    let build = Build::compile_files(entry, modules, args.curve, is_warning_denied).map_err(
+        |diagnostics| {
+            report(diagnostics.as_slice(), is_message_format_json);
+            Error::Compilation
+        },
+    )?;
+    report(build.warnings.as_slice(), is_message_format_json);
 
-    if !args.witness_template_path.exists() {
-        File::create(&args.witness_template_path)
+    if !witness_template_path.exists() {
+        File::create(&witness_template_path)
             .map_err(OutputError::Creating)
             .map_err(Error::WitnessTemplateOutput)?
-            .write_all(bytecode.borrow().input_template_bytes().as_slice())
+            .write_all(Bytecode::template_bytes(build.input_template).as_slice())
             .map_err(OutputError::Writing)
             .map_err(Error::WitnessTemplateOutput)?;
-        log::info!(
-            "Witness template written to {:?}",
-            args.witness_template_path
-        );
+        log::info!("Witness template written to {:?}", witness_template_path);
     }
 
-    File::create(&args.public_data_template_path)
+    File::create(&public_data_template_path)
         .map_err(OutputError::Creating)
         .map_err(Error::PublicDataTemplateOutput)?
-        .write_all(bytecode.borrow().output_template_bytes().as_slice())
+        .write_all(Bytecode::template_bytes(build.output_template).as_slice())
         .map_err(OutputError::Writing)
         .map_err(Error::PublicDataTemplateOutput)?;
     log::info!(
         "Public data template written to {:?}",
-        args.public_data_template_path
+        public_data_template_path
     );
 
-    let bytecode = Rc::try_unwrap(bytecode)
-        .expect(zinc_compiler::PANIC_LAST_SHARED_REFERENCE)
-        .into_inner();
-
-    File::create(&args.bytecode_output_path)
+    File::create(&bytecode_output_path)
         .map_err(OutputError::Creating)
         .map_err(Error::BytecodeOutput)?
-        .write_all(bytecode.into_bytes().as_slice())
+        .write_all(build.program.to_bytes().as_slice())
         .map_err(OutputError::Writing)
         .map_err(Error::BytecodeOutput)?;
-    log::info!("Compiled to {:?}", args.bytecode_output_path);
+    log::info!("Compiled to {:?}", bytecode_output_path);
 
     Ok(())
 }
+
+///
+/// Prints the diagnostics either as the human-readable text to stderr, or as JSON records,
+/// one per line, to stdout.
+///
+fn report(diagnostics: &[Diagnostic], is_message_format_json: bool) {
+    for diagnostic in diagnostics.iter() {
+        if is_message_format_json {
+            println!("{}", diagnostic.to_json());
+        } else if let Some(ref rendered) = diagnostic.rendered {
+            eprint!("{}", rendered);
+        }
+    }
+}
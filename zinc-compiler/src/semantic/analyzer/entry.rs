@@ -11,8 +11,10 @@ use crate::generator::Tree;
 use crate::semantic::analyzer::statement::Analyzer as StatementAnalyzer;
 use crate::semantic::error::Error;
 use crate::semantic::scope::stack::Stack as ScopeStack;
+use crate::semantic::scope::state::State as SemanticState;
 use crate::semantic::scope::Scope;
 use crate::syntax::tree::Tree as SyntaxTree;
+use crate::warning::Warning;
 
 ///
 /// Analyzes the circuit entry, which must be located in the `main.zn` file.
@@ -21,6 +23,7 @@ use crate::syntax::tree::Tree as SyntaxTree;
 ///
 pub struct Analyzer {
     scope_stack: ScopeStack,
+    warnings: Rc<RefCell<Vec<Warning>>>,
 }
 
 impl Default for Analyzer {
@@ -31,36 +34,50 @@ impl Default for Analyzer {
 
 impl Analyzer {
     pub fn new() -> Self {
+        Self::new_in_build(
+            Rc::new(SemanticState::default()),
+            Rc::new(RefCell::new(Vec::new())),
+        )
+    }
+
+    ///
+    /// Initializes an analyzer, which shares the semantic `state` and the warning storage
+    /// with the other files of the build.
+    ///
+    pub fn new_in_build(state: Rc<SemanticState>, warnings: Rc<RefCell<Vec<Warning>>>) -> Self {
         Self {
-            scope_stack: ScopeStack::new_global(),
+            scope_stack: ScopeStack::new_global(state, warnings.clone()),
+            warnings,
         }
     }
 
+    ///
+    /// Analyzes the entry and yields its IR along with the warnings.
+    ///
     pub fn compile(
         self,
         program: SyntaxTree,
         dependencies: HashMap<String, Rc<RefCell<Scope>>>,
-    ) -> Result<Tree, CompilerError> {
-        let mut intermediate = Tree::new();
-
-        let mut analyzer = StatementAnalyzer::new(self.scope_stack.top(), dependencies);
-        for statement in program.statements.into_iter() {
-            if let Some(statement) = analyzer
-                .local_mod(statement)
-                .map_err(CompilerError::Semantic)?
-            {
-                intermediate.statements.push(statement);
-            }
-        }
+    ) -> Result<(Tree, Vec<Warning>), Vec<CompilerError>> {
+        let statements = StatementAnalyzer::new(self.scope_stack.top(), dependencies)
+            .module(program.statements)
+            .map_err(|errors| {
+                errors
+                    .into_iter()
+                    .map(CompilerError::Semantic)
+                    .collect::<Vec<CompilerError>>()
+            })?;
+        let intermediate = Tree { statements };
 
         Scope::resolve_item(
             self.scope_stack.top(),
             crate::semantic::element::r#type::function::user::FUNCTION_MAIN_IDENTIFIER,
         )
-        .map_err(|_| Error::EntryPointMissing)
-        .map_err(CompilerError::Semantic)?;
+        .map_err(|_| vec![CompilerError::Semantic(Error::EntryPointMissing)])?;
+
+        self.scope_stack.top().borrow().warn_unused_items(false);
 
-        Ok(intermediate)
+        Ok((intermediate, Warning::take_all(&self.warnings)))
     }
 }
 
@@ -0,0 +1,480 @@
+//!
+//! The constant function evaluator.
+//!
+
+mod tests;
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::rc::Rc;
+
+use num_bigint::BigInt;
+use num_traits::One;
+
+use crate::lexical::token::location::Location;
+use crate::semantic::analyzer::expression::hint::Hint as TranslationHint;
+use crate::semantic::analyzer::expression::Analyzer as ExpressionAnalyzer;
+use crate::semantic::analyzer::statement::Analyzer as StatementAnalyzer;
+use crate::semantic::element::constant::boolean::Boolean as BooleanConstant;
+use crate::semantic::element::constant::error::Error as ConstantError;
+use crate::semantic::element::constant::integer::Integer as IntegerConstant;
+use crate::semantic::element::constant::Constant;
+use crate::semantic::element::error::Error as ElementError;
+use crate::semantic::element::place::error::Error as PlaceError;
+use crate::semantic::element::r#type::function::user::Function as UserDefinedFunctionType;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::element::Element;
+use crate::semantic::error::Error;
+use crate::semantic::scope::item::variant::Variant as ScopeItemVariant;
+use crate::semantic::scope::stack::Stack as ScopeStack;
+use crate::semantic::scope::state::State;
+use crate::semantic::scope::Scope;
+use crate::syntax::tree::expression::block::Expression as BlockExpression;
+use crate::syntax::tree::expression::conditional::Expression as ConditionalExpression;
+use crate::syntax::tree::expression::tree::node::operand::Operand as ExpressionOperand;
+use crate::syntax::tree::expression::tree::node::operator::Operator as ExpressionOperator;
+use crate::syntax::tree::expression::tree::node::Node as ExpressionTreeNode;
+use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
+use crate::syntax::tree::identifier::Identifier;
+use crate::syntax::tree::pattern_binding::variant::Variant as BindingPatternVariant;
+use crate::syntax::tree::statement::local_fn::Statement as FunctionLocalStatement;
+use crate::syntax::tree::statement::r#for::Statement as ForStatement;
+use crate::syntax::tree::statement::r#let::Statement as LetStatement;
+
+///
+/// Evaluates the `const fn` calls with constant arguments at compile time.
+///
+/// The function body is interpreted statement by statement, with its arguments and local
+/// variables declared as constants, so the ordinary expressions are folded by the expression
+/// analyzer. The blocks, conditionals, loops and assignments to local variables are interpreted
+/// here, so only the taken branches are evaluated.
+///
+/// The call depth and step counters are kept in the build state, since the nested calls
+/// are evaluated by separate evaluators.
+///
+pub struct Evaluator {
+    function: String,
+    state: Rc<State>,
+    scope_stack: ScopeStack,
+    mutables: HashSet<String>,
+    result: Option<Constant>,
+}
+
+impl Evaluator {
+    /// The maximal depth of nested `const fn` calls, which prevents infinite recursion.
+    pub const LIMIT_RECURSION_DEPTH: usize = 32;
+    /// The maximal number of calls and loop iterations, which prevents endless evaluation.
+    pub const LIMIT_STEPS: usize = 65536;
+
+    ///
+    /// Evaluates the `function` call with the constant `arguments`, which have been already
+    /// checked against the function formal parameters.
+    ///
+    pub fn evaluate(
+        function: &UserDefinedFunctionType,
+        arguments: Vec<Constant>,
+        location: Location,
+    ) -> Result<Constant, Error> {
+        let (statement, scope) = function
+            .constant()
+            .expect(crate::PANIC_VALIDATED_DURING_SEMANTIC_ANALYSIS);
+
+        let state = scope.borrow().state();
+        let depth = state.evaluation_depth();
+        if depth >= Self::LIMIT_RECURSION_DEPTH {
+            return Err(Error::FunctionConstantRecursionLimit {
+                location,
+                function: function.identifier().to_owned(),
+                limit: Self::LIMIT_RECURSION_DEPTH,
+            });
+        }
+        if depth == 0 {
+            state.reset_evaluation_steps();
+        }
+        Self::step(&state, function.identifier(), location)?;
+
+        let mut evaluator = Self {
+            function: function.identifier().to_owned(),
+            state: state.clone(),
+            scope_stack: ScopeStack::new(Scope::new_child(scope)),
+            mutables: HashSet::new(),
+            result: None,
+        };
+
+        let bindings = statement
+            .argument_bindings
+            .iter()
+            .filter(|binding| match binding.variant {
+                BindingPatternVariant::Wildcard => false,
+                _ => true,
+            });
+        for (binding, argument) in bindings.zip(arguments.into_iter()) {
+            let (identifier, is_mutable) = match binding.variant {
+                BindingPatternVariant::Binding(ref identifier) => (identifier.to_owned(), false),
+                BindingPatternVariant::MutableBinding(ref identifier) => {
+                    (identifier.to_owned(), true)
+                }
+                BindingPatternVariant::Wildcard => continue,
+            };
+            evaluator.declare(identifier, is_mutable, argument)?;
+        }
+
+        let body = statement
+            .body
+            .to_owned()
+            .expect(crate::PANIC_VALIDATED_DURING_SEMANTIC_ANALYSIS);
+
+        state.set_evaluation_depth(depth + 1);
+        let result = evaluator.block(body);
+        state.set_evaluation_depth(depth);
+
+        let result = result?;
+        Ok(evaluator.result.take().unwrap_or(result))
+    }
+
+    ///
+    /// Evaluates the block statements one by one and returns the block result.
+    ///
+    /// If a `return` statement has been evaluated, the rest of the block is skipped.
+    ///
+    fn block(&mut self, block: BlockExpression) -> Result<Constant, Error> {
+        self.scope_stack.push();
+
+        for statement in block.statements.into_iter() {
+            self.statement(statement)?;
+            if self.result.is_some() {
+                self.scope_stack.pop();
+                return Ok(Constant::Unit);
+            }
+        }
+
+        let result = match block.expression {
+            Some(expression) => self.expression(*expression)?,
+            None => Constant::Unit,
+        };
+
+        self.scope_stack.pop();
+
+        Ok(result)
+    }
+
+    fn statement(&mut self, statement: FunctionLocalStatement) -> Result<(), Error> {
+        match statement {
+            FunctionLocalStatement::Let(statement) => self.r#let(statement),
+            FunctionLocalStatement::Const(statement) => {
+                StatementAnalyzer::new(self.scope_stack.top(), HashMap::new())
+                    .local_fn(FunctionLocalStatement::Const(statement))?;
+                Ok(())
+            }
+            FunctionLocalStatement::For(statement) => self.r#for(statement),
+            FunctionLocalStatement::Return(statement) => {
+                let result = match statement.expression {
+                    Some(expression) => self.expression(expression)?,
+                    None => Constant::Unit,
+                };
+                if self.result.is_none() {
+                    self.result = Some(result);
+                }
+                Ok(())
+            }
+            FunctionLocalStatement::Break(statement) => {
+                Err(Error::FunctionConstantUnsupportedStatement {
+                    location: statement.location,
+                    function: self.function.to_owned(),
+                })
+            }
+            FunctionLocalStatement::Continue(statement) => {
+                Err(Error::FunctionConstantUnsupportedStatement {
+                    location: statement.location,
+                    function: self.function.to_owned(),
+                })
+            }
+            FunctionLocalStatement::Expression(expression) => {
+                self.expression(expression)?;
+                Ok(())
+            }
+            FunctionLocalStatement::Empty(_location) => Ok(()),
+        }
+    }
+
+    fn r#let(&mut self, statement: LetStatement) -> Result<(), Error> {
+        let constant = self.expression(statement.expression)?;
+
+        let constant = match statement.r#type {
+            Some(r#type) => {
+                let type_location = r#type.location;
+                let r#type = Type::from_type_variant(&r#type.variant, self.scope_stack.top())?;
+                constant
+                    .cast_declared(r#type)
+                    .map_err(ElementError::Constant)
+                    .map_err(|error| Error::Element(type_location, error))?
+            }
+            None => constant,
+        };
+
+        self.declare(statement.identifier, statement.is_mutable, constant)
+    }
+
+    ///
+    /// Evaluates the loop body for each value of the constant range, until the `while`
+    /// condition is false.
+    ///
+    fn r#for(&mut self, statement: ForStatement) -> Result<(), Error> {
+        let location = statement.location;
+        let bounds_expression_location = statement.bounds_expression.location;
+
+        let (start, end, bitlength, is_signed, is_inclusive) =
+            match self.expression(statement.bounds_expression)? {
+                Constant::RangeInclusive(range) => (
+                    range.start,
+                    range.end,
+                    range.bitlength,
+                    range.is_signed,
+                    true,
+                ),
+                Constant::Range(range) => (
+                    range.start,
+                    range.end,
+                    range.bitlength,
+                    range.is_signed,
+                    false,
+                ),
+                constant => {
+                    return Err(Error::LoopBoundsExpectedConstantRangeExpression {
+                        location: bounds_expression_location,
+                        found: constant.to_string(),
+                    });
+                }
+            };
+
+        let step = if start > end {
+            -BigInt::one()
+        } else {
+            BigInt::one()
+        };
+        let mut index = start;
+        loop {
+            if index == end && !is_inclusive {
+                break;
+            }
+            Self::step(&self.state, self.function.as_str(), location)?;
+
+            self.scope_stack.push();
+            self.declare(
+                statement.index_identifier.to_owned(),
+                false,
+                Constant::Integer(IntegerConstant::new(index.clone(), is_signed, bitlength)),
+            )?;
+
+            if let Some(ref expression) = statement.while_condition {
+                if !self.condition(expression.to_owned())? {
+                    self.scope_stack.pop();
+                    break;
+                }
+            }
+
+            self.block(statement.block.to_owned())?;
+            self.scope_stack.pop();
+
+            if self.result.is_some() || index == end {
+                break;
+            }
+            index += &step;
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Counts a call or loop iteration of the `function`, checking the evaluation step limit.
+    ///
+    fn step(state: &State, function: &str, location: Location) -> Result<(), Error> {
+        if state.evaluation_step() > Self::LIMIT_STEPS {
+            return Err(Error::FunctionConstantStepLimit {
+                location,
+                function: function.to_owned(),
+                limit: Self::LIMIT_STEPS,
+            });
+        }
+        Ok(())
+    }
+
+    ///
+    /// Evaluates the expression, interpreting the blocks, conditionals and assignments,
+    /// and folding the rest of expressions with the expression analyzer.
+    ///
+    fn expression(&mut self, expression: ExpressionTree) -> Result<Constant, Error> {
+        let location = expression.location;
+
+        match *expression.value {
+            ExpressionTreeNode::Operand(ExpressionOperand::Block(block)) => self.block(block),
+            ExpressionTreeNode::Operand(ExpressionOperand::Conditional(conditional)) => {
+                self.conditional(conditional)
+            }
+            ExpressionTreeNode::Operator(operator @ ExpressionOperator::Assignment)
+            | ExpressionTreeNode::Operator(operator @ ExpressionOperator::AssignmentBitwiseOr)
+            | ExpressionTreeNode::Operator(operator @ ExpressionOperator::AssignmentBitwiseXor)
+            | ExpressionTreeNode::Operator(operator @ ExpressionOperator::AssignmentBitwiseAnd)
+            | ExpressionTreeNode::Operator(
+                operator @ ExpressionOperator::AssignmentBitwiseShiftLeft,
+            )
+            | ExpressionTreeNode::Operator(
+                operator @ ExpressionOperator::AssignmentBitwiseShiftRight,
+            )
+            | ExpressionTreeNode::Operator(operator @ ExpressionOperator::AssignmentAddition)
+            | ExpressionTreeNode::Operator(operator @ ExpressionOperator::AssignmentSubtraction)
+            | ExpressionTreeNode::Operator(
+                operator @ ExpressionOperator::AssignmentMultiplication,
+            )
+            | ExpressionTreeNode::Operator(operator @ ExpressionOperator::AssignmentDivision)
+            | ExpressionTreeNode::Operator(operator @ ExpressionOperator::AssignmentRemainder) => {
+                match (expression.left, expression.right) {
+                    (Some(left), Some(right)) => self.assignment(operator, *left, *right, location),
+                    _ => panic!(crate::PANIC_VALIDATED_DURING_SYNTAX_ANALYSIS),
+                }
+            }
+            value => {
+                let expression = ExpressionTree {
+                    location,
+                    value: Box::new(value),
+                    left: expression.left,
+                    right: expression.right,
+                };
+                match ExpressionAnalyzer::new_constant(self.scope_stack.top(), true)
+                    .analyze(expression, TranslationHint::Value)?
+                {
+                    (Element::Constant(constant), _intermediate) => Ok(constant),
+                    (element, _intermediate) => {
+                        Err(Error::ConstantExpressionHasNonConstantElement {
+                            location,
+                            found: element.to_string(),
+                        })
+                    }
+                }
+            }
+        }
+    }
+
+    ///
+    /// Evaluates the condition and only the branch it selects.
+    ///
+    fn conditional(&mut self, conditional: ConditionalExpression) -> Result<Constant, Error> {
+        if self.condition(*conditional.condition)? {
+            self.block(conditional.main_block)
+        } else {
+            match conditional.else_block {
+                Some(block) => self.block(block),
+                None => Ok(Constant::Unit),
+            }
+        }
+    }
+
+    fn condition(&mut self, expression: ExpressionTree) -> Result<bool, Error> {
+        let location = expression.location;
+
+        match self.expression(expression)? {
+            Constant::Boolean(BooleanConstant { inner }) => Ok(inner),
+            constant => Err(Error::ConditionalExpectedBooleanCondition {
+                location,
+                found: constant.r#type().to_string(),
+            }),
+        }
+    }
+
+    ///
+    /// Evaluates the assignment to a local variable, applying the operation to its current
+    /// value if the assignment is compound, e.g. `value *= 2`.
+    ///
+    fn assignment(
+        &mut self,
+        operator: ExpressionOperator,
+        left: ExpressionTree,
+        right: ExpressionTree,
+        location: Location,
+    ) -> Result<Constant, Error> {
+        let identifier = match *left.value {
+            ExpressionTreeNode::Operand(ExpressionOperand::Identifier(identifier)) => identifier,
+            _ => {
+                return Err(Error::FunctionConstantUnsupportedStatement {
+                    location: left.location,
+                    function: self.function.to_owned(),
+                })
+            }
+        };
+
+        let item = Scope::resolve_item(self.scope_stack.top(), identifier.name.as_str())
+            .map_err(|error| Error::Scope(identifier.location, error))?;
+        let current = match item.variant {
+            ScopeItemVariant::Constant(constant)
+                if self.mutables.contains(identifier.name.as_str()) =>
+            {
+                constant
+            }
+            _ => {
+                return Err(Error::Element(
+                    location,
+                    ElementError::Place(PlaceError::MutatingImmutableMemory {
+                        name: identifier.name,
+                        reference: item.location,
+                    }),
+                ));
+            }
+        };
+
+        let operand = self.expression(right)?;
+        let result = match operator {
+            ExpressionOperator::Assignment => Ok(operand),
+            ExpressionOperator::AssignmentBitwiseOr => current.clone().bitwise_or(operand),
+            ExpressionOperator::AssignmentBitwiseXor => current.clone().bitwise_xor(operand),
+            ExpressionOperator::AssignmentBitwiseAnd => current.clone().bitwise_and(operand),
+            ExpressionOperator::AssignmentBitwiseShiftLeft => {
+                current.clone().bitwise_shift_left(operand)
+            }
+            ExpressionOperator::AssignmentBitwiseShiftRight => {
+                current.clone().bitwise_shift_right(operand)
+            }
+            ExpressionOperator::AssignmentAddition => current.clone().add(operand),
+            ExpressionOperator::AssignmentSubtraction => current.clone().subtract(operand),
+            ExpressionOperator::AssignmentMultiplication => current.clone().multiply(operand),
+            ExpressionOperator::AssignmentDivision => current.clone().divide(operand),
+            ExpressionOperator::AssignmentRemainder => current.clone().remainder(operand),
+            _ => panic!(crate::PANIC_VALIDATED_DURING_SYNTAX_ANALYSIS),
+        }
+        .map_err(|error: ConstantError| Error::Element(location, ElementError::Constant(error)))?;
+
+        if result.r#type() != current.r#type() {
+            return Err(Error::Element(
+                location,
+                ElementError::Place(PlaceError::MutatingWithDifferentType {
+                    expected: result.r#type().to_string(),
+                    found: current.r#type().to_string(),
+                }),
+            ));
+        }
+
+        Scope::update_constant(self.scope_stack.top(), identifier.name.as_str(), result);
+
+        Ok(Constant::Unit)
+    }
+
+    ///
+    /// Declares a function argument or local variable as a constant.
+    ///
+    fn declare(
+        &mut self,
+        identifier: Identifier,
+        is_mutable: bool,
+        constant: Constant,
+    ) -> Result<(), Error> {
+        let location = identifier.location;
+
+        if is_mutable {
+            self.mutables.insert(identifier.name.to_owned());
+        } else {
+            self.mutables.remove(identifier.name.as_str());
+        }
+
+        Scope::declare_constant(self.scope_stack.top(), identifier, constant, false)
+            .map_err(|error| Error::Scope(location, error))
+    }
+}
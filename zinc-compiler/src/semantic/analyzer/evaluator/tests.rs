@@ -0,0 +1,254 @@
+//!
+//! The constant function evaluator tests.
+//!
+
+#![cfg(test)]
+
+use crate::error::Error;
+use crate::lexical::token::location::Location;
+use crate::semantic::analyzer::evaluator::Evaluator;
+use crate::semantic::element::error::Error as ElementError;
+use crate::semantic::element::place::error::Error as PlaceError;
+use crate::semantic::error::Error as SemanticError;
+
+static STACK_SIZE_RECURSION: usize = 64 * 1024 * 1024;
+
+#[test]
+fn ok_array_size() {
+    let input = r#"
+const fn depth(leaves: u64) -> u8 {
+    let mut result: u8 = 0;
+    let mut size: u64 = 1;
+    for i in 0..64 while size < leaves {
+        size *= 2 as u64;
+        result += 1;
+    };
+    result
+}
+
+const DEPTH: u8 = depth(1000 as u64);
+
+fn main() -> [bool; DEPTH] {
+    [false; depth(1024 as u64)]
+}
+"#;
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn ok_recursion() {
+    let input = r#"
+const fn factorial(n: u64) -> u64 {
+    if n == 0 as u64 { 1 as u64 } else { n * factorial(n - 1 as u64) }
+}
+
+fn main() -> [u8; factorial(4 as u64)] {
+    [0; 24]
+}
+"#;
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn ok_return() {
+    let input = r#"
+const fn max(a: u8, b: u8) -> u8 {
+    if a > b {
+        return a;
+    };
+    b
+}
+
+const MAX: u8 = max(max(3, 5), 4);
+
+fn main() -> [u8; MAX] {
+    [0; 5]
+}
+"#;
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn ok_runtime_call() {
+    let input = r#"
+const fn double(value: u8) -> u8 {
+    value * 2
+}
+
+fn main(witness: u8) -> u8 {
+    double(witness) + double(21)
+}
+"#;
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn error_non_constant_element() {
+    let input = r#"
+fn identity(value: u8) -> u8 {
+    value
+}
+
+const fn double(value: u8) -> u8 {
+    identity(value) * 2
+}
+
+const DOUBLE: u8 = double(21);
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::ConstantExpressionHasNonConstantElement {
+            location: Location::new(7, 21),
+            found: "<integer> of type 'u8'".to_owned(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_mutating_immutable_memory() {
+    let input = r#"
+const fn double(value: u8) -> u8 {
+    let result = value;
+    result *= 2;
+    result
+}
+
+const DOUBLE: u8 = double(21);
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(4, 12),
+        ElementError::Place(PlaceError::MutatingImmutableMemory {
+            name: "result".to_owned(),
+            reference: Some(Location::new(3, 9)),
+        }),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_unsupported_statement() {
+    let input = r#"
+const fn first(value: u8) -> u8 {
+    let mut array = [0, 0];
+    array[0] = value;
+    array[0]
+}
+
+const FIRST: u8 = first(42);
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::FunctionConstantUnsupportedStatement {
+            location: Location::new(4, 10),
+            function: "first".to_owned(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_recursion_limit() {
+    let input = r#"
+const fn infinite(value: u8) -> u8 {
+    infinite(value)
+}
+
+const VALUE: u8 = infinite(42);
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::FunctionConstantRecursionLimit {
+            location: Location::new(3, 13),
+            function: "infinite".to_owned(),
+            limit: Evaluator::LIMIT_RECURSION_DEPTH,
+        },
+    ));
+
+    // the test threads have too small stack for the maximal recursion depth
+    let result = std::thread::Builder::new()
+        .stack_size(STACK_SIZE_RECURSION)
+        .spawn(move || crate::semantic::tests::compile_entry(input))
+        .expect("The thread is spawned")
+        .join()
+        .expect("The thread does not panic");
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_step_limit() {
+    let input = r#"
+const fn endless() -> u64 {
+    let mut result: u64 = 0;
+    for i in 0..18446744073709551615 {
+        result = i;
+    }
+    result
+}
+
+const VALUE: u64 = endless();
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionConstantStepLimit {
+        location: Location::new(4, 5),
+        function: "endless".to_owned(),
+        limit: Evaluator::LIMIT_STEPS,
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_generic() {
+    let input = r#"
+const fn identity<T>(value: T) -> T {
+    value
+}
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionConstantGeneric {
+        location: Location::new(2, 1),
+        function: "identity".to_owned(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
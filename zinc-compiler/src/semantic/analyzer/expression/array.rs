@@ -9,6 +9,8 @@ use crate::generator::expression::operand::array::builder::Builder as GeneratorA
 use crate::generator::expression::operand::Operand as GeneratorExpressionOperand;
 use crate::semantic::analyzer::expression::hint::Hint as TranslationHint;
 use crate::semantic::analyzer::expression::Analyzer as ExpressionAnalyzer;
+use crate::semantic::element::constant::array::error::Error as ArrayConstantError;
+use crate::semantic::element::constant::array::Array as ArrayConstant;
 use crate::semantic::element::constant::error::Error as ConstantError;
 use crate::semantic::element::constant::Constant;
 use crate::semantic::element::error::Error as ElementError;
@@ -30,11 +32,16 @@ impl Analyzer {
     ///
     /// Returns the semantic element and the intermediate representation.
     ///
+    /// If the expression `is_constant` and all the elements are constant, the array is
+    /// a constant as well.
+    ///
     pub fn analyze(
         scope: Rc<RefCell<Scope>>,
         array: ArrayExpression,
+        is_constant: bool,
     ) -> Result<(Element, GeneratorExpressionOperand), Error> {
         let mut result = Array::default();
+        let mut constants = if is_constant { Some(Vec::new()) } else { None };
         let mut builder = GeneratorArrayExpressionBuilder::default();
 
         match array.variant {
@@ -42,8 +49,9 @@ impl Analyzer {
                 for expression in elements.into_iter() {
                     let expression_location = expression.location;
 
-                    let (element, expression) = ExpressionAnalyzer::new(scope.clone())
-                        .analyze(expression, TranslationHint::Value)?;
+                    let (element, expression) =
+                        ExpressionAnalyzer::new_constant(scope.clone(), is_constant)
+                            .analyze(expression, TranslationHint::Value)?;
                     let element_type = Type::from_element(&element, scope.clone())?;
                     result.push(element_type).map_err(|error| {
                         Error::Element(
@@ -52,6 +60,14 @@ impl Analyzer {
                         )
                     })?;
 
+                    constants = match (constants, element) {
+                        (Some(mut constants), Element::Constant(constant)) => {
+                            constants.push(constant);
+                            Some(constants)
+                        }
+                        _ => None,
+                    };
+
                     builder.push_expression(expression);
                 }
             }
@@ -62,7 +78,7 @@ impl Analyzer {
                 let expression_location = expression.location;
                 let size_expression_location = size_expression.location;
 
-                let size = match ExpressionAnalyzer::new(scope.clone())
+                let size = match ExpressionAnalyzer::new_constant(scope.clone(), true)
                     .analyze(size_expression, TranslationHint::Value)?
                 {
                     (Element::Constant(Constant::Integer(integer)), _intermediate) => {
@@ -81,9 +97,11 @@ impl Analyzer {
                     }
                 };
 
-                let (element, expression) = ExpressionAnalyzer::new(scope.clone())
-                    .analyze(expression, TranslationHint::Value)?;
+                let (element, expression) =
+                    ExpressionAnalyzer::new_constant(scope.clone(), is_constant)
+                        .analyze(expression, TranslationHint::Value)?;
                 let element_type = Type::from_element(&element, scope)?;
+                let total_size = size.saturating_mul(element_type.size());
                 result.extend(element_type, size).map_err(|error| {
                     Error::Element(
                         expression_location,
@@ -91,13 +109,40 @@ impl Analyzer {
                     )
                 })?;
 
+                constants = match (constants, element) {
+                    (Some(_), Element::Constant(_))
+                        if total_size > crate::LIMIT_CONSTANT_ARRAY_SIZE =>
+                    {
+                        return Err(Error::Element(
+                            size_expression_location,
+                            ElementError::Constant(ConstantError::Array(
+                                ArrayConstantError::SizeLimitExceeded {
+                                    size: total_size,
+                                    limit: crate::LIMIT_CONSTANT_ARRAY_SIZE,
+                                },
+                            )),
+                        ));
+                    }
+                    (Some(_), Element::Constant(constant)) => Some(vec![constant; size]),
+                    _ => None,
+                };
+
                 builder.push_expression(expression);
                 builder.set_size(size);
             }
         }
 
         let intermediate = GeneratorExpressionOperand::Array(builder.finish());
-        let element = Element::Value(Value::Array(result));
+        let element = match constants {
+            Some(constants) => {
+                let element_type = match result.r#type() {
+                    Type::Array { r#type, .. } => *r#type,
+                    _ => panic!(crate::PANIC_VALIDATED_DURING_SEMANTIC_ANALYSIS),
+                };
+                Element::Constant(Constant::Array(ArrayConstant::new(element_type, constants)))
+            }
+            None => Element::Value(Value::Array(result)),
+        };
 
         Ok((element, intermediate))
     }
@@ -11,12 +11,15 @@ use crate::generator::expression::operand::block::Expression as GeneratorBlockEx
 use crate::semantic::analyzer::expression::hint::Hint as TranslationHint;
 use crate::semantic::analyzer::expression::Analyzer as ExpressionAnalyzer;
 use crate::semantic::analyzer::statement::Analyzer as StatementAnalyzer;
+use crate::semantic::element::r#type::Type;
 use crate::semantic::element::value::Value;
 use crate::semantic::element::Element;
 use crate::semantic::error::Error;
+use crate::semantic::scope::error::Error as ScopeError;
 use crate::semantic::scope::stack::Stack as ScopeStack;
 use crate::semantic::scope::Scope;
 use crate::syntax::tree::expression::block::Expression as BlockExpression;
+use crate::syntax::tree::statement::local_fn::Statement as FunctionLocalStatement;
 
 pub struct Analyzer {}
 
@@ -26,6 +29,13 @@ impl Analyzer {
     ///
     /// Returns the semantic element and the intermediate representation.
     ///
+    /// An erroneous statement does not stop the analysis. The block fails with the first error,
+    /// and the errors of the other independent statements are stored in the scope, so they are
+    /// reported along with it.
+    ///
+    /// The binding of a failed `let` or `const` statement is declared invalid, and the errors
+    /// of using it are dropped, since they are caused by the error of the statement itself.
+    ///
     pub fn analyze(
         scope: Rc<RefCell<Scope>>,
         block: BlockExpression,
@@ -35,19 +45,82 @@ impl Analyzer {
         let mut scope_stack = ScopeStack::new(scope);
         scope_stack.push();
 
-        for statement in block.statements.into_iter() {
-            if let Some(statement) =
-                StatementAnalyzer::new(scope_stack.top(), HashMap::new()).local_fn(statement)?
+        let mut errors = Vec::new();
+        let mut has_guards = false;
+        let statements_count = block.statements.len();
+        for (index, statement) in block.statements.into_iter().enumerate() {
+            let return_count = Scope::get_return_count(scope_stack.top());
+            let loop_control_count = Self::get_loop_control_count(scope_stack.top());
+            let binding = match statement {
+                FunctionLocalStatement::Let(ref statement) => {
+                    Some(statement.identifier.name.clone())
+                }
+                FunctionLocalStatement::Const(ref statement) => {
+                    Some(statement.identifier.name.clone())
+                }
+                _ => None,
+            };
+            let statement = match StatementAnalyzer::new(scope_stack.top(), HashMap::new())
+                .local_fn(statement)
             {
+                Ok(statement) => statement,
+                Err(error) => {
+                    if let Some(binding) = binding {
+                        Scope::declare_invalid(scope_stack.top(), binding.as_str());
+                    }
+                    errors.push(error);
+                    continue;
+                }
+            };
+            if let Some(statement) = statement {
                 builder.push_statement(statement);
+
+                let is_last = index == statements_count - 1 && block.expression.is_none();
+                if is_last {
+                    continue;
+                }
+                if Scope::get_return_count(scope_stack.top()) > return_count {
+                    builder.push_return_guard();
+                    has_guards = true;
+                }
+                if Self::get_loop_control_count(scope_stack.top()) > loop_control_count {
+                    builder.push_loop_guard();
+                    has_guards = true;
+                }
+            }
+        }
+
+        let expression = match block.expression {
+            Some(expression) => match ExpressionAnalyzer::new(scope_stack.top())
+                .analyze(*expression, TranslationHint::Value)
+            {
+                Ok(expression) => Some(expression),
+                Err(error) => {
+                    errors.push(error);
+                    None
+                }
+            },
+            None => None,
+        };
+        if errors.iter().any(|error| !Self::is_invalid_item(error)) {
+            errors.retain(|error| !Self::is_invalid_item(error));
+        } else {
+            errors.truncate(1);
+        }
+        if !errors.is_empty() {
+            let error = errors.remove(0);
+            for error in errors.into_iter() {
+                scope_stack.top().borrow().store_error(error);
             }
+            return Err(error);
         }
 
-        let element = match block.expression {
-            Some(expression) => {
-                let (element, expression) = ExpressionAnalyzer::new(scope_stack.top())
-                    .analyze(*expression, TranslationHint::Value)?;
+        let element = match expression {
+            Some((element, expression)) => {
                 builder.set_expression(expression);
+                if has_guards {
+                    builder.set_expression_type(&Type::from_element(&element, scope_stack.top())?);
+                }
                 element
             }
             None => Element::Value(Value::Unit),
@@ -57,4 +130,17 @@ impl Analyzer {
 
         Ok((element, builder.finish()))
     }
+
+    fn get_loop_control_count(scope: Rc<RefCell<Scope>>) -> usize {
+        Scope::get_loop_context(scope)
+            .map(|context| context.control_count())
+            .unwrap_or_default()
+    }
+
+    fn is_invalid_item(error: &Error) -> bool {
+        match error {
+            Error::Scope(_, ScopeError::ItemInvalid { .. }) => true,
+            _ => false,
+        }
+    }
 }
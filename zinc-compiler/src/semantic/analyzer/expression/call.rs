@@ -8,11 +8,15 @@ use std::rc::Rc;
 
 use crate::generator::expression::operator::Operator as GeneratorExpressionOperator;
 use crate::lexical::token::location::Location;
+use crate::semantic::analyzer::evaluator::Evaluator as ConstantFunctionEvaluator;
+use crate::semantic::analyzer::statement::Analyzer as StatementAnalyzer;
+use crate::semantic::element::constant::Constant;
 use crate::semantic::element::error::Error as ElementError;
 use crate::semantic::element::r#type::error::Error as TypeError;
 use crate::semantic::element::r#type::function::builtin::error::Error as BuiltInFunctionTypeError;
 use crate::semantic::element::r#type::function::builtin::Function as BuiltInFunctionType;
 use crate::semantic::element::r#type::function::error::Error as FunctionTypeError;
+use crate::semantic::element::r#type::function::user::Function as UserDefinedFunctionType;
 use crate::semantic::element::r#type::function::Function as FunctionType;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::element::value::Value;
@@ -29,13 +33,28 @@ impl Analyzer {
     ///
     /// Returns the semantic element and the intermediate representation.
     ///
+    /// If a `const fn` is called with constant arguments, it is evaluated at compile time.
+    /// In this case, the call intermediate representation is not returned, and the caller
+    /// must replace the call with the constant result.
+    ///
+    /// The integer constant arguments of generic functions are casted to the instance argument
+    /// types. Such constants are returned with their argument indexes, and the caller must
+    /// replace them in the argument list intermediate representation.
+    ///
     pub fn analyze(
         scope: Rc<RefCell<Scope>>,
         operand_1: Element,
         operand_2: Element,
         is_call_builtin: bool,
         location: Location,
-    ) -> Result<(Element, GeneratorExpressionOperator), Error> {
+    ) -> Result<
+        (
+            Element,
+            Option<GeneratorExpressionOperator>,
+            Vec<(usize, Constant)>,
+        ),
+        Error,
+    > {
         let function = match operand_1 {
             Element::Type(Type::Function(function)) => function,
             Element::Path(path) => match Scope::resolve_path(scope.clone(), &path)?.variant {
@@ -59,7 +78,7 @@ impl Analyzer {
             }
         };
 
-        let argument_elements = match operand_2 {
+        let mut argument_elements = match operand_2 {
             Element::ArgumentList(values) => values,
             _ => panic!(crate::PANIC_VALIDATED_DURING_SYNTAX_ANALYSIS),
         };
@@ -68,6 +87,29 @@ impl Analyzer {
             input_size += Type::from_element(element, scope.clone())?.size();
         }
 
+        let mut casted_constants = Vec::new();
+        let function = match function {
+            FunctionType::Generic(function) => {
+                let mut argument_types = Vec::with_capacity(argument_elements.len());
+                for element in argument_elements.iter() {
+                    argument_types.push((
+                        Type::from_element(element, scope.clone())?,
+                        Self::is_integer_constant(element),
+                    ));
+                }
+
+                let instance = StatementAnalyzer::instantiate_function(
+                    &function,
+                    argument_types.as_slice(),
+                    location,
+                )?;
+                casted_constants =
+                    Self::cast_constants(&instance, argument_elements.as_mut_slice());
+                FunctionType::UserDefined(instance)
+            }
+            function => function,
+        };
+
         let (return_type, intermediate) = match function {
             FunctionType::BuiltInFunction(function) => {
                 if !is_call_builtin {
@@ -145,11 +187,54 @@ impl Analyzer {
 
                 let unique_id = function.unique_id();
 
+                let constant_arguments = if function.is_constant() {
+                    argument_elements
+                        .iter()
+                        .map(|element| match element {
+                            Element::Constant(constant) => Some(constant.to_owned()),
+                            _ => None,
+                        })
+                        .collect::<Option<Vec<Constant>>>()
+                } else {
+                    None
+                };
+
+                let return_type = function
+                    .to_owned()
+                    .call(argument_elements)
+                    .map_err(|error| {
+                        Error::Element(location, ElementError::Type(TypeError::Function(error)))
+                    })?;
+
+                if let Some(arguments) = constant_arguments {
+                    let constant =
+                        ConstantFunctionEvaluator::evaluate(&function, arguments, location)?;
+                    return Ok((Element::Constant(constant), None, casted_constants));
+                }
+
+                let intermediate = GeneratorExpressionOperator::call(unique_id, input_size);
+
+                (return_type, intermediate)
+            }
+            FunctionType::Generic(_) => panic!(crate::PANIC_VALIDATED_DURING_SEMANTIC_ANALYSIS),
+            FunctionType::Variant(function) => {
+                if is_call_builtin {
+                    return Err(Error::Element(
+                        location,
+                        ElementError::Type(TypeError::Function(FunctionTypeError::BuiltIn(
+                            BuiltInFunctionTypeError::unknown(function.identifier()),
+                        ))),
+                    ));
+                }
+
+                let tag = function.tag();
+                let size = function.tagged_union().size();
+
                 let return_type = function.call(argument_elements).map_err(|error| {
                     Error::Element(location, ElementError::Type(TypeError::Function(error)))
                 })?;
 
-                let intermediate = GeneratorExpressionOperator::call(unique_id, input_size);
+                let intermediate = GeneratorExpressionOperator::variant(tag, input_size, size);
 
                 (return_type, intermediate)
             }
@@ -161,6 +246,46 @@ impl Analyzer {
                 .map_err(|error| Error::Element(location, error))?,
         );
 
-        Ok((element, intermediate))
+        Ok((element, Some(intermediate), casted_constants))
+    }
+
+    ///
+    /// Checks whether the `element` is a non-enumeration integer constant.
+    ///
+    fn is_integer_constant(element: &Element) -> bool {
+        match element {
+            Element::Constant(Constant::Integer(integer)) => integer.enumeration.is_none(),
+            _ => false,
+        }
+    }
+
+    ///
+    /// Casts the integer constant `arguments` to the argument types of the generic `function`
+    /// instance. The constants which do not fit are left as is and rejected by the call.
+    ///
+    fn cast_constants(
+        function: &UserDefinedFunctionType,
+        arguments: &mut [Element],
+    ) -> Vec<(usize, Constant)> {
+        let mut casted = Vec::new();
+        for (index, (argument, (_name, r#type))) in arguments
+            .iter_mut()
+            .zip(function.formal_params().iter())
+            .enumerate()
+        {
+            if !Self::is_integer_constant(argument) {
+                continue;
+            }
+
+            let constant = match argument {
+                Element::Constant(constant) if &constant.r#type() != r#type => constant.to_owned(),
+                _ => continue,
+            };
+            if let Ok(constant) = constant.cast(r#type.to_owned()) {
+                *argument = Element::Constant(constant.clone());
+                casted.push((index, constant));
+            }
+        }
+        casted
     }
 }
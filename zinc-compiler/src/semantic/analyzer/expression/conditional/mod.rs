@@ -5,6 +5,7 @@
 mod tests;
 
 use std::cell::RefCell;
+use std::convert::TryFrom;
 use std::rc::Rc;
 
 use crate::generator::expression::operand::conditional::builder::Builder as GeneratorConditionalExpressionBuilder;
@@ -12,7 +13,10 @@ use crate::generator::expression::operand::Operand as GeneratorExpressionOperand
 use crate::semantic::analyzer::expression::block::Analyzer as BlockAnalyzer;
 use crate::semantic::analyzer::expression::hint::Hint as TranslationHint;
 use crate::semantic::analyzer::expression::Analyzer as ExpressionAnalyzer;
+use crate::semantic::element::constant::Constant;
+use crate::semantic::element::error::Error as ElementError;
 use crate::semantic::element::r#type::Type;
+use crate::semantic::element::value::Value;
 use crate::semantic::element::Element;
 use crate::semantic::error::Error;
 use crate::semantic::scope::stack::Stack as ScopeStack;
@@ -77,16 +81,16 @@ impl Analyzer {
         scope_stack.pop();
         builder.set_main_block(main_block);
 
-        let else_type = if let Some(else_block) = conditional.else_block {
+        let (else_result, else_type) = if let Some(else_block) = conditional.else_block {
             scope_stack.push();
             let (else_result, else_block) = BlockAnalyzer::analyze(scope_stack.top(), else_block)?;
             let else_type = Type::from_element(&else_result, scope_stack.top())?;
             scope_stack.pop();
             builder.set_else_block(else_block);
 
-            else_type
+            (else_result, else_type)
         } else {
-            Type::Unit
+            (Element::Value(Value::Unit), Type::Unit)
         };
 
         // check if the two branches return equals types
@@ -99,7 +103,19 @@ impl Analyzer {
             });
         }
 
-        let element = main_result;
+        // the result is only known at compile time if the condition is constant as well
+        let element = match condition_result {
+            Element::Constant(Constant::Boolean(condition)) if condition.inner => main_result,
+            Element::Constant(Constant::Boolean(_)) => else_result,
+            _ => match main_result {
+                Element::Constant(constant) => Element::Value(
+                    Value::try_from(&constant.r#type())
+                        .map_err(ElementError::Value)
+                        .map_err(|error| Error::Element(main_expression_location, error))?,
+                ),
+                result => result,
+            },
+        };
         let intermediate = GeneratorExpressionOperand::Conditional(builder.finish());
 
         Ok((element, intermediate))
@@ -50,3 +50,18 @@ fn main() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn ok_constant_condition() {
+    let input = r#"
+const SIZE: u8 = if false { 1 } else { 2 };
+
+fn main() -> [u8; SIZE] {
+    [0; 2]
+}
+"#;
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert!(result.is_ok());
+}
@@ -2,7 +2,7 @@
 //! The tuple index semantic analyzer.
 //!
 
-use std::convert::TryFrom;
+use zinc_bytecode::Curve;
 
 use crate::semantic::element::constant::error::Error as ConstantError;
 use crate::semantic::element::constant::integer::Integer as IntegerConstant;
@@ -22,10 +22,10 @@ impl Analyzer {
     ///
     /// Analyzes the field integer, which is a tuple field index.
     ///
-    pub fn integer(integer: TupleIndex) -> Result<Element, Error> {
+    pub fn integer(integer: TupleIndex, curve: Curve) -> Result<Element, Error> {
         let location = integer.location;
 
-        let integer = IntegerConstant::try_from(&integer.literal)
+        let integer = IntegerConstant::try_from_literal(&integer.literal, curve)
             .map_err(|error| {
                 Error::Element(
                     location,
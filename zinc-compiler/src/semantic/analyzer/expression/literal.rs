@@ -2,7 +2,7 @@
 //! The literal semantic analyzer.
 //!
 
-use std::convert::TryFrom;
+use zinc_bytecode::Curve;
 
 use crate::generator::expression::operand::constant::Constant as GeneratorConstant;
 use crate::generator::expression::operand::Operand as GeneratorExpressionOperand;
@@ -38,16 +38,18 @@ impl Analyzer {
     }
 
     ///
-    /// Analyzes the integer literal.
+    /// Analyzes the integer literal, which is a field element of the `curve` if it is larger
+    /// than the largest integer type.
     ///
     /// Returns the semantic element and the intermediate representation if it is available.
     ///
     pub fn integer(
         literal: IntegerLiteral,
+        curve: Curve,
     ) -> Result<(Element, Option<GeneratorExpressionOperand>), Error> {
         let location = literal.location;
 
-        let constant = IntegerConstant::try_from(&literal)
+        let constant = IntegerConstant::try_from_literal(&literal, curve)
             .map(Constant::Integer)
             .map_err(|error| {
                 Error::Element(
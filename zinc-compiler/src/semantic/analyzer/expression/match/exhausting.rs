@@ -2,62 +2,280 @@
 //! The match expression exhausting data.
 //!
 
-use std::collections::HashMap;
-
 use num_bigint::BigInt;
 use num_traits::One;
 use num_traits::Zero;
 
 use crate::lexical::token::location::Location;
-use crate::semantic::element::r#type::enumeration::Enumeration;
+use crate::semantic::element::r#type::Type;
+
+///
+/// The simplified match pattern, used to check the match exhaustiveness and branch reachability.
+///
+/// Structure patterns are represented as tuples of their fields in the declaration order.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub enum Pattern {
+    /// a wildcard or binding, which matches any value
+    Wildcard,
+    /// a scalar constant, e.g. a boolean, integer or enumeration variant
+    Constant(BigInt),
+    /// a tuple or structure pattern
+    Tuple(Vec<Self>),
+    /// a tagged union variant pattern with its tag and payload pattern
+    Variant(usize, Box<Self>),
+}
+
+///
+/// The set of values a pattern may be matched against.
+///
+#[derive(Debug, Clone)]
+enum Domain {
+    /// booleans and enumerations, which can be exhausted by listing all their values
+    Finite(Vec<BigInt>),
+    /// integers and fields, which can only be exhausted by a wildcard or binding
+    Infinite,
+    /// tuples and structures
+    Product(Vec<Self>),
+    /// tagged unions, whose variant payloads are indexed with the variant tags
+    Sum(Vec<Self>),
+}
 
 pub struct Data {
-    patterns: HashMap<BigInt, Location>,
-    enumeration_type: Option<Enumeration>,
+    domain: Domain,
+    patterns: Vec<(Pattern, Location)>,
 }
 
 impl Data {
-    const DEFAULT_INITIAL_PATTERN_HASHMAP_SIZE: usize = 4;
+    const DEFAULT_INITIAL_PATTERN_VECTOR_SIZE: usize = 4;
 
-    pub fn new() -> Self {
+    pub fn new(scrutinee_type: &Type) -> Self {
         Self {
-            patterns: HashMap::with_capacity(Self::DEFAULT_INITIAL_PATTERN_HASHMAP_SIZE),
-            enumeration_type: None,
+            domain: Domain::from(scrutinee_type),
+            patterns: Vec::with_capacity(Self::DEFAULT_INITIAL_PATTERN_VECTOR_SIZE),
         }
     }
 
-    pub fn insert_boolean(&mut self, value: bool, location: Location) -> Option<Location> {
-        self.patterns
-            .insert(if value { BigInt::one() } else { BigInt::zero() }, location)
+    ///
+    /// Inserts the pattern of the next branch.
+    ///
+    /// Returns the location of the previous identical pattern, if it exists.
+    ///
+    pub fn insert(&mut self, pattern: Pattern, location: Location) -> Option<Location> {
+        let duplicate = self
+            .patterns
+            .iter()
+            .find(|(existing, _location)| existing == &pattern)
+            .map(|(_pattern, location)| *location);
+        self.patterns.push((pattern, location));
+        duplicate
     }
 
-    pub fn insert_integer(
-        &mut self,
-        value: BigInt,
-        enumeration_type: Option<Enumeration>,
-        location: Location,
-    ) -> Option<Location> {
-        self.enumeration_type = enumeration_type;
-        self.patterns.insert(value, location)
+    ///
+    /// Checks whether the pattern matches at least one value, which is not matched by
+    /// the previously inserted patterns.
+    ///
+    pub fn is_reachable(&self, pattern: &Pattern) -> bool {
+        Self::is_useful(
+            self.rows().as_slice(),
+            vec![pattern.to_owned()],
+            vec![self.domain.to_owned()],
+        )
     }
 
-    pub fn is_exhausted_boolean(&self) -> bool {
-        let mut current = self.patterns.keys().cloned().collect::<Vec<BigInt>>();
-        current.sort();
-        let full = vec![BigInt::zero(), BigInt::one()];
+    ///
+    /// Checks whether the previously inserted patterns match all the possible values.
+    ///
+    pub fn is_exhausted(&self) -> bool {
+        !self.is_reachable(&Pattern::Wildcard)
+    }
 
-        current == full
+    fn rows(&self) -> Vec<Vec<Pattern>> {
+        self.patterns
+            .iter()
+            .map(|(pattern, _location)| vec![pattern.to_owned()])
+            .collect()
     }
 
-    pub fn is_exhausted_integer(&self) -> bool {
-        match self.enumeration_type {
-            Some(ref enumeration) => {
-                let mut current = self.patterns.keys().cloned().collect::<Vec<BigInt>>();
-                current.sort();
+    ///
+    /// Checks whether the `vector` of patterns is useful with respect to the `rows` matrix,
+    /// that is, whether it matches a value which is not matched by any of the rows.
+    ///
+    /// Each column of the matrix and the vector element is matched against the domain
+    /// at the same position.
+    ///
+    fn is_useful(rows: &[Vec<Pattern>], vector: Vec<Pattern>, domains: Vec<Domain>) -> bool {
+        let mut vector = vector.into_iter();
+        let mut domains = domains.into_iter();
+        let (head, domain) = match (vector.next(), domains.next()) {
+            (Some(head), Some(domain)) => (head, domain),
+            _ => return rows.is_empty(),
+        };
+        let vector: Vec<Pattern> = vector.collect();
+        let domains: Vec<Domain> = domains.collect();
 
-                current == enumeration.values
+        match (head, domain) {
+            (Pattern::Tuple(elements), Domain::Product(element_domains)) => {
+                Self::is_useful_tuple(rows, elements, vector, element_domains, domains)
             }
-            None => false,
+            (Pattern::Wildcard, Domain::Product(element_domains)) => {
+                let elements = vec![Pattern::Wildcard; element_domains.len()];
+                Self::is_useful_tuple(rows, elements, vector, element_domains, domains)
+            }
+            (Pattern::Variant(tag, payload), Domain::Sum(payload_domains)) => {
+                Self::is_useful_variant(rows, tag, *payload, vector, payload_domains, domains)
+            }
+            (Pattern::Wildcard, Domain::Sum(payload_domains)) => {
+                let is_complete = (0..payload_domains.len()).all(|tag| {
+                    rows.iter().any(|row| match row.first() {
+                        Some(Pattern::Variant(row_tag, _)) => *row_tag == tag,
+                        _ => false,
+                    })
+                });
+
+                if is_complete {
+                    (0..payload_domains.len()).any(|tag| {
+                        Self::is_useful_variant(
+                            rows,
+                            tag,
+                            Pattern::Wildcard,
+                            vector.clone(),
+                            payload_domains.clone(),
+                            domains.clone(),
+                        )
+                    })
+                } else {
+                    Self::is_useful(Self::default_rows(rows).as_slice(), vector, domains)
+                }
+            }
+            (Pattern::Constant(value), _) => Self::is_useful(
+                Self::specialize_constant(rows, &value).as_slice(),
+                vector,
+                domains,
+            ),
+            (Pattern::Wildcard, Domain::Finite(values)) => {
+                let is_complete = values.iter().all(|value| {
+                    rows.iter()
+                        .any(|row| row.first() == Some(&Pattern::Constant(value.to_owned())))
+                });
+
+                if is_complete {
+                    values.iter().any(|value| {
+                        Self::is_useful(
+                            Self::specialize_constant(rows, value).as_slice(),
+                            vector.clone(),
+                            domains.clone(),
+                        )
+                    })
+                } else {
+                    Self::is_useful(Self::default_rows(rows).as_slice(), vector, domains)
+                }
+            }
+            (_, _) => Self::is_useful(Self::default_rows(rows).as_slice(), vector, domains),
+        }
+    }
+
+    fn is_useful_tuple(
+        rows: &[Vec<Pattern>],
+        elements: Vec<Pattern>,
+        vector: Vec<Pattern>,
+        element_domains: Vec<Domain>,
+        domains: Vec<Domain>,
+    ) -> bool {
+        let size = element_domains.len();
+        let rows: Vec<Vec<Pattern>> = rows
+            .iter()
+            .map(|row| {
+                let mut specialized = match row.first() {
+                    Some(Pattern::Tuple(elements)) => elements.to_owned(),
+                    _ => vec![Pattern::Wildcard; size],
+                };
+                specialized.extend(row.iter().skip(1).cloned());
+                specialized
+            })
+            .collect();
+
+        let mut elements = elements;
+        elements.extend(vector);
+        let mut element_domains = element_domains;
+        element_domains.extend(domains);
+
+        Self::is_useful(rows.as_slice(), elements, element_domains)
+    }
+
+    fn is_useful_variant(
+        rows: &[Vec<Pattern>],
+        tag: usize,
+        payload: Pattern,
+        vector: Vec<Pattern>,
+        payload_domains: Vec<Domain>,
+        domains: Vec<Domain>,
+    ) -> bool {
+        let rows: Vec<Vec<Pattern>> = rows
+            .iter()
+            .filter_map(|row| {
+                let mut specialized = match row.first() {
+                    Some(Pattern::Variant(row_tag, payload)) if *row_tag == tag => {
+                        vec![*payload.to_owned()]
+                    }
+                    Some(Pattern::Variant(_, _)) => return None,
+                    _ => vec![Pattern::Wildcard],
+                };
+                specialized.extend(row.iter().skip(1).cloned());
+                Some(specialized)
+            })
+            .collect();
+
+        let mut elements = vec![payload];
+        elements.extend(vector);
+        let mut element_domains = vec![payload_domains
+            .into_iter()
+            .nth(tag)
+            .expect(crate::PANIC_VALIDATED_DURING_SEMANTIC_ANALYSIS)];
+        element_domains.extend(domains);
+
+        Self::is_useful(rows.as_slice(), elements, element_domains)
+    }
+
+    fn specialize_constant(rows: &[Vec<Pattern>], value: &BigInt) -> Vec<Vec<Pattern>> {
+        rows.iter()
+            .filter(|row| match row.first() {
+                Some(Pattern::Constant(constant)) => constant == value,
+                _ => true,
+            })
+            .map(|row| row.iter().skip(1).cloned().collect())
+            .collect()
+    }
+
+    fn default_rows(rows: &[Vec<Pattern>]) -> Vec<Vec<Pattern>> {
+        rows.iter()
+            .filter(|row| row.first() == Some(&Pattern::Wildcard))
+            .map(|row| row.iter().skip(1).cloned().collect())
+            .collect()
+    }
+}
+
+impl From<&Type> for Domain {
+    fn from(r#type: &Type) -> Self {
+        match r#type {
+            Type::Boolean => Self::Finite(vec![BigInt::zero(), BigInt::one()]),
+            Type::Enumeration(enumeration) => Self::Finite(enumeration.values.to_owned()),
+            Type::Tuple { types } => Self::Product(types.iter().map(Self::from).collect()),
+            Type::Structure(structure) => Self::Product(
+                structure
+                    .fields
+                    .iter()
+                    .map(|(_name, r#type)| Self::from(r#type))
+                    .collect(),
+            ),
+            Type::TaggedUnion(tagged_union) => Self::Sum(
+                tagged_union
+                    .variants
+                    .iter()
+                    .map(|(_name, r#type)| Self::from(r#type))
+                    .collect(),
+            ),
+            _ => Self::Infinite,
         }
     }
 }
@@ -10,10 +10,15 @@ use std::cell::RefCell;
 use std::convert::TryFrom;
 use std::rc::Rc;
 
+use num_bigint::BigInt;
+use num_traits::One;
+use num_traits::Zero;
+
 use crate::generator::expression::operand::constant::Constant as GeneratorConstant;
 use crate::generator::expression::operand::r#match::builder::Builder as GeneratorMatchExpressionBuilder;
 use crate::generator::expression::operand::Operand as GeneratorExpressionOperand;
 use crate::generator::r#type::Type as GeneratorType;
+use crate::lexical::token::location::Location;
 use crate::semantic::analyzer::expression::hint::Hint as TranslationHint;
 use crate::semantic::analyzer::expression::Analyzer as ExpressionAnalyzer;
 use crate::semantic::element::constant::boolean::Boolean as BooleanConstant;
@@ -21,19 +26,51 @@ use crate::semantic::element::constant::error::Error as ConstantError;
 use crate::semantic::element::constant::integer::Integer as IntegerConstant;
 use crate::semantic::element::constant::Constant;
 use crate::semantic::element::error::Error as ElementError;
+use crate::semantic::element::r#type::error::Error as TypeError;
+use crate::semantic::element::r#type::function::variant::Function as VariantFunction;
+use crate::semantic::element::r#type::function::Function as FunctionType;
+use crate::semantic::element::r#type::structure::error::Error as StructureTypeError;
+use crate::semantic::element::r#type::structure::Structure as StructureType;
+use crate::semantic::element::r#type::tagged_union::TaggedUnion;
 use crate::semantic::element::r#type::Type;
+use crate::semantic::element::value::error::Error as ValueError;
+use crate::semantic::element::value::structure::error::Error as StructureValueError;
+use crate::semantic::element::value::Value;
 use crate::semantic::element::Element;
 use crate::semantic::error::Error;
 use crate::semantic::scope::item::variant::variable::Variable as ScopeVariableItem;
 use crate::semantic::scope::stack::Stack as ScopeStack;
 use crate::semantic::scope::Scope;
 use crate::syntax::tree::expression::r#match::Expression as MatchExpression;
+use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
+use crate::syntax::tree::identifier::Identifier;
 use crate::syntax::tree::pattern_match::variant::Variant as MatchPatternVariant;
+use crate::syntax::tree::pattern_match::Pattern as MatchPattern;
+use crate::warning::Warning;
 
 use self::exhausting::Data as ExhaustingData;
+use self::exhausting::Pattern as ExhaustingPattern;
 
 pub struct Analyzer {}
 
+///
+/// The data collected from a possibly nested branch pattern.
+///
+/// The `tests` are the scalar constants the scrutinee elements at the specified offsets
+/// must be equal to, and the `bindings` are the variables declared by the pattern along with
+/// their types and offsets within the scrutinee.
+///
+/// The bindings inside tagged union payloads are marked as casted, since the payloads are
+/// stored as fields and must be casted back to the binding types. The `payload_depth` is
+/// the number of tagged union payloads the pattern being analyzed is nested into.
+///
+#[derive(Default)]
+struct PatternData {
+    tests: Vec<(usize, GeneratorConstant)>,
+    bindings: Vec<(Identifier, Type, usize, bool)>,
+    payload_depth: usize,
+}
+
 impl Analyzer {
     ///
     /// Analyzes the match expression.
@@ -57,220 +94,566 @@ impl Analyzer {
             ExpressionAnalyzer::new(scope_stack.top())
                 .analyze(r#match.scrutinee, TranslationHint::Value)?;
         let scrutinee_type = Type::from_element(&scrutinee_result, scope_stack.top())?;
-        if scrutinee_type.is_scalar() {
-            builder.set_scrutinee(
-                scrutinee_expression,
-                GeneratorType::try_from_semantic(&scrutinee_type)
-                    .expect(crate::PANIC_VALIDATED_DURING_SEMANTIC_ANALYSIS),
-            );
-        } else {
-            return Err(Error::MatchScrutineeInvalidType {
-                location: scrutinee_location,
-                found: scrutinee_type.to_string(),
-            });
+        match scrutinee_type {
+            ref r#type if r#type.is_scalar() => {}
+            Type::Tuple { .. } | Type::Structure(_) | Type::TaggedUnion(_) => {}
+            ref r#type => {
+                return Err(Error::MatchScrutineeInvalidType {
+                    location: scrutinee_location,
+                    found: r#type.to_string(),
+                });
+            }
         }
+        builder.set_scrutinee(
+            scrutinee_expression,
+            GeneratorType::try_from_semantic(&scrutinee_type)
+                .expect(crate::PANIC_VALIDATED_DURING_SEMANTIC_ANALYSIS),
+        );
 
         if r#match.branches.len() < 2 {
             return Err(Error::MatchLessThanTwoBranches { location });
         }
 
         let first_branch_expression_location = r#match.branches[0].1.location;
-        let mut is_exhausted = false;
-        let mut exhausting_data = ExhaustingData::new();
+        let mut exhausting_data = ExhaustingData::new(&scrutinee_type);
         let mut branch_results = Vec::with_capacity(r#match.branches.len());
 
         for (pattern, expression) in r#match.branches.into_iter() {
             let pattern_location = pattern.location;
             let expression_location = expression.location;
 
-            if is_exhausted {
-                return Err(Error::MatchBranchUnreachable {
-                    location: pattern.location,
-                });
+            if exhausting_data.is_exhausted() {
+                scope_stack
+                    .top()
+                    .borrow()
+                    .emit_warning(Warning::MatchBranchUnreachable {
+                        location: pattern_location,
+                    });
+                continue;
+            }
+
+            let mut pattern_data = PatternData::default();
+            let exhausting_pattern = Self::pattern(
+                scope_stack.top(),
+                pattern,
+                &scrutinee_type,
+                0,
+                scrutinee_location,
+                &mut pattern_data,
+            )?;
+
+            let is_reachable = exhausting_data.is_reachable(&exhausting_pattern);
+            let duplicate = exhausting_data.insert(exhausting_pattern, pattern_location);
+            if !is_reachable {
+                match duplicate {
+                    Some(duplicate) => {
+                        return Err(Error::MatchBranchDuplicate {
+                            location: pattern_location,
+                            reference: duplicate,
+                        })
+                    }
+                    None => {
+                        scope_stack
+                            .top()
+                            .borrow()
+                            .emit_warning(Warning::MatchBranchUnreachable {
+                                location: pattern_location,
+                            });
+                        continue;
+                    }
+                }
+            }
+
+            scope_stack.push();
+            let mut bindings = Vec::with_capacity(pattern_data.bindings.len());
+            for (identifier, r#type, offset, is_casted) in pattern_data.bindings.into_iter() {
+                let location = identifier.location;
+                let casted_type = if is_casted {
+                    Some(
+                        GeneratorType::try_from_semantic(&r#type)
+                            .expect(crate::PANIC_VALIDATED_DURING_SEMANTIC_ANALYSIS),
+                    )
+                } else {
+                    None
+                };
+                bindings.push((identifier.name.clone(), offset, casted_type));
+                Scope::declare_variable(
+                    scope_stack.top(),
+                    identifier,
+                    ScopeVariableItem::new(false, r#type),
+                )
+                .map_err(|error| Error::Scope(location, error))?;
+            }
+            let (result, branch) = ExpressionAnalyzer::new(scope_stack.top())
+                .analyze(expression, TranslationHint::Value)?;
+            scope_stack.pop();
+
+            builder.push_branch(pattern_data.tests, bindings, branch);
+
+            let result_type = Type::from_element(&result, scope_stack.top())?;
+            if let Some(first_branch_result) = branch_results.get(0) {
+                let first_branch_result_type =
+                    Type::from_element(first_branch_result, scope_stack.top())?;
+                if result_type != first_branch_result_type {
+                    return Err(Error::MatchBranchExpressionInvalidType {
+                        location: expression_location,
+                        expected: first_branch_result_type.to_string(),
+                        found: result_type.to_string(),
+                        reference: first_branch_expression_location,
+                    });
+                }
+            }
+
+            branch_results.push(result);
+        }
+
+        if !exhausting_data.is_exhausted() {
+            return Err(Error::MatchNotExhausted { location });
+        }
+
+        // the branch is selected at runtime, so a constant branch result is not a constant anymore
+        let element = match branch_results.pop() {
+            Some(Element::Constant(constant)) => Element::Value(
+                Value::try_from(&constant.r#type())
+                    .map_err(ElementError::Value)
+                    .map_err(|error| Error::Element(location, error))?,
+            ),
+            Some(result) => result,
+            None => Element::Constant(Constant::Unit),
+        };
+        let intermediate = GeneratorExpressionOperand::Match(builder.finish());
+
+        Ok((element, intermediate))
+    }
+
+    ///
+    /// Analyzes the possibly nested branch pattern, which is matched against the scrutinee
+    /// element of type `r#type` located at `offset` within the scrutinee.
+    ///
+    /// Returns the simplified pattern used for the exhaustiveness checking.
+    ///
+    fn pattern(
+        scope: Rc<RefCell<Scope>>,
+        pattern: MatchPattern,
+        r#type: &Type,
+        offset: usize,
+        scrutinee_location: Location,
+        data: &mut PatternData,
+    ) -> Result<ExhaustingPattern, Error> {
+        let location = pattern.location;
+
+        match pattern.variant {
+            MatchPatternVariant::BooleanLiteral(boolean) => {
+                let constant = BooleanConstant::from(boolean);
+                let pattern_type = constant.r#type();
+                if &pattern_type != r#type {
+                    return Err(Error::MatchBranchPatternInvalidType {
+                        location,
+                        expected: r#type.to_string(),
+                        found: pattern_type.to_string(),
+                        reference: scrutinee_location,
+                    });
+                }
+
+                let value = if constant.inner {
+                    BigInt::one()
+                } else {
+                    BigInt::zero()
+                };
+                let constant = GeneratorConstant::try_from_semantic(&Constant::Boolean(constant))
+                    .expect(crate::PANIC_VALIDATED_DURING_SYNTAX_ANALYSIS);
+                data.tests.push((offset, constant));
+
+                Ok(ExhaustingPattern::Constant(value))
             }
+            MatchPatternVariant::IntegerLiteral(integer) => {
+                let constant = IntegerConstant::try_from_literal(
+                    &integer,
+                    scope.borrow().state().curve(),
+                )
+                .map_err(|error| {
+                    Error::Element(
+                        location,
+                        ElementError::Constant(ConstantError::Integer(error)),
+                    )
+                })?;
+                let pattern_type = constant.r#type();
+                if &pattern_type != r#type {
+                    return Err(Error::MatchBranchPatternInvalidType {
+                        location,
+                        expected: r#type.to_string(),
+                        found: pattern_type.to_string(),
+                        reference: scrutinee_location,
+                    });
+                }
 
-            let result = match pattern.variant {
-                MatchPatternVariant::BooleanLiteral(boolean) => {
-                    let location = boolean.location;
+                let value = constant.value.clone();
+                let constant = GeneratorConstant::try_from_semantic(&Constant::Integer(constant))
+                    .expect(crate::PANIC_VALIDATED_DURING_SYNTAX_ANALYSIS);
+                data.tests.push((offset, constant));
 
-                    let constant = BooleanConstant::from(boolean);
-                    let pattern_type = constant.r#type();
-                    if pattern_type != scrutinee_type {
+                Ok(ExhaustingPattern::Constant(value))
+            }
+            MatchPatternVariant::Path(path) => {
+                if let Type::TaggedUnion(tagged_union) = r#type {
+                    let variant =
+                        Self::variant(scope, path, tagged_union, location, scrutinee_location)?;
+                    if variant.payload() != &Type::unit() {
                         return Err(Error::MatchBranchPatternInvalidType {
-                            location: pattern_location,
-                            expected: scrutinee_type.to_string(),
-                            found: pattern_type.to_string(),
+                            location,
+                            expected: variant.to_string(),
+                            found: variant.identifier(),
                             reference: scrutinee_location,
                         });
                     }
+                    data.tests.push((offset, Self::tag_constant(variant.tag())));
 
-                    if let Some(duplicate) =
-                        exhausting_data.insert_boolean(constant.inner, location)
-                    {
-                        return Err(Error::MatchBranchDuplicate {
+                    return Ok(ExhaustingPattern::Variant(
+                        variant.tag(),
+                        Box::new(ExhaustingPattern::Wildcard),
+                    ));
+                }
+
+                let path_location = path.location;
+
+                let constant =
+                    match ExpressionAnalyzer::new(scope).analyze(path, TranslationHint::Value)? {
+                        (Element::Constant(constant), _intermediate) => constant,
+                        (element, _intermediate) => {
+                            return Err(Error::MatchBranchPatternPathExpectedConstant {
+                                location: path_location,
+                                found: element.to_string(),
+                            });
+                        }
+                    };
+
+                let value = match constant {
+                    Constant::Boolean(ref boolean) if boolean.inner => BigInt::one(),
+                    Constant::Boolean(_) => BigInt::zero(),
+                    Constant::Integer(ref integer) => integer.value.to_owned(),
+                    ref constant => {
+                        return Err(Error::MatchBranchPatternInvalidType {
                             location,
-                            reference: duplicate,
+                            expected: r#type.to_string(),
+                            found: constant.r#type().to_string(),
+                            reference: scrutinee_location,
                         });
                     }
+                };
+                let pattern_type = constant.r#type();
+                if &pattern_type != r#type {
+                    return Err(Error::MatchBranchPatternInvalidType {
+                        location,
+                        expected: r#type.to_string(),
+                        found: pattern_type.to_string(),
+                        reference: scrutinee_location,
+                    });
+                }
 
-                    let constant =
-                        GeneratorConstant::try_from_semantic(&Constant::Boolean(constant))
-                            .expect(crate::PANIC_VALIDATED_DURING_SYNTAX_ANALYSIS);
-                    let (result, branch) = ExpressionAnalyzer::new(scope_stack.top())
-                        .analyze(expression, TranslationHint::Value)?;
-
-                    if exhausting_data.is_exhausted_boolean() {
-                        is_exhausted = true;
-                        builder.set_wildcard_branch(branch);
-                    } else {
-                        builder.push_branch(constant, branch);
-                    }
+                let constant = GeneratorConstant::try_from_semantic(&constant)
+                    .expect(crate::PANIC_VALIDATED_DURING_SYNTAX_ANALYSIS);
+                data.tests.push((offset, constant));
 
-                    result
+                Ok(ExhaustingPattern::Constant(value))
+            }
+            MatchPatternVariant::Binding(identifier) => {
+                data.bindings.push((
+                    identifier,
+                    r#type.to_owned(),
+                    offset,
+                    data.payload_depth > 0,
+                ));
+
+                Ok(ExhaustingPattern::Wildcard)
+            }
+            MatchPatternVariant::Wildcard => Ok(ExhaustingPattern::Wildcard),
+            MatchPatternVariant::Tuple(elements) => {
+                let types = match r#type {
+                    Type::Tuple { types } if types.len() == elements.len() => types,
+                    r#type => {
+                        return Err(Error::MatchBranchPatternInvalidType {
+                            location,
+                            expected: r#type.to_string(),
+                            found: Self::tuple_pattern_shape(elements.len()),
+                            reference: scrutinee_location,
+                        });
+                    }
+                };
+
+                let mut offset = offset;
+                let mut patterns = Vec::with_capacity(elements.len());
+                for (element, r#type) in elements.into_iter().zip(types.iter()) {
+                    patterns.push(Self::pattern(
+                        scope.clone(),
+                        element,
+                        r#type,
+                        offset,
+                        scrutinee_location,
+                        data,
+                    )?);
+                    offset += r#type.size();
                 }
-                MatchPatternVariant::IntegerLiteral(integer) => {
-                    let location = integer.location;
 
-                    let constant = IntegerConstant::try_from(&integer).map_err(|error| {
-                        Error::Element(
-                            location,
-                            ElementError::Constant(ConstantError::Integer(error)),
-                        )
-                    })?;
-                    let pattern_type = constant.r#type();
-                    if pattern_type != scrutinee_type {
+                Ok(ExhaustingPattern::Tuple(patterns))
+            }
+            MatchPatternVariant::TupleVariant { path, elements } => {
+                let tagged_union = match r#type {
+                    Type::TaggedUnion(tagged_union) => tagged_union,
+                    r#type => {
                         return Err(Error::MatchBranchPatternInvalidType {
-                            location: pattern_location,
-                            expected: scrutinee_type.to_string(),
-                            found: pattern_type.to_string(),
+                            location,
+                            expected: r#type.to_string(),
+                            found: Self::tuple_pattern_shape(elements.len()),
                             reference: scrutinee_location,
                         });
                     }
-
-                    if let Some(duplicate) =
-                        exhausting_data.insert_integer(constant.value.clone(), None, location)
-                    {
-                        return Err(Error::MatchBranchDuplicate {
+                };
+                let variant = Self::variant(
+                    scope.clone(),
+                    path,
+                    tagged_union,
+                    location,
+                    scrutinee_location,
+                )?;
+                let types = match variant.payload() {
+                    Type::Tuple { types } if types.len() == elements.len() => types.to_owned(),
+                    _ => {
+                        return Err(Error::MatchBranchPatternInvalidType {
                             location,
-                            reference: duplicate,
+                            expected: variant.to_string(),
+                            found: format!(
+                                "{}{}",
+                                variant.identifier(),
+                                Self::tuple_pattern_shape(elements.len())
+                            ),
+                            reference: scrutinee_location,
                         });
                     }
+                };
+                data.tests.push((offset, Self::tag_constant(variant.tag())));
+
+                data.payload_depth += 1;
+                let mut offset = offset + 1;
+                let mut patterns = Vec::with_capacity(elements.len());
+                for (element, r#type) in elements.into_iter().zip(types.iter()) {
+                    patterns.push(Self::pattern(
+                        scope.clone(),
+                        element,
+                        r#type,
+                        offset,
+                        scrutinee_location,
+                        data,
+                    )?);
+                    offset += r#type.size();
+                }
+                data.payload_depth -= 1;
 
-                    let constant =
-                        GeneratorConstant::try_from_semantic(&Constant::Integer(constant))
-                            .expect(crate::PANIC_VALIDATED_DURING_SYNTAX_ANALYSIS);
-                    let (result, branch) = ExpressionAnalyzer::new(scope_stack.top())
-                        .analyze(expression, TranslationHint::Value)?;
-
-                    if exhausting_data.is_exhausted_integer() {
-                        is_exhausted = true;
-                        builder.set_wildcard_branch(branch);
-                    } else {
-                        builder.push_branch(constant, branch);
+                Ok(ExhaustingPattern::Variant(
+                    variant.tag(),
+                    Box::new(ExhaustingPattern::Tuple(patterns)),
+                ))
+            }
+            MatchPatternVariant::Structure {
+                path,
+                fields,
+                is_rest,
+            } => {
+                let pattern_type = match ExpressionAnalyzer::new(scope.clone())
+                    .analyze(path, TranslationHint::Type)?
+                {
+                    (Element::Type(r#type), _intermediate) => r#type,
+                    (element, _intermediate) => {
+                        return Err(Error::MatchBranchPatternInvalidType {
+                            location,
+                            expected: r#type.to_string(),
+                            found: element.to_string(),
+                            reference: scrutinee_location,
+                        });
                     }
-
-                    result
-                }
-                MatchPatternVariant::Path(path) => {
-                    let location = path.location;
-
-                    let constant = match ExpressionAnalyzer::new(scope_stack.top())
-                        .analyze(path, TranslationHint::Value)?
-                    {
-                        (Element::Constant(constant), _intermediate) => {
-                            if let Constant::Integer(ref integer) = constant {
-                                if let Some(duplicate) = exhausting_data.insert_integer(
-                                    integer.value.to_owned(),
-                                    integer.enumeration.to_owned(),
-                                    location,
-                                ) {
-                                    return Err(Error::MatchBranchDuplicate {
-                                        location,
-                                        reference: duplicate,
-                                    });
-                                }
-                            }
-                            constant
+                };
+
+                if let Type::Function(FunctionType::Variant(variant)) = pattern_type {
+                    let structure = match (r#type, variant.payload()) {
+                        (Type::TaggedUnion(tagged_union), Type::Structure(structure))
+                            if variant.tagged_union() == tagged_union =>
+                        {
+                            structure.to_owned()
                         }
-                        (element, _intermediate) => {
-                            return Err(Error::MatchBranchPatternPathExpectedConstant {
+                        (r#type, _payload) => {
+                            return Err(Error::MatchBranchPatternInvalidType {
                                 location,
-                                found: element.to_string(),
+                                expected: r#type.to_string(),
+                                found: variant.to_string(),
+                                reference: scrutinee_location,
                             });
                         }
                     };
-
-                    let constant = GeneratorConstant::try_from_semantic(&constant)
-                        .expect(crate::PANIC_VALIDATED_DURING_SYNTAX_ANALYSIS);
-                    let (result, branch) = ExpressionAnalyzer::new(scope_stack.top())
-                        .analyze(expression, TranslationHint::Value)?;
-
-                    if exhausting_data.is_exhausted_integer() {
-                        is_exhausted = true;
-                        builder.set_wildcard_branch(branch);
-                    } else {
-                        builder.push_branch(constant, branch);
-                    }
-
-                    result
+                    data.tests.push((offset, Self::tag_constant(variant.tag())));
+
+                    data.payload_depth += 1;
+                    let patterns = Self::structure_fields(
+                        scope,
+                        &structure,
+                        fields,
+                        is_rest,
+                        offset + 1,
+                        location,
+                        scrutinee_location,
+                        data,
+                    )?;
+                    data.payload_depth -= 1;
+
+                    return Ok(ExhaustingPattern::Variant(
+                        variant.tag(),
+                        Box::new(ExhaustingPattern::Tuple(patterns)),
+                    ));
                 }
-                MatchPatternVariant::Binding(identifier) => {
-                    let location = identifier.location;
-                    is_exhausted = true;
-
-                    scope_stack.push();
-                    Scope::declare_variable(
-                        scope_stack.top(),
-                        identifier.clone(),
-                        ScopeVariableItem::new(false, scrutinee_type.clone()),
-                    )
-                    .map_err(|error| Error::Scope(location, error))?;
-                    let (result, branch) = ExpressionAnalyzer::new(scope_stack.top())
-                        .analyze(expression, TranslationHint::Value)?;
-                    scope_stack.pop();
 
-                    builder.set_binding_branch(branch, identifier.name);
-
-                    result
-                }
-                MatchPatternVariant::Wildcard => {
-                    is_exhausted = true;
-                    let (result, branch) = ExpressionAnalyzer::new(scope_stack.top())
-                        .analyze(expression, TranslationHint::Value)?;
+                let structure = match r#type {
+                    Type::Structure(structure) if &pattern_type == r#type => structure,
+                    r#type => {
+                        return Err(Error::MatchBranchPatternInvalidType {
+                            location,
+                            expected: r#type.to_string(),
+                            found: pattern_type.to_string(),
+                            reference: scrutinee_location,
+                        });
+                    }
+                };
+
+                let patterns = Self::structure_fields(
+                    scope,
+                    structure,
+                    fields,
+                    is_rest,
+                    offset,
+                    location,
+                    scrutinee_location,
+                    data,
+                )?;
+
+                Ok(ExhaustingPattern::Tuple(patterns))
+            }
+        }
+    }
 
-                    builder.set_wildcard_branch(branch);
+    ///
+    /// Analyzes the structure pattern fields, which are matched against the `structure`
+    /// fields located at `offset` within the scrutinee.
+    ///
+    /// Returns the simplified patterns of all the structure fields in the declaration order.
+    ///
+    #[allow(clippy::too_many_arguments)]
+    fn structure_fields(
+        scope: Rc<RefCell<Scope>>,
+        structure: &StructureType,
+        mut fields: Vec<(Identifier, MatchPattern)>,
+        is_rest: bool,
+        offset: usize,
+        location: Location,
+        scrutinee_location: Location,
+        data: &mut PatternData,
+    ) -> Result<Vec<ExhaustingPattern>, Error> {
+        if let Some((identifier, _pattern)) = fields.iter().find(|(identifier, _)| {
+            !structure
+                .fields
+                .iter()
+                .any(|(name, _type)| name == &identifier.name)
+        }) {
+            return Err(Error::Element(
+                identifier.location,
+                ElementError::Value(ValueError::Structure(
+                    StructureValueError::FieldDoesNotExist {
+                        type_identifier: structure.identifier.to_owned(),
+                        field_name: identifier.name.to_owned(),
+                    },
+                )),
+            ));
+        }
 
-                    result
+        let mut offset = offset;
+        let mut patterns = Vec::with_capacity(structure.fields.len());
+        for (name, r#type) in structure.fields.iter() {
+            let pattern = match fields
+                .iter()
+                .position(|(identifier, _)| &identifier.name == name)
+            {
+                Some(position) => {
+                    let (_identifier, pattern) = fields.remove(position);
+                    Self::pattern(
+                        scope.clone(),
+                        pattern,
+                        r#type,
+                        offset,
+                        scrutinee_location,
+                        data,
+                    )?
                 }
-            };
-
-            let result_type = Type::from_element(&result, scope_stack.top())?;
-            if let Some(first_branch_result) = branch_results.get(0) {
-                let first_branch_result_type =
-                    Type::from_element(first_branch_result, scope_stack.top())?;
-                if result_type != first_branch_result_type {
-                    return Err(Error::MatchBranchExpressionInvalidType {
-                        location: expression_location,
-                        expected: first_branch_result_type.to_string(),
-                        found: result_type.to_string(),
-                        reference: first_branch_expression_location,
+                None if is_rest => ExhaustingPattern::Wildcard,
+                None => {
+                    return Err(Error::MatchBranchPatternStructureFieldMissing {
+                        location,
+                        type_identifier: structure.identifier.to_owned(),
+                        field_name: name.to_owned(),
                     });
                 }
-            }
+            };
+            patterns.push(pattern);
+            offset += r#type.size();
+        }
 
-            branch_results.push(result);
+        if let Some((identifier, _pattern)) = fields.into_iter().next() {
+            return Err(Error::Element(
+                identifier.location,
+                ElementError::Type(TypeError::Structure(StructureTypeError::DuplicateField {
+                    type_identifier: structure.identifier.to_owned(),
+                    field_name: identifier.name,
+                })),
+            ));
         }
 
-        if !is_exhausted {
-            return Err(Error::MatchNotExhausted { location });
+        Ok(patterns)
+    }
+
+    ///
+    /// Resolves the `path` pattern to a variant of the `tagged_union` scrutinee type.
+    ///
+    fn variant(
+        scope: Rc<RefCell<Scope>>,
+        path: ExpressionTree,
+        tagged_union: &TaggedUnion,
+        location: Location,
+        scrutinee_location: Location,
+    ) -> Result<VariantFunction, Error> {
+        match ExpressionAnalyzer::new(scope).analyze(path, TranslationHint::Type)? {
+            (Element::Type(Type::Function(FunctionType::Variant(variant))), _intermediate)
+                if variant.tagged_union() == tagged_union =>
+            {
+                Ok(variant)
+            }
+            (element, _intermediate) => Err(Error::MatchBranchPatternInvalidType {
+                location,
+                expected: tagged_union.to_string(),
+                found: element.to_string(),
+                reference: scrutinee_location,
+            }),
         }
+    }
 
-        let element = match branch_results.pop() {
-            Some(result) => result,
-            None => Element::Constant(Constant::Unit),
-        };
-        let intermediate = GeneratorExpressionOperand::Match(builder.finish());
+    ///
+    /// The tagged union variant tag constant, which is stored as a field.
+    ///
+    fn tag_constant(tag: usize) -> GeneratorConstant {
+        GeneratorConstant::new_field(BigInt::from(tag))
+    }
 
-        Ok((element, intermediate))
+    ///
+    /// Describes a tuple pattern of `size` elements, e.g. `(_, _, _)`.
+    ///
+    fn tuple_pattern_shape(size: usize) -> String {
+        match size {
+            1 => "(_,)".to_owned(),
+            size => format!("({})", vec!["_"; size].join(", ")),
+        }
     }
 }
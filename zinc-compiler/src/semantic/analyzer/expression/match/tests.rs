@@ -8,11 +8,14 @@ use std::cell::RefCell;
 use std::collections::HashMap;
 use std::rc::Rc;
 
+use zinc_bytecode::Curve;
+
 use crate::error::Error;
 use crate::lexical::token::location::Location;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::error::Error as SemanticError;
 use crate::semantic::scope::Scope;
+use crate::warning::Warning;
 
 static PANIC_COMPILE_DEPENDENCY: &str = "Dependencies must be successfully compiled";
 
@@ -80,11 +83,11 @@ fn main() {
 }
 
 #[test]
-fn error_match_branch_unreachable() {
+fn warning_match_branch_unreachable() {
     let input = r#"
 fn main() {
     let scrutinee = 42;
-    let result = match scrutinee {
+    let _result = match scrutinee {
         1 => 10,
         _ => 101,
         2 => 20,
@@ -92,21 +95,21 @@ fn main() {
 }
 "#;
 
-    let expected = Err(Error::Semantic(SemanticError::MatchBranchUnreachable {
+    let expected = Ok(vec![Warning::MatchBranchUnreachable {
         location: Location::new(7, 9),
-    }));
+    }]);
 
-    let result = crate::semantic::tests::compile_entry(input);
+    let result = crate::semantic::tests::compile_entry_warnings(input);
 
     assert_eq!(result, expected);
 }
 
 #[test]
-fn error_match_branch_unreachable_exhausted_boolean() {
+fn warning_match_branch_unreachable_exhausted_boolean() {
     let input = r#"
 fn main() {
     let scrutinee = true;
-    let result = match scrutinee {
+    let _result = match scrutinee {
         false => 10,
         true => 101,
         _ => 20,
@@ -114,17 +117,17 @@ fn main() {
 }
 "#;
 
-    let expected = Err(Error::Semantic(SemanticError::MatchBranchUnreachable {
+    let expected = Ok(vec![Warning::MatchBranchUnreachable {
         location: Location::new(7, 9),
-    }));
+    }]);
 
-    let result = crate::semantic::tests::compile_entry(input);
+    let result = crate::semantic::tests::compile_entry_warnings(input);
 
     assert_eq!(result, expected);
 }
 
 #[test]
-fn error_match_branch_unreachable_exhausted_enumeration() {
+fn warning_match_branch_unreachable_exhausted_enumeration() {
     let input = r#"
 enum List {
     One = 1,
@@ -134,7 +137,7 @@ enum List {
 
 fn main() {
     let scrutinee = List::One;
-    let result = match scrutinee {
+    let _result = match scrutinee {
         List::One => 1,
         List::Two => 2,
         List::Three => 3,
@@ -143,11 +146,11 @@ fn main() {
 }
 "#;
 
-    let expected = Err(Error::Semantic(SemanticError::MatchBranchUnreachable {
+    let expected = Ok(vec![Warning::MatchBranchUnreachable {
         location: Location::new(14, 9),
-    }));
+    }]);
 
-    let result = crate::semantic::tests::compile_entry(input);
+    let result = crate::semantic::tests::compile_entry_warnings(input);
 
     assert_eq!(result, expected);
 }
@@ -155,7 +158,7 @@ fn main() {
 #[test]
 fn error_match_branch_pattern_path_expected_constant() {
     let module_1 = r#"
-type X = field;
+pub type X = field;
 "#;
 
     let binary = r#"
@@ -173,7 +176,7 @@ fn main() -> u8 {
     let expected = Err(Error::Semantic(
         SemanticError::MatchBranchPatternPathExpectedConstant {
             location: Location::new(7, 17),
-            found: Type::field().to_string(),
+            found: Type::field(Curve::default()).to_string(),
         },
     ));
 
@@ -284,3 +287,188 @@ fn main() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn error_match_not_exhausted_tuple() {
+    let input = r#"
+fn main() {
+    let scrutinee = (true, 42);
+    let result = match scrutinee {
+        (true, _) => 10,
+        (false, 0) => 20,
+    };
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::MatchNotExhausted {
+        location: Location::new(4, 18),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn warning_match_branch_unreachable_tuple() {
+    let input = r#"
+fn main() {
+    let scrutinee = (true, 42);
+    let _result = match scrutinee {
+        (true, _) => 10,
+        (_, 1) => 20,
+        (true, 2) => 30,
+        _ => 40,
+    };
+}
+"#;
+
+    let expected = Ok(vec![Warning::MatchBranchUnreachable {
+        location: Location::new(7, 9),
+    }]);
+
+    let result = crate::semantic::tests::compile_entry_warnings(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_match_branch_pattern_invalid_type_tuple() {
+    let input = r#"
+fn main() {
+    let scrutinee = (true, 42);
+    let result = match scrutinee {
+        (true, _, _) => 10,
+        _ => 20,
+    };
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::MatchBranchPatternInvalidType {
+            location: Location::new(5, 9),
+            expected: Type::tuple(vec![Type::boolean(), Type::integer_unsigned(8)]).to_string(),
+            found: "(_, _, _)".to_owned(),
+            reference: Location::new(4, 24),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_match_branch_pattern_structure_field_missing() {
+    let input = r#"
+struct Data {
+    a: u8,
+    b: bool,
+}
+
+fn main() {
+    let scrutinee = Data { a: 42, b: true };
+    let result = match scrutinee {
+        Data { a: 42 } => 10,
+        _ => 20,
+    };
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::MatchBranchPatternStructureFieldMissing {
+            location: Location::new(10, 9),
+            type_identifier: "Data".to_owned(),
+            field_name: "b".to_owned(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_match_not_exhausted_tagged_union() {
+    let input = r#"
+enum Operation {
+    Noop,
+    Burn(u64),
+    Transfer { to: field, amount: u64 },
+}
+
+fn main() {
+    let scrutinee = Operation::Burn(42 as u64);
+    let result = match scrutinee {
+        Operation::Noop => 0,
+        Operation::Transfer { amount, .. } => 1,
+    };
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::MatchNotExhausted {
+        location: Location::new(10, 18),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn warning_match_branch_unreachable_tagged_union() {
+    let input = r#"
+enum Operation {
+    Noop,
+    Burn(u64),
+}
+
+fn main() {
+    let scrutinee = Operation::Noop;
+    let _result = match scrutinee {
+        Operation::Burn(_) => 0,
+        Operation::Noop => 1,
+        _ => 2,
+    };
+}
+"#;
+
+    let expected = Ok(vec![Warning::MatchBranchUnreachable {
+        location: Location::new(12, 9),
+    }]);
+
+    let result = crate::semantic::tests::compile_entry_warnings(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_match_branch_pattern_invalid_type_tagged_union() {
+    let input = r#"
+enum Operation {
+    Noop,
+    Burn(u64),
+}
+
+fn main() {
+    let scrutinee = Operation::Noop;
+    let result = match scrutinee {
+        Operation::Burn(_, _) => 0,
+        _ => 1,
+    };
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::MatchBranchPatternInvalidType {
+            location: Location::new(10, 9),
+            expected: "fn Operation::Burn(u64) -> enum Operation".to_owned(),
+            found: "Operation::Burn(_, _)".to_owned(),
+            reference: Location::new(9, 24),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
@@ -34,12 +34,16 @@ use crate::semantic::element::error::Error as ElementError;
 use crate::semantic::element::place::element::Element as PlaceElement;
 use crate::semantic::element::place::error::Error as PlaceError;
 use crate::semantic::element::place::Place;
+use crate::semantic::element::r#type::error::Error as TypeError;
+use crate::semantic::element::r#type::function::user::Function as UserDefinedFunctionType;
+use crate::semantic::element::r#type::function::Function as FunctionType;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::element::value::Value;
 use crate::semantic::element::Element;
 use crate::semantic::error::Error;
 use crate::semantic::scope::stack::Stack as ScopeStack;
 use crate::semantic::scope::Scope;
+use crate::syntax::tree::expression::structure::Expression as StructureExpression;
 use crate::syntax::tree::expression::tree::node::operand::Operand as ExpressionOperand;
 use crate::syntax::tree::expression::tree::node::operator::Operator as ExpressionOperator;
 use crate::syntax::tree::expression::tree::node::Node as ExpressionTreeNode;
@@ -73,6 +77,8 @@ pub struct Analyzer {
     evaluation_stack: EvaluationStack,
     intermediate: GeneratorExpression,
     is_next_call_builtin: bool,
+    next_call_receiver: Option<Element>,
+    is_constant: bool,
 }
 
 impl Analyzer {
@@ -85,6 +91,21 @@ impl Analyzer {
             evaluation_stack: EvaluationStack::new(),
             intermediate: GeneratorExpression::new(),
             is_next_call_builtin: false,
+            next_call_receiver: None,
+            is_constant: false,
+        }
+    }
+
+    ///
+    /// Initializes a new analyzer of the expression, which is constant if `is_constant` is set.
+    ///
+    /// The array, tuple, and structure literals with constant elements are only constants
+    /// in constant expressions, like `const` item initializers or array sizes.
+    ///
+    pub fn new_constant(scope: Rc<RefCell<Scope>>, is_constant: bool) -> Self {
+        Self {
+            is_constant,
+            ..Self::new(scope)
         }
     }
 
@@ -134,6 +155,7 @@ impl Analyzer {
             ExpressionTreeNode::Operand(operand) => {
                 return Self::evaluate(
                     self.scope_stack.top(),
+                    self.is_constant,
                     StackElement::NotEvaluated(operand),
                     hint,
                 );
@@ -467,17 +489,17 @@ impl Analyzer {
                 ExpressionOperator::Field => {
                     self.left_local(tree.left, operator)?;
                     self.right_local(tree.right, operator)?;
-                    let intermediate = self.field(tree.location)?;
+                    let intermediate = self.field(tree.location, hint)?;
                     if let Some(intermediate) = intermediate {
                         self.intermediate.push_operator(tree.location, intermediate);
                     }
                 }
 
                 ExpressionOperator::Call => {
+                    let intermediate_length = self.intermediate.len();
                     self.left_local(tree.left, operator)?;
                     self.right_local(tree.right, operator)?;
-                    let operator = self.call(tree.location)?;
-                    self.intermediate.push_operator(tree.location, operator);
+                    self.call(tree.location, intermediate_length)?;
                 }
                 ExpressionOperator::CallBuiltIn => {
                     self.is_next_call_builtin = true;
@@ -486,13 +508,43 @@ impl Analyzer {
 
                 ExpressionOperator::Path => {
                     self.left_local(tree.left, operator)?;
-                    self.right_local(tree.right, operator)?;
-                    self.path(tree.location)?;
+                    match tree.right.map(|right| *right) {
+                        Some(ExpressionTree {
+                            value,
+                            location,
+                            left,
+                            right,
+                        }) => match *value {
+                            ExpressionTreeNode::Operand(ExpressionOperand::Structure(
+                                structure,
+                            )) => {
+                                self.variant_structure(structure, tree.location)?;
+                            }
+                            value => {
+                                self.right_local(
+                                    Some(Box::new(ExpressionTree {
+                                        location,
+                                        value: Box::new(value),
+                                        left,
+                                        right,
+                                    })),
+                                    operator,
+                                )?;
+                                self.path(tree.location)?;
+                            }
+                        },
+                        None => panic!(crate::PANIC_VALIDATED_DURING_SYNTAX_ANALYSIS),
+                    }
                 }
             },
         }
 
-        Self::evaluate(self.scope_stack.top(), self.evaluation_stack.pop(), hint)
+        Self::evaluate(
+            self.scope_stack.top(),
+            self.is_constant,
+            self.evaluation_stack.pop(),
+            hint,
+        )
     }
 
     ///
@@ -592,11 +644,13 @@ impl Analyzer {
     {
         let (operand_2, _) = Self::evaluate(
             self.scope_stack.top(),
+            self.is_constant,
             self.evaluation_stack.pop(),
             TranslationHint::Value,
         )?;
         let (operand_1, _) = Self::evaluate(
             self.scope_stack.top(),
+            self.is_constant,
             self.evaluation_stack.pop(),
             TranslationHint::Place,
         )?;
@@ -627,6 +681,7 @@ impl Analyzer {
                 }),
             ));
         }
+        Scope::mark_mutated(self.scope_stack.top(), place.identifier.as_str());
 
         self.evaluation_stack
             .push(StackElement::Evaluated(Element::Value(Value::Unit)));
@@ -643,11 +698,13 @@ impl Analyzer {
     {
         let (operand_2, _) = Self::evaluate(
             self.scope_stack.top(),
+            self.is_constant,
             self.evaluation_stack.pop(),
             TranslationHint::Value,
         )?;
         let (operand_1, _) = Self::evaluate(
             self.scope_stack.top(),
+            self.is_constant,
             self.evaluation_stack.pop(),
             TranslationHint::Value,
         )?;
@@ -672,11 +729,13 @@ impl Analyzer {
     {
         let (operand_2, _) = Self::evaluate(
             self.scope_stack.top(),
+            self.is_constant,
             self.evaluation_stack.pop(),
             TranslationHint::Value,
         )?;
         let (operand_1, _) = Self::evaluate(
             self.scope_stack.top(),
+            self.is_constant,
             self.evaluation_stack.pop(),
             TranslationHint::Value,
         )?;
@@ -689,7 +748,7 @@ impl Analyzer {
             _ => panic!(crate::PANIC_VALIDATED_DURING_SYNTAX_ANALYSIS),
         };
         let intermediate = GeneratorExpressionOperand::Constant(
-            GeneratorExpressionConstant::new_integer(start, false, crate::BITLENGTH_FIELD),
+            GeneratorExpressionConstant::new_field(start),
         );
 
         self.evaluation_stack.push(StackElement::Evaluated(result));
@@ -706,11 +765,13 @@ impl Analyzer {
     ) -> Result<Option<GeneratorExpressionOperator>, Error> {
         let (operand_2, _) = Self::evaluate(
             self.scope_stack.top(),
+            self.is_constant,
             self.evaluation_stack.pop(),
             TranslationHint::Type,
         )?;
         let (operand_1, _) = Self::evaluate(
             self.scope_stack.top(),
+            self.is_constant,
             self.evaluation_stack.pop(),
             TranslationHint::Value,
         )?;
@@ -736,6 +797,7 @@ impl Analyzer {
     {
         let (operand, _) = Self::evaluate(
             self.scope_stack.top(),
+            self.is_constant,
             self.evaluation_stack.pop(),
             TranslationHint::Value,
         )?;
@@ -756,14 +818,19 @@ impl Analyzer {
     ) -> Result<Option<GeneratorExpressionOperator>, Error> {
         let (operand_2, _) = Self::evaluate(
             self.scope_stack.top(),
+            self.is_constant,
             self.evaluation_stack.pop(),
             TranslationHint::Value,
         )?;
-        let (operand_1, _) = Self::evaluate(
+        let (operand_1, intermediate) = Self::evaluate(
             self.scope_stack.top(),
+            self.is_constant,
             self.evaluation_stack.pop(),
             TranslationHint::Place,
         )?;
+        if let Some(intermediate) = intermediate {
+            self.intermediate.push_operand(intermediate);
+        }
 
         let (result, access) = Element::index(operand_1, operand_2.clone())
             .map_err(|error| Error::Element(location, error))?;
@@ -810,17 +877,55 @@ impl Analyzer {
     ///
     /// Analyzes the tuple or structure field access operation.
     ///
-    fn field(&mut self, location: Location) -> Result<Option<GeneratorExpressionOperator>, Error> {
+    /// If the field access is the function call operand, the method with the `self` receiver
+    /// is looked for first. The receiver is written to the IR as the first function argument.
+    ///
+    fn field(
+        &mut self,
+        location: Location,
+        hint: TranslationHint,
+    ) -> Result<Option<GeneratorExpressionOperator>, Error> {
         let (operand_2, _) = Self::evaluate(
             self.scope_stack.top(),
+            self.is_constant,
             self.evaluation_stack.pop(),
             TranslationHint::Field,
         )?;
-        let (operand_1, _) = Self::evaluate(
+        let (operand_1, intermediate) = Self::evaluate(
             self.scope_stack.top(),
+            self.is_constant,
             self.evaluation_stack.pop(),
             TranslationHint::Place,
         )?;
+        if let Some(intermediate) = intermediate {
+            self.intermediate.push_operand(intermediate);
+        }
+
+        if let TranslationHint::Type = hint {
+            if let Some(method) = self.method(&operand_1, &operand_2)? {
+                let receiver = match operand_1 {
+                    Element::Place(place) => {
+                        let (element, intermediate) =
+                            PlaceTranslator::translate(place, TranslationHint::Value)?;
+                        if let Some(intermediate) = intermediate {
+                            self.intermediate.push_operand(intermediate);
+                        }
+                        element
+                    }
+                    element => element,
+                };
+                self.next_call_receiver = Some(receiver);
+
+                self.evaluation_stack
+                    .push(StackElement::Evaluated(Element::Type(Type::Function(
+                        FunctionType::UserDefined(method),
+                    ))));
+
+                return Ok(None);
+            }
+        }
+
+        self.check_field(&operand_1, &operand_2)?;
 
         let (result, access) = Element::field(operand_1, operand_2)
             .map_err(|error| Error::Element(location, error))?;
@@ -842,25 +947,97 @@ impl Analyzer {
         }
     }
 
+    ///
+    /// Checks whether the structure field `operand_2` of the `operand_1` value is accessible
+    /// from the current module.
+    ///
+    fn check_field(&self, operand_1: &Element, operand_2: &Element) -> Result<(), Error> {
+        let identifier = match operand_2 {
+            Element::Identifier(identifier) => identifier,
+            _ => return Ok(()),
+        };
+
+        match operand_1 {
+            Element::Place(_) | Element::Value(_) | Element::Constant(_) => {
+                if let Type::Structure(structure) =
+                    Type::from_element(operand_1, self.scope_stack.top())?
+                {
+                    Scope::check_field(
+                        self.scope_stack.top(),
+                        &structure,
+                        identifier.name.as_str(),
+                    )
+                    .map_err(|error| Error::Scope(identifier.location, error))?;
+                }
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    ///
+    /// Looks for the method `operand_2` in the implementation of the `operand_1` type.
+    ///
+    fn method(
+        &self,
+        operand_1: &Element,
+        operand_2: &Element,
+    ) -> Result<Option<UserDefinedFunctionType>, Error> {
+        let identifier = match operand_2 {
+            Element::Identifier(identifier) => identifier,
+            _ => return Ok(None),
+        };
+
+        let scope = match operand_1 {
+            Element::Place(_) | Element::Value(_) | Element::Constant(_) => {
+                match Type::from_element(operand_1, self.scope_stack.top())? {
+                    Type::Structure(structure) => structure.scope,
+                    Type::Enumeration(enumeration) => enumeration.scope,
+                    Type::TaggedUnion(tagged_union) => tagged_union.scope,
+                    _ => return Ok(None),
+                }
+            }
+            _ => return Ok(None),
+        };
+
+        Scope::resolve_method(self.scope_stack.top(), scope, identifier.name.as_str())
+            .map_err(|error| Error::Scope(identifier.location, error))
+    }
+
     ///
     /// Analyzes the function call operation.
     ///
-    fn call(&mut self, location: Location) -> Result<GeneratorExpressionOperator, Error> {
+    /// If the call has been evaluated at compile time, the IR written since `intermediate_length`,
+    /// that is, the arguments and the method receiver, is replaced with the constant result.
+    ///
+    fn call(&mut self, location: Location, intermediate_length: usize) -> Result<(), Error> {
         let is_call_builtin = self.is_next_call_builtin;
         self.is_next_call_builtin = false;
 
         let (operand_2, _intermediate_2) = Self::evaluate(
             self.scope_stack.top(),
+            self.is_constant,
             self.evaluation_stack.pop(),
             TranslationHint::Value,
         )?;
         let (operand_1, _intermediate_1) = Self::evaluate(
             self.scope_stack.top(),
+            self.is_constant,
             self.evaluation_stack.pop(),
             TranslationHint::Type,
         )?;
 
-        let (element, operator) = CallAnalyzer::analyze(
+        let mut receiver_count = 0;
+        let operand_2 = match (self.next_call_receiver.take(), operand_2) {
+            (Some(receiver), Element::ArgumentList(mut arguments)) => {
+                receiver_count = 1;
+                arguments.insert(0, receiver);
+                Element::ArgumentList(arguments)
+            }
+            (_, operand_2) => operand_2,
+        };
+
+        let (element, operator, casted_constants) = CallAnalyzer::analyze(
             self.scope_stack.top(),
             operand_1,
             operand_2,
@@ -868,9 +1045,27 @@ impl Analyzer {
             location,
         )?;
 
+        for (index, constant) in casted_constants.into_iter() {
+            if index >= receiver_count {
+                self.intermediate
+                    .replace_argument_constant(index - receiver_count, &constant);
+            }
+        }
+
+        match (operator, &element) {
+            (Some(operator), _) => self.intermediate.push_operator(location, operator),
+            (None, Element::Constant(constant)) => {
+                self.intermediate.truncate(intermediate_length);
+                if let Some(operand) = GeneratorExpressionOperand::try_from_constant(constant) {
+                    self.intermediate.push_operand(operand);
+                }
+            }
+            (None, _) => panic!(crate::PANIC_VALIDATED_DURING_SEMANTIC_ANALYSIS),
+        }
+
         self.evaluation_stack.push(StackElement::Evaluated(element));
 
-        Ok(operator)
+        Ok(())
     }
 
     ///
@@ -879,11 +1074,13 @@ impl Analyzer {
     fn path(&mut self, location: Location) -> Result<(), Error> {
         let (operand_2, _) = Self::evaluate(
             self.scope_stack.top(),
+            self.is_constant,
             self.evaluation_stack.pop(),
             TranslationHint::Field,
         )?;
         let (operand_1, _) = Self::evaluate(
             self.scope_stack.top(),
+            self.is_constant,
             self.evaluation_stack.pop(),
             TranslationHint::Path,
         )?;
@@ -895,11 +1092,91 @@ impl Analyzer {
         Ok(())
     }
 
+    ///
+    /// Analyzes the enumeration variant literal with named fields, e.g.
+    /// `Operation::Transfer { to: 42, amount: 1 }`.
+    ///
+    /// The path left operand is already on the evaluation stack.
+    ///
+    fn variant_structure(
+        &mut self,
+        structure: StructureExpression,
+        location: Location,
+    ) -> Result<(), Error> {
+        let identifier_location = structure.identifier.location;
+
+        let (operand_1, _) = Self::evaluate(
+            self.scope_stack.top(),
+            self.is_constant,
+            self.evaluation_stack.pop(),
+            TranslationHint::Path,
+        )?;
+        let path = Element::path(operand_1, Element::Identifier(structure.identifier))
+            .map_err(|error| Error::Element(location, error))?;
+
+        let (variant, structure_type) = match Self::evaluate(
+            self.scope_stack.top(),
+            self.is_constant,
+            StackElement::Evaluated(path),
+            TranslationHint::Type,
+        )? {
+            (Element::Type(Type::Function(FunctionType::Variant(variant))), _) => {
+                match variant.payload() {
+                    Type::Structure(structure_type) => {
+                        let structure_type = structure_type.to_owned();
+                        (variant, structure_type)
+                    }
+                    _ => {
+                        return Err(Error::Element(
+                            identifier_location,
+                            ElementError::Type(TypeError::AliasDoesNotPointToStructure {
+                                found: variant.to_string(),
+                            }),
+                        ))
+                    }
+                }
+            }
+            (element, _) => {
+                return Err(Error::Element(
+                    identifier_location,
+                    ElementError::Type(TypeError::AliasDoesNotPointToStructure {
+                        found: element.to_string(),
+                    }),
+                ))
+            }
+        };
+
+        let (_payload, intermediate) = StructureAnalyzer::fields(
+            self.scope_stack.top(),
+            structure_type,
+            structure.fields,
+            self.is_constant,
+        )?;
+        self.intermediate.push_operand(intermediate);
+
+        let tagged_union = variant.tagged_union().to_owned();
+        self.intermediate.push_operator(
+            location,
+            GeneratorExpressionOperator::variant(
+                variant.tag(),
+                variant.payload().size(),
+                tagged_union.size(),
+            ),
+        );
+        self.evaluation_stack
+            .push(StackElement::Evaluated(Element::Value(Value::TaggedUnion(
+                tagged_union,
+            ))));
+
+        Ok(())
+    }
+
     ///
     /// Evaluates the element, turning it to the state specified with `hint`.
     ///
     fn evaluate(
         scope: Rc<RefCell<Scope>>,
+        is_constant: bool,
         element: StackElement,
         hint: TranslationHint,
     ) -> Result<(Element, Option<GeneratorExpressionOperand>), Error> {
@@ -907,21 +1184,32 @@ impl Analyzer {
             StackElement::NotEvaluated(operand) => match operand {
                 ExpressionOperand::Unit => Ok((Element::Constant(Constant::Unit), None)),
                 ExpressionOperand::LiteralBoolean(inner) => LiteralAnalyzer::boolean(inner),
-                ExpressionOperand::LiteralInteger(inner) => LiteralAnalyzer::integer(inner),
+                ExpressionOperand::LiteralInteger(inner) => {
+                    LiteralAnalyzer::integer(inner, scope.borrow().state().curve())
+                }
                 ExpressionOperand::LiteralString(inner) => {
                     Ok((LiteralAnalyzer::string(inner)?, None))
                 }
-                ExpressionOperand::TupleIndex(inner) => Ok((MemberAnalyzer::integer(inner)?, None)),
+                ExpressionOperand::TupleIndex(inner) => Ok((
+                    MemberAnalyzer::integer(inner, scope.borrow().state().curve())?,
+                    None,
+                )),
                 ExpressionOperand::Identifier(inner) => {
                     IdentifierAnalyzer::analyze(scope, inner, hint)
                 }
                 ExpressionOperand::Type(inner) => Ok((TypeAnalyzer::analyze(scope, inner)?, None)),
-                ExpressionOperand::Array(inner) => ArrayAnalyzer::analyze(scope, inner)
-                    .map(|(element, intermediate)| (element, Some(intermediate))),
-                ExpressionOperand::Tuple(inner) => TupleAnalyzer::analyze(scope, inner)
-                    .map(|(element, intermediate)| (element, Some(intermediate))),
-                ExpressionOperand::Structure(inner) => StructureAnalyzer::analyze(scope, inner)
-                    .map(|(element, intermediate)| (element, Some(intermediate))),
+                ExpressionOperand::Array(inner) => {
+                    ArrayAnalyzer::analyze(scope, inner, is_constant)
+                        .map(|(element, intermediate)| (element, Some(intermediate)))
+                }
+                ExpressionOperand::Tuple(inner) => {
+                    TupleAnalyzer::analyze(scope, inner, is_constant)
+                        .map(|(element, intermediate)| (element, Some(intermediate)))
+                }
+                ExpressionOperand::Structure(inner) => {
+                    StructureAnalyzer::analyze(scope, inner, is_constant)
+                        .map(|(element, intermediate)| (element, Some(intermediate)))
+                }
                 ExpressionOperand::List(inner) => ListAnalyzer::analyze(scope, inner)
                     .map(|(element, intermediate)| (element, Some(intermediate))),
                 ExpressionOperand::Block(inner) => {
@@ -6,13 +6,20 @@ use std::cell::RefCell;
 use std::convert::TryFrom;
 use std::rc::Rc;
 
+use num_bigint::BigInt;
+use num_traits::Zero;
+
 use crate::generator::expression::operand::constant::Constant as GeneratorConstant;
+use crate::generator::expression::operand::group::Expression as GeneratorGroupExpression;
 use crate::generator::expression::operand::Operand as GeneratorExpressionOperand;
 use crate::generator::r#type::Type as GeneratorType;
 use crate::semantic::analyzer::expression::hint::Hint as TranslationHint;
+use crate::semantic::element::constant::Constant;
 use crate::semantic::element::error::Error as ElementError;
 use crate::semantic::element::path::Path;
 use crate::semantic::element::place::Place;
+use crate::semantic::element::r#type::function::Function as FunctionType;
+use crate::semantic::element::r#type::Type;
 use crate::semantic::element::value::Value;
 use crate::semantic::element::Element;
 use crate::semantic::error::Error;
@@ -46,9 +53,19 @@ impl Translator {
                     )),
                     None,
                 )),
-                ScopeItemVariant::Constant(constant) => Ok((Element::Constant(constant), None)),
+                ScopeItemVariant::Constant(constant) => {
+                    let intermediate = match constant {
+                        Constant::Array(_) | Constant::Tuple(_) | Constant::Structure(_) => {
+                            GeneratorExpressionOperand::try_from_constant(&constant)
+                        }
+                        _ => None,
+                    };
+                    let element = Element::Constant(constant);
+                    Ok((element, intermediate))
+                }
                 ScopeItemVariant::Type(r#type) => Ok((Element::Type(r#type), None)),
                 ScopeItemVariant::Module(_) => Ok((Element::Module(path_last_element_name), None)),
+                ScopeItemVariant::Trait(_) => Ok((Element::Path(path), None)),
             },
             TranslationHint::Value => match Scope::resolve_path(scope, &path)?.variant {
                 ScopeItemVariant::Variable(variable) => {
@@ -71,13 +88,27 @@ impl Translator {
                     Ok((element, intermediate))
                 }
                 ScopeItemVariant::Constant(constant) => {
-                    let intermediate = GeneratorConstant::try_from_semantic(&constant)
-                        .map(GeneratorExpressionOperand::Constant);
+                    let intermediate = GeneratorExpressionOperand::try_from_constant(&constant);
                     let element = Element::Constant(constant);
                     Ok((element, intermediate))
                 }
+                ScopeItemVariant::Type(Type::Function(FunctionType::Variant(variant)))
+                    if variant.payload() == &Type::unit() =>
+                {
+                    let mut constants = vec![GeneratorConstant::new_field(BigInt::from(variant.tag()))];
+                    constants.extend((0..variant.tagged_union().payload_size()).map(|_| {
+                        GeneratorConstant::new_field(BigInt::zero())
+                    }));
+                    let intermediate = GeneratorExpressionOperand::Group(
+                        GeneratorGroupExpression::new_constants(constants),
+                    );
+                    let element =
+                        Element::Value(Value::TaggedUnion(variant.tagged_union().to_owned()));
+                    Ok((element, Some(intermediate)))
+                }
                 ScopeItemVariant::Type(r#type) => Ok((Element::Type(r#type), None)),
                 ScopeItemVariant::Module(_) => Ok((Element::Module(path_last_element_name), None)),
+                ScopeItemVariant::Trait(_) => Ok((Element::Path(path), None)),
             },
 
             TranslationHint::Type => match Scope::resolve_path(scope, &path)?.variant {
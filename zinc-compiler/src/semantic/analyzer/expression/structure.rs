@@ -7,10 +7,15 @@ use std::rc::Rc;
 
 use crate::generator::expression::operand::group::builder::Builder as GeneratorGroupExpressionBuilder;
 use crate::generator::expression::operand::Operand as GeneratorExpressionOperand;
+use crate::generator::expression::Expression as GeneratorExpression;
 use crate::semantic::analyzer::expression::hint::Hint as TranslationHint;
 use crate::semantic::analyzer::expression::Analyzer as ExpressionAnalyzer;
+use crate::semantic::analyzer::statement::Analyzer as StatementAnalyzer;
+use crate::semantic::element::constant::structure::Structure as StructureConstant;
+use crate::semantic::element::constant::Constant;
 use crate::semantic::element::error::Error as ElementError;
 use crate::semantic::element::r#type::error::Error as TypeError;
+use crate::semantic::element::r#type::structure::Structure as StructureType;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::element::value::error::Error as ValueError;
 use crate::semantic::element::value::structure::Structure;
@@ -20,6 +25,8 @@ use crate::semantic::error::Error;
 use crate::semantic::scope::item::variant::Variant as ScopeItemVariant;
 use crate::semantic::scope::Scope;
 use crate::syntax::tree::expression::structure::Expression as StructureExpression;
+use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
+use crate::syntax::tree::identifier::Identifier;
 
 pub struct Analyzer {}
 
@@ -32,16 +39,28 @@ impl Analyzer {
     pub fn analyze(
         scope: Rc<RefCell<Scope>>,
         structure: StructureExpression,
+        is_constant: bool,
     ) -> Result<(Element, GeneratorExpressionOperand), Error> {
         let identifier_location = structure.identifier.location;
 
-        let mut builder = GeneratorGroupExpressionBuilder::default();
-
         let structure_type = match Scope::resolve_item(scope.clone(), &structure.identifier.name)
             .map_err(|error| Error::Scope(identifier_location, error))?
             .variant
         {
             ScopeItemVariant::Type(Type::Structure(structure)) => structure,
+            ScopeItemVariant::Type(Type::GenericStructure(generic)) => {
+                let fields = Self::field_values(scope.clone(), structure.fields, is_constant)?;
+                let field_types: Vec<(String, Type)> = fields
+                    .iter()
+                    .map(|(identifier, r#type, _expression, _constant)| {
+                        (identifier.name.to_owned(), r#type.to_owned())
+                    })
+                    .collect();
+                let arguments = generic.infer(field_types.as_slice(), identifier_location)?;
+                let structure_type = StatementAnalyzer::instantiate_structure(&generic, arguments)?;
+                Self::check_fields(scope, &structure_type, fields.as_slice())?;
+                return Self::value(structure_type, fields, is_constant);
+            }
             item => {
                 return Err(Error::Element(
                     identifier_location,
@@ -51,14 +70,96 @@ impl Analyzer {
                 ));
             }
         };
-        let mut result = Structure::new(structure_type);
 
-        for (identifier, expression) in structure.fields.into_iter() {
-            let identifier_location = identifier.location;
+        Self::fields(scope, structure_type, structure.fields, is_constant)
+    }
+
+    ///
+    /// Analyzes the fields of the literal of the `structure_type` structure.
+    ///
+    /// Returns the semantic structure value and the intermediate representation.
+    ///
+    pub fn fields(
+        scope: Rc<RefCell<Scope>>,
+        structure_type: StructureType,
+        fields: Vec<(Identifier, ExpressionTree)>,
+        is_constant: bool,
+    ) -> Result<(Element, GeneratorExpressionOperand), Error> {
+        let fields = Self::field_values(scope.clone(), fields, is_constant)?;
+        Self::check_fields(scope, &structure_type, fields.as_slice())?;
+        Self::value(structure_type, fields, is_constant)
+    }
+
+    ///
+    /// Checks whether the fields of the `structure_type` literal are accessible from `scope`.
+    ///
+    fn check_fields(
+        scope: Rc<RefCell<Scope>>,
+        structure_type: &StructureType,
+        fields: &[(Identifier, Type, GeneratorExpression, Option<Constant>)],
+    ) -> Result<(), Error> {
+        for (identifier, _type, _expression, _constant) in fields.iter() {
+            Scope::check_field(scope.clone(), structure_type, identifier.name.as_str())
+                .map_err(|error| Error::Scope(identifier.location, error))?;
+        }
+        Ok(())
+    }
 
-            let (element, expression) = ExpressionAnalyzer::new(scope.clone())
-                .analyze(expression, TranslationHint::Value)?;
+    ///
+    /// Analyzes the field expressions, returning their types, intermediate representation,
+    /// and values if they are constant.
+    ///
+    fn field_values(
+        scope: Rc<RefCell<Scope>>,
+        fields: Vec<(Identifier, ExpressionTree)>,
+        is_constant: bool,
+    ) -> Result<Vec<(Identifier, Type, GeneratorExpression, Option<Constant>)>, Error> {
+        let mut values = Vec::with_capacity(fields.len());
+        for (identifier, expression) in fields.into_iter() {
+            let (element, expression) =
+                ExpressionAnalyzer::new_constant(scope.clone(), is_constant)
+                    .analyze(expression, TranslationHint::Value)?;
             let element_type = Type::from_element(&element, scope.clone())?;
+            let constant = match element {
+                Element::Constant(constant) => Some(constant),
+                _ => None,
+            };
+            values.push((identifier, element_type, expression, constant));
+        }
+        Ok(values)
+    }
+
+    ///
+    /// Checks the analyzed field values against the `structure_type` structure.
+    ///
+    /// If the expression `is_constant` and all the field values are constant, the structure
+    /// is a constant as well.
+    ///
+    fn value(
+        structure_type: StructureType,
+        fields: Vec<(Identifier, Type, GeneratorExpression, Option<Constant>)>,
+        is_constant: bool,
+    ) -> Result<(Element, GeneratorExpressionOperand), Error> {
+        let mut builder = GeneratorGroupExpressionBuilder::default();
+
+        let mut result = Structure::new(structure_type.clone());
+        let mut constants = if is_constant {
+            Some(Vec::with_capacity(fields.len()))
+        } else {
+            None
+        };
+
+        for (identifier, element_type, expression, constant) in fields.into_iter() {
+            let identifier_location = identifier.location;
+
+            constants = match (constants, constant) {
+                (Some(mut constants), Some(constant)) => {
+                    constants.push((identifier.name.clone(), constant));
+                    Some(constants)
+                }
+                _ => None,
+            };
+
             result
                 .push(identifier.name, element_type.clone())
                 .map_err(|error| {
@@ -71,7 +172,13 @@ impl Analyzer {
             builder.push_expression(element_type, expression);
         }
 
-        let element = Element::Value(Value::Structure(result));
+        let element = match constants {
+            Some(constants) => Element::Constant(Constant::Structure(StructureConstant::new(
+                structure_type,
+                constants,
+            ))),
+            None => Element::Value(Value::Structure(result)),
+        };
         let intermediate = GeneratorExpressionOperand::Group(builder.finish());
 
         Ok((element, intermediate))
@@ -9,6 +9,8 @@ use crate::generator::expression::operand::group::builder::Builder as GeneratorG
 use crate::generator::expression::operand::Operand as GeneratorExpressionOperand;
 use crate::semantic::analyzer::expression::hint::Hint as TranslationHint;
 use crate::semantic::analyzer::expression::Analyzer as ExpressionAnalyzer;
+use crate::semantic::element::constant::tuple::Tuple as TupleConstant;
+use crate::semantic::element::constant::Constant;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::element::value::tuple::Tuple;
 use crate::semantic::element::value::Value;
@@ -25,23 +27,40 @@ impl Analyzer {
     ///
     /// Returns the semantic element and the intermediate representation.
     ///
+    /// If the expression `is_constant` and all the elements are constant, the tuple is
+    /// a constant as well.
+    ///
     pub fn analyze(
         scope: Rc<RefCell<Scope>>,
         tuple: TupleExpression,
+        is_constant: bool,
     ) -> Result<(Element, GeneratorExpressionOperand), Error> {
         let mut result = Tuple::default();
+        let mut constants = if is_constant { Some(Vec::new()) } else { None };
         let mut builder = GeneratorGroupExpressionBuilder::default();
 
         for expression in tuple.elements.into_iter() {
-            let (element, expression) = ExpressionAnalyzer::new(scope.clone())
-                .analyze(expression, TranslationHint::Value)?;
+            let (element, expression) =
+                ExpressionAnalyzer::new_constant(scope.clone(), is_constant)
+                    .analyze(expression, TranslationHint::Value)?;
             let element_type = Type::from_element(&element, scope.clone())?;
             result.push(element_type.clone());
 
+            constants = match (constants, element) {
+                (Some(mut constants), Element::Constant(constant)) => {
+                    constants.push(constant);
+                    Some(constants)
+                }
+                _ => None,
+            };
+
             builder.push_expression(element_type, expression);
         }
 
-        let element = Element::Value(Value::Tuple(result));
+        let element = match constants {
+            Some(constants) => Element::Constant(Constant::Tuple(TupleConstant::new(constants))),
+            None => Element::Value(Value::Tuple(result)),
+        };
         let intermediate = GeneratorExpressionOperand::Group(builder.finish());
 
         Ok((element, intermediate))
@@ -3,6 +3,7 @@
 //!
 
 pub mod entry;
+pub mod evaluator;
 pub mod expression;
 pub mod module;
 pub mod statement;
@@ -10,8 +10,10 @@ use crate::error::Error as CompilerError;
 use crate::generator::Tree;
 use crate::semantic::analyzer::statement::Analyzer as StatementAnalyzer;
 use crate::semantic::scope::stack::Stack as ScopeStack;
+use crate::semantic::scope::state::State as SemanticState;
 use crate::semantic::scope::Scope;
 use crate::syntax::tree::Tree as SyntaxTree;
+use crate::warning::Warning;
 
 ///
 /// Analyzes a module, which are located in non-`main.zn` files.
@@ -20,6 +22,7 @@ use crate::syntax::tree::Tree as SyntaxTree;
 ///
 pub struct Analyzer {
     scope_stack: ScopeStack,
+    warnings: Rc<RefCell<Vec<Warning>>>,
 }
 
 impl Default for Analyzer {
@@ -30,24 +33,53 @@ impl Default for Analyzer {
 
 impl Analyzer {
     pub fn new() -> Self {
+        Self::new_in_build(
+            Rc::new(SemanticState::default()),
+            Rc::new(RefCell::new(Vec::new())),
+        )
+    }
+
+    ///
+    /// Initializes an analyzer, which shares the semantic `state` and the warning storage
+    /// with the other files of the build.
+    ///
+    pub fn new_in_build(state: Rc<SemanticState>, warnings: Rc<RefCell<Vec<Warning>>>) -> Self {
         Self {
-            scope_stack: ScopeStack::new_global(),
+            scope_stack: ScopeStack::new_global(state, warnings.clone()),
+            warnings,
         }
     }
 
-    pub fn compile(self, program: SyntaxTree) -> Result<(Rc<RefCell<Scope>>, Tree), CompilerError> {
-        let mut intermediate = Tree::new();
-
-        let mut analyzer = StatementAnalyzer::new(self.scope_stack.top(), HashMap::new());
-        for statement in program.statements.into_iter() {
-            if let Some(statement) = analyzer
-                .local_mod(statement)
-                .map_err(CompilerError::Semantic)?
-            {
-                intermediate.statements.push(statement);
-            }
-        }
+    ///
+    /// Analyzes the module and yields its scope and IR along with the warnings.
+    ///
+    /// Only the unused private module items are warned about, since the public ones may be used
+    /// by other modules.
+    ///
+    /// The `dependencies` are the scopes of the module own submodules, which are analyzed
+    /// before their parent module.
+    ///
+    pub fn compile(
+        self,
+        program: SyntaxTree,
+        dependencies: HashMap<String, Rc<RefCell<Scope>>>,
+    ) -> Result<(Rc<RefCell<Scope>>, Tree, Vec<Warning>), Vec<CompilerError>> {
+        let statements = StatementAnalyzer::new(self.scope_stack.top(), dependencies)
+            .module(program.statements)
+            .map_err(|errors| {
+                errors
+                    .into_iter()
+                    .map(CompilerError::Semantic)
+                    .collect::<Vec<CompilerError>>()
+            })?;
+        let intermediate = Tree { statements };
+
+        self.scope_stack.top().borrow().warn_unused_items(true);
 
-        Ok((self.scope_stack.top(), intermediate))
+        Ok((
+            self.scope_stack.top(),
+            intermediate,
+            Warning::take_all(&self.warnings),
+        ))
     }
 }
@@ -14,7 +14,12 @@ use num_traits::ToPrimitive;
 use crate::generator::statement::declaration::Statement as GeneratorDeclarationStatement;
 use crate::generator::statement::function::Statement as GeneratorFunctionStatement;
 use crate::generator::statement::loop_for::Statement as GeneratorForLoopStatement;
+use crate::generator::statement::r#break::Statement as GeneratorBreakStatement;
+use crate::generator::statement::r#continue::Statement as GeneratorContinueStatement;
+use crate::generator::statement::r#return::Statement as GeneratorReturnStatement;
 use crate::generator::statement::Statement as GeneratorStatement;
+use crate::lexical::token::lexeme::keyword::Keyword;
+use crate::lexical::token::location::Location;
 use crate::semantic::analyzer::expression::block::Analyzer as BlockAnalyzer;
 use crate::semantic::analyzer::expression::hint::Hint as TranslationHint;
 use crate::semantic::analyzer::expression::Analyzer as ExpressionAnalyzer;
@@ -24,29 +29,45 @@ use crate::semantic::element::constant::Constant;
 use crate::semantic::element::error::Error as ElementError;
 use crate::semantic::element::r#type::error::Error as TypeError;
 use crate::semantic::element::r#type::function::error::Error as FunctionTypeError;
+use crate::semantic::element::r#type::function::generic::Function as GenericFunctionType;
 use crate::semantic::element::r#type::function::user::Function as UserDefinedFunctionType;
 use crate::semantic::element::r#type::function::Function as FunctionType;
+use crate::semantic::element::r#type::generic::Argument as GenericArgument;
+use crate::semantic::element::r#type::generic::Parameter as GenericParameter;
 use crate::semantic::element::r#type::structure::error::Error as StructureTypeError;
+use crate::semantic::element::r#type::structure::generic::Generic as GenericStructureType;
+use crate::semantic::element::r#type::structure::Structure as StructureType;
 use crate::semantic::element::r#type::Type;
-use crate::semantic::element::r#type::INDEX as TYPE_INDEX;
 use crate::semantic::element::Element;
 use crate::semantic::error::Error;
+use crate::semantic::scope::function::Context as ScopeFunctionContext;
+use crate::semantic::scope::item::variant::r#trait::Trait as ScopeTraitItem;
 use crate::semantic::scope::item::variant::variable::Variable as ScopeVariableItem;
 use crate::semantic::scope::item::variant::Variant as ScopeItemVariant;
+use crate::semantic::scope::item::Item as ScopeItem;
 use crate::semantic::scope::stack::Stack as ScopeStack;
 use crate::semantic::scope::Scope;
+use crate::syntax::tree::expression::block::Expression as BlockExpression;
+use crate::syntax::tree::expression::tree::node::operand::Operand as ExpressionOperand;
+use crate::syntax::tree::expression::tree::node::Node as ExpressionTreeNode;
+use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
 use crate::syntax::tree::pattern_binding::variant::Variant as BindingPatternVariant;
 use crate::syntax::tree::statement::local_fn::Statement as FunctionLocalStatement;
 use crate::syntax::tree::statement::local_impl::Statement as ImplementationLocalStatement;
 use crate::syntax::tree::statement::local_mod::Statement as ModuleLocalStatement;
+use crate::syntax::tree::statement::local_trait::Statement as TraitLocalStatement;
 use crate::syntax::tree::statement::module::Statement as ModStatement;
+use crate::syntax::tree::statement::r#break::Statement as BreakStatement;
 use crate::syntax::tree::statement::r#const::Statement as ConstStatement;
+use crate::syntax::tree::statement::r#continue::Statement as ContinueStatement;
 use crate::syntax::tree::statement::r#enum::Statement as EnumStatement;
 use crate::syntax::tree::statement::r#fn::Statement as FnStatement;
 use crate::syntax::tree::statement::r#for::Statement as ForStatement;
 use crate::syntax::tree::statement::r#impl::Statement as ImplStatement;
 use crate::syntax::tree::statement::r#let::Statement as LetStatement;
+use crate::syntax::tree::statement::r#return::Statement as ReturnStatement;
 use crate::syntax::tree::statement::r#struct::Statement as StructStatement;
+use crate::syntax::tree::statement::r#trait::Statement as TraitStatement;
 use crate::syntax::tree::statement::r#type::Statement as TypeStatement;
 use crate::syntax::tree::statement::r#use::Statement as UseStatement;
 
@@ -72,6 +93,40 @@ impl Analyzer {
         }
     }
 
+    ///
+    /// Analyzes the statements of a module and yields their IR.
+    ///
+    /// An erroneous statement does not stop the analysis, so the errors of all the independent
+    /// statements are collected and reported at once.
+    ///
+    pub fn module(
+        &mut self,
+        statements: Vec<ModuleLocalStatement>,
+    ) -> Result<Vec<GeneratorStatement>, Vec<Error>> {
+        let scope = self.scope_stack.top();
+
+        let mut intermediate = Vec::with_capacity(statements.len());
+        let mut errors = Vec::new();
+        for statement in statements.into_iter() {
+            match self.local_mod(statement) {
+                Ok(Some(statement)) => intermediate.push(statement),
+                Ok(None) => {}
+                Err(error) => {
+                    errors.push(error);
+                    errors.extend(scope.borrow().take_errors());
+                    // the failed statement may have left its nested scopes on the stack
+                    self.scope_stack = ScopeStack::new(scope.clone());
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(intermediate)
+        } else {
+            Err(errors)
+        }
+    }
+
     ///
     /// Analyzes a statement local to a module.
     ///
@@ -99,7 +154,7 @@ impl Analyzer {
                 Ok(None)
             }
             ModuleLocalStatement::Fn(statement) => {
-                let intermediate = GeneratorStatement::Function(self.r#fn(statement)?);
+                let intermediate = self.r#fn(statement)?;
                 Ok(Some(intermediate))
             }
             ModuleLocalStatement::Mod(statement) => {
@@ -114,6 +169,10 @@ impl Analyzer {
                 let intermediate = GeneratorStatement::Implementation(self.r#impl(statement)?);
                 Ok(Some(intermediate))
             }
+            ModuleLocalStatement::Trait(statement) => {
+                self.r#trait(statement)?;
+                Ok(None)
+            }
             ModuleLocalStatement::Empty(_location) => Ok(None),
         }
     }
@@ -138,6 +197,15 @@ impl Analyzer {
             FunctionLocalStatement::For(statement) => {
                 Ok(Some(GeneratorStatement::Loop(self.r#for(statement)?)))
             }
+            FunctionLocalStatement::Return(statement) => {
+                Ok(Some(GeneratorStatement::Return(self.r#return(statement)?)))
+            }
+            FunctionLocalStatement::Break(statement) => {
+                Ok(Some(GeneratorStatement::Break(self.r#break(statement)?)))
+            }
+            FunctionLocalStatement::Continue(statement) => Ok(Some(GeneratorStatement::Continue(
+                self.r#continue(statement)?,
+            ))),
             FunctionLocalStatement::Expression(expression) => {
                 let (_result, expression) = ExpressionAnalyzer::new(self.scope_stack.top())
                     .analyze(expression, TranslationHint::Value)?;
@@ -163,7 +231,7 @@ impl Analyzer {
                 Ok(None)
             }
             ImplementationLocalStatement::Fn(statement) => {
-                let intermediate = GeneratorStatement::Function(self.r#fn(statement)?);
+                let intermediate = self.r#fn(statement)?;
                 Ok(Some(intermediate))
             }
             ImplementationLocalStatement::Empty(_location) => Ok(None),
@@ -173,43 +241,219 @@ impl Analyzer {
     ///
     /// Analyzes a function statement and returns its IR for the next compiler phase.
     ///
-    fn r#fn(&mut self, statement: FnStatement) -> Result<GeneratorFunctionStatement, Error> {
+    /// A generic function is only declared here, since its instances are analyzed when
+    /// the function is called.
+    ///
+    /// A `const fn` is analyzed as an ordinar function, but also keeps its statement to be
+    /// evaluated at compile time, when it is called with constant arguments.
+    ///
+    fn r#fn(&mut self, statement: FnStatement) -> Result<GeneratorStatement, Error> {
         let location = statement.location;
 
+        if statement.body.is_none() {
+            return Err(Error::FunctionWithoutBody {
+                location,
+                function: statement.identifier.name,
+            });
+        }
+
+        if !statement.generics.is_empty() {
+            if statement.is_constant {
+                return Err(Error::FunctionConstantGeneric {
+                    location,
+                    function: statement.identifier.name,
+                });
+            }
+
+            return self.generic_fn(statement);
+        }
+
+        let (mut function_type, public_arguments) =
+            self.function_type(&statement, statement.identifier.name.clone())?;
+        if statement.is_constant {
+            function_type.set_constant(statement.clone(), self.scope_stack.top());
+        }
+        Scope::declare_type(
+            self.scope_stack.top(),
+            statement.identifier.clone(),
+            Type::Function(FunctionType::UserDefined(function_type.clone())),
+            statement.is_public,
+        )
+        .map_err(|error| Error::Scope(location, error))?;
+
+        let intermediate = self.function_body(statement, function_type, public_arguments)?;
+
+        Ok(GeneratorStatement::Function(intermediate))
+    }
+
+    ///
+    /// Declares a generic function and returns the IR of its instances, which is filled
+    /// as the function is called.
+    ///
+    fn generic_fn(&mut self, statement: FnStatement) -> Result<GeneratorStatement, Error> {
+        let location = statement.location;
+
+        if statement.identifier.name.as_str()
+            == crate::semantic::element::r#type::function::user::FUNCTION_MAIN_IDENTIFIER
+        {
+            return Err(Error::FunctionGenericEntry { location });
+        }
+
+        for (index, generic) in statement.generics.iter().enumerate() {
+            if statement.generics[..index]
+                .iter()
+                .any(|previous| previous.identifier.name == generic.identifier.name)
+            {
+                return Err(Error::FunctionGenericDuplicate {
+                    location: generic.location,
+                    function: statement.identifier.name.clone(),
+                    name: generic.identifier.name.clone(),
+                });
+            }
+        }
+
+        let mut parameters = Vec::with_capacity(statement.generics.len());
+        for generic in statement.generics.iter() {
+            parameters.push(GenericParameter::new(generic, self.scope_stack.top())?);
+        }
+
+        let identifier = statement.identifier.clone();
+        let is_public = statement.is_public;
+        let function = GenericFunctionType::new(statement, parameters, self.scope_stack.top());
+        let intermediate = function.intermediate();
+        Scope::declare_type(
+            self.scope_stack.top(),
+            identifier,
+            Type::Function(FunctionType::Generic(function)),
+            is_public,
+        )
+        .map_err(|error| Error::Scope(location, error))?;
+
+        Ok(GeneratorStatement::GenericFunction(intermediate))
+    }
+
+    ///
+    /// Returns the generic function instance for the call argument types, analyzing it
+    /// if the function has not been called with such generic arguments yet.
+    ///
+    pub fn instantiate_function(
+        function: &GenericFunctionType,
+        argument_types: &[(Type, bool)],
+        location: Location,
+    ) -> Result<UserDefinedFunctionType, Error> {
+        let arguments = function.infer(argument_types, location)?;
+        if let Some(instance) = function.get_instance(arguments.as_slice()) {
+            return Ok(instance);
+        }
+
+        let statement = function.statement().to_owned();
+        let scope = Rc::new(RefCell::new(Scope::new(Some(function.scope()))));
+        for (parameter, argument) in function.parameters().iter().zip(arguments.iter()) {
+            argument
+                .to_owned()
+                .declare(scope.clone(), parameter)
+                .map_err(|error| Error::Scope(parameter.identifier.location, error))?;
+        }
+
+        let mut analyzer = Self::new(scope, HashMap::new());
+        let (instance, public_arguments) = analyzer.function_type(
+            &statement,
+            function.instance_identifier(arguments.as_slice()),
+        )?;
+        function.insert_instance(arguments, instance.clone());
+
+        let intermediate = analyzer.function_body(statement, instance.clone(), public_arguments)?;
+        function.push_intermediate(intermediate);
+
+        Ok(instance)
+    }
+
+    ///
+    /// Resolves the function argument and return types, and allocates the function unique ID.
+    ///
+    /// Returns the function type named `identifier` and the list of its public arguments.
+    ///
+    fn function_type(
+        &mut self,
+        statement: &FnStatement,
+        identifier: String,
+    ) -> Result<(UserDefinedFunctionType, Vec<String>), Error> {
+        let is_main = statement.identifier.name.as_str()
+            == crate::semantic::element::r#type::function::user::FUNCTION_MAIN_IDENTIFIER;
+
         let mut arguments = Vec::with_capacity(statement.argument_bindings.len());
-        for argument_binding in statement.argument_bindings.iter() {
+        let mut public_arguments = Vec::new();
+        for (index, argument_binding) in statement.argument_bindings.iter().enumerate() {
+            if argument_binding.is_public && !is_main {
+                return Err(Error::FunctionPublicArgumentOutsideEntry {
+                    location: argument_binding.location,
+                    function: statement.identifier.name.clone(),
+                });
+            }
+
             let identifier = match argument_binding.variant {
                 BindingPatternVariant::Binding(ref identifier) => identifier,
                 BindingPatternVariant::MutableBinding(ref identifier) => identifier,
                 BindingPatternVariant::Wildcard => continue,
             };
+            if identifier.name == Keyword::SelfLowercase.to_string() && index > 0 {
+                return Err(Error::FunctionSelfArgumentNotFirst {
+                    location: argument_binding.location,
+                    function: statement.identifier.name.clone(),
+                });
+            }
             arguments.push((
                 identifier.name.clone(),
                 Type::from_type_variant(&argument_binding.r#type.variant, self.scope_stack.top())?,
             ));
+            if argument_binding.is_public {
+                public_arguments.push(identifier.name.clone());
+            }
         }
         let expected_type = match statement.return_type {
             Some(ref r#type) => Type::from_type_variant(&r#type.variant, self.scope_stack.top())?,
             None => Type::unit(),
         };
 
-        let unique_id = TYPE_INDEX.read().expect(crate::PANIC_MUTEX_SYNC).len();
-        let function_type = UserDefinedFunctionType::new(
-            statement.identifier.name.clone(),
-            unique_id,
-            arguments.clone(),
-            expected_type.clone(),
-        );
-        let r#type = Type::Function(FunctionType::UserDefined(function_type));
+        let unique_id = self.scope_stack.top().borrow().state().next_type_id();
+        let function_type =
+            UserDefinedFunctionType::new(identifier, unique_id, arguments, expected_type);
 
-        TYPE_INDEX
-            .write()
-            .expect(crate::PANIC_MUTEX_SYNC)
-            .insert(unique_id, r#type.to_string());
-        Scope::declare_type(self.scope_stack.top(), statement.identifier.clone(), r#type)
-            .map_err(|error| Error::Scope(location, error))?;
+        Ok((function_type, public_arguments))
+    }
+
+    ///
+    /// Analyzes the function body and returns the function IR for the next compiler phase.
+    ///
+    fn function_body(
+        &mut self,
+        mut statement: FnStatement,
+        function_type: UserDefinedFunctionType,
+        public_arguments: Vec<String>,
+    ) -> Result<GeneratorFunctionStatement, Error> {
+        let location = statement.location;
+        let body = statement
+            .body
+            .take()
+            .expect(crate::PANIC_VALIDATED_DURING_SEMANTIC_ANALYSIS);
+
+        let is_main = statement.identifier.name.as_str()
+            == crate::semantic::element::r#type::function::user::FUNCTION_MAIN_IDENTIFIER;
+        let expected_type = function_type.return_type().to_owned();
 
         self.scope_stack.push();
+        self.scope_stack
+            .top()
+            .borrow_mut()
+            .declare_function(ScopeFunctionContext::new(
+                statement.identifier.name.clone(),
+                expected_type.clone(),
+                statement
+                    .return_type
+                    .as_ref()
+                    .map(|r#type| r#type.location)
+                    .unwrap_or(statement.location),
+            ));
         for argument_binding in statement.argument_bindings.into_iter() {
             let (identifier, is_mutable) = match argument_binding.variant {
                 BindingPatternVariant::Binding(identifier) => (identifier, false),
@@ -228,29 +472,29 @@ impl Analyzer {
             .map_err(|error| Error::Scope(identifier_location, error))?;
         }
 
-        let return_expression_location = match statement
-            .body
+        let return_expression_location = match body
             .expression
             .as_ref()
             .map(|expression| expression.location)
         {
             Some(location) => location,
-            None => statement
-                .body
+            None => body
                 .statements
                 .last()
                 .map(|statement| statement.location())
                 .unwrap_or(statement.location),
         };
-        let (result, body) = BlockAnalyzer::analyze(self.scope_stack.top(), statement.body)?;
+        let is_terminated_by_return = Self::is_terminated_by_return(&body);
+        let (result, body) = BlockAnalyzer::analyze(self.scope_stack.top(), body)?;
+        let has_return = Scope::get_return_count(self.scope_stack.top()) > 0;
         self.scope_stack.pop();
 
         let result_type = Type::from_element(&result, self.scope_stack.top())?;
-        if expected_type != result_type {
+        if expected_type != result_type && !is_terminated_by_return {
             return Err(Error::Element(
                 return_expression_location,
                 ElementError::Type(TypeError::Function(FunctionTypeError::return_type(
-                    statement.identifier.name.clone(),
+                    function_type.identifier().to_owned(),
                     expected_type.to_string(),
                     result_type.to_string(),
                     statement
@@ -261,35 +505,96 @@ impl Analyzer {
             ));
         }
 
-        let is_main = statement.identifier.name.as_str()
-            == crate::semantic::element::r#type::function::user::FUNCTION_MAIN_IDENTIFIER;
-
         Ok(GeneratorFunctionStatement::new(
             location,
-            statement.identifier.name,
-            arguments,
+            function_type.identifier().to_owned(),
+            function_type.formal_params().to_vec(),
+            public_arguments,
             body,
             expected_type,
-            unique_id,
+            function_type.unique_id(),
             is_main,
+            has_return,
+            is_terminated_by_return,
         ))
     }
 
+    ///
+    /// Checks whether the block returns from the function on every path, that is, whether it
+    /// ends with a `return` statement, or with a conditional or match expression, every branch
+    /// of which is terminated by `return` as well.
+    ///
+    fn is_terminated_by_return(block: &BlockExpression) -> bool {
+        match block.expression {
+            Some(ref expression) => Self::is_expression_terminated_by_return(expression),
+            None => match block.statements.last() {
+                Some(FunctionLocalStatement::Return(_)) => true,
+                Some(FunctionLocalStatement::Expression(expression)) => {
+                    Self::is_expression_terminated_by_return(expression)
+                }
+                _ => false,
+            },
+        }
+    }
+
+    ///
+    /// Checks whether the expression is a block, conditional, or match expression, which is
+    /// terminated by `return` on every path.
+    ///
+    fn is_expression_terminated_by_return(expression: &ExpressionTree) -> bool {
+        if expression.left.is_some() || expression.right.is_some() {
+            return false;
+        }
+
+        match *expression.value {
+            ExpressionTreeNode::Operand(ExpressionOperand::Block(ref block)) => {
+                Self::is_terminated_by_return(block)
+            }
+            ExpressionTreeNode::Operand(ExpressionOperand::Conditional(ref conditional)) => {
+                match conditional.else_block {
+                    Some(ref else_block) => {
+                        Self::is_terminated_by_return(&conditional.main_block)
+                            && Self::is_terminated_by_return(else_block)
+                    }
+                    None => false,
+                }
+            }
+            ExpressionTreeNode::Operand(ExpressionOperand::Match(ref r#match)) => r#match
+                .branches
+                .iter()
+                .all(|(_, expression)| Self::is_expression_terminated_by_return(expression)),
+            _ => false,
+        }
+    }
+
     ///
     /// Analyzes an implementation statement and returns its IR for the next compiler phase.
     ///
+    /// A trait implementation must define the trait methods without a default body and may
+    /// only define the trait methods. The default methods are analyzed for the implementing type.
+    ///
+    /// The trait methods are public regardless of the `pub` keyword, since they are a part of
+    /// the trait interface.
+    ///
     fn r#impl(&mut self, statement: ImplStatement) -> Result<Vec<GeneratorStatement>, Error> {
         let identifier_location = statement.identifier.location;
 
         let mut intermediate = Vec::new();
 
-        let structure_scope =
+        let (structure_scope, r#type) =
             match Scope::resolve_item(self.scope_stack.top(), statement.identifier.name.as_str())
                 .map_err(|error| Error::Scope(identifier_location, error))?
                 .variant
             {
-                ScopeItemVariant::Type(Type::Structure(structure)) => structure.scope,
-                ScopeItemVariant::Type(Type::Enumeration(enumeration)) => enumeration.scope,
+                ScopeItemVariant::Type(Type::Structure(structure)) => {
+                    (structure.scope.clone(), Type::Structure(structure))
+                }
+                ScopeItemVariant::Type(Type::Enumeration(enumeration)) => {
+                    (enumeration.scope.clone(), Type::Enumeration(enumeration))
+                }
+                ScopeItemVariant::Type(Type::TaggedUnion(tagged_union)) => {
+                    (tagged_union.scope.clone(), Type::TaggedUnion(tagged_union))
+                }
                 item => {
                     return Err(Error::ImplStatementExpectedStructureOrEnumeration {
                         location: identifier_location,
@@ -298,17 +603,187 @@ impl Analyzer {
                 }
             };
 
-        self.scope_stack.push_scope(structure_scope);
-        for statement in statement.statements.into_iter() {
+        let r#trait = match statement.r#trait {
+            Some(ref identifier) => {
+                match Scope::resolve_item(self.scope_stack.top(), identifier.name.as_str())
+                    .map_err(|error| Error::Scope(identifier.location, error))?
+                    .variant
+                {
+                    ScopeItemVariant::Trait(r#trait) => Some(r#trait),
+                    item => {
+                        return Err(Error::ImplStatementExpectedTrait {
+                            location: identifier.location,
+                            found: item.to_string(),
+                        });
+                    }
+                }
+            }
+            None => None,
+        };
+
+        let mut methods = HashMap::with_capacity(statement.statements.len());
+        for statement in statement.statements.iter() {
+            if let ImplementationLocalStatement::Fn(ref statement) = statement {
+                if let Some(ref r#trait) = r#trait {
+                    if r#trait.method(statement.identifier.name.as_str()).is_none() {
+                        return Err(Error::ImplStatementTraitMethodNotMember {
+                            location: statement.location,
+                            r#trait: r#trait.identifier.to_owned(),
+                            method: statement.identifier.name.to_owned(),
+                        });
+                    }
+                }
+                methods.insert(statement.identifier.name.to_owned(), statement.location);
+            }
+        }
+
+        if let Some(ref r#trait) = r#trait {
+            r#trait.implement(r#type.clone());
+        }
+
+        self.scope_stack.push_scope(structure_scope.clone());
+        for mut statement in statement.statements.into_iter() {
+            if r#trait.is_some() {
+                statement.set_public();
+            }
             if let Some(statement) = self.local_impl(statement)? {
                 intermediate.push(statement);
             }
         }
         self.scope_stack.pop();
 
+        if let Some(ref r#trait) = r#trait {
+            intermediate.extend(Self::trait_methods(
+                r#trait,
+                r#type,
+                structure_scope,
+                methods,
+                identifier_location,
+            )?);
+        }
+
         Ok(intermediate)
     }
 
+    ///
+    /// Checks the `implemented` trait methods against the trait method signatures, and analyzes
+    /// the default methods, which are not implemented, declaring them in the `structure_scope`.
+    ///
+    /// The trait methods are resolved in the trait declaration scope with `Self` declared as
+    /// the implementing `type`.
+    ///
+    fn trait_methods(
+        r#trait: &ScopeTraitItem,
+        r#type: Type,
+        structure_scope: Rc<RefCell<Scope>>,
+        implemented: HashMap<String, Location>,
+        location: Location,
+    ) -> Result<Vec<GeneratorStatement>, Error> {
+        let scope = Scope::new_child(r#trait.scope.clone());
+        scope.borrow_mut().declare_self(r#type);
+
+        let mut intermediate = Vec::new();
+        for method in r#trait.methods.iter() {
+            let identifier = method.identifier.name.as_str();
+
+            if let Some(method_location) = implemented.get(identifier) {
+                let mut arguments = Vec::with_capacity(method.argument_bindings.len());
+                for argument_binding in method.argument_bindings.iter() {
+                    let name = match argument_binding.variant {
+                        BindingPatternVariant::Binding(ref identifier) => identifier,
+                        BindingPatternVariant::MutableBinding(ref identifier) => identifier,
+                        BindingPatternVariant::Wildcard => continue,
+                    };
+                    arguments.push((
+                        name.name.to_owned(),
+                        Type::from_type_variant(&argument_binding.r#type.variant, scope.clone())?,
+                    ));
+                }
+                let return_type = match method.return_type {
+                    Some(ref r#type) => Type::from_type_variant(&r#type.variant, scope.clone())?,
+                    None => Type::unit(),
+                };
+                let expected =
+                    UserDefinedFunctionType::new(identifier.to_owned(), 0, arguments, return_type);
+
+                let found = Scope::resolve_item(structure_scope.clone(), identifier)
+                    .map_err(|error| Error::Scope(*method_location, error))?
+                    .variant;
+                let is_matching = match found {
+                    ScopeItemVariant::Type(Type::Function(FunctionType::UserDefined(
+                        ref function,
+                    ))) => {
+                        function.return_type() == expected.return_type()
+                            && function.formal_params().len() == expected.formal_params().len()
+                            && function
+                                .formal_params()
+                                .iter()
+                                .zip(expected.formal_params().iter())
+                                .all(|((_, found), (_, expected))| found == expected)
+                    }
+                    _ => false,
+                };
+                if !is_matching {
+                    return Err(Error::ImplStatementTraitMethodSignatureMismatch {
+                        location: *method_location,
+                        r#trait: r#trait.identifier.to_owned(),
+                        expected: expected.to_string(),
+                        found: found.to_string(),
+                        reference: method.location,
+                    });
+                }
+            } else if method.body.is_some() {
+                let mut analyzer = Self::new(scope.clone(), HashMap::new());
+                intermediate.push(analyzer.r#fn(method.to_owned())?);
+
+                let mut item = Scope::resolve_item(scope.clone(), identifier)
+                    .map_err(|error| Error::Scope(method.location, error))?;
+                item.is_public = true;
+                Scope::declare_item(structure_scope.clone(), method.identifier.to_owned(), item)
+                    .map_err(|error| Error::Scope(location, error))?;
+            } else {
+                return Err(Error::ImplStatementTraitMethodMissing {
+                    location,
+                    r#trait: r#trait.identifier.to_owned(),
+                    method: identifier.to_owned(),
+                });
+            }
+        }
+
+        Ok(intermediate)
+    }
+
+    ///
+    /// Declares a trait, whose methods are analyzed within its implementations.
+    ///
+    fn r#trait(&mut self, statement: TraitStatement) -> Result<(), Error> {
+        let location = statement.location;
+
+        let methods = statement
+            .statements
+            .into_iter()
+            .filter_map(|statement| match statement {
+                TraitLocalStatement::Fn(statement) => Some(statement),
+                TraitLocalStatement::Empty(_location) => None,
+            })
+            .collect();
+
+        let r#trait = ScopeTraitItem::new(
+            statement.identifier.name.clone(),
+            methods,
+            self.scope_stack.top(),
+        );
+        Scope::declare_trait(
+            self.scope_stack.top(),
+            statement.identifier,
+            r#trait,
+            statement.is_public,
+        )
+        .map_err(|error| Error::Scope(location, error))?;
+
+        Ok(())
+    }
+
     ///
     /// Analyzes a variable declaration statement and returns its IR for the next compiler phase.
     ///
@@ -355,7 +830,7 @@ impl Analyzer {
         let bounds_expression_location = statement.bounds_expression.location;
 
         let (range_start, range_end, index_bitlength, is_index_signed, is_inclusive) =
-            match ExpressionAnalyzer::new(self.scope_stack.top())
+            match ExpressionAnalyzer::new_constant(self.scope_stack.top(), true)
                 .analyze(statement.bounds_expression, TranslationHint::Value)?
             {
                 (Element::Constant(Constant::RangeInclusive(range)), _intermediate) => (
@@ -381,6 +856,7 @@ impl Analyzer {
             };
 
         self.scope_stack.push();
+        self.scope_stack.top().borrow_mut().declare_loop();
 
         let index_identifier = statement.index_identifier.name.to_owned();
         Scope::declare_variable(
@@ -411,7 +887,13 @@ impl Analyzer {
             None
         };
 
+        let return_count = Scope::get_return_count(self.scope_stack.top());
         let (_result, body) = BlockAnalyzer::analyze(self.scope_stack.top(), statement.block)?;
+        let has_return = Scope::get_return_count(self.scope_stack.top()) > return_count;
+        let (has_break, has_continue) = match Scope::get_loop_context(self.scope_stack.top()) {
+            Some(context) => (context.break_count > 0, context.continue_count > 0),
+            None => (false, false),
+        };
 
         self.scope_stack.pop();
 
@@ -454,6 +936,77 @@ impl Analyzer {
             index_bitlength,
             while_condition,
             body,
+            has_return,
+            has_break,
+            has_continue,
+        ))
+    }
+
+    ///
+    /// Analyzes a break statement and returns its IR for the next compiler phase.
+    ///
+    fn r#break(&mut self, statement: BreakStatement) -> Result<GeneratorBreakStatement, Error> {
+        let location = statement.location;
+
+        if !Scope::register_break(self.scope_stack.top()) {
+            return Err(Error::BreakOutsideLoop { location });
+        }
+
+        Ok(GeneratorBreakStatement::new(location))
+    }
+
+    ///
+    /// Analyzes a continue statement and returns its IR for the next compiler phase.
+    ///
+    fn r#continue(
+        &mut self,
+        statement: ContinueStatement,
+    ) -> Result<GeneratorContinueStatement, Error> {
+        let location = statement.location;
+
+        if !Scope::register_continue(self.scope_stack.top()) {
+            return Err(Error::ContinueOutsideLoop { location });
+        }
+
+        Ok(GeneratorContinueStatement::new(location))
+    }
+
+    ///
+    /// Analyzes a return statement and returns its IR for the next compiler phase.
+    ///
+    fn r#return(&mut self, statement: ReturnStatement) -> Result<GeneratorReturnStatement, Error> {
+        let location = statement.location;
+
+        let function = Scope::register_return(self.scope_stack.top())
+            .ok_or(Error::ReturnOutsideFunction { location })?;
+
+        let (result_type, expression, expression_location) = match statement.expression {
+            Some(expression) => {
+                let expression_location = expression.location;
+                let (result, expression) = ExpressionAnalyzer::new(self.scope_stack.top())
+                    .analyze(expression, TranslationHint::Value)?;
+                let result_type = Type::from_element(&result, self.scope_stack.top())?;
+                (result_type, Some(expression), expression_location)
+            }
+            None => (Type::unit(), None, location),
+        };
+
+        if function.return_type != result_type {
+            return Err(Error::Element(
+                expression_location,
+                ElementError::Type(TypeError::Function(FunctionTypeError::return_type(
+                    function.identifier,
+                    function.return_type.to_string(),
+                    result_type.to_string(),
+                    function.return_type_location,
+                ))),
+            ));
+        }
+
+        Ok(GeneratorReturnStatement::new(
+            location,
+            expression,
+            function.return_type,
         ))
     }
 
@@ -465,14 +1018,15 @@ impl Analyzer {
         let type_location = statement.r#type.location;
         let expression_location = statement.expression.location;
 
-        let (element, _intermediate) = ExpressionAnalyzer::new(self.scope_stack.top())
-            .analyze(statement.expression, TranslationHint::Value)?;
+        let (element, _intermediate) =
+            ExpressionAnalyzer::new_constant(self.scope_stack.top(), true)
+                .analyze(statement.expression, TranslationHint::Value)?;
 
         let const_type =
             Type::from_type_variant(&statement.r#type.variant, self.scope_stack.top())?;
         let constant = match element {
             Element::Constant(constant) => constant
-                .cast(const_type)
+                .cast_declared(const_type)
                 .map_err(ElementError::Constant)
                 .map_err(|error| Error::Element(type_location, error))?,
             element => {
@@ -483,8 +1037,13 @@ impl Analyzer {
             }
         };
 
-        Scope::declare_constant(self.scope_stack.top(), statement.identifier, constant)
-            .map_err(|error| Error::Scope(location, error))?;
+        Scope::declare_constant(
+            self.scope_stack.top(),
+            statement.identifier,
+            constant,
+            statement.is_public,
+        )
+        .map_err(|error| Error::Scope(location, error))?;
 
         Ok(())
     }
@@ -497,8 +1056,13 @@ impl Analyzer {
 
         let r#type = Type::from_type_variant(&statement.r#type.variant, self.scope_stack.top())?;
 
-        Scope::declare_type(self.scope_stack.top(), statement.identifier, r#type)
-            .map_err(|error| Error::Scope(location, error))?;
+        Scope::declare_type(
+            self.scope_stack.top(),
+            statement.identifier,
+            r#type,
+            statement.is_public,
+        )
+        .map_err(|error| Error::Scope(location, error))?;
 
         Ok(())
     }
@@ -509,8 +1073,127 @@ impl Analyzer {
     fn r#struct(&mut self, statement: StructStatement) -> Result<(), Error> {
         let location = statement.location;
 
+        if !statement.generics.is_empty() {
+            return self.generic_struct(statement);
+        }
+
+        let fields = Self::structure_fields(&statement, self.scope_stack.top())?;
+        let private_fields = Self::structure_private_fields(&statement);
+
+        let unique_id = self.scope_stack.top().borrow().state().next_type_id();
+        let r#type = Type::Structure(StructureType::new_generic(
+            statement.identifier.name.clone(),
+            unique_id,
+            fields,
+            private_fields,
+            vec![],
+            Some(self.scope_stack.top()),
+        ));
+
+        Scope::declare_type(
+            self.scope_stack.top(),
+            statement.identifier,
+            r#type,
+            statement.is_public,
+        )
+        .map_err(|error| Error::Scope(location, error))?;
+
+        Ok(())
+    }
+
+    ///
+    /// Declares a structure with constant generic parameters, whose instances are created
+    /// as the structure is used with different arguments.
+    ///
+    fn generic_struct(&mut self, statement: StructStatement) -> Result<(), Error> {
+        let location = statement.location;
+
+        let mut parameters: Vec<GenericParameter> = Vec::with_capacity(statement.generics.len());
+        for generic in statement.generics.iter() {
+            let error = if !generic.is_constant() {
+                Some(StructureTypeError::GenericExpectedConstant {
+                    type_identifier: statement.identifier.name.clone(),
+                    name: generic.identifier.name.clone(),
+                })
+            } else if parameters
+                .iter()
+                .any(|parameter| parameter.identifier.name == generic.identifier.name)
+            {
+                Some(StructureTypeError::DuplicateGeneric {
+                    type_identifier: statement.identifier.name.clone(),
+                    name: generic.identifier.name.clone(),
+                })
+            } else {
+                None
+            };
+            if let Some(error) = error {
+                return Err(Error::Element(
+                    generic.location,
+                    ElementError::Type(TypeError::Structure(error)),
+                ));
+            }
+
+            parameters.push(GenericParameter::new(generic, self.scope_stack.top())?);
+        }
+
+        let identifier = statement.identifier.clone();
+        let is_public = statement.is_public;
+        let r#type = Type::GenericStructure(GenericStructureType::new(
+            statement,
+            parameters,
+            self.scope_stack.top(),
+        ));
+        Scope::declare_type(self.scope_stack.top(), identifier, r#type, is_public)
+            .map_err(|error| Error::Scope(location, error))?;
+
+        Ok(())
+    }
+
+    ///
+    /// Returns the generic structure instance for the `arguments`, creating it if the
+    /// structure has not been used with such arguments yet.
+    ///
+    pub fn instantiate_structure(
+        structure: &GenericStructureType,
+        arguments: Vec<GenericArgument>,
+    ) -> Result<StructureType, Error> {
+        if let Some(instance) = structure.get_instance(arguments.as_slice()) {
+            return Ok(instance);
+        }
+
+        let scope = Rc::new(RefCell::new(Scope::new(Some(structure.scope()))));
+        for (parameter, argument) in structure.parameters().iter().zip(arguments.iter()) {
+            argument
+                .to_owned()
+                .declare(scope.clone(), parameter)
+                .map_err(|error| Error::Scope(parameter.identifier.location, error))?;
+        }
+        let unique_id = scope.borrow().state().next_type_id();
+        let fields = Self::structure_fields(structure.statement(), scope)?;
+
+        let instance = StructureType::new_generic(
+            structure.instance_identifier(arguments.as_slice()),
+            unique_id,
+            fields,
+            Self::structure_private_fields(structure.statement()),
+            arguments.clone(),
+            Some(structure.scope()),
+        );
+
+        structure.insert_instance(arguments, instance.clone());
+
+        Ok(instance)
+    }
+
+    ///
+    /// Resolves the structure field types in `scope`, checking the field names for duplicates.
+    ///
+    fn structure_fields(
+        statement: &StructStatement,
+        scope: Rc<RefCell<Scope>>,
+    ) -> Result<Vec<(String, Type)>, Error> {
         let mut fields: Vec<(String, Type)> = Vec::with_capacity(statement.fields.len());
-        for field in statement.fields.into_iter() {
+        for field in statement.fields.iter() {
             if fields
                 .iter()
                 .any(|(name, _type)| name == &field.identifier.name)
@@ -518,33 +1201,29 @@ impl Analyzer {
                 return Err(Error::Element(
                     field.location,
                     ElementError::Type(TypeError::Structure(StructureTypeError::DuplicateField {
-                        type_identifier: statement.identifier.name,
-                        field_name: field.identifier.name,
+                        type_identifier: statement.identifier.name.clone(),
+                        field_name: field.identifier.name.clone(),
                     })),
                 ));
             }
             fields.push((
-                field.identifier.name,
-                Type::from_type_variant(&field.r#type.variant, self.scope_stack.top())?,
+                field.identifier.name.clone(),
+                Type::from_type_variant(&field.r#type.variant, scope.clone())?,
             ));
         }
+        Ok(fields)
+    }
 
-        let unique_id = TYPE_INDEX.read().expect(crate::PANIC_MUTEX_SYNC).len();
-        let r#type = Type::structure(
-            statement.identifier.name.clone(),
-            unique_id,
-            fields,
-            Some(self.scope_stack.top()),
-        );
-
-        TYPE_INDEX
-            .write()
-            .expect(crate::PANIC_MUTEX_SYNC)
-            .insert(unique_id, r#type.to_string());
-        Scope::declare_type(self.scope_stack.top(), statement.identifier, r#type)
-            .map_err(|error| Error::Scope(location, error))?;
-
-        Ok(())
+    ///
+    /// Returns the names of the structure fields declared without the `pub` keyword.
+    ///
+    fn structure_private_fields(statement: &StructStatement) -> Vec<String> {
+        statement
+            .fields
+            .iter()
+            .filter(|field| !field.is_public)
+            .map(|field| field.identifier.name.clone())
+            .collect()
     }
 
     ///
@@ -553,20 +1232,21 @@ impl Analyzer {
     fn r#enum(&mut self, statement: EnumStatement) -> Result<(), Error> {
         let location = statement.location;
 
-        let unique_id = TYPE_INDEX.read().expect(crate::PANIC_MUTEX_SYNC).len();
+        let unique_id = self.scope_stack.top().borrow().state().next_type_id();
         let r#type = Type::enumeration(
             statement.identifier.clone(),
             unique_id,
             statement.variants,
-            Some(self.scope_stack.top()),
+            self.scope_stack.top(),
         )?;
 
-        TYPE_INDEX
-            .write()
-            .expect(crate::PANIC_MUTEX_SYNC)
-            .insert(unique_id, r#type.to_string());
-        Scope::declare_type(self.scope_stack.top(), statement.identifier, r#type)
-            .map_err(|error| Error::Scope(location, error))?;
+        Scope::declare_type(
+            self.scope_stack.top(),
+            statement.identifier,
+            r#type,
+            statement.is_public,
+        )
+        .map_err(|error| Error::Scope(location, error))?;
 
         Ok(())
     }
@@ -587,8 +1267,13 @@ impl Analyzer {
                 });
             }
         };
-        Scope::declare_module(self.scope_stack.top(), statement.identifier, module)
-            .map_err(|error| Error::Scope(identifier_location, error))?;
+        Scope::declare_module(
+            self.scope_stack.top(),
+            statement.identifier,
+            module,
+            statement.is_public,
+        )
+        .map_err(|error| Error::Scope(identifier_location, error))?;
 
         Ok(())
     }
@@ -615,8 +1300,16 @@ impl Analyzer {
             .elements
             .last()
             .expect(crate::PANIC_VALIDATED_DURING_SYNTAX_ANALYSIS);
-        Scope::declare_item(self.scope_stack.top(), path_last_element.to_owned(), item)
-            .map_err(|error| Error::Scope(path_last_element.location, error))?;
+        Scope::declare_item(
+            self.scope_stack.top(),
+            path_last_element.to_owned(),
+            ScopeItem::new_import(
+                item.variant,
+                Some(path_last_element.location),
+                statement.is_public,
+            ),
+        )
+        .map_err(|error| Error::Scope(path_last_element.location, error))?;
 
         Ok(())
     }
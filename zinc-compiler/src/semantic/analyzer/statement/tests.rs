@@ -4,8 +4,12 @@
 
 #![cfg(test)]
 
+use std::collections::HashMap;
+
 use num_bigint::BigInt;
 
+use zinc_bytecode::Curve;
+
 use crate::error::Error;
 use crate::lexical::token::location::Location;
 use crate::semantic::element::constant::boolean::Boolean as BooleanConstant;
@@ -13,9 +17,11 @@ use crate::semantic::element::constant::integer::Integer as IntegerConstant;
 use crate::semantic::element::constant::Constant;
 use crate::semantic::element::error::Error as ElementError;
 use crate::semantic::element::r#type::error::Error as TypeError;
+use crate::semantic::element::r#type::function::error::Error as FunctionTypeError;
 use crate::semantic::element::r#type::structure::error::Error as StructureTypeError;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::error::Error as SemanticError;
+use crate::semantic::scope::error::Error as ScopeError;
 
 #[test]
 fn error_for_bounds_expected_constant_range_expression() {
@@ -90,6 +96,27 @@ fn main() -> u8 {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn error_enumeration_variants_mixed() {
+    let input = r#"
+enum Operation {
+    Noop = 0,
+    Burn(u64),
+}
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::EnumerationVariantsMixed {
+        location: Location::new(3, 5),
+        type_identifier: "Operation".to_owned(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn error_impl_expected_structure_or_enumeration() {
     let input = r#"
@@ -105,7 +132,7 @@ fn main() {}
     let expected = Err(Error::Semantic(
         SemanticError::ImplStatementExpectedStructureOrEnumeration {
             location: Location::new(4, 6),
-            found: Type::field().to_string(),
+            found: Type::field(Curve::default()).to_string(),
         },
     ));
 
@@ -149,3 +176,540 @@ fn main() {}
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn error_function_public_argument_outside_entry() {
+    let input = r#"
+fn helper(pub a: u8) -> u8 {
+    a
+}
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::FunctionPublicArgumentOutsideEntry {
+            location: Location::new(2, 11),
+            function: "helper".to_owned(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_return_in_every_branch() {
+    let input = r#"
+fn conditional(value: u8) -> u8 {
+    if value > 10 {
+        return 10;
+    } else {
+        return value;
+    }
+}
+
+fn matched(value: u8) -> u8 {
+    match value {
+        0 => {
+            return 1;
+        },
+        _ => {
+            return value;
+        },
+    }
+}
+
+fn main() {}
+"#;
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, Ok(()));
+}
+
+#[test]
+fn error_return_in_some_branches() {
+    let input = r#"
+fn another(value: u8) -> u8 {
+    if value > 10 {
+        return 10;
+    }
+}
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(3, 5),
+        ElementError::Type(TypeError::Function(FunctionTypeError::return_type(
+            "another".to_owned(),
+            Type::integer_unsigned(crate::BITLENGTH_BYTE).to_string(),
+            Type::unit().to_string(),
+            Location::new(2, 26),
+        ))),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_return_type() {
+    let input = r#"
+fn another(value: u8) -> u8 {
+    if value > 10 {
+        return true;
+    };
+    value
+}
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(4, 16),
+        ElementError::Type(TypeError::Function(FunctionTypeError::return_type(
+            "another".to_owned(),
+            Type::integer_unsigned(crate::BITLENGTH_BYTE).to_string(),
+            Type::boolean().to_string(),
+            Location::new(2, 26),
+        ))),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_return_outside_function() {
+    let input = r#"
+const VALUE: u8 = {
+    return 42;
+};
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::ReturnOutsideFunction {
+        location: Location::new(3, 5),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_break_outside_loop() {
+    let input = r#"
+fn main() {
+    if true {
+        break;
+    };
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::BreakOutsideLoop {
+        location: Location::new(4, 9),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_continue_outside_loop() {
+    let input = r#"
+fn main() {
+    continue;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::ContinueOutsideLoop {
+        location: Location::new(3, 5),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_function_self_argument_not_first() {
+    let input = r#"
+struct Data {
+    value: u8,
+}
+
+impl Data {
+    fn method(value: u8, self) -> u8 {
+        value
+    }
+}
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::FunctionSelfArgumentNotFirst {
+            location: Location::new(7, 26),
+            function: "method".to_owned(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_function_generic_duplicate() {
+    let input = r#"
+fn pick<T, T>(a: T, b: T) -> T {
+    a
+}
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionGenericDuplicate {
+        location: Location::new(2, 12),
+        function: "pick".to_owned(),
+        name: "T".to_owned(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_function_generic_entry() {
+    let input = r#"
+fn main<T>(value: T) -> T {
+    value
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionGenericEntry {
+        location: Location::new(2, 1),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_function_generic_bound_expected_trait() {
+    let input = r#"
+struct Data {
+    value: u8,
+}
+
+fn pick<T: Data>(value: T) -> T {
+    value
+}
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::FunctionGenericBoundExpectedTrait {
+            location: Location::new(6, 12),
+            found: "struct Data".to_owned(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_function_generic_bound_not_satisfied() {
+    let input = r#"
+trait Zero {
+    fn zero() -> Self;
+}
+
+struct Data {
+    value: u8,
+}
+
+fn pick<T: Zero>(value: T) -> T {
+    value
+}
+
+fn main() {
+    let data = pick(Data { value: 42 });
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::FunctionGenericBoundNotSatisfied {
+            location: Location::new(15, 20),
+            function: "pick".to_owned(),
+            r#type: "struct Data".to_owned(),
+            r#trait: "Zero".to_owned(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_function_without_body() {
+    let input = r#"
+fn zero() -> u8;
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionWithoutBody {
+        location: Location::new(2, 1),
+        function: "zero".to_owned(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_impl_expected_trait() {
+    let input = r#"
+struct Data {
+    value: u8,
+}
+
+impl Data for Data {}
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::ImplStatementExpectedTrait {
+        location: Location::new(6, 6),
+        found: "struct Data".to_owned(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_impl_trait_method_missing() {
+    let input = r#"
+trait Zero {
+    fn zero() -> Self;
+}
+
+struct Data {
+    value: u8,
+}
+
+impl Zero for Data {}
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::ImplStatementTraitMethodMissing {
+            location: Location::new(10, 15),
+            r#trait: "Zero".to_owned(),
+            method: "zero".to_owned(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_impl_trait_method_not_member() {
+    let input = r#"
+trait Zero {}
+
+struct Data {
+    value: u8,
+}
+
+impl Zero for Data {
+    fn one() -> Self {
+        Data { value: 1 }
+    }
+}
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::ImplStatementTraitMethodNotMember {
+            location: Location::new(9, 5),
+            r#trait: "Zero".to_owned(),
+            method: "one".to_owned(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_impl_trait_method_signature_mismatch() {
+    let input = r#"
+trait Zero {
+    fn is_zero(self) -> bool;
+}
+
+struct Data {
+    value: u8,
+}
+
+impl Zero for Data {
+    fn is_zero(self) -> u8 {
+        self.value
+    }
+}
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::ImplStatementTraitMethodSignatureMismatch {
+            location: Location::new(11, 5),
+            r#trait: "Zero".to_owned(),
+            expected: "fn is_zero(self: struct Data) -> bool".to_owned(),
+            found: "fn is_zero(self: struct Data) -> u8".to_owned(),
+            reference: Location::new(3, 5),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_multiple() {
+    let input = r#"
+fn first() -> u8 {
+    true
+}
+
+fn second() -> bool {
+    first()
+}
+
+fn main() -> u8 {
+    undeclared
+}
+"#;
+
+    let expected = Err(vec![
+        Error::Semantic(SemanticError::Element(
+            Location::new(3, 5),
+            ElementError::Type(TypeError::Function(FunctionTypeError::return_type(
+                "first".to_owned(),
+                Type::integer_unsigned(crate::BITLENGTH_BYTE).to_string(),
+                Type::boolean().to_string(),
+                Location::new(2, 15),
+            ))),
+        )),
+        Error::Semantic(SemanticError::Element(
+            Location::new(7, 10),
+            ElementError::Type(TypeError::Function(FunctionTypeError::return_type(
+                "second".to_owned(),
+                Type::boolean().to_string(),
+                Type::integer_unsigned(crate::BITLENGTH_BYTE).to_string(),
+                Location::new(6, 16),
+            ))),
+        )),
+        Error::Semantic(SemanticError::Scope(
+            Location::new(11, 5),
+            ScopeError::ItemUndeclared {
+                name: "undeclared".to_owned(),
+            },
+        )),
+    ]);
+
+    let result = crate::semantic::tests::compile_entry_all_errors(input, HashMap::new());
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_multiple_in_function() {
+    let input = r#"
+fn main() -> u8 {
+    let mut sum = 0;
+    for i in true {
+        sum = sum + i;
+    }
+    if sum == 0 {
+        sum = undeclared;
+    };
+    for j in 0..10 while 42 {
+        sum = sum + j;
+    }
+    sum
+}
+"#;
+
+    let expected = Err(vec![
+        Error::Semantic(SemanticError::LoopBoundsExpectedConstantRangeExpression {
+            location: Location::new(4, 14),
+            found: Constant::Boolean(BooleanConstant::new(true)).to_string(),
+        }),
+        Error::Semantic(SemanticError::Scope(
+            Location::new(8, 15),
+            ScopeError::ItemUndeclared {
+                name: "undeclared".to_owned(),
+            },
+        )),
+        Error::Semantic(SemanticError::LoopWhileExpectedBooleanCondition {
+            location: Location::new(10, 26),
+            found: Type::integer_unsigned(crate::BITLENGTH_BYTE).to_string(),
+        }),
+    ]);
+
+    let result = crate::semantic::tests::compile_entry_all_errors(input, HashMap::new());
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_multiple_failed_binding() {
+    let input = r#"
+fn main() -> u8 {
+    let value = undeclared;
+    let result = value + 1;
+    if value == 0 {
+        result
+    } else {
+        value
+    }
+}
+"#;
+
+    let expected = Err(vec![Error::Semantic(SemanticError::Scope(
+        Location::new(3, 17),
+        ScopeError::ItemUndeclared {
+            name: "undeclared".to_owned(),
+        },
+    ))]);
+
+    let result = crate::semantic::tests::compile_entry_all_errors(input, HashMap::new());
+
+    assert_eq!(result, expected);
+}
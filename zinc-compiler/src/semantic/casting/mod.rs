@@ -37,19 +37,19 @@ impl Caster {
         match (from, to) {
             (Type::IntegerUnsigned { .. }, Type::IntegerUnsigned { .. }) => Ok(()),
             (Type::IntegerUnsigned { .. }, Type::IntegerSigned { .. }) => Ok(()),
-            (Type::IntegerUnsigned { .. }, Type::Field) => Ok(()),
+            (Type::IntegerUnsigned { .. }, Type::Field { .. }) => Ok(()),
             (from @ Type::IntegerUnsigned { .. }, to) => {
                 Err(Error::casting_to_invalid_type(from, to))
             }
             (Type::IntegerSigned { .. }, Type::IntegerSigned { .. }) => Ok(()),
             (Type::IntegerSigned { .. }, Type::IntegerUnsigned { .. }) => Ok(()),
-            (Type::IntegerSigned { .. }, Type::Field) => Ok(()),
+            (Type::IntegerSigned { .. }, Type::Field { .. }) => Ok(()),
             (from @ Type::IntegerSigned { .. }, to) => {
                 Err(Error::casting_to_invalid_type(from, to))
             }
             (Type::Enumeration(_), Type::IntegerSigned { .. }) => Ok(()),
             (Type::Enumeration(_), Type::IntegerUnsigned { .. }) => Ok(()),
-            (Type::Enumeration(_), Type::Field) => Ok(()),
+            (Type::Enumeration(_), Type::Field { .. }) => Ok(()),
             (from, to) => {
                 if from == to {
                     Ok(())
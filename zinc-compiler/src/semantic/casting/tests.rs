@@ -4,6 +4,8 @@
 
 #![cfg(test)]
 
+use zinc_bytecode::Curve;
+
 use crate::error::Error;
 use crate::lexical::token::location::Location;
 use crate::semantic::casting::error::Error as CastingError;
@@ -25,7 +27,7 @@ fn main() {
         Location::new(4, 24),
         ElementError::Value(ValueError::Casting(
             CastingError::casting_from_invalid_type(
-                &Type::field(),
+                &Type::field(Curve::default()),
                 &Type::integer_unsigned(crate::BITLENGTH_BYTE),
             ),
         )),
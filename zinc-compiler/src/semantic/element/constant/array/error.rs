@@ -0,0 +1,12 @@
+//!
+//! The semantic analyzer array constant element error.
+//!
+
+#[derive(Debug, PartialEq)]
+pub enum Error {
+    IndexOutOfRange { index: String, size: usize },
+    SliceStartOutOfRange { start: String },
+    SliceEndOutOfRange { end: String, size: usize },
+    SliceEndLesserThanStart { start: String, end: String },
+    SizeLimitExceeded { size: usize, limit: usize },
+}
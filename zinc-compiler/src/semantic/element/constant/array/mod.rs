@@ -0,0 +1,160 @@
+//!
+//! The semantic analyzer constant array element.
+//!
+
+mod tests;
+
+pub mod error;
+
+use std::fmt;
+
+use num_bigint::BigInt;
+use num_traits::One;
+use num_traits::Signed;
+use num_traits::ToPrimitive;
+
+use crate::semantic::element::access::Index as IndexAccess;
+use crate::semantic::element::constant::Constant;
+use crate::semantic::element::r#type::Type;
+
+use self::error::Error;
+
+///
+/// Constant arrays are compile-time collections of constants of the same type.
+///
+/// They are usually declared with `const` items and used as lookup tables,
+/// which are indexed at compile time without producing any constraints.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Array {
+    pub element_type: Type,
+    pub values: Vec<Constant>,
+}
+
+impl Array {
+    pub fn new(element_type: Type, values: Vec<Constant>) -> Self {
+        Self {
+            element_type,
+            values,
+        }
+    }
+
+    pub fn r#type(&self) -> Type {
+        Type::array(self.element_type.to_owned(), self.values.len())
+    }
+
+    pub fn has_the_same_type_as(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.element_type == other.element_type
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn slice_single(self, index: BigInt) -> Result<(Constant, IndexAccess), Error> {
+        let access = IndexAccess::new(self.element_type.size(), self.r#type().size());
+        let size = self.len();
+
+        match index.to_usize() {
+            Some(position) if position < size => Ok((
+                self.values
+                    .into_iter()
+                    .nth(position)
+                    .expect(crate::PANIC_VALIDATED_DURING_SYNTAX_ANALYSIS),
+                access,
+            )),
+            _ => Err(Error::IndexOutOfRange {
+                index: index.to_string(),
+                size,
+            }),
+        }
+    }
+
+    pub fn slice_range(self, start: BigInt, end: BigInt) -> Result<(Constant, IndexAccess), Error> {
+        if start.is_negative() {
+            return Err(Error::SliceStartOutOfRange {
+                start: start.to_string(),
+            });
+        }
+
+        if end > BigInt::from(self.len()) {
+            return Err(Error::SliceEndOutOfRange {
+                end: end.to_string(),
+                size: self.len(),
+            });
+        }
+
+        if end < start {
+            return Err(Error::SliceEndLesserThanStart {
+                start: start.to_string(),
+                end: end.to_string(),
+            });
+        }
+
+        self.slice(start, end)
+    }
+
+    pub fn slice_range_inclusive(
+        self,
+        start: BigInt,
+        end: BigInt,
+    ) -> Result<(Constant, IndexAccess), Error> {
+        if start.is_negative() {
+            return Err(Error::SliceStartOutOfRange {
+                start: start.to_string(),
+            });
+        }
+
+        if end >= BigInt::from(self.len()) {
+            return Err(Error::SliceEndOutOfRange {
+                end: end.to_string(),
+                size: self.len(),
+            });
+        }
+
+        if end < start {
+            return Err(Error::SliceEndLesserThanStart {
+                start: start.to_string(),
+                end: end.to_string(),
+            });
+        }
+
+        self.slice(start, end + BigInt::one())
+    }
+
+    ///
+    /// Cuts the `start .. end` elements out of the array, whose bounds are already checked.
+    ///
+    fn slice(self, start: BigInt, end: BigInt) -> Result<(Constant, IndexAccess), Error> {
+        let start_usize = start
+            .to_usize()
+            .ok_or_else(|| Error::SliceStartOutOfRange {
+                start: start.to_string(),
+            })?;
+        let end_usize = end.to_usize().ok_or_else(|| Error::SliceEndOutOfRange {
+            end: end.to_string(),
+            size: self.len(),
+        })?;
+
+        let access = IndexAccess::new(
+            self.element_type.size() * (end_usize - start_usize),
+            self.r#type().size(),
+        );
+        let values = self.values[start_usize..end_usize].to_vec();
+
+        Ok((
+            Constant::Array(Self::new(self.element_type, values)),
+            access,
+        ))
+    }
+}
+
+impl fmt::Display for Array {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "constant array of type '{}'", self.r#type())
+    }
+}
@@ -0,0 +1,188 @@
+//!
+//! The array constant element tests.
+//!
+
+#![cfg(test)]
+
+use num_bigint::BigInt;
+
+use crate::error::Error;
+use crate::lexical::token::location::Location;
+use crate::semantic::element::constant::array::error::Error as ArrayConstantError;
+use crate::semantic::element::constant::error::Error as ConstantError;
+use crate::semantic::element::error::Error as ElementError;
+use crate::semantic::error::Error as SemanticError;
+
+#[test]
+fn ok_index_constant() {
+    let input = r#"
+const ARRAY: [u8; 4] = [1, 2, 3, 4];
+const SECOND: u8 = ARRAY[1];
+
+fn main() -> [u8; 5] {
+    [0; ARRAY[2] + SECOND]
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_index_nested() {
+    let input = r#"
+const TABLE: [[u8; 2]; 3] = [[1, 2], [3, 4], [5, 6]];
+
+fn main() -> [bool; 6] {
+    [false; TABLE[2][1]]
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_index_variable() {
+    let input = r#"
+const ARRAY: [field; 4] = [1, 2, 3, 4];
+
+fn main() -> field {
+    let mut sum: field = 0;
+    for i in 0..4 {
+        sum += ARRAY[i];
+    }
+    sum
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_index_out_of_range() {
+    let input = r#"
+const ARRAY: [u8; 3] = [1, 2, 3];
+
+fn main() {
+    let value = ARRAY[5];
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(5, 22),
+        ElementError::Constant(ConstantError::Array(ArrayConstantError::IndexOutOfRange {
+            index: BigInt::from(5).to_string(),
+            size: 3,
+        })),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_slice_start_out_of_range() {
+    let input = r#"
+const ARRAY: [u8; 5] = [1, 2, 3, 4, 5];
+
+fn main() {
+    let slice = ARRAY[-1 .. 1];
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(5, 22),
+        ElementError::Constant(ConstantError::Array(
+            ArrayConstantError::SliceStartOutOfRange {
+                start: BigInt::from(-1).to_string(),
+            },
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_slice_end_out_of_range() {
+    let input = r#"
+const ARRAY: [u8; 5] = [1, 2, 3, 4, 5];
+
+fn main() {
+    let slice = ARRAY[0 .. 6];
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(5, 22),
+        ElementError::Constant(ConstantError::Array(
+            ArrayConstantError::SliceEndOutOfRange {
+                end: BigInt::from(6).to_string(),
+                size: 5,
+            },
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_slice_end_lesser_than_start() {
+    let input = r#"
+const ARRAY: [u8; 5] = [1, 2, 3, 4, 5];
+
+fn main() {
+    let slice = ARRAY[2 .. 1];
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(5, 22),
+        ElementError::Constant(ConstantError::Array(
+            ArrayConstantError::SliceEndLesserThanStart {
+                start: BigInt::from(2).to_string(),
+                end: BigInt::from(1).to_string(),
+            },
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_size_limit_exceeded() {
+    let input = r#"
+const TABLE: [[u8; 1024]; 1024] = [[0; 1024]; 1024];
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(2, 47),
+        ElementError::Constant(ConstantError::Array(ArrayConstantError::SizeLimitExceeded {
+            size: 1024 * 1024,
+            limit: crate::LIMIT_CONSTANT_ARRAY_SIZE,
+        })),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_size_limit_variable() {
+    let input = r#"
+fn main() -> u8 {
+    let table = [[0; 1024]; 1024];
+    table[1][2]
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
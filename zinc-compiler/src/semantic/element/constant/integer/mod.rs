@@ -7,7 +7,6 @@ mod tests;
 pub mod error;
 
 use std::cmp;
-use std::convert::TryFrom;
 use std::fmt;
 
 use num_bigint::BigInt;
@@ -15,6 +14,7 @@ use num_traits::Num;
 use num_traits::Signed;
 use num_traits::ToPrimitive;
 
+use zinc_bytecode::Curve;
 use zinc_utils::euclidean;
 
 use crate::lexical::token::lexeme::literal::integer::Integer as LexicalIntegerLiteral;
@@ -65,6 +65,14 @@ impl Integer {
         }
     }
 
+    ///
+    /// The field elements are the only integers wider than the largest integer type,
+    /// since the field bitlength depends on the curve.
+    ///
+    pub fn is_field(&self) -> bool {
+        self.bitlength > crate::BITLENGTH_MAX_INT
+    }
+
     pub fn has_the_same_type_as(&self, other: &Self) -> bool {
         self.is_signed == other.is_signed
             && self.bitlength == other.bitlength
@@ -183,7 +191,7 @@ impl Integer {
             });
         }
 
-        if self.bitlength == crate::BITLENGTH_FIELD {
+        if self.is_field() {
             return Err(Error::ForbiddenFieldBitwise);
         }
 
@@ -205,7 +213,7 @@ impl Integer {
             });
         }
 
-        if self.bitlength == crate::BITLENGTH_FIELD {
+        if self.is_field() {
             return Err(Error::ForbiddenFieldBitwise);
         }
 
@@ -227,7 +235,7 @@ impl Integer {
             });
         }
 
-        if self.bitlength == crate::BITLENGTH_FIELD {
+        if self.is_field() {
             return Err(Error::ForbiddenFieldBitwise);
         }
 
@@ -242,7 +250,7 @@ impl Integer {
     }
 
     pub fn bitwise_shift_left(self, other: Self) -> Result<Self, Error> {
-        if self.bitlength == crate::BITLENGTH_FIELD {
+        if self.is_field() {
             return Err(Error::ForbiddenFieldBitwise);
         }
 
@@ -261,7 +269,11 @@ impl Integer {
                 value: other.value,
                 bitlength: self.bitlength,
             })?;
-        let result = self.value << other;
+        let result = Self::wrap(
+            self.value << cmp::min(other, self.bitlength),
+            self.is_signed,
+            self.bitlength,
+        );
 
         Ok(Self {
             value: result,
@@ -272,7 +284,7 @@ impl Integer {
     }
 
     pub fn bitwise_shift_right(self, other: Self) -> Result<Self, Error> {
-        if self.bitlength == crate::BITLENGTH_FIELD {
+        if self.is_field() {
             return Err(Error::ForbiddenFieldBitwise);
         }
 
@@ -291,7 +303,7 @@ impl Integer {
                 value: other.value,
                 bitlength: self.bitlength,
             })?;
-        let result = self.value >> other;
+        let result = self.value >> cmp::min(other, self.bitlength);
 
         Ok(Self {
             value: result,
@@ -313,14 +325,14 @@ impl Integer {
         if result.is_negative() && !self.is_signed {
             return Err(Error::OverflowAddition {
                 value: result,
-                r#type: Type::integer(self.is_signed, self.bitlength).to_string(),
+                r#type: Type::scalar(self.is_signed, self.bitlength).to_string(),
             });
         }
 
         if Self::minimal_bitlength(&result, self.is_signed)? > self.bitlength {
             return Err(Error::OverflowAddition {
                 value: result,
-                r#type: Type::integer(self.is_signed, self.bitlength).to_string(),
+                r#type: Type::scalar(self.is_signed, self.bitlength).to_string(),
             });
         }
 
@@ -344,14 +356,14 @@ impl Integer {
         if result.is_negative() && !self.is_signed {
             return Err(Error::OverflowSubtraction {
                 value: result,
-                r#type: Type::integer(self.is_signed, self.bitlength).to_string(),
+                r#type: Type::scalar(self.is_signed, self.bitlength).to_string(),
             });
         }
 
         if Self::minimal_bitlength(&result, self.is_signed)? > self.bitlength {
             return Err(Error::OverflowSubtraction {
                 value: result,
-                r#type: Type::integer(self.is_signed, self.bitlength).to_string(),
+                r#type: Type::scalar(self.is_signed, self.bitlength).to_string(),
             });
         }
 
@@ -375,14 +387,14 @@ impl Integer {
         if result.is_negative() && !self.is_signed {
             return Err(Error::OverflowMultiplication {
                 value: result,
-                r#type: Type::integer(self.is_signed, self.bitlength).to_string(),
+                r#type: Type::scalar(self.is_signed, self.bitlength).to_string(),
             });
         }
 
         if Self::minimal_bitlength(&result, self.is_signed)? > self.bitlength {
             return Err(Error::OverflowMultiplication {
                 value: result,
-                r#type: Type::integer(self.is_signed, self.bitlength).to_string(),
+                r#type: Type::scalar(self.is_signed, self.bitlength).to_string(),
             });
         }
 
@@ -402,7 +414,7 @@ impl Integer {
             });
         }
 
-        if self.bitlength == crate::BITLENGTH_FIELD {
+        if self.is_field() {
             return Err(Error::ForbiddenFieldDivision);
         }
 
@@ -411,14 +423,14 @@ impl Integer {
         if result.is_negative() && !self.is_signed {
             return Err(Error::OverflowDivision {
                 value: result,
-                r#type: Type::integer(self.is_signed, self.bitlength).to_string(),
+                r#type: Type::scalar(self.is_signed, self.bitlength).to_string(),
             });
         }
 
         if Self::minimal_bitlength(&result, self.is_signed)? > self.bitlength {
             return Err(Error::OverflowDivision {
                 value: result,
-                r#type: Type::integer(self.is_signed, self.bitlength).to_string(),
+                r#type: Type::scalar(self.is_signed, self.bitlength).to_string(),
             });
         }
 
@@ -438,7 +450,7 @@ impl Integer {
             });
         }
 
-        if self.bitlength == crate::BITLENGTH_FIELD {
+        if self.is_field() {
             return Err(Error::ForbiddenFieldRemainder);
         }
 
@@ -447,14 +459,14 @@ impl Integer {
         if result.is_negative() && !self.is_signed {
             return Err(Error::OverflowRemainder {
                 value: result,
-                r#type: Type::integer(self.is_signed, self.bitlength).to_string(),
+                r#type: Type::scalar(self.is_signed, self.bitlength).to_string(),
             });
         }
 
         if Self::minimal_bitlength(&result, self.is_signed)? > self.bitlength {
             return Err(Error::OverflowRemainder {
                 value: result,
-                r#type: Type::integer(self.is_signed, self.bitlength).to_string(),
+                r#type: Type::scalar(self.is_signed, self.bitlength).to_string(),
             });
         }
 
@@ -470,14 +482,14 @@ impl Integer {
         if self.value.is_negative() && !is_signed {
             return Err(Error::OverflowCasting {
                 value: self.value,
-                r#type: Type::integer(is_signed, bitlength).to_string(),
+                r#type: Type::scalar(is_signed, bitlength).to_string(),
             });
         }
 
         if Self::minimal_bitlength(&self.value, is_signed)? > bitlength {
             return Err(Error::OverflowCasting {
                 value: self.value,
-                r#type: Type::integer(is_signed, bitlength).to_string(),
+                r#type: Type::scalar(is_signed, bitlength).to_string(),
             });
         }
 
@@ -489,11 +501,11 @@ impl Integer {
     }
 
     pub fn bitwise_not(self) -> Result<Self, Error> {
-        if self.bitlength == crate::BITLENGTH_FIELD {
+        if self.is_field() {
             return Err(Error::ForbiddenFieldBitwise);
         }
 
-        let result = !self.value;
+        let result = Self::wrap(!self.value, self.is_signed, self.bitlength);
 
         Ok(Self {
             value: result,
@@ -504,7 +516,7 @@ impl Integer {
     }
 
     pub fn negate(self) -> Result<Self, Error> {
-        if self.bitlength == crate::BITLENGTH_FIELD {
+        if self.is_field() {
             return Err(Error::ForbiddenFieldNegation);
         }
 
@@ -534,13 +546,49 @@ impl Integer {
     }
 
     ///
-    /// Calculates the minimal bitlength required to represent each element of `literals`.
+    /// Converts `literal` to a `BigInt` and its bitlength.
+    /// For now, the minimal bitlength enough to contain the number is inferred. The literals
+    /// larger than the largest integer type are field elements of the `curve`.
+    ///
+    pub fn try_from_literal(literal: &IntegerLiteral, curve: Curve) -> Result<Self, Error> {
+        let (string, base) = match literal.inner {
+            LexicalIntegerLiteral::Binary { ref inner } => (inner, crate::BASE_BINARY as u32),
+            LexicalIntegerLiteral::Octal { ref inner } => (inner, crate::BASE_OCTAL as u32),
+            LexicalIntegerLiteral::Decimal { ref inner } => (inner, crate::BASE_DECIMAL as u32),
+            LexicalIntegerLiteral::Hexadecimal { ref inner } => {
+                (inner, crate::BASE_HEXADECIMAL as u32)
+            }
+        };
+
+        let value = BigInt::from_str_radix(string, base)
+            .expect(crate::PANIC_VALIDATED_DURING_LEXICAL_ANALYSIS);
+        let mut bitlength = Self::minimal_bitlength(&value, false)?;
+        if bitlength > crate::BITLENGTH_MAX_INT {
+            if bitlength > curve.bitlength_field() {
+                return Err(Error::IntegerTooLarge {
+                    value,
+                    bitlength: curve.bitlength_field(),
+                });
+            }
+
+            bitlength = curve.bitlength_field();
+        }
+
+        Ok(Self::new(value, false, bitlength))
+    }
+
+    ///
+    /// Calculates the minimal bitlength required to represent each element of `literals`
+    /// on the `curve`.
     ///
-    pub fn minimal_bitlength_literals(literals: &[&IntegerLiteral]) -> Result<usize, Error> {
+    pub fn minimal_bitlength_literals(
+        literals: &[&IntegerLiteral],
+        curve: Curve,
+    ) -> Result<usize, Error> {
         let mut result = crate::BITLENGTH_BYTE;
 
         for literal in literals.iter() {
-            let bitlength = Self::try_from(*literal)?.bitlength;
+            let bitlength = Self::try_from_literal(literal, curve)?.bitlength;
             if bitlength > result {
                 result = bitlength;
             }
@@ -570,6 +618,9 @@ impl Integer {
     /// Infers the minimal bitlength enough to represent the `value` with sign specified
     /// as `is_signed`.
     ///
+    /// The integer bitlengths are multiples of a byte up to `BITLENGTH_MAX_INT`. The larger values
+    /// get their exact bitlength, which is checked against the field bitlength by the caller.
+    ///
     pub fn minimal_bitlength(value: &BigInt, is_signed: bool) -> Result<usize, Error> {
         let mut bitlength = crate::BITLENGTH_BYTE;
         let mut exponent = BigInt::from(1 << crate::BITLENGTH_BYTE);
@@ -586,17 +637,17 @@ impl Integer {
             value >= &exponent
         } {
             if bitlength == crate::BITLENGTH_MAX_INT {
-                exponent <<= crate::BITLENGTH_FIELD - crate::BITLENGTH_MAX_INT;
-                bitlength += crate::BITLENGTH_FIELD - crate::BITLENGTH_MAX_INT;
-            } else if bitlength == crate::BITLENGTH_FIELD {
-                return Err(Error::IntegerTooLarge {
-                    value: value.to_owned(),
-                    bitlength: crate::BITLENGTH_FIELD,
-                });
-            } else {
-                exponent <<= crate::BITLENGTH_BYTE;
-                bitlength += crate::BITLENGTH_BYTE;
+                let magnitude = if value.is_negative() {
+                    -value - BigInt::from(1)
+                } else {
+                    value.to_owned()
+                };
+                bitlength = magnitude.bits() + if is_signed { 1 } else { 0 };
+                break;
             }
+
+            exponent <<= crate::BITLENGTH_BYTE;
+            bitlength += crate::BITLENGTH_BYTE;
         }
 
         if value.is_negative() && !is_signed {
@@ -608,30 +659,20 @@ impl Integer {
 
         Ok(bitlength)
     }
-}
-
-impl TryFrom<&IntegerLiteral> for Integer {
-    type Error = Error;
 
     ///
-    /// Converts `literal` to a `BigInt` and its bitlength.
-    /// For now, the minimal bitlength enough to contain the number is inferred.
+    /// Truncates the `value` to `bitlength` bits, reading the result as two's complement
+    /// if `is_signed` is set. The VM bitwise gadgets produce the same values.
     ///
-    fn try_from(literal: &IntegerLiteral) -> Result<Self, Self::Error> {
-        let (string, base) = match literal.inner {
-            LexicalIntegerLiteral::Binary { ref inner } => (inner, crate::BASE_BINARY as u32),
-            LexicalIntegerLiteral::Octal { ref inner } => (inner, crate::BASE_OCTAL as u32),
-            LexicalIntegerLiteral::Decimal { ref inner } => (inner, crate::BASE_DECIMAL as u32),
-            LexicalIntegerLiteral::Hexadecimal { ref inner } => {
-                (inner, crate::BASE_HEXADECIMAL as u32)
-            }
-        };
+    fn wrap(value: BigInt, is_signed: bool, bitlength: usize) -> BigInt {
+        let modulus = BigInt::from(1) << bitlength;
 
-        let value = BigInt::from_str_radix(&string, base)
-            .expect(crate::PANIC_VALIDATED_DURING_LEXICAL_ANALYSIS);
-        let bitlength = Self::minimal_bitlength(&value, false)?;
+        let mut result = value & (&modulus - BigInt::from(1));
+        if is_signed && result >= (&modulus >> 1) {
+            result -= modulus;
+        }
 
-        Ok(Self::new(value, false, bitlength))
+        result
     }
 }
 
@@ -8,6 +8,8 @@ use std::str::FromStr;
 
 use num_bigint::BigInt;
 
+use zinc_bytecode::Curve;
+
 use crate::error::Error;
 use crate::lexical::token::location::Location;
 use crate::semantic::element::constant::error::Error as ConstantError;
@@ -130,7 +132,7 @@ fn main() {
         ElementError::Constant(ConstantError::Integer(
             IntegerConstantError::IntegerTooLarge {
                 value: BigInt::from_str("115792089237316195423570985008687907853269984665640564039457584007913129639935").expect(crate::semantic::tests::PANIC_TEST_DATA),
-                bitlength: crate::BITLENGTH_FIELD,
+                bitlength: Curve::default().bitlength_field(),
             },
         )),
     )));
@@ -153,7 +155,7 @@ fn main() {
         ElementError::Constant(ConstantError::Integer(
             IntegerConstantError::IntegerTooLarge {
                 value: BigInt::from_str("115792089237316195423570985008687907853269984665640564039457584007913129639935").expect(crate::semantic::tests::PANIC_TEST_DATA),
-                bitlength: crate::BITLENGTH_FIELD,
+                bitlength: Curve::default().bitlength_field(),
             },
         )),
     )));
@@ -181,7 +183,7 @@ fn main() {
         ElementError::Constant(ConstantError::Integer(
             IntegerConstantError::IntegerTooLarge {
                 value: BigInt::from_str("115792089237316195423570985008687907853269984665640564039457584007913129639935").expect(crate::semantic::tests::PANIC_TEST_DATA),
-                bitlength: crate::BITLENGTH_FIELD,
+                bitlength: Curve::default().bitlength_field(),
             },
         )),
     )));
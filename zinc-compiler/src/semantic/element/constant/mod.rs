@@ -4,22 +4,32 @@
 
 mod tests;
 
+pub mod array;
 pub mod boolean;
 pub mod error;
 pub mod integer;
 pub mod range;
 pub mod range_inclusive;
+pub mod structure;
+pub mod tuple;
 
+use std::convert::TryFrom;
 use std::fmt;
 
 use crate::semantic::casting::Caster;
+use crate::semantic::element::access::Field as FieldAccess;
+use crate::semantic::element::access::Index as IndexAccess;
 use crate::semantic::element::r#type::Type;
+use crate::semantic::element::value::Value;
 
+use self::array::Array;
 use self::boolean::Boolean;
 use self::error::Error;
 use self::integer::Integer;
 use self::range::Range;
 use self::range_inclusive::RangeInclusive;
+use self::structure::Structure;
+use self::tuple::Tuple;
 
 ///
 /// Constants are parts of a constant expression.
@@ -32,6 +42,9 @@ pub enum Constant {
     Range(Range),
     RangeInclusive(RangeInclusive),
     String(String),
+    Array(Array),
+    Tuple(Tuple),
+    Structure(Structure),
 }
 
 impl Constant {
@@ -43,6 +56,9 @@ impl Constant {
             Self::Range(inner) => inner.r#type(),
             Self::RangeInclusive(inner) => inner.r#type(),
             Self::String(_) => Type::string(),
+            Self::Array(inner) => inner.r#type(),
+            Self::Tuple(inner) => inner.r#type(),
+            Self::Structure(inner) => inner.r#type(),
         }
     }
 
@@ -59,6 +75,11 @@ impl Constant {
             (Self::RangeInclusive(inner_1), Self::RangeInclusive(inner_2)) => {
                 inner_1.has_the_same_type_as(inner_2)
             }
+            (Self::Array(inner_1), Self::Array(inner_2)) => inner_1.has_the_same_type_as(inner_2),
+            (Self::Tuple(inner_1), Self::Tuple(inner_2)) => inner_1.has_the_same_type_as(inner_2),
+            (Self::Structure(inner_1), Self::Structure(inner_2)) => {
+                inner_1.has_the_same_type_as(inner_2)
+            }
             _ => false,
         }
     }
@@ -477,7 +498,7 @@ impl Constant {
         let (is_signed, bitlength) = match to {
             Type::IntegerUnsigned { bitlength } => (false, bitlength),
             Type::IntegerSigned { bitlength } => (true, bitlength),
-            Type::Field => (false, crate::BITLENGTH_FIELD),
+            Type::Field { bitlength } => (false, bitlength),
             _ => return Ok(self),
         };
 
@@ -489,6 +510,92 @@ impl Constant {
             operand => operand,
         })
     }
+
+    ///
+    /// Casts the constant to the type of its `const` item declaration.
+    ///
+    /// Unlike the `as` operator, casts the arrays and tuples element by element, so a literal
+    /// like `[1, 2, 3]` can initialize a `[field; 3]` constant.
+    ///
+    pub fn cast_declared(self, to: Type) -> Result<Self, Error> {
+        match (self, to) {
+            (Self::Array(array), Type::Array { r#type, size }) if array.len() == size => {
+                let values = array
+                    .values
+                    .into_iter()
+                    .map(|value| value.cast_declared(*r#type.to_owned()))
+                    .collect::<Result<Vec<Self>, Error>>()?;
+                Ok(Self::Array(Array::new(*r#type, values)))
+            }
+            (Self::Tuple(tuple), Type::Tuple { types }) if tuple.len() == types.len() => {
+                let values = tuple
+                    .values
+                    .into_iter()
+                    .zip(types.into_iter())
+                    .map(|(value, r#type)| value.cast_declared(r#type))
+                    .collect::<Result<Vec<Self>, Error>>()?;
+                Ok(Self::Tuple(Tuple::new(values)))
+            }
+            (constant, to) => constant.cast(to),
+        }
+    }
+
+    pub fn index_value(self, other: Value) -> Result<(Value, IndexAccess), Error> {
+        match self {
+            Self::Array(array) => match other {
+                Value::Integer(_) => {
+                    let access = IndexAccess::new(array.element_type.size(), array.r#type().size());
+                    let value = Value::try_from(&array.element_type)
+                        .expect(crate::PANIC_VALIDATED_DURING_SYNTAX_ANALYSIS);
+                    Ok((value, access))
+                }
+                value => Err(Error::OperatorIndexSecondOperandExpectedIntegerOrRange {
+                    found: value.to_string(),
+                }),
+            },
+            constant => Err(Error::OperatorIndexFirstOperandExpectedArray {
+                found: constant.to_string(),
+            }),
+        }
+    }
+
+    pub fn index_constant(self, other: Self) -> Result<(Self, IndexAccess), Error> {
+        match self {
+            Self::Array(array) => match other {
+                Self::Integer(integer) => array.slice_single(integer.value).map_err(Error::Array),
+                Self::Range(range) => array
+                    .slice_range(range.start, range.end)
+                    .map_err(Error::Array),
+                Self::RangeInclusive(range) => array
+                    .slice_range_inclusive(range.start, range.end)
+                    .map_err(Error::Array),
+                constant => Err(Error::OperatorIndexSecondOperandExpectedIntegerOrRange {
+                    found: constant.to_string(),
+                }),
+            },
+            constant => Err(Error::OperatorIndexFirstOperandExpectedArray {
+                found: constant.to_string(),
+            }),
+        }
+    }
+
+    pub fn field_tuple(self, field_index: usize) -> Result<(Self, FieldAccess), Error> {
+        match self {
+            Self::Tuple(tuple) => tuple.slice(field_index).map_err(Error::Tuple),
+            constant => Err(Error::OperatorFieldFirstOperandExpectedTuple {
+                found: constant.to_string(),
+            }),
+        }
+    }
+
+    pub fn field_structure(self, field_name: String) -> Result<(Self, FieldAccess), Error> {
+        match self {
+            Self::Structure(structure) => structure.slice(field_name).map_err(Error::Structure),
+            constant => Err(Error::OperatorFieldFirstOperandExpectedStructure {
+                found: constant.to_string(),
+            }),
+        }
+    }
 }
 
 impl fmt::Display for Constant {
@@ -500,6 +607,9 @@ impl fmt::Display for Constant {
             Self::Range(inner) => write!(f, "{}", inner),
             Self::RangeInclusive(inner) => write!(f, "{}", inner),
             Self::String(constant) => write!(f, "string constant '{}'", constant),
+            Self::Array(inner) => write!(f, "{}", inner),
+            Self::Tuple(inner) => write!(f, "{}", inner),
+            Self::Structure(inner) => write!(f, "{}", inner),
         }
     }
 }
@@ -0,0 +1,67 @@
+//!
+//! The semantic analyzer constant structure element.
+//!
+
+mod tests;
+
+pub mod error;
+
+use std::fmt;
+
+use crate::semantic::element::access::Field as FieldAccess;
+use crate::semantic::element::constant::Constant;
+use crate::semantic::element::r#type::structure::Structure as StructureType;
+use crate::semantic::element::r#type::Type;
+
+use self::error::Error;
+
+///
+/// Constant structures are compile-time collections of named constants of different types.
+///
+/// The field values are stored in the order of the structure type declaration.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Structure {
+    pub r#type: StructureType,
+    pub values: Vec<(String, Constant)>,
+}
+
+impl Structure {
+    pub fn new(r#type: StructureType, values: Vec<(String, Constant)>) -> Self {
+        Self { r#type, values }
+    }
+
+    pub fn r#type(&self) -> Type {
+        Type::Structure(self.r#type.to_owned())
+    }
+
+    pub fn has_the_same_type_as(&self, other: &Self) -> bool {
+        self.r#type.unique_id == other.r#type.unique_id
+    }
+
+    pub fn slice(self, field_name: String) -> Result<(Constant, FieldAccess), Error> {
+        let mut offset = 0;
+        let total_size = self.r#type().size();
+
+        for (index, (name, value)) in self.values.into_iter().enumerate() {
+            let size = value.r#type().size();
+            if name == field_name {
+                let access = FieldAccess::new(index, offset, size, total_size);
+
+                return Ok((value, access));
+            }
+            offset += size;
+        }
+
+        Err(Error::FieldDoesNotExist {
+            type_identifier: self.r#type.identifier,
+            field_name,
+        })
+    }
+}
+
+impl fmt::Display for Structure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "constant structure '{}'", self.r#type.identifier)
+    }
+}
@@ -0,0 +1,64 @@
+//!
+//! The structure constant element tests.
+//!
+
+#![cfg(test)]
+
+use crate::error::Error;
+use crate::lexical::token::location::Location;
+use crate::semantic::element::constant::error::Error as ConstantError;
+use crate::semantic::element::constant::structure::error::Error as StructureConstantError;
+use crate::semantic::element::error::Error as ElementError;
+use crate::semantic::error::Error as SemanticError;
+
+#[test]
+fn ok_field() {
+    let input = r#"
+struct Parameters {
+    rounds: u8,
+    keys: [field; 2],
+}
+
+const PARAMETERS: Parameters = Parameters {
+    rounds: 3,
+    keys: [1 as field, 2 as field],
+};
+
+fn main() -> [field; 3] {
+    [PARAMETERS.keys[1]; PARAMETERS.rounds]
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_field_does_not_exist() {
+    let input = r#"
+struct Data {
+    a: u8,
+}
+
+const DATA: Data = Data {
+    a: 0,
+};
+
+fn main() {
+    let result = DATA.b;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(11, 22),
+        ElementError::Constant(ConstantError::Structure(
+            StructureConstantError::FieldDoesNotExist {
+                type_identifier: "Data".to_owned(),
+                field_name: "b".to_owned(),
+            },
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
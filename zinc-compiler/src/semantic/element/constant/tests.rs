@@ -9,9 +9,11 @@ use num_bigint::BigInt;
 use crate::error::Error;
 use crate::lexical::token::location::Location;
 use crate::semantic::casting::error::Error as CastingError;
+use crate::semantic::element::constant::array::Array as ArrayConstant;
 use crate::semantic::element::constant::boolean::Boolean as BooleanConstant;
 use crate::semantic::element::constant::error::Error as ConstantError;
 use crate::semantic::element::constant::integer::Integer as IntegerConstant;
+use crate::semantic::element::constant::tuple::Tuple as TupleConstant;
 use crate::semantic::element::constant::Constant;
 use crate::semantic::element::error::Error as ElementError;
 use crate::semantic::element::r#type::Type;
@@ -1129,3 +1131,114 @@ fn main() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn error_operator_index_1st_operand_expected_array() {
+    let input = r#"
+const TUPLE: (bool, bool, bool) = (true, false, true);
+
+fn main() {
+    let value = TUPLE[1];
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(5, 22),
+        ElementError::Constant(ConstantError::OperatorIndexFirstOperandExpectedArray {
+            found: Constant::Tuple(TupleConstant::new(vec![
+                Constant::Boolean(BooleanConstant::new(true)),
+                Constant::Boolean(BooleanConstant::new(false)),
+                Constant::Boolean(BooleanConstant::new(true)),
+            ]))
+            .to_string(),
+        }),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_operator_index_2nd_operand_expected_integer_or_range() {
+    let input = r#"
+const ARRAY: [u8; 3] = [1, 2, 3];
+
+fn main() {
+    let value = ARRAY[true];
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(5, 22),
+        ElementError::Constant(
+            ConstantError::OperatorIndexSecondOperandExpectedIntegerOrRange {
+                found: Constant::Boolean(BooleanConstant::new(true)).to_string(),
+            },
+        ),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_operator_field_1st_operand_expected_tuple() {
+    let input = r#"
+const ARRAY: [bool; 3] = [true, true, false];
+
+fn main() {
+    let value = ARRAY.1;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(5, 22),
+        ElementError::Constant(ConstantError::OperatorFieldFirstOperandExpectedTuple {
+            found: Constant::Array(ArrayConstant::new(
+                Type::boolean(),
+                vec![
+                    Constant::Boolean(BooleanConstant::new(true)),
+                    Constant::Boolean(BooleanConstant::new(true)),
+                    Constant::Boolean(BooleanConstant::new(false)),
+                ],
+            ))
+            .to_string(),
+        }),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_operator_field_1st_operand_expected_structure() {
+    let input = r#"
+const ARRAY: [bool; 3] = [true, true, false];
+
+fn main() {
+    let value = ARRAY.first;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(5, 22),
+        ElementError::Constant(ConstantError::OperatorFieldFirstOperandExpectedStructure {
+            found: Constant::Array(ArrayConstant::new(
+                Type::boolean(),
+                vec![
+                    Constant::Boolean(BooleanConstant::new(true)),
+                    Constant::Boolean(BooleanConstant::new(true)),
+                    Constant::Boolean(BooleanConstant::new(false)),
+                ],
+            ))
+            .to_string(),
+        }),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
@@ -0,0 +1,11 @@
+//!
+//! The semantic analyzer tuple constant element error.
+//!
+
+#[derive(Debug, PartialEq)]
+pub enum Error {
+    FieldDoesNotExist {
+        type_identifier: String,
+        field_index: usize,
+    },
+}
@@ -0,0 +1,77 @@
+//!
+//! The semantic analyzer constant tuple element.
+//!
+
+mod tests;
+
+pub mod error;
+
+use std::fmt;
+
+use crate::semantic::element::access::Field as FieldAccess;
+use crate::semantic::element::constant::Constant;
+use crate::semantic::element::r#type::Type;
+
+use self::error::Error;
+
+///
+/// Constant tuples are compile-time collections of constants of different types.
+///
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Tuple {
+    pub values: Vec<Constant>,
+}
+
+impl Tuple {
+    pub fn new(values: Vec<Constant>) -> Self {
+        Self { values }
+    }
+
+    pub fn r#type(&self) -> Type {
+        Type::tuple(self.values.iter().map(Constant::r#type).collect())
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn has_the_same_type_as(&self, other: &Self) -> bool {
+        self.r#type() == other.r#type()
+    }
+
+    pub fn slice(self, index: usize) -> Result<(Constant, FieldAccess), Error> {
+        let total_size = self.r#type().size();
+
+        if index >= self.values.len() {
+            return Err(Error::FieldDoesNotExist {
+                type_identifier: self.r#type().to_string(),
+                field_index: index,
+            });
+        }
+
+        let offset = self.values[..index]
+            .iter()
+            .map(|value| value.r#type().size())
+            .sum();
+
+        let sliced = self
+            .values
+            .into_iter()
+            .nth(index)
+            .expect(crate::PANIC_VALIDATED_DURING_SYNTAX_ANALYSIS);
+
+        let access = FieldAccess::new(index, offset, sliced.r#type().size(), total_size);
+
+        Ok((sliced, access))
+    }
+}
+
+impl fmt::Display for Tuple {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "constant tuple of type '{}'", self.r#type())
+    }
+}
@@ -0,0 +1,51 @@
+//!
+//! The tuple constant element tests.
+//!
+
+#![cfg(test)]
+
+use crate::error::Error;
+use crate::lexical::token::location::Location;
+use crate::semantic::element::constant::error::Error as ConstantError;
+use crate::semantic::element::constant::tuple::error::Error as TupleConstantError;
+use crate::semantic::element::error::Error as ElementError;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::error::Error as SemanticError;
+
+#[test]
+fn ok_field() {
+    let input = r#"
+const PAIR: (u8, bool) = (4, true);
+
+fn main() -> [bool; 4] {
+    [PAIR.1; PAIR.0]
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_field_does_not_exist() {
+    let input = r#"
+const TUPLE: (bool, bool, bool) = (true, true, false);
+
+fn main() {
+    let result = TUPLE.5;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(5, 23),
+        ElementError::Constant(ConstantError::Tuple(
+            TupleConstantError::FieldDoesNotExist {
+                type_identifier: Type::tuple(vec![Type::boolean(); 3]).to_string(),
+                field_index: 5,
+            },
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
@@ -74,10 +74,10 @@ pub enum Error {
     OperatorBitwiseAndSecondOperandExpectedEvaluable { found: String },
 
     OperatorBitwiseShiftLeftFirstOperandExpectedEvaluable { found: String },
-    OperatorBitwiseShiftLeftSecondOperandExpectedConstant { found: String },
+    OperatorBitwiseShiftLeftSecondOperandExpectedEvaluable { found: String },
 
     OperatorBitwiseShiftRightFirstOperandExpectedEvaluable { found: String },
-    OperatorBitwiseShiftRightSecondOperandExpectedConstant { found: String },
+    OperatorBitwiseShiftRightSecondOperandExpectedEvaluable { found: String },
 
     OperatorAdditionFirstOperandExpectedEvaluable { found: String },
     OperatorAdditionSecondOperandExpectedEvaluable { found: String },
@@ -80,13 +80,13 @@ impl Element {
             Self::Place(place) => {
                 let value_1 = Value::try_from(&place.r#type).map_err(Error::Value)?;
                 match other {
-                    // Self::Value(value_2) => {
-                    //     value_1
-                    //         .bitwise_or(value_2)
-                    //         .map(Self::Value)
-                    //         .map_err(Error::Value)?;
-                    //     Ok(place)
-                    // }
+                    Self::Value(value_2) => {
+                        value_1
+                            .bitwise_or(value_2)
+                            .map(Self::Value)
+                            .map_err(Error::Value)?;
+                        Ok(place)
+                    }
                     Self::Constant(value_2) => {
                         value_1
                             .bitwise_or(Value::try_from(value_2).map_err(Error::Value)?)
@@ -114,13 +114,13 @@ impl Element {
             Self::Place(place) => {
                 let value_1 = Value::try_from(&place.r#type).map_err(Error::Value)?;
                 match other {
-                    // Self::Value(value_2) => {
-                    //     value_1
-                    //         .bitwise_xor(value_2)
-                    //         .map(Self::Value)
-                    //         .map_err(Error::Value)?;
-                    //     Ok(place)
-                    // }
+                    Self::Value(value_2) => {
+                        value_1
+                            .bitwise_xor(value_2)
+                            .map(Self::Value)
+                            .map_err(Error::Value)?;
+                        Ok(place)
+                    }
                     Self::Constant(value_2) => {
                         value_1
                             .bitwise_xor(Value::try_from(value_2).map_err(Error::Value)?)
@@ -148,13 +148,13 @@ impl Element {
             Self::Place(place) => {
                 let value_1 = Value::try_from(&place.r#type).map_err(Error::Value)?;
                 match other {
-                    // Self::Value(value_2) => {
-                    //     value_1
-                    //         .bitwise_and(value_2)
-                    //         .map(Self::Value)
-                    //         .map_err(Error::Value)?;
-                    //     Ok(place)
-                    // }
+                    Self::Value(value_2) => {
+                        value_1
+                            .bitwise_and(value_2)
+                            .map(Self::Value)
+                            .map_err(Error::Value)?;
+                        Ok(place)
+                    }
                     Self::Constant(value_2) => {
                         value_1
                             .bitwise_and(Value::try_from(value_2).map_err(Error::Value)?)
@@ -182,13 +182,13 @@ impl Element {
             Self::Place(place) => {
                 let value_1 = Value::try_from(&place.r#type).map_err(Error::Value)?;
                 match other {
-                    // Self::Value(value_2) => {
-                    //     value_1
-                    //         .bitwise_shift_left(value_2)
-                    //         .map(Self::Value)
-                    //         .map_err(Error::Value)?;
-                    //     Ok(place)
-                    // }
+                    Self::Value(value_2) => {
+                        value_1
+                            .bitwise_shift_left(value_2)
+                            .map(Self::Value)
+                            .map_err(Error::Value)?;
+                        Ok(place)
+                    }
                     Self::Constant(value_2) => {
                         value_1
                             .bitwise_shift_left(Value::try_from(value_2).map_err(Error::Value)?)
@@ -216,13 +216,13 @@ impl Element {
             Self::Place(place) => {
                 let value_1 = Value::try_from(&place.r#type).map_err(Error::Value)?;
                 match other {
-                    // Self::Value(value_2) => {
-                    //     value_1
-                    //         .bitwise_shift_right(value_2)
-                    //         .map(Self::Value)
-                    //         .map_err(Error::Value)?;
-                    //     Ok(place)
-                    // }
+                    Self::Value(value_2) => {
+                        value_1
+                            .bitwise_shift_right(value_2)
+                            .map(Self::Value)
+                            .map_err(Error::Value)?;
+                        Ok(place)
+                    }
                     Self::Constant(value_2) => {
                         value_1
                             .bitwise_shift_right(Value::try_from(value_2).map_err(Error::Value)?)
@@ -759,24 +759,24 @@ impl Element {
 
     pub fn bitwise_or(self, other: Self) -> Result<Self, Error> {
         match (self, other) {
-            // (Element::Value(value_1), Element::Value(value_2)) => value_1
-            //     .bitwise_or(value_2)
-            //     .map(Self::Value)
-            //     .map_err(Error::Value),
-            // (Element::Value(value_1), Element::Constant(value_2)) => value_1
-            //     .bitwise_or(Value::try_from(value_2).map_err(Error::Value)?)
-            //     .map(Self::Value)
-            //     .map_err(Error::Value),
-            // (Element::Value(_), element_2) => {
-            //     Err(Error::OperatorBitwiseOrSecondOperandExpectedEvaluable {
-            //         found: element_2.to_string(),
-            //     })
-            // }
-            // (Element::Constant(value_1), Element::Value(value_2)) => Value::try_from(value_1)
-            //     .map_err(Error::Value)?
-            //     .bitwise_or(value_2)
-            //     .map(Self::Value)
-            //     .map_err(Error::Value),
+            (Element::Value(value_1), Element::Value(value_2)) => value_1
+                .bitwise_or(value_2)
+                .map(Self::Value)
+                .map_err(Error::Value),
+            (Element::Value(value_1), Element::Constant(value_2)) => value_1
+                .bitwise_or(Value::try_from(value_2).map_err(Error::Value)?)
+                .map(Self::Value)
+                .map_err(Error::Value),
+            (Element::Value(_), element_2) => {
+                Err(Error::OperatorBitwiseOrSecondOperandExpectedEvaluable {
+                    found: element_2.to_string(),
+                })
+            }
+            (Element::Constant(value_1), Element::Value(value_2)) => Value::try_from(value_1)
+                .map_err(Error::Value)?
+                .bitwise_or(value_2)
+                .map(Self::Value)
+                .map_err(Error::Value),
             (Element::Constant(value_1), Element::Constant(value_2)) => value_1
                 .bitwise_or(value_2)
                 .map(Self::Constant)
@@ -794,24 +794,24 @@ impl Element {
 
     pub fn bitwise_xor(self, other: Self) -> Result<Self, Error> {
         match (self, other) {
-            // (Element::Value(value_1), Element::Value(value_2)) => value_1
-            //     .bitwise_xor(value_2)
-            //     .map(Self::Value)
-            //     .map_err(Error::Value),
-            // (Element::Value(value_1), Element::Constant(value_2)) => value_1
-            //     .bitwise_xor(Value::try_from(value_2).map_err(Error::Value)?)
-            //     .map(Self::Value)
-            //     .map_err(Error::Value),
-            // (Element::Value(_), element_2) => {
-            //     Err(Error::OperatorBitwiseXorSecondOperandExpectedEvaluable {
-            //         found: element_2.to_string(),
-            //     })
-            // }
-            // (Element::Constant(value_1), Element::Value(value_2)) => Value::try_from(value_1)
-            //     .map_err(Error::Value)?
-            //     .bitwise_xor(value_2)
-            //     .map(Self::Value)
-            //     .map_err(Error::Value),
+            (Element::Value(value_1), Element::Value(value_2)) => value_1
+                .bitwise_xor(value_2)
+                .map(Self::Value)
+                .map_err(Error::Value),
+            (Element::Value(value_1), Element::Constant(value_2)) => value_1
+                .bitwise_xor(Value::try_from(value_2).map_err(Error::Value)?)
+                .map(Self::Value)
+                .map_err(Error::Value),
+            (Element::Value(_), element_2) => {
+                Err(Error::OperatorBitwiseXorSecondOperandExpectedEvaluable {
+                    found: element_2.to_string(),
+                })
+            }
+            (Element::Constant(value_1), Element::Value(value_2)) => Value::try_from(value_1)
+                .map_err(Error::Value)?
+                .bitwise_xor(value_2)
+                .map(Self::Value)
+                .map_err(Error::Value),
             (Element::Constant(value_1), Element::Constant(value_2)) => value_1
                 .bitwise_xor(value_2)
                 .map(Self::Constant)
@@ -829,24 +829,24 @@ impl Element {
 
     pub fn bitwise_and(self, other: Self) -> Result<Self, Error> {
         match (self, other) {
-            // (Element::Value(value_1), Element::Value(value_2)) => value_1
-            //     .bitwise_and(value_2)
-            //     .map(Self::Value)
-            //     .map_err(Error::Value),
-            // (Element::Value(value_1), Element::Constant(value_2)) => value_1
-            //     .bitwise_and(Value::try_from(value_2).map_err(Error::Value)?)
-            //     .map(Self::Value)
-            //     .map_err(Error::Value),
-            // (Element::Value(_), element_2) => {
-            //     Err(Error::OperatorBitwiseAndSecondOperandExpectedEvaluable {
-            //         found: element_2.to_string(),
-            //     })
-            // }
-            // (Element::Constant(value_1), Element::Value(value_2)) => Value::try_from(value_1)
-            //     .map_err(Error::Value)?
-            //     .bitwise_and(value_2)
-            //     .map(Self::Value)
-            //     .map_err(Error::Value),
+            (Element::Value(value_1), Element::Value(value_2)) => value_1
+                .bitwise_and(value_2)
+                .map(Self::Value)
+                .map_err(Error::Value),
+            (Element::Value(value_1), Element::Constant(value_2)) => value_1
+                .bitwise_and(Value::try_from(value_2).map_err(Error::Value)?)
+                .map(Self::Value)
+                .map_err(Error::Value),
+            (Element::Value(_), element_2) => {
+                Err(Error::OperatorBitwiseAndSecondOperandExpectedEvaluable {
+                    found: element_2.to_string(),
+                })
+            }
+            (Element::Constant(value_1), Element::Value(value_2)) => Value::try_from(value_1)
+                .map_err(Error::Value)?
+                .bitwise_and(value_2)
+                .map(Self::Value)
+                .map_err(Error::Value),
             (Element::Constant(value_1), Element::Constant(value_2)) => value_1
                 .bitwise_and(value_2)
                 .map(Self::Constant)
@@ -864,21 +864,30 @@ impl Element {
 
     pub fn bitwise_shift_left(self, other: Self) -> Result<Self, Error> {
         match (self, other) {
-            // (Element::Value(value_1), Element::Constant(value_2)) => value_1
-            //     .bitwise_shift_left(Value::try_from(value_2).map_err(Error::Value)?)
-            //     .map(Self::Value)
-            //     .map_err(Error::Value),
-            // (Element::Value(_), element_2) => Err(
-            //     Error::OperatorBitwiseShiftLeftSecondOperandExpectedConstant {
-            //         found: element_2.to_string(),
-            //     },
-            // ),
+            (Element::Value(value_1), Element::Value(value_2)) => value_1
+                .bitwise_shift_left(value_2)
+                .map(Self::Value)
+                .map_err(Error::Value),
+            (Element::Value(value_1), Element::Constant(value_2)) => value_1
+                .bitwise_shift_left(Value::try_from(value_2).map_err(Error::Value)?)
+                .map(Self::Value)
+                .map_err(Error::Value),
+            (Element::Value(_), element_2) => Err(
+                Error::OperatorBitwiseShiftLeftSecondOperandExpectedEvaluable {
+                    found: element_2.to_string(),
+                },
+            ),
+            (Element::Constant(value_1), Element::Value(value_2)) => Value::try_from(value_1)
+                .map_err(Error::Value)?
+                .bitwise_shift_left(value_2)
+                .map(Self::Value)
+                .map_err(Error::Value),
             (Element::Constant(value_1), Element::Constant(value_2)) => value_1
                 .bitwise_shift_left(value_2)
                 .map(Self::Constant)
                 .map_err(Error::Constant),
             (Element::Constant(_), element_2) => Err(
-                Error::OperatorBitwiseShiftLeftSecondOperandExpectedConstant {
+                Error::OperatorBitwiseShiftLeftSecondOperandExpectedEvaluable {
                     found: element_2.to_string(),
                 },
             ),
@@ -892,21 +901,30 @@ impl Element {
 
     pub fn bitwise_shift_right(self, other: Self) -> Result<Self, Error> {
         match (self, other) {
-            // (Element::Value(value_1), Element::Constant(value_2)) => value_1
-            //     .bitwise_shift_right(Value::try_from(value_2).map_err(Error::Value)?)
-            //     .map(Self::Value)
-            //     .map_err(Error::Value),
-            // (Element::Value(_), element_2) => Err(
-            //     Error::OperatorBitwiseShiftRightSecondOperandExpectedConstant {
-            //         found: element_2.to_string(),
-            //     },
-            // ),
+            (Element::Value(value_1), Element::Value(value_2)) => value_1
+                .bitwise_shift_right(value_2)
+                .map(Self::Value)
+                .map_err(Error::Value),
+            (Element::Value(value_1), Element::Constant(value_2)) => value_1
+                .bitwise_shift_right(Value::try_from(value_2).map_err(Error::Value)?)
+                .map(Self::Value)
+                .map_err(Error::Value),
+            (Element::Value(_), element_2) => Err(
+                Error::OperatorBitwiseShiftRightSecondOperandExpectedEvaluable {
+                    found: element_2.to_string(),
+                },
+            ),
+            (Element::Constant(value_1), Element::Value(value_2)) => Value::try_from(value_1)
+                .map_err(Error::Value)?
+                .bitwise_shift_right(value_2)
+                .map(Self::Value)
+                .map_err(Error::Value),
             (Element::Constant(value_1), Element::Constant(value_2)) => value_1
                 .bitwise_shift_right(value_2)
                 .map(Self::Constant)
                 .map_err(Error::Constant),
             (Element::Constant(_), element_2) => Err(
-                Error::OperatorBitwiseShiftRightSecondOperandExpectedConstant {
+                Error::OperatorBitwiseShiftRightSecondOperandExpectedEvaluable {
                     found: element_2.to_string(),
                 },
             ),
@@ -1128,7 +1146,7 @@ impl Element {
 
     pub fn bitwise_not(self) -> Result<Self, Error> {
         match self {
-            // Element::Value(value) => value.bitwise_not().map(Self::Value).map_err(Error::Value),
+            Element::Value(value) => value.bitwise_not().map(Self::Value).map_err(Error::Value),
             Element::Constant(constant) => constant
                 .bitwise_not()
                 .map(Self::Constant)
@@ -1180,6 +1198,21 @@ impl Element {
                     found: element.to_string(),
                 }),
             },
+            Self::Constant(constant @ Constant::Array(_))
+            | Self::Constant(constant @ Constant::Tuple(_))
+            | Self::Constant(constant @ Constant::Structure(_)) => match other {
+                Self::Value(index) => constant
+                    .index_value(index)
+                    .map(|(value, access)| (Element::Value(value), access))
+                    .map_err(Error::Constant),
+                Self::Constant(index) => constant
+                    .index_constant(index)
+                    .map(|(constant, access)| (Element::Constant(constant), access))
+                    .map_err(Error::Constant),
+                element => Err(Error::OperatorIndexSecondOperandExpectedEvaluable {
+                    found: element.to_string(),
+                }),
+            },
             element => Err(Error::OperatorIndexFirstOperandExpectedPlaceOrEvaluable {
                 found: element.to_string(),
             }),
@@ -1214,6 +1247,21 @@ impl Element {
                     found: element.to_string(),
                 }),
             },
+            Self::Constant(constant @ Constant::Array(_))
+            | Self::Constant(constant @ Constant::Tuple(_))
+            | Self::Constant(constant @ Constant::Structure(_)) => match other {
+                Self::TupleIndex(index) => constant
+                    .field_tuple(index)
+                    .map(|(constant, access)| (Element::Constant(constant), access))
+                    .map_err(Error::Constant),
+                Self::Identifier(identifier) => constant
+                    .field_structure(identifier.name)
+                    .map(|(constant, access)| (Element::Constant(constant), access))
+                    .map_err(Error::Constant),
+                element => Err(Error::OperatorFieldSecondOperandExpectedIdentifier {
+                    found: element.to_string(),
+                }),
+            },
             element => Err(Error::OperatorFieldFirstOperandExpectedPlaceOrEvaluable {
                 found: element.to_string(),
             }),
@@ -8,6 +8,8 @@ use std::convert::TryFrom;
 
 use num_bigint::BigInt;
 
+use zinc_bytecode::Curve;
+
 use crate::error::Error;
 use crate::lexical::token::location::Location;
 use crate::semantic::element::constant::integer::Integer as IntegerConstant;
@@ -1189,20 +1191,19 @@ fn main() {
 }
 
 #[test]
-fn error_operator_bitwise_shift_left_2nd_operand_expected_constant() {
+fn error_operator_bitwise_shift_left_2nd_operand_expected_evaluable() {
     let input = r#"
+type X = u8;
+
 fn main() {
-    let offset = 2;
-    let value = 42 << offset;
+    let value = 42 << X;
 }
 "#;
 
     let expected = Err(Error::Semantic(SemanticError::Element(
-        Location::new(4, 20),
-        ElementError::OperatorBitwiseShiftLeftSecondOperandExpectedConstant {
-            found: Value::try_from(&Type::integer_unsigned(crate::BITLENGTH_BYTE))
-                .expect(crate::semantic::tests::PANIC_TEST_DATA)
-                .to_string(),
+        Location::new(5, 20),
+        ElementError::OperatorBitwiseShiftLeftSecondOperandExpectedEvaluable {
+            found: Element::Type(Type::integer_unsigned(crate::BITLENGTH_BYTE)).to_string(),
         },
     )));
 
@@ -1234,20 +1235,19 @@ fn main() {
 }
 
 #[test]
-fn error_operator_bitwise_shift_right_2nd_operand_expected_constant() {
+fn error_operator_bitwise_shift_right_2nd_operand_expected_evaluable() {
     let input = r#"
+type X = u8;
+
 fn main() {
-    let offset = 2;
-    let value = 42 >> offset;
+    let value = 42 >> X;
 }
 "#;
 
     let expected = Err(Error::Semantic(SemanticError::Element(
-        Location::new(4, 20),
-        ElementError::OperatorBitwiseShiftRightSecondOperandExpectedConstant {
-            found: Value::try_from(&Type::integer_unsigned(crate::BITLENGTH_BYTE))
-                .expect(crate::semantic::tests::PANIC_TEST_DATA)
-                .to_string(),
+        Location::new(5, 20),
+        ElementError::OperatorBitwiseShiftRightSecondOperandExpectedEvaluable {
+            found: Element::Type(Type::integer_unsigned(crate::BITLENGTH_BYTE)).to_string(),
         },
     )));
 
@@ -1603,7 +1603,7 @@ fn main() {
     let expected = Err(Error::Semantic(SemanticError::Element(
         Location::new(6, 23),
         ElementError::OperatorIndexSecondOperandExpectedEvaluable {
-            found: Element::Type(Type::field()).to_string(),
+            found: Element::Type(Type::field(Curve::default())).to_string(),
         },
     )));
 
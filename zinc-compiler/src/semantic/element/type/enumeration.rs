@@ -3,7 +3,6 @@
 //!
 
 use std::cell::RefCell;
-use std::convert::TryFrom;
 use std::fmt;
 use std::rc::Rc;
 
@@ -17,7 +16,7 @@ use crate::semantic::element::r#type::Type;
 use crate::semantic::error::Error;
 use crate::semantic::scope::Scope;
 use crate::syntax::tree::identifier::Identifier;
-use crate::syntax::tree::variant::Variant;
+use crate::syntax::tree::literal::integer::Literal as IntegerLiteral;
 
 ///
 /// Describes an enumeration type.
@@ -39,20 +38,21 @@ impl Enumeration {
     pub fn new(
         identifier: Identifier,
         unique_id: usize,
-        variants: Vec<Variant>,
+        variants: Vec<(Identifier, IntegerLiteral)>,
         scope_parent: Option<Rc<RefCell<Scope>>>,
     ) -> Result<Self, Error> {
         let scope = Rc::new(RefCell::new(Scope::new(scope_parent)));
 
+        let curve = scope.borrow().state().curve();
         let mut variants_bigint = Vec::with_capacity(variants.len());
-        for variant in variants.into_iter() {
-            let value = IntegerConstant::try_from(&variant.literal).map_err(|error| {
+        for (identifier, literal) in variants.into_iter() {
+            let value = IntegerConstant::try_from_literal(&literal, curve).map_err(|error| {
                 Error::Element(
-                    variant.identifier.location,
+                    identifier.location,
                     ElementError::Constant(ConstantError::Integer(error)),
                 )
             })?;
-            variants_bigint.push((variant.identifier, value.value.clone()));
+            variants_bigint.push((identifier, value.value.clone()));
         }
         let bigints: Vec<BigInt> = variants_bigint
             .iter()
@@ -85,7 +85,7 @@ impl Enumeration {
 
             constant.set_enumeration(enumeration.clone());
 
-            Scope::declare_constant(scope.clone(), identifier, Constant::Integer(constant))
+            Scope::declare_constant(scope.clone(), identifier, Constant::Integer(constant), true)
                 .map_err(|error| Error::Scope(location, error))?;
         }
 
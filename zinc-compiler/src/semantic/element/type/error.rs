@@ -9,6 +9,10 @@ use crate::semantic::element::r#type::structure::error::Error as StructureTypeEr
 pub enum Error {
     AliasDoesNotPointToType { found: String },
     AliasDoesNotPointToStructure { found: String },
+    AliasDoesNotPointToGenericType { found: String },
+
+    GenericConstantExpectedInteger { found: String },
+    GenericArgumentExpectedIntegerConstant { found: String },
 
     Function(FunctionTypeError),
     Structure(StructureTypeError),
@@ -40,6 +40,10 @@ pub enum Error {
     NonCallable {
         name: String,
     },
+    GenericNotInferred {
+        function: String,
+        name: String,
+    },
 
     BuiltIn(BuiltInFunctionTypeError),
     StandardLibrary(StandardLibraryFunctionTypeError),
@@ -109,4 +113,8 @@ impl Error {
     pub fn non_callable(name: String) -> Self {
         Self::NonCallable { name }
     }
+
+    pub fn generic_not_inferred(function: String, name: String) -> Self {
+        Self::GenericNotInferred { function, name }
+    }
 }
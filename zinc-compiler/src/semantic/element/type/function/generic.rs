@@ -0,0 +1,212 @@
+//!
+//! The semantic analyzer generic function element.
+//!
+
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::Rc;
+
+use crate::generator::statement::function::Statement as GeneratorFunctionStatement;
+use crate::lexical::token::location::Location;
+use crate::semantic::element::constant::error::Error as ConstantError;
+use crate::semantic::element::error::Error as ElementError;
+use crate::semantic::element::r#type::error::Error as TypeError;
+use crate::semantic::element::r#type::function::error::Error as FunctionTypeError;
+use crate::semantic::element::r#type::function::user::Function as UserFunction;
+use crate::semantic::element::r#type::generic::Argument as GenericArgument;
+use crate::semantic::element::r#type::generic::Inferrer as GenericInferrer;
+use crate::semantic::element::r#type::generic::Parameter as GenericParameter;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::error::Error;
+use crate::semantic::scope::Scope;
+use crate::syntax::tree::pattern_binding::variant::Variant as BindingPatternVariant;
+use crate::syntax::tree::r#type::variant::Variant as TypeVariant;
+use crate::syntax::tree::statement::r#fn::Statement as FnStatement;
+
+///
+/// The generic function, which is instantiated for each set of its generic arguments.
+///
+/// The function body is analyzed separately for each instance, with the type parameters
+/// declared as aliases to the types inferred from the call arguments, and the constant
+/// parameters declared as constants. The instances are shared between the function copies
+/// and written to the bytecode in place of the function.
+///
+#[derive(Debug, Clone)]
+pub struct Function {
+    statement: FnStatement,
+    parameters: Vec<GenericParameter>,
+    scope: Rc<RefCell<Scope>>,
+    instances: Rc<RefCell<Vec<(Vec<GenericArgument>, UserFunction)>>>,
+    intermediate: Rc<RefCell<Vec<GeneratorFunctionStatement>>>,
+}
+
+impl Function {
+    pub fn new(
+        statement: FnStatement,
+        parameters: Vec<GenericParameter>,
+        scope: Rc<RefCell<Scope>>,
+    ) -> Self {
+        Self {
+            statement,
+            parameters,
+            scope,
+            instances: Rc::new(RefCell::new(Vec::new())),
+            intermediate: Rc::new(RefCell::new(Vec::new())),
+        }
+    }
+
+    pub fn identifier(&self) -> &str {
+        self.statement.identifier.name.as_str()
+    }
+
+    pub fn statement(&self) -> &FnStatement {
+        &self.statement
+    }
+
+    pub fn parameters(&self) -> &[GenericParameter] {
+        self.parameters.as_slice()
+    }
+
+    ///
+    /// The scope the function is declared in, which the instances are analyzed in.
+    ///
+    pub fn scope(&self) -> Rc<RefCell<Scope>> {
+        self.scope.clone()
+    }
+
+    pub fn intermediate(&self) -> Rc<RefCell<Vec<GeneratorFunctionStatement>>> {
+        self.intermediate.clone()
+    }
+
+    ///
+    /// The instance identifier, e.g. `max<u8>`, which is used as the bytecode function name.
+    ///
+    pub fn instance_identifier(&self, arguments: &[GenericArgument]) -> String {
+        format!(
+            "{}{}",
+            self.identifier(),
+            GenericArgument::list_to_string(arguments)
+        )
+    }
+
+    pub fn get_instance(&self, arguments: &[GenericArgument]) -> Option<UserFunction> {
+        self.instances
+            .borrow()
+            .iter()
+            .find(|(instance_arguments, _function)| instance_arguments.as_slice() == arguments)
+            .map(|(_arguments, function)| function.to_owned())
+    }
+
+    pub fn insert_instance(&self, arguments: Vec<GenericArgument>, function: UserFunction) {
+        self.instances.borrow_mut().push((arguments, function));
+    }
+
+    pub fn push_intermediate(&self, statement: GeneratorFunctionStatement) {
+        self.intermediate.borrow_mut().push(statement);
+    }
+
+    ///
+    /// Infers the generic arguments from the actual argument types, and checks whether
+    /// the type arguments implement the parameter trait bounds.
+    ///
+    /// Each actual type is paired with the flag telling whether the argument is an integer
+    /// constant, which is casted to the instance argument type if it is wider.
+    ///
+    pub fn infer(
+        &self,
+        actual_types: &[(Type, bool)],
+        location: Location,
+    ) -> Result<Vec<GenericArgument>, Error> {
+        let formal_types: Vec<&TypeVariant> = self
+            .statement
+            .argument_bindings
+            .iter()
+            .filter_map(|binding| match binding.variant {
+                BindingPatternVariant::Wildcard => None,
+                _ => Some(&binding.r#type.variant),
+            })
+            .collect();
+        if actual_types.len() != formal_types.len() {
+            return Err(Self::error(
+                location,
+                FunctionTypeError::argument_count(
+                    self.identifier().to_owned(),
+                    formal_types.len(),
+                    actual_types.len(),
+                ),
+            ));
+        }
+
+        let mut inferrer = GenericInferrer::new(self.parameters.as_slice());
+        for (formal_type, (actual_type, is_integer_constant)) in
+            formal_types.into_iter().zip(actual_types.iter())
+        {
+            let result = if *is_integer_constant {
+                inferrer.infer_literal(formal_type, actual_type)
+            } else {
+                inferrer.infer(formal_type, actual_type)
+            };
+            result.map_err(|error| {
+                Error::Element(
+                    location,
+                    ElementError::Constant(ConstantError::Integer(error)),
+                )
+            })?;
+        }
+
+        let arguments = inferrer
+            .finish()
+            .into_iter()
+            .zip(self.parameters.iter())
+            .map(|(argument, parameter)| {
+                argument.ok_or_else(|| {
+                    Self::error(
+                        location,
+                        FunctionTypeError::generic_not_inferred(
+                            self.identifier().to_owned(),
+                            parameter.identifier.name.to_owned(),
+                        ),
+                    )
+                })
+            })
+            .collect::<Result<Vec<GenericArgument>, Error>>()?;
+
+        for (argument, parameter) in arguments.iter().zip(self.parameters.iter()) {
+            if let GenericArgument::Type(ref r#type) = argument {
+                if let Some(r#trait) = parameter
+                    .bounds
+                    .iter()
+                    .find(|r#trait| !r#trait.is_implemented_by(r#type))
+                {
+                    return Err(Error::FunctionGenericBoundNotSatisfied {
+                        location,
+                        function: self.identifier().to_owned(),
+                        r#type: r#type.to_string(),
+                        r#trait: r#trait.identifier.to_owned(),
+                    });
+                }
+            }
+        }
+
+        Ok(arguments)
+    }
+
+    fn error(location: Location, error: FunctionTypeError) -> Error {
+        Error::Element(location, ElementError::Type(TypeError::Function(error)))
+    }
+}
+
+impl fmt::Display for Function {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "fn {}<{}>",
+            self.identifier(),
+            self.parameters
+                .iter()
+                .map(|parameter| parameter.identifier.name.to_owned())
+                .collect::<Vec<String>>()
+                .join(", ")
+        )
+    }
+}
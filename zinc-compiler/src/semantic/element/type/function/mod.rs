@@ -6,18 +6,24 @@ mod tests;
 
 pub mod builtin;
 pub mod error;
+pub mod generic;
 pub mod stdlib;
 pub mod user;
+pub mod variant;
 
 use std::fmt;
 
 use zinc_bytecode::builtins::BuiltinIdentifier;
+use zinc_bytecode::Curve;
 
+use crate::semantic::element::r#type::tagged_union::TaggedUnion;
 use crate::semantic::element::r#type::Type;
 
 use self::builtin::Function as BuiltInFunction;
+use self::generic::Function as GenericFunction;
 use self::stdlib::Function as StandardLibraryFunction;
 use self::user::Function as UserFunction;
+use self::variant::Function as VariantFunction;
 
 ///
 /// Describes a function, which is a special type.
@@ -33,6 +39,11 @@ pub enum Function {
     /// Ordinar functions declared anywhere within a circuit. There is a special `main` function,
     /// which is also declared by user, but serves as the circuit entry point.
     UserDefined(UserFunction),
+    /// Functions with generic parameters, which are instantiated for each set of types
+    /// they are called with.
+    Generic(GenericFunction),
+    /// The tagged union variants, which construct the tagged union values.
+    Variant(VariantFunction),
 }
 
 impl Function {
@@ -44,8 +55,8 @@ impl Function {
         Self::BuiltInFunction(BuiltInFunction::new_assert())
     }
 
-    pub fn new_std(identifier: BuiltinIdentifier) -> Self {
-        Self::StandardLibrary(StandardLibraryFunction::new(identifier))
+    pub fn new_std(identifier: BuiltinIdentifier, curve: Curve) -> Self {
+        Self::StandardLibrary(StandardLibraryFunction::new(identifier, curve))
     }
 
     pub fn new_user_defined(
@@ -62,11 +73,17 @@ impl Function {
         ))
     }
 
+    pub fn new_variant(tagged_union: TaggedUnion, tag: usize) -> Self {
+        Self::Variant(VariantFunction::new(tagged_union, tag))
+    }
+
     pub fn identifier(&self) -> String {
         match self {
             Function::BuiltInFunction(inner) => inner.identifier().to_owned(),
             Function::StandardLibrary(inner) => inner.identifier().to_owned(),
             Function::UserDefined(inner) => inner.identifier().to_owned(),
+            Function::Generic(inner) => inner.identifier().to_owned(),
+            Function::Variant(inner) => inner.identifier(),
         }
     }
 }
@@ -77,6 +94,8 @@ impl fmt::Display for Function {
             Self::BuiltInFunction(inner) => write!(f, "{}", inner),
             Self::StandardLibrary(inner) => write!(f, "{}", inner),
             Self::UserDefined(inner) => write!(f, "{}", inner),
+            Self::Generic(inner) => write!(f, "{}", inner),
+            Self::Variant(inner) => write!(f, "{}", inner),
         }
     }
 }
@@ -6,6 +6,7 @@ use std::fmt;
 use std::ops::Deref;
 
 use zinc_bytecode::builtins::BuiltinIdentifier;
+use zinc_bytecode::Curve;
 
 use crate::semantic::element::r#type::function::error::Error;
 use crate::semantic::element::r#type::Type;
@@ -15,16 +16,21 @@ use crate::semantic::element::Element;
 pub struct Function {
     builtin_identifier: BuiltinIdentifier,
     identifier: &'static str,
+    bitlength_field: usize,
 }
 
 impl Function {
     pub const ARGUMENT_INDEX_BITS: usize = 0;
     pub const ARGUMENT_COUNT: usize = 1;
 
-    pub fn new(builtin_identifier: BuiltinIdentifier) -> Self {
+    ///
+    /// The bits array length must be equal to the `curve` scalar field bitlength.
+    ///
+    pub fn new(builtin_identifier: BuiltinIdentifier, curve: Curve) -> Self {
         Self {
             builtin_identifier,
             identifier: "from_bits_field",
+            bitlength_field: curve.bitlength_field(),
         }
     }
 
@@ -55,13 +61,15 @@ impl Function {
 
         let return_type = match actual_params.get(Self::ARGUMENT_INDEX_BITS) {
             Some(Type::Array { r#type, size }) => match (r#type.deref(), *size) {
-                (Type::Boolean, crate::BITLENGTH_FIELD) => Type::field(),
+                (Type::Boolean, size) if size == self.bitlength_field => Type::Field {
+                    bitlength: self.bitlength_field,
+                },
                 (r#type, size) => {
                     return Err(Error::argument_type(
                         self.identifier.to_owned(),
                         "bits".to_owned(),
                         Self::ARGUMENT_INDEX_BITS + 1,
-                        format!("[bool; {}]", crate::BITLENGTH_FIELD),
+                        format!("[bool; {}]", self.bitlength_field),
                         format!("[{}; {}]", r#type, size),
                     ))
                 }
@@ -71,7 +79,7 @@ impl Function {
                     self.identifier.to_owned(),
                     "bits".to_owned(),
                     Self::ARGUMENT_INDEX_BITS + 1,
-                    format!("[bool; {}]", crate::BITLENGTH_FIELD),
+                    format!("[bool; {}]", self.bitlength_field),
                     r#type.to_string(),
                 ))
             }
@@ -101,8 +109,7 @@ impl fmt::Display for Function {
         write!(
             f,
             "fn std::convert::{}(bits: [bool; {}]) -> field",
-            self.identifier,
-            crate::BITLENGTH_FIELD,
+            self.identifier, self.bitlength_field,
         )
     }
 }
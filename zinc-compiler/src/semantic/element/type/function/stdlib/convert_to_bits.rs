@@ -5,6 +5,7 @@
 use std::fmt;
 
 use zinc_bytecode::builtins::BuiltinIdentifier;
+use zinc_bytecode::Curve;
 
 use crate::semantic::element::r#type::function::error::Error;
 use crate::semantic::element::r#type::Type;
@@ -14,16 +15,21 @@ use crate::semantic::element::Element;
 pub struct Function {
     builtin_identifier: BuiltinIdentifier,
     identifier: &'static str,
+    bitlength_field: usize,
 }
 
 impl Function {
     pub const ARGUMENT_INDEX_VALUE: usize = 0;
     pub const ARGUMENT_COUNT: usize = 1;
 
-    pub fn new(builtin_identifier: BuiltinIdentifier) -> Self {
+    ///
+    /// A `field` value is converted into as many bits as the `curve` scalar field bitlength.
+    ///
+    pub fn new(builtin_identifier: BuiltinIdentifier, curve: Curve) -> Self {
         Self {
             builtin_identifier,
             identifier: "to_bits",
+            bitlength_field: curve.bitlength_field(),
         }
     }
 
@@ -56,7 +62,7 @@ impl Function {
             Some(Type::Boolean) => Type::array(Type::boolean(), crate::BITLENGTH_BOOLEAN),
             Some(Type::IntegerUnsigned { bitlength }) => Type::array(Type::boolean(), *bitlength),
             Some(Type::IntegerSigned { bitlength }) => Type::array(Type::boolean(), *bitlength),
-            Some(Type::Field) => Type::array(Type::boolean(), crate::BITLENGTH_FIELD),
+            Some(Type::Field { .. }) => Type::array(Type::boolean(), self.bitlength_field),
             Some(r#type) => {
                 return Err(Error::argument_type(
                     self.identifier.to_owned(),
@@ -6,6 +6,7 @@ use std::fmt;
 use std::ops::Deref;
 
 use zinc_bytecode::builtins::BuiltinIdentifier;
+use zinc_bytecode::Curve;
 
 use crate::semantic::element::r#type::function::error::Error;
 use crate::semantic::element::r#type::Type;
@@ -22,11 +23,11 @@ impl Function {
     pub const ARGUMENT_INDEX_PREIMAGE: usize = 0;
     pub const ARGUMENT_COUNT: usize = 1;
 
-    pub fn new(builtin_identifier: BuiltinIdentifier) -> Self {
+    pub fn new(builtin_identifier: BuiltinIdentifier, curve: Curve) -> Self {
         Self {
             builtin_identifier,
             identifier: "pedersen",
-            return_type: Box::new(Type::tuple(vec![Type::field(), Type::field()])),
+            return_type: Box::new(Type::tuple(vec![Type::field(curve), Type::field(curve)])),
         }
     }
 
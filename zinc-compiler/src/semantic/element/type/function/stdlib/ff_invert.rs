@@ -5,6 +5,7 @@
 use std::fmt;
 
 use zinc_bytecode::builtins::BuiltinIdentifier;
+use zinc_bytecode::Curve;
 
 use crate::semantic::element::r#type::function::error::Error;
 use crate::semantic::element::r#type::Type;
@@ -21,11 +22,11 @@ impl Function {
     pub const ARGUMENT_INDEX_VALUE: usize = 0;
     pub const ARGUMENT_COUNT: usize = 1;
 
-    pub fn new(builtin_identifier: BuiltinIdentifier) -> Self {
+    pub fn new(builtin_identifier: BuiltinIdentifier, curve: Curve) -> Self {
         Self {
             builtin_identifier,
             identifier: "invert",
-            return_type: Box::new(Type::field()),
+            return_type: Box::new(Type::field(curve)),
         }
     }
 
@@ -55,13 +56,13 @@ impl Function {
         }
 
         match actual_params.get(Self::ARGUMENT_INDEX_VALUE) {
-            Some(Type::Field) => {}
+            Some(Type::Field { .. }) => {}
             Some(r#type) => {
                 return Err(Error::argument_type(
                     self.identifier.to_owned(),
                     "value".to_owned(),
                     Self::ARGUMENT_INDEX_VALUE + 1,
-                    Type::field().to_string(),
+                    self.return_type.to_string(),
                     r#type.to_string(),
                 ))
             }
@@ -20,6 +20,7 @@ pub mod ff_invert;
 use std::fmt;
 
 use zinc_bytecode::builtins::BuiltinIdentifier;
+use zinc_bytecode::Curve;
 
 use crate::semantic::element::r#type::function::error::Error;
 use crate::semantic::element::r#type::Type;
@@ -56,17 +57,19 @@ pub enum Function {
 }
 
 impl Function {
-    pub fn new(identifier: BuiltinIdentifier) -> Self {
+    pub fn new(identifier: BuiltinIdentifier, curve: Curve) -> Self {
         match identifier {
             BuiltinIdentifier::CryptoSha256 => Self::CryptoSha256(Sha256Function::new(identifier)),
             BuiltinIdentifier::CryptoPedersen => {
-                Self::CryptoPedersen(PedersenFunction::new(identifier))
+                Self::CryptoPedersen(PedersenFunction::new(identifier, curve))
             }
             BuiltinIdentifier::CryptoSchnorrSignatureVerify => {
                 Self::CryptoSchnorrSignatureVerify(SchnorrSignatureVerifyFunction::new(identifier))
             }
 
-            BuiltinIdentifier::ToBits => Self::ConvertToBits(ToBitsFunction::new(identifier)),
+            BuiltinIdentifier::ToBits => {
+                Self::ConvertToBits(ToBitsFunction::new(identifier, curve))
+            }
             BuiltinIdentifier::UnsignedFromBits => {
                 Self::ConvertFromBitsUnsigned(FromBitsUnsignedFunction::new(identifier))
             }
@@ -74,7 +77,7 @@ impl Function {
                 Self::ConvertFromBitsSigned(FromBitsSignedFunction::new(identifier))
             }
             BuiltinIdentifier::FieldFromBits => {
-                Self::ConvertFromBitsField(FromBitsFieldFunction::new(identifier))
+                Self::ConvertFromBitsField(FromBitsFieldFunction::new(identifier, curve))
             }
 
             BuiltinIdentifier::ArrayReverse => {
@@ -85,7 +88,7 @@ impl Function {
             }
             BuiltinIdentifier::ArrayPad => Self::ArrayPad(ArrayPadFunction::new(identifier)),
 
-            BuiltinIdentifier::FieldInverse => Self::FfInvert(FfInvertFunction::new(identifier)),
+            BuiltinIdentifier::FieldInverse => Self::FfInvert(FfInvertFunction::new(identifier, curve)),
         }
     }
 
@@ -8,6 +8,8 @@ use std::str::FromStr;
 
 use num_bigint::BigInt;
 
+use zinc_bytecode::Curve;
+
 use crate::error::Error;
 use crate::lexical::token::location::Location;
 use crate::semantic::element::constant::integer::Integer as IntegerConstant;
@@ -902,7 +904,7 @@ fn main() {
             "from_bits_field".to_owned(),
             "bits".to_owned(),
             ConvertFromBitsFieldFunction::ARGUMENT_INDEX_BITS + 1,
-            format!("[bool; {}]", crate::BITLENGTH_FIELD),
+            format!("[bool; {}]", Curve::default().bitlength_field()),
             Type::integer_unsigned(crate::BITLENGTH_BYTE).to_string(),
         ))),
     )));
@@ -926,7 +928,7 @@ fn main() {
             "from_bits_field".to_owned(),
             "bits".to_owned(),
             ConvertFromBitsFieldFunction::ARGUMENT_INDEX_BITS + 1,
-            format!("[bool; {}]", crate::BITLENGTH_FIELD),
+            format!("[bool; {}]", Curve::default().bitlength_field()),
             Type::array(Type::boolean(), 0).to_string(),
         ))),
     )));
@@ -950,7 +952,7 @@ fn main() {
             "from_bits_field".to_owned(),
             "bits".to_owned(),
             ConvertFromBitsFieldFunction::ARGUMENT_INDEX_BITS + 1,
-            format!("[bool; {}]", crate::BITLENGTH_FIELD),
+            format!("[bool; {}]", Curve::default().bitlength_field()),
             Type::array(Type::boolean(), crate::BITLENGTH_MAX_INT).to_string(),
         ))),
     )));
@@ -1482,7 +1484,7 @@ fn main() {
             "invert".to_owned(),
             "value".to_owned(),
             FfInvertFunction::ARGUMENT_INDEX_VALUE + 1,
-            Type::field().to_string(),
+            Type::field(Curve::default()).to_string(),
             Type::boolean().to_string(),
         ))),
     )));
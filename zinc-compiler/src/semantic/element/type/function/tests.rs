@@ -66,6 +66,101 @@ fn main() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn error_argument_type_generic() {
+    let input = r#"
+fn max<T>(a: T, b: T) -> T {
+    if a > b { a } else { b }
+}
+
+fn main() {
+    let value = max(42, false);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(7, 20),
+        ElementError::Type(TypeError::Function(FunctionTypeError::argument_type(
+            "max<u8>".to_owned(),
+            "b".to_owned(),
+            2,
+            Type::integer_unsigned(crate::BITLENGTH_BYTE).to_string(),
+            Type::boolean().to_string(),
+        ))),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_generic_literal_widened() {
+    let input = r#"
+fn max<T>(a: T, b: T) -> T {
+    if a > b { a } else { b }
+}
+
+fn main(value: u16) -> u16 {
+    max(42, value) + max(value, 300)
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_argument_type_generic_literal_wider() {
+    let input = r#"
+fn max<T>(a: T, b: T) -> T {
+    if a > b { a } else { b }
+}
+
+fn main(value: u8) {
+    let result = max(300, value);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(7, 21),
+        ElementError::Type(TypeError::Function(FunctionTypeError::argument_type(
+            "max<u16>".to_owned(),
+            "b".to_owned(),
+            2,
+            Type::integer_unsigned(crate::BITLENGTH_BYTE * 2).to_string(),
+            Type::integer_unsigned(crate::BITLENGTH_BYTE).to_string(),
+        ))),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_generic_not_inferred() {
+    let input = r#"
+fn convert<T, U>(value: T) -> U {
+    value
+}
+
+fn main() {
+    let value = convert(42);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(7, 24),
+        ElementError::Type(TypeError::Function(
+            FunctionTypeError::generic_not_inferred("convert".to_owned(), "U".to_owned()),
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn error_argument_constantness() {
     let input = r#"
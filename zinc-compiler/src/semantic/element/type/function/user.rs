@@ -2,21 +2,33 @@
 //! The semantic analyzer user-defined function element.
 //!
 
+use std::cell::RefCell;
 use std::fmt;
 use std::ops::Deref;
+use std::rc::Rc;
 
+use crate::lexical::token::lexeme::keyword::Keyword;
 use crate::semantic::element::r#type::function::error::Error;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::element::Element;
+use crate::semantic::scope::Scope;
+use crate::syntax::tree::statement::r#fn::Statement as FnStatement;
 
 pub static FUNCTION_MAIN_IDENTIFIER: &str = "main";
 
+///
+/// The user-defined function.
+///
+/// The `const fn` items also keep their statement and the scope they are declared in,
+/// so their calls with constant arguments can be evaluated at compile time.
+///
 #[derive(Debug, Clone)]
 pub struct Function {
     identifier: String,
     unique_id: usize,
     formal_params: Vec<(String, Type)>,
     return_type: Box<Type>,
+    constant: Option<(Rc<FnStatement>, Rc<RefCell<Scope>>)>,
 }
 
 impl Function {
@@ -31,9 +43,25 @@ impl Function {
             formal_params: arguments,
             return_type: Box::new(return_type),
             unique_id,
+            constant: None,
         }
     }
 
+    ///
+    /// Makes the function evaluable at compile time with the `statement` body analyzed in `scope`.
+    ///
+    pub fn set_constant(&mut self, statement: FnStatement, scope: Rc<RefCell<Scope>>) {
+        self.constant = Some((Rc::new(statement), scope));
+    }
+
+    pub fn is_constant(&self) -> bool {
+        self.constant.is_some()
+    }
+
+    pub fn constant(&self) -> Option<(Rc<FnStatement>, Rc<RefCell<Scope>>)> {
+        self.constant.clone()
+    }
+
     pub fn identifier(&self) -> &str {
         self.identifier.as_str()
     }
@@ -46,6 +74,16 @@ impl Function {
         self.formal_params.as_slice()
     }
 
+    ///
+    /// Checks whether the function is a method, that is, its first argument is `self`.
+    ///
+    pub fn is_method(&self) -> bool {
+        self.formal_params
+            .first()
+            .map(|(name, _type)| name == &Keyword::SelfLowercase.to_string())
+            .unwrap_or(false)
+    }
+
     pub fn return_type(&self) -> &Type {
         self.return_type.deref()
     }
@@ -109,7 +147,8 @@ impl fmt::Display for Function {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
             f,
-            "fn {}({}) -> {}",
+            "{}fn {}({}) -> {}",
+            if self.is_constant() { "const " } else { "" },
             self.identifier,
             self.formal_params
                 .iter()
@@ -0,0 +1,112 @@
+//!
+//! The semantic analyzer enumeration variant constructor element.
+//!
+
+use std::fmt;
+
+use crate::semantic::element::r#type::function::error::Error;
+use crate::semantic::element::r#type::tagged_union::TaggedUnion;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::element::Element;
+
+///
+/// The tagged union variant, which constructs the tagged union value from its payload.
+///
+/// Only the variants with unnamed fields, e.g. `Burn(u64)`, are called like ordinary
+/// functions. The unit and structure variants are written as paths and structure literals.
+///
+#[derive(Debug, Clone)]
+pub struct Function {
+    tagged_union: TaggedUnion,
+    tag: usize,
+}
+
+impl Function {
+    pub fn new(tagged_union: TaggedUnion, tag: usize) -> Self {
+        Self { tagged_union, tag }
+    }
+
+    pub fn identifier(&self) -> String {
+        format!("{}::{}", self.tagged_union.identifier, self.name())
+    }
+
+    pub fn name(&self) -> &str {
+        self.tagged_union.variants[self.tag].0.as_str()
+    }
+
+    pub fn tag(&self) -> usize {
+        self.tag
+    }
+
+    pub fn payload(&self) -> &Type {
+        &self.tagged_union.variants[self.tag].1
+    }
+
+    pub fn tagged_union(&self) -> &TaggedUnion {
+        &self.tagged_union
+    }
+
+    pub fn call(self, actual_elements: Vec<Element>) -> Result<Type, Error> {
+        let formal_types = match self.payload() {
+            Type::Tuple { types } => types.to_owned(),
+            _ => return Err(Error::non_callable(self.identifier())),
+        };
+
+        if actual_elements.len() != formal_types.len() {
+            return Err(Error::argument_count(
+                self.identifier(),
+                formal_types.len(),
+                actual_elements.len(),
+            ));
+        }
+
+        for (index, (element, formal_type)) in actual_elements
+            .into_iter()
+            .zip(formal_types.into_iter())
+            .enumerate()
+        {
+            let actual_type = match element {
+                Element::Value(value) => value.r#type(),
+                Element::Constant(constant) => constant.r#type(),
+                element => {
+                    return Err(Error::argument_not_evaluable(
+                        self.identifier(),
+                        index + 1,
+                        element.to_string(),
+                    ))
+                }
+            };
+
+            if actual_type != formal_type {
+                return Err(Error::argument_type(
+                    self.identifier(),
+                    index.to_string(),
+                    index + 1,
+                    formal_type.to_string(),
+                    actual_type.to_string(),
+                ));
+            }
+        }
+
+        Ok(Type::TaggedUnion(self.tagged_union))
+    }
+}
+
+impl fmt::Display for Function {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.payload() {
+            Type::Tuple { types } => write!(
+                f,
+                "fn {}({}) -> {}",
+                self.identifier(),
+                types
+                    .iter()
+                    .map(|r#type| r#type.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", "),
+                self.tagged_union,
+            ),
+            _ => write!(f, "{}", self.identifier()),
+        }
+    }
+}
@@ -0,0 +1,330 @@
+//!
+//! The semantic analyzer generic parameter element.
+//!
+
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::Rc;
+
+use num_bigint::BigInt;
+
+use crate::semantic::element::constant::integer::error::Error as IntegerConstantError;
+use crate::semantic::element::constant::integer::Integer as IntegerConstant;
+use crate::semantic::element::constant::Constant;
+use crate::semantic::element::error::Error as ElementError;
+use crate::semantic::element::r#type::error::Error as TypeError;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::error::Error;
+use crate::semantic::scope::error::Error as ScopeError;
+use crate::semantic::scope::item::variant::r#trait::Trait;
+use crate::semantic::scope::item::variant::Variant as ScopeItemVariant;
+use crate::semantic::scope::Scope;
+use crate::syntax::tree::expression::tree::node::operand::Operand as ExpressionOperand;
+use crate::syntax::tree::expression::tree::node::Node as ExpressionTreeNode;
+use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
+use crate::syntax::tree::generic::Generic;
+use crate::syntax::tree::identifier::Identifier;
+use crate::syntax::tree::r#type::variant::Variant as TypeVariant;
+
+///
+/// The generic parameter with its constant type and trait bounds resolved.
+///
+/// Type parameters have no type, and constant parameters are always integers.
+///
+#[derive(Debug, Clone)]
+pub struct Parameter {
+    pub identifier: Identifier,
+    pub r#type: Option<Type>,
+    pub bounds: Vec<Trait>,
+}
+
+impl Parameter {
+    ///
+    /// Resolves the constant parameter type in `scope`, which must be an integer type,
+    /// and the type parameter bounds, which must be traits.
+    ///
+    pub fn new(generic: &Generic, scope: Rc<RefCell<Scope>>) -> Result<Self, Error> {
+        let mut bounds = Vec::with_capacity(generic.bounds.len());
+        for bound in generic.bounds.iter() {
+            match Scope::resolve_item(scope.clone(), bound.name.as_str())
+                .map_err(|error| Error::Scope(bound.location, error))?
+                .variant
+            {
+                ScopeItemVariant::Trait(r#trait) => bounds.push(r#trait),
+                item => {
+                    return Err(Error::FunctionGenericBoundExpectedTrait {
+                        location: bound.location,
+                        found: item.to_string(),
+                    })
+                }
+            }
+        }
+
+        let r#type = match generic.r#type {
+            Some(ref r#type) => match Type::from_type_variant(&r#type.variant, scope)? {
+                r#type @ Type::IntegerUnsigned { .. } | r#type @ Type::IntegerSigned { .. } => {
+                    Some(r#type)
+                }
+                r#type => {
+                    return Err(Error::Element(
+                        generic.location,
+                        ElementError::Type(TypeError::GenericConstantExpectedInteger {
+                            found: r#type.to_string(),
+                        }),
+                    ));
+                }
+            },
+            None => None,
+        };
+
+        Ok(Self {
+            identifier: generic.identifier.to_owned(),
+            r#type,
+            bounds,
+        })
+    }
+
+    pub fn is_constant(&self) -> bool {
+        self.r#type.is_some()
+    }
+
+    ///
+    /// Casts the constant `value` to the parameter type.
+    ///
+    pub fn constant(&self, value: BigInt) -> Result<Argument, IntegerConstantError> {
+        let (is_signed, bitlength) = match self.r#type {
+            Some(Type::IntegerUnsigned { bitlength }) => (false, bitlength),
+            Some(Type::IntegerSigned { bitlength }) => (true, bitlength),
+            _ => panic!(crate::PANIC_VALIDATED_DURING_SEMANTIC_ANALYSIS),
+        };
+
+        let minimal_bitlength = IntegerConstant::minimal_bitlength(&value, is_signed)?;
+        IntegerConstant::new(value, is_signed, minimal_bitlength)
+            .cast(is_signed, bitlength)
+            .map(Argument::Constant)
+    }
+}
+
+///
+/// The generic argument, which is a type for a type parameter, or an integer constant
+/// for a constant parameter.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub enum Argument {
+    Type(Type),
+    Constant(IntegerConstant),
+}
+
+impl Argument {
+    ///
+    /// Declares the argument in `scope` with the name of the generic `parameter`.
+    ///
+    pub fn declare(
+        self,
+        scope: Rc<RefCell<Scope>>,
+        parameter: &Parameter,
+    ) -> Result<(), ScopeError> {
+        let identifier = parameter.identifier.to_owned();
+        match self {
+            Self::Type(r#type) => Scope::declare_type(scope, identifier, r#type, false),
+            Self::Constant(integer) => {
+                Scope::declare_constant(scope, identifier, Constant::Integer(integer), false)
+            }
+        }
+    }
+
+    ///
+    /// Formats the argument list as a suffix of an instance identifier, e.g. `<u8, 10>`.
+    ///
+    pub fn list_to_string(arguments: &[Self]) -> String {
+        format!(
+            "<{}>",
+            arguments
+                .iter()
+                .map(|argument| argument.to_string())
+                .collect::<Vec<String>>()
+                .join(", ")
+        )
+    }
+}
+
+impl fmt::Display for Argument {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Type(r#type) => write!(f, "{}", r#type),
+            Self::Constant(integer) => write!(f, "{}", integer.value),
+        }
+    }
+}
+
+///
+/// Infers the generic arguments by matching the formal types written in terms of
+/// the generic parameters against the actual types.
+///
+/// A parameter takes the value it is first found with. The actual types are checked
+/// against the instance types afterwards, so the conflicting values are reported there.
+///
+/// The types of integer constants are weaker than the other ones, since the constants are
+/// casted to the instance types, so a wider type of another argument replaces them.
+///
+pub struct Inferrer<'a> {
+    parameters: &'a [Parameter],
+    arguments: Vec<Option<Argument>>,
+    is_weak: Vec<bool>,
+}
+
+impl<'a> Inferrer<'a> {
+    pub fn new(parameters: &'a [Parameter]) -> Self {
+        Self {
+            parameters,
+            arguments: vec![None; parameters.len()],
+            is_weak: vec![false; parameters.len()],
+        }
+    }
+
+    pub fn infer(
+        &mut self,
+        formal: &TypeVariant,
+        actual: &Type,
+    ) -> Result<(), IntegerConstantError> {
+        match (formal, actual) {
+            (TypeVariant::Alias { path, arguments }, actual) if arguments.is_empty() => {
+                if let Some(index) = self.position(path, false) {
+                    let is_bound = match self.arguments[index] {
+                        None => false,
+                        Some(Argument::Type(ref bound)) if self.is_weak[index] => {
+                            actual != bound && !Self::is_wider(actual, bound)
+                        }
+                        Some(_) => true,
+                    };
+                    if !is_bound {
+                        self.arguments[index] = Some(Argument::Type(actual.to_owned()));
+                        self.is_weak[index] = false;
+                    }
+                }
+            }
+            (TypeVariant::Alias { arguments, .. }, Type::Structure(structure))
+                if arguments.len() == structure.generics.len() =>
+            {
+                for (expression, argument) in arguments.iter().zip(structure.generics.iter()) {
+                    if let (Some(index), Argument::Constant(integer)) =
+                        (self.position(expression, true), argument)
+                    {
+                        self.infer_constant(index, integer.value.to_owned())?;
+                    }
+                }
+            }
+            (
+                TypeVariant::Array { inner, size },
+                Type::Array {
+                    r#type,
+                    size: actual_size,
+                },
+            ) => {
+                self.infer(inner, r#type)?;
+                if let Some(index) = self.position(size, true) {
+                    self.infer_constant(index, BigInt::from(*actual_size))?;
+                }
+            }
+            (TypeVariant::Tuple { inners }, Type::Tuple { types }) => {
+                for (inner, r#type) in inners.iter().zip(types.iter()) {
+                    self.infer(inner, r#type)?;
+                }
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Infers the parameters from the `actual` type of an integer constant.
+    ///
+    /// If the parameter is bound by integer constants only, it takes the widest of their types.
+    ///
+    pub fn infer_literal(
+        &mut self,
+        formal: &TypeVariant,
+        actual: &Type,
+    ) -> Result<(), IntegerConstantError> {
+        let index = match formal {
+            TypeVariant::Alias { path, arguments } if arguments.is_empty() => {
+                self.position(path, false)
+            }
+            _ => None,
+        };
+
+        match index {
+            Some(index) => {
+                let is_wider = match self.arguments[index] {
+                    None => true,
+                    Some(Argument::Type(ref bound)) if self.is_weak[index] => {
+                        Self::is_wider(actual, bound)
+                    }
+                    Some(_) => false,
+                };
+                if is_wider {
+                    self.arguments[index] = Some(Argument::Type(actual.to_owned()));
+                    self.is_weak[index] = true;
+                }
+                Ok(())
+            }
+            None => self.infer(formal, actual),
+        }
+    }
+
+    pub fn finish(self) -> Vec<Option<Argument>> {
+        self.arguments
+    }
+
+    fn infer_constant(&mut self, index: usize, value: BigInt) -> Result<(), IntegerConstantError> {
+        if self.arguments[index].is_none() {
+            self.arguments[index] = Some(self.parameters[index].constant(value)?);
+        }
+        Ok(())
+    }
+
+    ///
+    /// Checks whether the `actual` type is wider than the `bound` one.
+    ///
+    fn is_wider(actual: &Type, bound: &Type) -> bool {
+        match (actual, bound) {
+            (
+                Type::IntegerUnsigned { bitlength },
+                Type::IntegerUnsigned {
+                    bitlength: bound_bitlength,
+                },
+            ) => bitlength > bound_bitlength,
+            (
+                Type::IntegerSigned { bitlength },
+                Type::IntegerSigned {
+                    bitlength: bound_bitlength,
+                },
+            ) => bitlength > bound_bitlength,
+            (Type::Field { .. }, Type::IntegerUnsigned { .. }) => true,
+            _ => false,
+        }
+    }
+
+    ///
+    /// Returns the index of the parameter, if the `expression` is its bare identifier.
+    ///
+    fn position(&self, expression: &ExpressionTree, is_constant: bool) -> Option<usize> {
+        let name = match (
+            expression.value.as_ref(),
+            &expression.left,
+            &expression.right,
+        ) {
+            (
+                ExpressionTreeNode::Operand(ExpressionOperand::Identifier(identifier)),
+                None,
+                None,
+            ) => identifier.name.as_str(),
+            _ => return None,
+        };
+
+        self.parameters.iter().position(|parameter| {
+            parameter.identifier.name == name && parameter.is_constant() == is_constant
+        })
+    }
+}
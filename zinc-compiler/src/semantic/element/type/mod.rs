@@ -7,27 +7,26 @@ mod tests;
 pub mod enumeration;
 pub mod error;
 pub mod function;
+pub mod generic;
 pub mod structure;
+pub mod tagged_union;
 
 use std::cell::RefCell;
-use std::collections::HashMap;
 use std::fmt;
 use std::rc::Rc;
-use std::sync::RwLock;
-
-use lazy_static::lazy_static;
 
 use zinc_bytecode::builtins::BuiltinIdentifier;
+use zinc_bytecode::Curve;
 
 use crate::semantic::analyzer::expression::hint::Hint as TranslationHint;
 use crate::semantic::analyzer::expression::Analyzer as ExpressionAnalyzer;
+use crate::semantic::analyzer::statement::Analyzer as StatementAnalyzer;
 use crate::semantic::element::constant::error::Error as ConstantError;
 use crate::semantic::element::constant::Constant;
 use crate::semantic::element::error::Error as ElementError;
 use crate::semantic::element::r#type::error::Error as TypeError;
 use crate::semantic::element::Element;
 use crate::semantic::error::Error;
-use crate::semantic::scope::builtin::BuiltInItems;
 use crate::semantic::scope::item::variant::Variant as ScopeItemVariant;
 use crate::semantic::scope::Scope;
 use crate::syntax::tree::identifier::Identifier;
@@ -36,22 +35,9 @@ use crate::syntax::tree::variant::Variant;
 
 use self::enumeration::Enumeration;
 use self::function::Function;
+use self::structure::generic::Generic as GenericStructure;
 use self::structure::Structure;
-
-lazy_static! {
-    pub static ref INDEX: RwLock<HashMap<usize, String>> = {
-        let mut index = HashMap::with_capacity(BuiltInItems::TYPE_ID_FIRST_AVAILABLE);
-        index.insert(
-            BuiltInItems::TYPE_ID_STD_CRYPTO_ECC_POINT,
-            "struct std::crypto::ecc::Point".to_owned(),
-        );
-        index.insert(
-            BuiltInItems::TYPE_ID_STD_CRYPTO_SCHNORR_SIGNATURE,
-            "struct std::crypto::schnorr::Signature".to_owned(),
-        );
-        RwLock::new(index)
-    };
-}
+use self::tagged_union::TaggedUnion;
 
 ///
 /// Describes a type.
@@ -66,8 +52,8 @@ pub enum Type {
     IntegerUnsigned { bitlength: usize },
     /// the `i{N}` type
     IntegerSigned { bitlength: usize },
-    /// the `field` type
-    Field,
+    /// the `field` type, whose bitlength is the one of the build curve scalar field
+    Field { bitlength: usize },
     /// the compile-time only type used mostly for `dbg!` format strings and `assert!` messages
     String,
     /// the compile-time only type used for loop bounds and array slicing
@@ -80,8 +66,12 @@ pub enum Type {
     Tuple { types: Vec<Self> },
     /// the ordinar structure type declared with a `struct` statement
     Structure(Structure),
+    /// the structure type declared with constant generic parameters, which must be instantiated
+    GenericStructure(GenericStructure),
     /// the ordinar enumeration type declared with an `enum` statement
     Enumeration(Enumeration),
+    /// the enumeration type declared with an `enum` statement, whose variants carry data
+    TaggedUnion(TaggedUnion),
     /// the special function type declared with an `fn` statement
     Function(Function),
 }
@@ -117,17 +107,22 @@ impl Type {
         }
     }
 
-    pub fn field() -> Self {
-        Self::Field
+    pub fn field(curve: Curve) -> Self {
+        Self::Field {
+            bitlength: curve.bitlength_field(),
+        }
     }
 
+    ///
+    /// The scalars wider than the largest integer type are field elements.
+    ///
     pub fn scalar(is_signed: bool, bitlength: usize) -> Self {
         if is_signed {
             Self::integer_signed(bitlength)
         } else {
             match bitlength {
                 crate::BITLENGTH_BOOLEAN => Self::Boolean,
-                crate::BITLENGTH_FIELD => Self::Field,
+                bitlength if bitlength > crate::BITLENGTH_MAX_INT => Self::Field { bitlength },
                 bitlength => Self::integer_unsigned(bitlength),
             }
         }
@@ -169,17 +164,40 @@ impl Type {
         Self::Structure(Structure::new(identifier, unique_id, fields, scope_parent))
     }
 
+    ///
+    /// Creates an enumeration type, if all the `variants` have explicit values, or
+    /// a tagged union type, if none of them has.
+    ///
     pub fn enumeration(
         identifier: Identifier,
         unique_id: usize,
         variants: Vec<Variant>,
-        scope_parent: Option<Rc<RefCell<Scope>>>,
+        scope_parent: Rc<RefCell<Scope>>,
     ) -> Result<Self, Error> {
-        Enumeration::new(identifier, unique_id, variants, scope_parent).map(Self::Enumeration)
+        if variants.iter().all(|variant| variant.literal.is_some()) {
+            let variants = variants
+                .into_iter()
+                .filter_map(|variant| {
+                    let identifier = variant.identifier;
+                    variant.literal.map(|literal| (identifier, literal))
+                })
+                .collect();
+            return Enumeration::new(identifier, unique_id, variants, Some(scope_parent))
+                .map(Self::Enumeration);
+        }
+
+        if let Some(variant) = variants.iter().find(|variant| variant.literal.is_some()) {
+            return Err(Error::EnumerationVariantsMixed {
+                location: variant.location,
+                type_identifier: identifier.name,
+            });
+        }
+
+        TaggedUnion::new(identifier, unique_id, variants, scope_parent).map(Self::TaggedUnion)
     }
 
-    pub fn new_std_function(builtin_identifier: BuiltinIdentifier) -> Self {
-        Self::Function(Function::new_std(builtin_identifier))
+    pub fn new_std_function(builtin_identifier: BuiltinIdentifier, curve: Curve) -> Self {
+        Self::Function(Function::new_std(builtin_identifier, curve))
     }
 
     pub fn new_user_defined_function(
@@ -202,7 +220,7 @@ impl Type {
             Self::Boolean => 1,
             Self::IntegerUnsigned { .. } => 1,
             Self::IntegerSigned { .. } => 1,
-            Self::Field => 1,
+            Self::Field { .. } => 1,
             Self::String { .. } => 0,
             Self::Range { .. } => 0,
             Self::RangeInclusive { .. } => 0,
@@ -213,7 +231,9 @@ impl Type {
                 .iter()
                 .map(|(_name, r#type)| r#type.size())
                 .sum(),
+            Self::GenericStructure { .. } => 0,
             Self::Enumeration { .. } => 1,
+            Self::TaggedUnion(tagged_union) => tagged_union.size(),
             Self::Function { .. } => 0,
         }
     }
@@ -223,7 +243,7 @@ impl Type {
             Self::Boolean => true,
             Self::IntegerUnsigned { .. } => true,
             Self::IntegerSigned { .. } => true,
-            Self::Field => true,
+            Self::Field { .. } => true,
             Self::Enumeration { .. } => true,
             _ => false,
         }
@@ -232,7 +252,7 @@ impl Type {
     pub fn is_scalar_unsigned(&self) -> bool {
         match self {
             Self::IntegerUnsigned { .. } => true,
-            Self::Field => true,
+            Self::Field { .. } => true,
             Self::Enumeration { .. } => true,
             _ => false,
         }
@@ -275,12 +295,12 @@ impl Type {
             TypeVariant::Boolean => Self::boolean(),
             TypeVariant::IntegerUnsigned { bitlength } => Self::integer_unsigned(*bitlength),
             TypeVariant::IntegerSigned { bitlength } => Self::integer_signed(*bitlength),
-            TypeVariant::Field => Self::field(),
+            TypeVariant::Field => Self::field(scope.borrow().state().curve()),
             TypeVariant::Array { inner, size } => {
                 let r#type = Self::from_type_variant(&*inner, scope.clone())?;
 
                 let size_location = size.location;
-                let size = match ExpressionAnalyzer::new(scope)
+                let size = match ExpressionAnalyzer::new_constant(scope, true)
                     .analyze(size.to_owned(), TranslationHint::Value)?
                 {
                     (Element::Constant(Constant::Integer(integer)), _intermediate) => {
@@ -308,9 +328,9 @@ impl Type {
                 }
                 Self::tuple(types)
             }
-            TypeVariant::Alias { path } => {
+            TypeVariant::Alias { path, arguments } => {
                 let location = path.location;
-                match ExpressionAnalyzer::new(scope)
+                let r#type = match ExpressionAnalyzer::new(scope.clone())
                     .analyze(path.to_owned(), TranslationHint::Type)?
                 {
                     (Element::Type(r#type), _intermediate) => r#type,
@@ -322,6 +342,24 @@ impl Type {
                             }),
                         ));
                     }
+                };
+
+                match r#type {
+                    Self::GenericStructure(structure) => {
+                        let arguments = structure.arguments(arguments, scope, location)?;
+                        Self::Structure(StatementAnalyzer::instantiate_structure(
+                            &structure, arguments,
+                        )?)
+                    }
+                    r#type if arguments.is_empty() => r#type,
+                    r#type => {
+                        return Err(Error::Element(
+                            location,
+                            ElementError::Type(TypeError::AliasDoesNotPointToGenericType {
+                                found: r#type.to_string(),
+                            }),
+                        ));
+                    }
                 }
             }
         })
@@ -355,7 +393,7 @@ impl PartialEq<Type> for Type {
             (Self::IntegerSigned { bitlength: b1 }, Self::IntegerSigned { bitlength: b2 }) => {
                 b1 == b2
             }
-            (Self::Field, Self::Field) => true,
+            (Self::Field { .. }, Self::Field { .. }) => true,
             (Self::String, Self::String) => true,
             (Self::Range { r#type: type_1 }, Self::Range { r#type: type_2 }) => type_1 == type_2,
             (Self::RangeInclusive { r#type: type_1 }, Self::RangeInclusive { r#type: type_2 }) => {
@@ -378,6 +416,9 @@ impl PartialEq<Type> for Type {
             (Self::Enumeration(enumeration_1), Self::Enumeration(enumeration_2)) => {
                 enumeration_1 == enumeration_2
             }
+            (Self::TaggedUnion(tagged_union_1), Self::TaggedUnion(tagged_union_2)) => {
+                tagged_union_1 == tagged_union_2
+            }
             _ => false,
         }
     }
@@ -390,7 +431,7 @@ impl fmt::Display for Type {
             Self::Boolean => write!(f, "bool"),
             Self::IntegerUnsigned { bitlength } => write!(f, "u{}", bitlength),
             Self::IntegerSigned { bitlength } => write!(f, "i{}", bitlength),
-            Self::Field => write!(f, "field"),
+            Self::Field { .. } => write!(f, "field"),
             Self::String => write!(f, "str"),
             Self::Range { r#type } => write!(f, "{0}..{0}", r#type),
             Self::RangeInclusive { r#type } => write!(f, "{0}..={0}", r#type),
@@ -405,7 +446,9 @@ impl fmt::Display for Type {
                     .join(", ")
             ),
             Self::Structure(inner) => write!(f, "{}", inner),
+            Self::GenericStructure(inner) => write!(f, "{}", inner),
             Self::Enumeration(inner) => write!(f, "{}", inner),
+            Self::TaggedUnion(inner) => write!(f, "{}", inner),
             Self::Function(inner) => write!(f, "{}", inner),
         }
     }
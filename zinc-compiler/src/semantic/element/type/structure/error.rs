@@ -8,4 +8,21 @@ pub enum Error {
         type_identifier: String,
         field_name: String,
     },
+    DuplicateGeneric {
+        type_identifier: String,
+        name: String,
+    },
+    GenericExpectedConstant {
+        type_identifier: String,
+        name: String,
+    },
+    GenericArgumentCount {
+        type_identifier: String,
+        expected: usize,
+        found: usize,
+    },
+    GenericNotInferred {
+        type_identifier: String,
+        name: String,
+    },
 }
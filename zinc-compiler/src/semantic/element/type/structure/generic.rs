@@ -0,0 +1,208 @@
+//!
+//! The semantic analyzer generic structure type element.
+//!
+
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::Rc;
+
+use crate::lexical::token::location::Location;
+use crate::semantic::analyzer::expression::hint::Hint as TranslationHint;
+use crate::semantic::analyzer::expression::Analyzer as ExpressionAnalyzer;
+use crate::semantic::element::constant::error::Error as ConstantError;
+use crate::semantic::element::constant::Constant;
+use crate::semantic::element::error::Error as ElementError;
+use crate::semantic::element::r#type::error::Error as TypeError;
+use crate::semantic::element::r#type::generic::Argument as GenericArgument;
+use crate::semantic::element::r#type::generic::Inferrer as GenericInferrer;
+use crate::semantic::element::r#type::generic::Parameter as GenericParameter;
+use crate::semantic::element::r#type::structure::error::Error as StructureTypeError;
+use crate::semantic::element::r#type::structure::Structure;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::element::Element;
+use crate::semantic::error::Error;
+use crate::semantic::scope::Scope;
+use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
+use crate::syntax::tree::statement::r#struct::Statement as StructStatement;
+
+///
+/// The structure with constant generic parameters, which is instantiated for each set
+/// of their values, e.g. `Path<10>`.
+///
+/// The field types are resolved separately for each instance, with the parameters declared
+/// as constants. The instances are shared between the structure copies, so the equal sets
+/// of arguments always produce the same structure type.
+///
+#[derive(Debug, Clone)]
+pub struct Generic {
+    statement: StructStatement,
+    parameters: Vec<GenericParameter>,
+    scope: Rc<RefCell<Scope>>,
+    instances: Rc<RefCell<Vec<(Vec<GenericArgument>, Structure)>>>,
+}
+
+impl Generic {
+    pub fn new(
+        statement: StructStatement,
+        parameters: Vec<GenericParameter>,
+        scope: Rc<RefCell<Scope>>,
+    ) -> Self {
+        Self {
+            statement,
+            parameters,
+            scope,
+            instances: Rc::new(RefCell::new(Vec::new())),
+        }
+    }
+
+    pub fn identifier(&self) -> &str {
+        self.statement.identifier.name.as_str()
+    }
+
+    pub fn statement(&self) -> &StructStatement {
+        &self.statement
+    }
+
+    pub fn parameters(&self) -> &[GenericParameter] {
+        self.parameters.as_slice()
+    }
+
+    ///
+    /// The scope the structure is declared in, which the instance fields are resolved in.
+    ///
+    pub fn scope(&self) -> Rc<RefCell<Scope>> {
+        self.scope.clone()
+    }
+
+    ///
+    /// The instance identifier, e.g. `Path<10>`.
+    ///
+    pub fn instance_identifier(&self, arguments: &[GenericArgument]) -> String {
+        format!(
+            "{}{}",
+            self.identifier(),
+            GenericArgument::list_to_string(arguments)
+        )
+    }
+
+    pub fn get_instance(&self, arguments: &[GenericArgument]) -> Option<Structure> {
+        self.instances
+            .borrow()
+            .iter()
+            .find(|(instance_arguments, _structure)| instance_arguments.as_slice() == arguments)
+            .map(|(_arguments, structure)| structure.to_owned())
+    }
+
+    pub fn insert_instance(&self, arguments: Vec<GenericArgument>, structure: Structure) {
+        self.instances.borrow_mut().push((arguments, structure));
+    }
+
+    ///
+    /// Evaluates the explicit generic arguments, e.g. `10` and `N` in `Path<10, N>`.
+    ///
+    pub fn arguments(
+        &self,
+        expressions: &[ExpressionTree],
+        scope: Rc<RefCell<Scope>>,
+        location: Location,
+    ) -> Result<Vec<GenericArgument>, Error> {
+        if expressions.len() != self.parameters.len() {
+            return Err(Error::Element(
+                location,
+                ElementError::Type(TypeError::Structure(
+                    StructureTypeError::GenericArgumentCount {
+                        type_identifier: self.identifier().to_owned(),
+                        expected: self.parameters.len(),
+                        found: expressions.len(),
+                    },
+                )),
+            ));
+        }
+
+        let mut arguments = Vec::with_capacity(expressions.len());
+        for (expression, parameter) in expressions.iter().zip(self.parameters.iter()) {
+            let location = expression.location;
+            let integer = match ExpressionAnalyzer::new(scope.clone())
+                .analyze(expression.to_owned(), TranslationHint::Value)?
+            {
+                (Element::Constant(Constant::Integer(integer)), _intermediate) => integer,
+                (element, _intermediate) => {
+                    return Err(Error::Element(
+                        location,
+                        ElementError::Type(TypeError::GenericArgumentExpectedIntegerConstant {
+                            found: element.to_string(),
+                        }),
+                    ));
+                }
+            };
+
+            arguments.push(parameter.constant(integer.value).map_err(|error| {
+                Error::Element(
+                    location,
+                    ElementError::Constant(ConstantError::Integer(error)),
+                )
+            })?);
+        }
+        Ok(arguments)
+    }
+
+    ///
+    /// Infers the generic arguments from the actual types of the structure literal fields.
+    ///
+    pub fn infer(
+        &self,
+        actual_fields: &[(String, Type)],
+        location: Location,
+    ) -> Result<Vec<GenericArgument>, Error> {
+        let mut inferrer = GenericInferrer::new(self.parameters.as_slice());
+        for field in self.statement.fields.iter() {
+            if let Some((_name, actual_type)) = actual_fields
+                .iter()
+                .find(|(name, _type)| name == &field.identifier.name)
+            {
+                inferrer
+                    .infer(&field.r#type.variant, actual_type)
+                    .map_err(|error| {
+                        Error::Element(
+                            location,
+                            ElementError::Constant(ConstantError::Integer(error)),
+                        )
+                    })?;
+            }
+        }
+
+        inferrer
+            .finish()
+            .into_iter()
+            .zip(self.parameters.iter())
+            .map(|(argument, parameter)| {
+                argument.ok_or_else(|| {
+                    Error::Element(
+                        location,
+                        ElementError::Type(TypeError::Structure(
+                            StructureTypeError::GenericNotInferred {
+                                type_identifier: self.identifier().to_owned(),
+                                name: parameter.identifier.name.to_owned(),
+                            },
+                        )),
+                    )
+                })
+            })
+            .collect()
+    }
+}
+
+impl fmt::Display for Generic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "struct {}<{}>",
+            self.identifier(),
+            self.parameters
+                .iter()
+                .map(|parameter| parameter.identifier.name.to_owned())
+                .collect::<Vec<String>>()
+                .join(", ")
+        )
+    }
+}
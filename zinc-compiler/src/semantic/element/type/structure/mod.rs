@@ -5,11 +5,13 @@
 mod tests;
 
 pub mod error;
+pub mod generic;
 
 use std::cell::RefCell;
 use std::fmt;
 use std::rc::Rc;
 
+use crate::semantic::element::r#type::generic::Argument as GenericArgument;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::scope::Scope;
 
@@ -19,11 +21,19 @@ use crate::semantic::scope::Scope;
 /// Consists of the local structure `identifier` within its scope, global `unique_id`, `fields`,
 /// and the implementation `scope`, which contains the reference to its parent scope.
 ///
+/// The instances of generic structures also keep the `generics` arguments they have been
+/// created with.
+///
+/// The `private_fields` are declared without the `pub` keyword, so they may be accessed only
+/// within the module the structure is declared in.
+///
 #[derive(Debug, Clone)]
 pub struct Structure {
     pub identifier: String,
     pub unique_id: usize,
     pub fields: Vec<(String, Type)>,
+    pub private_fields: Vec<String>,
+    pub generics: Vec<GenericArgument>,
     pub scope: Rc<RefCell<Scope>>,
 }
 
@@ -33,6 +43,17 @@ impl Structure {
         unique_id: usize,
         fields: Vec<(String, Type)>,
         scope_parent: Option<Rc<RefCell<Scope>>>,
+    ) -> Self {
+        Self::new_generic(identifier, unique_id, fields, vec![], vec![], scope_parent)
+    }
+
+    pub fn new_generic(
+        identifier: String,
+        unique_id: usize,
+        fields: Vec<(String, Type)>,
+        private_fields: Vec<String>,
+        generics: Vec<GenericArgument>,
+        scope_parent: Option<Rc<RefCell<Scope>>>,
     ) -> Self {
         let scope = Rc::new(RefCell::new(Scope::new(scope_parent)));
 
@@ -40,6 +61,8 @@ impl Structure {
             identifier,
             unique_id,
             fields,
+            private_fields,
+            generics,
             scope: scope.clone(),
         };
         scope
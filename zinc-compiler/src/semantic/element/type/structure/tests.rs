@@ -37,3 +37,106 @@ fn main() -> u8 {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn error_duplicate_generic() {
+    let input = r#"
+struct Data<const N: u64, const N: u64> {
+    a: [u8; N],
+}
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(2, 27),
+        ElementError::Type(TypeError::Structure(StructureTypeError::DuplicateGeneric {
+            type_identifier: "Data".to_owned(),
+            name: "N".to_owned(),
+        })),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_generic_expected_constant() {
+    let input = r#"
+struct Data<T> {
+    a: T,
+}
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(2, 13),
+        ElementError::Type(TypeError::Structure(
+            StructureTypeError::GenericExpectedConstant {
+                type_identifier: "Data".to_owned(),
+                name: "T".to_owned(),
+            },
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_generic_argument_count() {
+    let input = r#"
+struct Data<const N: u64> {
+    a: [u8; N],
+}
+
+fn main() {
+    let data: Data<1, 2> = Data { a: [42] };
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(7, 15),
+        ElementError::Type(TypeError::Structure(
+            StructureTypeError::GenericArgumentCount {
+                type_identifier: "Data".to_owned(),
+                expected: 1,
+                found: 2,
+            },
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_generic_not_inferred() {
+    let input = r#"
+struct Data<const N: u64> {
+    a: [u8; N * 2],
+}
+
+fn main() {
+    let data = Data { a: [1, 2] };
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(7, 16),
+        ElementError::Type(TypeError::Structure(
+            StructureTypeError::GenericNotInferred {
+                type_identifier: "Data".to_owned(),
+                name: "N".to_owned(),
+            },
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
@@ -0,0 +1,156 @@
+//!
+//! The semantic analyzer tagged union type element.
+//!
+
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::Rc;
+
+use crate::semantic::element::error::Error as ElementError;
+use crate::semantic::element::r#type::error::Error as TypeError;
+use crate::semantic::element::r#type::function::Function as FunctionType;
+use crate::semantic::element::r#type::structure::error::Error as StructureTypeError;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::error::Error;
+use crate::semantic::scope::Scope;
+use crate::syntax::tree::identifier::Identifier;
+use crate::syntax::tree::variant::data::Data as VariantData;
+use crate::syntax::tree::variant::Variant;
+
+///
+/// Describes a tagged union type, that is, an enumeration whose variants carry data.
+///
+/// Consists of the local enumeration `identifier` within its scope, global `unique_id`,
+/// the variant names along with their payload types, and the implementation `scope`,
+/// which contains the variant constructors and reference to its parent scope.
+///
+/// The variant tag is its index in the declaration order. The payload type is either
+/// the unit type, a tuple, or a structure named after the variant.
+///
+#[derive(Debug, Clone)]
+pub struct TaggedUnion {
+    pub identifier: String,
+    pub unique_id: usize,
+    pub variants: Vec<(String, Type)>,
+    pub scope: Rc<RefCell<Scope>>,
+}
+
+impl TaggedUnion {
+    pub fn new(
+        identifier: Identifier,
+        unique_id: usize,
+        variants: Vec<Variant>,
+        scope_parent: Rc<RefCell<Scope>>,
+    ) -> Result<Self, Error> {
+        let scope = Rc::new(RefCell::new(Scope::new(Some(scope_parent.clone()))));
+
+        let mut variant_types: Vec<(String, Type)> = Vec::with_capacity(variants.len());
+        let mut variant_identifiers = Vec::with_capacity(variants.len());
+        for variant in variants.into_iter() {
+            let payload = match variant.data {
+                VariantData::Unit => Type::unit(),
+                VariantData::Tuple(types) => {
+                    let mut payload_types = Vec::with_capacity(types.len());
+                    for r#type in types.into_iter() {
+                        payload_types.push(Type::from_type_variant(
+                            &r#type.variant,
+                            scope_parent.clone(),
+                        )?);
+                    }
+                    Type::tuple(payload_types)
+                }
+                VariantData::Structure(fields) => {
+                    let structure_identifier =
+                        format!("{}::{}", identifier.name, variant.identifier.name);
+
+                    let mut field_types: Vec<(String, Type)> = Vec::with_capacity(fields.len());
+                    for field in fields.into_iter() {
+                        if field_types
+                            .iter()
+                            .any(|(name, _type)| name == &field.identifier.name)
+                        {
+                            return Err(Error::Element(
+                                field.location,
+                                ElementError::Type(TypeError::Structure(
+                                    StructureTypeError::DuplicateField {
+                                        type_identifier: structure_identifier,
+                                        field_name: field.identifier.name,
+                                    },
+                                )),
+                            ));
+                        }
+                        field_types.push((
+                            field.identifier.name,
+                            Type::from_type_variant(&field.r#type.variant, scope_parent.clone())?,
+                        ));
+                    }
+
+                    Type::structure(
+                        structure_identifier,
+                        scope_parent.borrow().state().next_type_id(),
+                        field_types,
+                        None,
+                    )
+                }
+            };
+
+            variant_types.push((variant.identifier.name.clone(), payload));
+            variant_identifiers.push(variant.identifier);
+        }
+
+        let tagged_union = Self {
+            identifier: identifier.name,
+            unique_id,
+            variants: variant_types,
+            scope: scope.clone(),
+        };
+
+        for (tag, identifier) in variant_identifiers.into_iter().enumerate() {
+            let location = identifier.location;
+
+            Scope::declare_type(
+                scope.clone(),
+                identifier,
+                Type::Function(FunctionType::new_variant(tagged_union.clone(), tag)),
+                true,
+            )
+            .map_err(|error| Error::Scope(location, error))?;
+        }
+
+        scope
+            .borrow_mut()
+            .declare_self(Type::TaggedUnion(tagged_union.clone()));
+
+        Ok(tagged_union)
+    }
+
+    ///
+    /// The size of the largest variant payload.
+    ///
+    pub fn payload_size(&self) -> usize {
+        self.variants
+            .iter()
+            .map(|(_name, r#type)| r#type.size())
+            .max()
+            .unwrap_or_default()
+    }
+
+    ///
+    /// The tag followed by the largest variant payload.
+    ///
+    pub fn size(&self) -> usize {
+        1 + self.payload_size()
+    }
+}
+
+impl PartialEq<Self> for TaggedUnion {
+    fn eq(&self, other: &Self) -> bool {
+        self.unique_id == other.unique_id
+    }
+}
+
+impl fmt::Display for TaggedUnion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "enum {}", self.identifier)
+    }
+}
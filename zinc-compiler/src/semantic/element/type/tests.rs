@@ -4,8 +4,12 @@
 
 #![cfg(test)]
 
+use zinc_bytecode::Curve;
+
 use crate::error::Error;
 use crate::lexical::token::location::Location;
+use crate::semantic::element::constant::boolean::Boolean as BooleanConstant;
+use crate::semantic::element::constant::Constant;
 use crate::semantic::element::error::Error as ElementError;
 use crate::semantic::element::path::Path;
 use crate::semantic::element::r#type::error::Error as TypeError;
@@ -30,7 +34,7 @@ fn main() {
         ElementError::Type(TypeError::AliasDoesNotPointToStructure {
             found: Path::new(
                 Location::new(5, 16),
-                Identifier::new(Location::new(5, 16), Type::field().to_string()),
+                Identifier::new(Location::new(5, 16), Type::field(Curve::default()).to_string()),
             )
             .to_string(),
         }),
@@ -65,3 +69,73 @@ fn main() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn error_alias_does_not_point_to_generic_type() {
+    let input = r#"
+struct Data {
+    a: u8,
+}
+
+fn main() {
+    let data: Data<10> = Data { a: 42 };
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(7, 15),
+        ElementError::Type(TypeError::AliasDoesNotPointToGenericType {
+            found: "struct Data".to_owned(),
+        }),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_generic_constant_expected_integer() {
+    let input = r#"
+fn sum<const N: bool>(values: [u8; N]) -> u8 {
+    0
+}
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(2, 8),
+        ElementError::Type(TypeError::GenericConstantExpectedInteger {
+            found: Type::boolean().to_string(),
+        }),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_generic_argument_expected_integer_constant() {
+    let input = r#"
+struct Data<const N: u64> {
+    a: [u8; N],
+}
+
+fn main() {
+    let data: Data<true> = Data { a: [42] };
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(7, 20),
+        ElementError::Type(TypeError::GenericArgumentExpectedIntegerConstant {
+            found: Constant::Boolean(BooleanConstant::new(true)).to_string(),
+        }),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
@@ -45,6 +45,13 @@ impl Integer {
         }
     }
 
+    ///
+    /// Checks if the value is a field element, whose bitlength is set by the build curve.
+    ///
+    pub fn is_field(&self) -> bool {
+        self.bitlength > crate::BITLENGTH_MAX_INT
+    }
+
     pub fn has_the_same_type_as(&self, other: &Self) -> bool {
         self.is_signed == other.is_signed
             && self.bitlength == other.bitlength
@@ -129,7 +136,7 @@ impl Integer {
             });
         }
 
-        if self.bitlength == crate::BITLENGTH_FIELD {
+        if self.is_field() {
             return Err(Error::ForbiddenFieldBitwise);
         }
 
@@ -144,7 +151,7 @@ impl Integer {
             });
         }
 
-        if self.bitlength == crate::BITLENGTH_FIELD {
+        if self.is_field() {
             return Err(Error::ForbiddenFieldBitwise);
         }
 
@@ -159,7 +166,7 @@ impl Integer {
             });
         }
 
-        if self.bitlength == crate::BITLENGTH_FIELD {
+        if self.is_field() {
             return Err(Error::ForbiddenFieldBitwise);
         }
 
@@ -167,13 +174,13 @@ impl Integer {
     }
 
     pub fn bitwise_shift_left(self, other: Self) -> Result<Self, Error> {
-        if self.bitlength == crate::BITLENGTH_FIELD {
+        if self.is_field() {
             return Err(Error::ForbiddenFieldBitwise);
         }
 
         if other.is_signed {
             return Err(
-                Error::OperatorBitwiseShiftRightSecondOperatorExpectedUnsigned {
+                Error::OperatorBitwiseShiftLeftSecondOperatorExpectedUnsigned {
                     found: other.to_string(),
                 },
             );
@@ -183,7 +190,7 @@ impl Integer {
     }
 
     pub fn bitwise_shift_right(self, other: Self) -> Result<Self, Error> {
-        if self.bitlength == crate::BITLENGTH_FIELD {
+        if self.is_field() {
             return Err(Error::ForbiddenFieldBitwise);
         }
 
@@ -239,7 +246,7 @@ impl Integer {
             });
         }
 
-        if self.bitlength == crate::BITLENGTH_FIELD {
+        if self.is_field() {
             return Err(Error::ForbiddenFieldDivision);
         }
 
@@ -254,7 +261,7 @@ impl Integer {
             });
         }
 
-        if self.bitlength == crate::BITLENGTH_FIELD {
+        if self.is_field() {
             return Err(Error::ForbiddenFieldRemainder);
         }
 
@@ -270,7 +277,7 @@ impl Integer {
     }
 
     pub fn bitwise_not(self) -> Result<Self, Error> {
-        if self.bitlength == crate::BITLENGTH_FIELD {
+        if self.is_field() {
             return Err(Error::ForbiddenFieldBitwise);
         }
 
@@ -278,7 +285,7 @@ impl Integer {
     }
 
     pub fn negate(mut self) -> Result<Self, Error> {
-        if self.bitlength == crate::BITLENGTH_FIELD {
+        if self.is_field() {
             return Err(Error::ForbiddenFieldNegation);
         }
 
@@ -17,6 +17,7 @@ use crate::semantic::casting::Caster;
 use crate::semantic::element::access::Field as FieldAccess;
 use crate::semantic::element::access::Index as IndexAccess;
 use crate::semantic::element::constant::Constant;
+use crate::semantic::element::r#type::tagged_union::TaggedUnion;
 use crate::semantic::element::r#type::Type;
 
 use self::array::Array;
@@ -36,6 +37,7 @@ pub enum Value {
     Array(Array),
     Tuple(Tuple),
     Structure(Structure),
+    TaggedUnion(TaggedUnion),
 }
 
 impl Value {
@@ -47,6 +49,7 @@ impl Value {
             Self::Array(array) => array.r#type(),
             Self::Tuple(tuple) => tuple.r#type(),
             Self::Structure(structure) => structure.r#type(),
+            Self::TaggedUnion(tagged_union) => Type::TaggedUnion(tagged_union.to_owned()),
         }
     }
 
@@ -62,6 +65,7 @@ impl Value {
             (Self::Structure(value_1), Self::Structure(value_2)) => {
                 value_1.has_the_same_type_as(value_2)
             }
+            (Self::TaggedUnion(value_1), Self::TaggedUnion(value_2)) => value_1 == value_2,
             _ => false,
         }
     }
@@ -407,7 +411,7 @@ impl Value {
         let (is_signed, bitlength) = match to {
             Type::IntegerUnsigned { bitlength } => (false, bitlength),
             Type::IntegerSigned { bitlength } => (true, bitlength),
-            Type::Field => (false, crate::BITLENGTH_FIELD),
+            Type::Field { bitlength } => (false, bitlength),
             _ => return Ok(self),
         };
 
@@ -514,7 +518,7 @@ impl TryFrom<&Type> for Value {
             Type::Boolean => Self::Boolean,
             Type::IntegerUnsigned { bitlength } => Self::Integer(Integer::new(false, *bitlength)),
             Type::IntegerSigned { bitlength } => Self::Integer(Integer::new(true, *bitlength)),
-            Type::Field => Self::Integer(Integer::new(false, crate::BITLENGTH_FIELD)),
+            Type::Field { bitlength } => Self::Integer(Integer::new(false, *bitlength)),
             Type::Array { r#type, size } => Self::Array(Array::new(*r#type.to_owned(), *size)),
             Type::Tuple { types } => Self::Tuple(Tuple::new(types.to_owned())),
             Type::Structure(structure) => Self::Structure(Structure::new(structure.to_owned())),
@@ -523,6 +527,7 @@ impl TryFrom<&Type> for Value {
                 integer.set_enumeration(enumeration.to_owned());
                 Self::Integer(integer)
             }
+            Type::TaggedUnion(tagged_union) => Self::TaggedUnion(tagged_union.to_owned()),
             _ => panic!(crate::PANIC_VALIDATED_DURING_SYNTAX_ANALYSIS),
         })
     }
@@ -545,6 +550,7 @@ impl fmt::Display for Value {
             Self::Array(inner) => write!(f, "{}", inner),
             Self::Tuple(inner) => write!(f, "{}", inner),
             Self::Structure(inner) => write!(f, "{}", inner),
+            Self::TaggedUnion(inner) => write!(f, "<enumeration> '{}'", inner.identifier),
         }
     }
 }
@@ -21,9 +21,6 @@ pub enum Error {
     MatchLessThanTwoBranches {
         location: Location,
     },
-    MatchBranchUnreachable {
-        location: Location,
-    },
     MatchBranchPatternPathExpectedConstant {
         location: Location,
         found: String,
@@ -44,6 +41,11 @@ pub enum Error {
         location: Location,
         reference: Location,
     },
+    MatchBranchPatternStructureFieldMissing {
+        location: Location,
+        type_identifier: String,
+        field_name: String,
+    },
 
     LoopWhileExpectedBooleanCondition {
         location: Location,
@@ -66,6 +68,63 @@ pub enum Error {
     },
 
     EntryPointMissing,
+    FunctionPublicArgumentOutsideEntry {
+        location: Location,
+        function: String,
+    },
+    FunctionSelfArgumentNotFirst {
+        location: Location,
+        function: String,
+    },
+    FunctionGenericDuplicate {
+        location: Location,
+        function: String,
+        name: String,
+    },
+    FunctionGenericEntry {
+        location: Location,
+    },
+    FunctionGenericBoundExpectedTrait {
+        location: Location,
+        found: String,
+    },
+    FunctionGenericBoundNotSatisfied {
+        location: Location,
+        function: String,
+        r#type: String,
+        r#trait: String,
+    },
+    FunctionWithoutBody {
+        location: Location,
+        function: String,
+    },
+    FunctionConstantGeneric {
+        location: Location,
+        function: String,
+    },
+    FunctionConstantUnsupportedStatement {
+        location: Location,
+        function: String,
+    },
+    FunctionConstantRecursionLimit {
+        location: Location,
+        function: String,
+        limit: usize,
+    },
+    FunctionConstantStepLimit {
+        location: Location,
+        function: String,
+        limit: usize,
+    },
+    ReturnOutsideFunction {
+        location: Location,
+    },
+    BreakOutsideLoop {
+        location: Location,
+    },
+    ContinueOutsideLoop {
+        location: Location,
+    },
 
     ModuleNotFound {
         location: Location,
@@ -81,9 +140,84 @@ pub enum Error {
         location: Location,
         found: String,
     },
+    ImplStatementExpectedTrait {
+        location: Location,
+        found: String,
+    },
+    ImplStatementTraitMethodMissing {
+        location: Location,
+        r#trait: String,
+        method: String,
+    },
+    ImplStatementTraitMethodNotMember {
+        location: Location,
+        r#trait: String,
+        method: String,
+    },
+    ImplStatementTraitMethodSignatureMismatch {
+        location: Location,
+        r#trait: String,
+        expected: String,
+        found: String,
+        reference: Location,
+    },
+
+    EnumerationVariantsMixed {
+        location: Location,
+        type_identifier: String,
+    },
 
     ConstantExpressionHasNonConstantElement {
         location: Location,
         found: String,
     },
 }
+
+impl Error {
+    ///
+    /// The error location, which is used to sort the errors before reporting them.
+    ///
+    pub fn location(&self) -> Option<Location> {
+        match self {
+            Self::Element(location, _) | Self::Scope(location, _) => Some(*location),
+
+            Self::EntryPointMissing => None,
+
+            Self::MatchScrutineeInvalidType { location, .. }
+            | Self::MatchNotExhausted { location }
+            | Self::MatchLessThanTwoBranches { location }
+            | Self::MatchBranchPatternPathExpectedConstant { location, .. }
+            | Self::MatchBranchPatternInvalidType { location, .. }
+            | Self::MatchBranchExpressionInvalidType { location, .. }
+            | Self::MatchBranchDuplicate { location, .. }
+            | Self::MatchBranchPatternStructureFieldMissing { location, .. }
+            | Self::LoopWhileExpectedBooleanCondition { location, .. }
+            | Self::LoopBoundsExpectedConstantRangeExpression { location, .. }
+            | Self::ConditionalExpectedBooleanCondition { location, .. }
+            | Self::ConditionalBranchTypesMismatch { location, .. }
+            | Self::FunctionPublicArgumentOutsideEntry { location, .. }
+            | Self::FunctionSelfArgumentNotFirst { location, .. }
+            | Self::FunctionGenericDuplicate { location, .. }
+            | Self::FunctionGenericEntry { location }
+            | Self::FunctionGenericBoundExpectedTrait { location, .. }
+            | Self::FunctionGenericBoundNotSatisfied { location, .. }
+            | Self::FunctionWithoutBody { location, .. }
+            | Self::FunctionConstantGeneric { location, .. }
+            | Self::FunctionConstantUnsupportedStatement { location, .. }
+            | Self::FunctionConstantRecursionLimit { location, .. }
+            | Self::FunctionConstantStepLimit { location, .. }
+            | Self::ReturnOutsideFunction { location }
+            | Self::BreakOutsideLoop { location }
+            | Self::ContinueOutsideLoop { location }
+            | Self::ModuleNotFound { location, .. }
+            | Self::UseExpectedPath { location, .. }
+            | Self::ImplStatementExpectedStructureOrEnumeration { location, .. }
+            | Self::ImplStatementExpectedTrait { location, .. }
+            | Self::ImplStatementTraitMethodMissing { location, .. }
+            | Self::ImplStatementTraitMethodNotMember { location, .. }
+            | Self::ImplStatementTraitMethodSignatureMismatch { location, .. }
+            | Self::EnumerationVariantsMixed { location, .. }
+            | Self::ConstantExpressionHasNonConstantElement { location, .. } => Some(*location),
+        }
+    }
+}
@@ -7,6 +7,7 @@ use std::collections::HashMap;
 use std::rc::Rc;
 
 use zinc_bytecode::builtins::BuiltinIdentifier;
+use zinc_bytecode::Curve;
 
 use crate::semantic::element::r#type::function::Function as FunctionType;
 use crate::semantic::element::r#type::structure::Structure as StructureType;
@@ -28,28 +29,29 @@ impl BuiltInItems {
     pub const TYPE_ID_STD_CRYPTO_SCHNORR_SIGNATURE: usize = 1;
     pub const TYPE_ID_FIRST_AVAILABLE: usize = 2;
 
-    pub fn new_map() -> HashMap<String, ScopeItem> {
+    pub fn new_map(curve: Curve) -> HashMap<String, ScopeItem> {
         let mut std_crypto_scope = Scope::default();
-        let std_crypto_sha256 = FunctionType::new_std(BuiltinIdentifier::CryptoSha256);
-        let std_crypto_pedersen = FunctionType::new_std(BuiltinIdentifier::CryptoPedersen);
+        let std_crypto_sha256 = FunctionType::new_std(BuiltinIdentifier::CryptoSha256, curve);
+        let std_crypto_pedersen = FunctionType::new_std(BuiltinIdentifier::CryptoPedersen, curve);
 
         let mut std_crypto_schnorr = Scope::default();
         let mut std_crypto_schnorr_signature_scope = Scope::default();
         let std_crypto_schnorr_verify =
-            FunctionType::new_std(BuiltinIdentifier::CryptoSchnorrSignatureVerify);
+            FunctionType::new_std(BuiltinIdentifier::CryptoSchnorrSignatureVerify, curve);
         std_crypto_schnorr_signature_scope.items.insert(
             std_crypto_schnorr_verify.identifier(),
             ScopeItem::new(
                 ScopeItemVariant::Type(Type::Function(std_crypto_schnorr_verify)),
                 None,
+                true,
             ),
         );
         let std_crypto_ecc_point = StructureType::new(
             "Point".to_owned(),
             Self::TYPE_ID_STD_CRYPTO_ECC_POINT,
             vec![
-                ("x".to_owned(), Type::field()),
-                ("y".to_owned(), Type::field()),
+                ("x".to_owned(), Type::field(curve)),
+                ("y".to_owned(), Type::field(curve)),
             ],
             None,
         );
@@ -61,7 +63,7 @@ impl BuiltInItems {
                     "r".to_owned(),
                     Type::Structure(std_crypto_ecc_point.clone()),
                 ),
-                ("s".to_owned(), Type::field()),
+                ("s".to_owned(), Type::field(curve)),
                 (
                     "pk".to_owned(),
                     Type::Structure(std_crypto_ecc_point.clone()),
@@ -74,6 +76,7 @@ impl BuiltInItems {
             ScopeItem::new(
                 ScopeItemVariant::Type(Type::Structure(std_crypto_schnorr_signature)),
                 None,
+                true,
             ),
         );
 
@@ -83,6 +86,7 @@ impl BuiltInItems {
             ScopeItem::new(
                 ScopeItemVariant::Type(Type::Structure(std_crypto_ecc_point)),
                 None,
+                true,
             ),
         );
 
@@ -91,6 +95,7 @@ impl BuiltInItems {
             ScopeItem::new(
                 ScopeItemVariant::Type(Type::Function(std_crypto_sha256)),
                 None,
+                true,
             ),
         );
         std_crypto_scope.items.insert(
@@ -98,6 +103,7 @@ impl BuiltInItems {
             ScopeItem::new(
                 ScopeItemVariant::Type(Type::Function(std_crypto_pedersen)),
                 None,
+                true,
             ),
         );
         std_crypto_scope.items.insert(
@@ -105,6 +111,7 @@ impl BuiltInItems {
             ScopeItem::new(
                 ScopeItemVariant::Module(Rc::new(RefCell::new(std_crypto_ecc))),
                 None,
+                true,
             ),
         );
         std_crypto_scope.items.insert(
@@ -112,20 +119,24 @@ impl BuiltInItems {
             ScopeItem::new(
                 ScopeItemVariant::Module(Rc::new(RefCell::new(std_crypto_schnorr))),
                 None,
+                true,
             ),
         );
 
         let mut std_convert_scope = Scope::default();
-        let std_convert_to_bits = FunctionType::new_std(BuiltinIdentifier::ToBits);
+        let std_convert_to_bits = FunctionType::new_std(BuiltinIdentifier::ToBits, curve);
         let std_convert_from_bits_unsigned =
-            FunctionType::new_std(BuiltinIdentifier::UnsignedFromBits);
-        let std_convert_from_bits_signed = FunctionType::new_std(BuiltinIdentifier::SignedFromBits);
-        let std_convert_from_bits_field = FunctionType::new_std(BuiltinIdentifier::FieldFromBits);
+            FunctionType::new_std(BuiltinIdentifier::UnsignedFromBits, curve);
+        let std_convert_from_bits_signed =
+            FunctionType::new_std(BuiltinIdentifier::SignedFromBits, curve);
+        let std_convert_from_bits_field =
+            FunctionType::new_std(BuiltinIdentifier::FieldFromBits, curve);
         std_convert_scope.items.insert(
             std_convert_to_bits.identifier(),
             ScopeItem::new(
                 ScopeItemVariant::Type(Type::Function(std_convert_to_bits)),
                 None,
+                true,
             ),
         );
         std_convert_scope.items.insert(
@@ -133,6 +144,7 @@ impl BuiltInItems {
             ScopeItem::new(
                 ScopeItemVariant::Type(Type::Function(std_convert_from_bits_unsigned)),
                 None,
+                true,
             ),
         );
         std_convert_scope.items.insert(
@@ -140,6 +152,7 @@ impl BuiltInItems {
             ScopeItem::new(
                 ScopeItemVariant::Type(Type::Function(std_convert_from_bits_signed)),
                 None,
+                true,
             ),
         );
         std_convert_scope.items.insert(
@@ -147,18 +160,20 @@ impl BuiltInItems {
             ScopeItem::new(
                 ScopeItemVariant::Type(Type::Function(std_convert_from_bits_field)),
                 None,
+                true,
             ),
         );
 
         let mut std_array_scope = Scope::default();
-        let std_array_reverse = FunctionType::new_std(BuiltinIdentifier::ArrayReverse);
-        let std_array_truncate = FunctionType::new_std(BuiltinIdentifier::ArrayTruncate);
-        let std_array_pad = FunctionType::new_std(BuiltinIdentifier::ArrayPad);
+        let std_array_reverse = FunctionType::new_std(BuiltinIdentifier::ArrayReverse, curve);
+        let std_array_truncate = FunctionType::new_std(BuiltinIdentifier::ArrayTruncate, curve);
+        let std_array_pad = FunctionType::new_std(BuiltinIdentifier::ArrayPad, curve);
         std_array_scope.items.insert(
             std_array_reverse.identifier(),
             ScopeItem::new(
                 ScopeItemVariant::Type(Type::Function(std_array_reverse)),
                 None,
+                true,
             ),
         );
         std_array_scope.items.insert(
@@ -166,18 +181,27 @@ impl BuiltInItems {
             ScopeItem::new(
                 ScopeItemVariant::Type(Type::Function(std_array_truncate)),
                 None,
+                true,
             ),
         );
         std_array_scope.items.insert(
             std_array_pad.identifier(),
-            ScopeItem::new(ScopeItemVariant::Type(Type::Function(std_array_pad)), None),
+            ScopeItem::new(
+                ScopeItemVariant::Type(Type::Function(std_array_pad)),
+                None,
+                true,
+            ),
         );
 
         let mut std_ff_scope = Scope::default();
-        let std_ff_invert = FunctionType::new_std(BuiltinIdentifier::FieldInverse);
+        let std_ff_invert = FunctionType::new_std(BuiltinIdentifier::FieldInverse, curve);
         std_ff_scope.items.insert(
             std_ff_invert.identifier(),
-            ScopeItem::new(ScopeItemVariant::Type(Type::Function(std_ff_invert)), None),
+            ScopeItem::new(
+                ScopeItemVariant::Type(Type::Function(std_ff_invert)),
+                None,
+                true,
+            ),
         );
 
         let mut std_scope = Scope::default();
@@ -186,6 +210,7 @@ impl BuiltInItems {
             ScopeItem::new(
                 ScopeItemVariant::Module(Rc::new(RefCell::new(std_crypto_scope))),
                 None,
+                true,
             ),
         );
         std_scope.items.insert(
@@ -193,6 +218,7 @@ impl BuiltInItems {
             ScopeItem::new(
                 ScopeItemVariant::Module(Rc::new(RefCell::new(std_convert_scope))),
                 None,
+                true,
             ),
         );
         std_scope.items.insert(
@@ -200,6 +226,7 @@ impl BuiltInItems {
             ScopeItem::new(
                 ScopeItemVariant::Module(Rc::new(RefCell::new(std_array_scope))),
                 None,
+                true,
             ),
         );
         std_scope.items.insert(
@@ -207,6 +234,7 @@ impl BuiltInItems {
             ScopeItem::new(
                 ScopeItemVariant::Module(Rc::new(RefCell::new(std_ff_scope))),
                 None,
+                true,
             ),
         );
 
@@ -218,6 +246,7 @@ impl BuiltInItems {
             ScopeItem::new(
                 ScopeItemVariant::Type(Type::Function(builtin_function_dbg)),
                 None,
+                true,
             ),
         );
         items.insert(
@@ -225,6 +254,7 @@ impl BuiltInItems {
             ScopeItem::new(
                 ScopeItemVariant::Type(Type::Function(builtin_function_assert)),
                 None,
+                true,
             ),
         );
         items.insert(
@@ -232,6 +262,7 @@ impl BuiltInItems {
             ScopeItem::new(
                 ScopeItemVariant::Module(Rc::new(RefCell::new(std_scope))),
                 None,
+                true,
             ),
         );
         items
@@ -13,7 +13,17 @@ pub enum Error {
         name: String,
         reference: Option<Location>,
     },
+    ItemInvalid {
+        name: String,
+    },
     ItemIsNotNamespace {
         name: String,
     },
+    ItemIsPrivate {
+        name: String,
+    },
+    FieldIsPrivate {
+        name: String,
+        structure: String,
+    },
 }
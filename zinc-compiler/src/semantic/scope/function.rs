@@ -0,0 +1,31 @@
+//!
+//! The semantic analyzer scope function context.
+//!
+
+use crate::lexical::token::location::Location;
+use crate::semantic::element::r#type::Type;
+
+///
+/// The context of the function being analyzed, which is stored in the function body scope.
+///
+/// It is used to check the `return` statements and to count them, so the generator knows
+/// which parts of the function must be guarded against execution after an early return.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Context {
+    pub identifier: String,
+    pub return_type: Type,
+    pub return_type_location: Location,
+    pub return_count: usize,
+}
+
+impl Context {
+    pub fn new(identifier: String, return_type: Type, return_type_location: Location) -> Self {
+        Self {
+            identifier,
+            return_type,
+            return_type_location,
+            return_count: 0,
+        }
+    }
+}
@@ -4,6 +4,7 @@
 
 pub mod variant;
 
+use std::cell::Cell;
 use std::fmt;
 
 use crate::lexical::token::location::Location;
@@ -15,15 +16,40 @@ use self::variant::Variant;
 ///
 /// Items are variables, constants, types, modules, etc.
 ///
+/// An item is marked as used when it is resolved, and as import when it is declared with
+/// a `use` statement, which is needed to warn about the unused ones.
+///
+/// An item is private by default, and may be accessed from another module only if it is
+/// declared with the `pub` keyword.
+///
 #[derive(Debug, Clone, PartialEq)]
 pub struct Item {
     pub variant: Variant,
     pub location: Option<Location>,
+    pub is_import: bool,
+    pub is_public: bool,
+    pub is_used: Cell<bool>,
 }
 
 impl Item {
-    pub fn new(variant: Variant, location: Option<Location>) -> Self {
-        Self { variant, location }
+    pub fn new(variant: Variant, location: Option<Location>, is_public: bool) -> Self {
+        Self {
+            variant,
+            location,
+            is_import: false,
+            is_public,
+            is_used: Cell::new(false),
+        }
+    }
+
+    pub fn new_import(variant: Variant, location: Option<Location>, is_public: bool) -> Self {
+        Self {
+            variant,
+            location,
+            is_import: true,
+            is_public,
+            is_used: Cell::new(false),
+        }
     }
 }
 
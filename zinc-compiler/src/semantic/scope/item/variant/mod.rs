@@ -2,6 +2,7 @@
 //! The semantic analyzer scope item variant.
 //!
 
+pub mod r#trait;
 pub mod variable;
 
 use std::cell::RefCell;
@@ -12,6 +13,7 @@ use crate::semantic::element::constant::Constant;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::scope::Scope;
 
+use self::r#trait::Trait;
 use self::variable::Variable;
 
 #[derive(Debug, Clone, PartialEq)]
@@ -20,6 +22,7 @@ pub enum Variant {
     Constant(Constant),
     Type(Type),
     Module(Rc<RefCell<Scope>>),
+    Trait(Trait),
 }
 
 impl Variant {
@@ -30,6 +33,7 @@ impl Variant {
             Self::Type(Type::Enumeration { .. }) => false,
             Self::Type(_) => true,
             Self::Module(_) => true,
+            Self::Trait(_) => false,
         }
     }
 }
@@ -41,6 +45,7 @@ impl fmt::Display for Variant {
             Self::Constant(inner) => write!(f, "{}", inner),
             Self::Type(inner) => write!(f, "{}", inner),
             Self::Module(_) => write!(f, "<module>"),
+            Self::Trait(inner) => write!(f, "{}", inner),
         }
     }
 }
@@ -0,0 +1,70 @@
+//!
+//! The semantic analyzer scope trait item variant.
+//!
+
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::Rc;
+
+use crate::semantic::element::r#type::Type;
+use crate::semantic::scope::Scope;
+use crate::syntax::tree::statement::r#fn::Statement as FnStatement;
+
+///
+/// The trait item, declared using a `trait` statement.
+///
+/// The trait methods are either prototypes, which must be defined by each implementation,
+/// or default methods, which are analyzed for each implementation not defining them.
+/// The method types are resolved in the trait declaration `scope` with `Self` declared as
+/// the implementing type.
+///
+#[derive(Debug, Clone)]
+pub struct Trait {
+    pub identifier: String,
+    pub methods: Vec<FnStatement>,
+    pub scope: Rc<RefCell<Scope>>,
+    implementations: Rc<RefCell<Vec<Type>>>,
+}
+
+impl Trait {
+    pub fn new(identifier: String, methods: Vec<FnStatement>, scope: Rc<RefCell<Scope>>) -> Self {
+        Self {
+            identifier,
+            methods,
+            scope,
+            implementations: Rc::new(RefCell::new(Vec::new())),
+        }
+    }
+
+    ///
+    /// Gets the method prototype or default method with the `identifier`.
+    ///
+    pub fn method(&self, identifier: &str) -> Option<&FnStatement> {
+        self.methods
+            .iter()
+            .find(|method| method.identifier.name.as_str() == identifier)
+    }
+
+    ///
+    /// Registers the `type` as implementing the trait, which is shared between the trait copies.
+    ///
+    pub fn implement(&self, r#type: Type) {
+        self.implementations.borrow_mut().push(r#type);
+    }
+
+    pub fn is_implemented_by(&self, r#type: &Type) -> bool {
+        self.implementations.borrow().contains(r#type)
+    }
+}
+
+impl PartialEq<Self> for Trait {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.implementations, &other.implementations)
+    }
+}
+
+impl fmt::Display for Trait {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "trait {}", self.identifier)
+    }
+}
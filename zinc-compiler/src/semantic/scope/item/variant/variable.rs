@@ -2,6 +2,7 @@
 //! The semantic analyzer scope variable item variant.
 //!
 
+use std::cell::Cell;
 use std::fmt;
 
 use crate::semantic::element::r#type::Type;
@@ -9,15 +10,23 @@ use crate::semantic::element::r#type::Type;
 ///
 /// The variable item, declared using a `let` statement.
 ///
+/// The variable is marked as mutated when it is assigned to, which is needed to warn about
+/// the unnecessary `mut` keyword.
+///
 #[derive(Debug, Clone, PartialEq)]
 pub struct Variable {
     pub is_mutable: bool,
     pub r#type: Type,
+    pub is_mutated: Cell<bool>,
 }
 
 impl Variable {
     pub fn new(is_mutable: bool, r#type: Type) -> Self {
-        Self { is_mutable, r#type }
+        Self {
+            is_mutable,
+            r#type,
+            is_mutated: Cell::new(false),
+        }
     }
 }
 
@@ -0,0 +1,28 @@
+//!
+//! The semantic analyzer scope loop context.
+//!
+
+///
+/// The context of the loop being analyzed, which is stored in the loop body scope.
+///
+/// It is used to check the `break` and `continue` statements and to count them, so the generator
+/// knows which parts of the loop body must be guarded against execution after them.
+///
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Context {
+    pub break_count: usize,
+    pub continue_count: usize,
+}
+
+impl Context {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    ///
+    /// The total number of the statements interrupting the loop iteration.
+    ///
+    pub fn control_count(&self) -> usize {
+        self.break_count + self.continue_count
+    }
+}
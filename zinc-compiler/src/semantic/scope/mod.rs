@@ -6,26 +6,38 @@ mod tests;
 
 pub mod builtin;
 pub mod error;
+pub mod function;
 pub mod item;
+pub mod r#loop;
 pub mod stack;
+pub mod state;
 
 use std::cell::RefCell;
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::rc::Rc;
 use std::str;
 
 use crate::lexical::token::lexeme::keyword::Keyword;
 use crate::semantic::element::constant::Constant;
 use crate::semantic::element::path::Path;
+use crate::semantic::element::r#type::function::user::Function as UserDefinedFunctionType;
+use crate::semantic::element::r#type::function::Function as FunctionType;
+use crate::semantic::element::r#type::structure::Structure as StructureType;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::error::Error as SemanticError;
 use crate::syntax::tree::identifier::Identifier;
+use crate::warning::Warning;
 
 use self::builtin::BuiltInItems;
 use self::error::Error;
+use self::function::Context as FunctionContext;
+use self::item::variant::r#trait::Trait as TraitItem;
 use self::item::variant::variable::Variable as VariableItem;
 use self::item::variant::Variant as ItemVariant;
 use self::item::Item;
+use self::r#loop::Context as LoopContext;
+use self::state::State;
 
 ///
 /// A scope consists of a hashmap of the declared items and a reference to its parent.
@@ -36,26 +48,63 @@ use self::item::Item;
 pub struct Scope {
     parent: Option<Rc<RefCell<Self>>>,
     items: HashMap<String, Item>,
+    invalid_items: HashSet<String>,
+    function: Option<FunctionContext>,
+    r#loop: Option<LoopContext>,
+    state: Rc<State>,
+    warnings: Rc<RefCell<Vec<Warning>>>,
+    errors: Rc<RefCell<Vec<SemanticError>>>,
 }
 
 impl Scope {
     ///
     /// Initializes a nested scope with an explicit optional parent.
     ///
+    /// The build state is inherited from the parent, and the warnings and errors are stored
+    /// along with the parent ones.
+    ///
     pub fn new(parent: Option<Rc<RefCell<Self>>>) -> Self {
+        let state = parent
+            .as_ref()
+            .map(|parent| parent.borrow().state.clone())
+            .unwrap_or_default();
+        let warnings = parent
+            .as_ref()
+            .map(|parent| parent.borrow().warnings.clone())
+            .unwrap_or_default();
+        let errors = parent
+            .as_ref()
+            .map(|parent| parent.borrow().errors.clone())
+            .unwrap_or_default();
+
         Self {
             parent,
             items: HashMap::new(),
+            invalid_items: HashSet::new(),
+            function: None,
+            r#loop: None,
+            state,
+            warnings,
+            errors,
         }
     }
 
     ///
     /// Initializes a global scope without a parent and with default items.
     ///
-    pub fn new_global() -> Self {
+    /// The standard library is built for the curve of the build `state`. The `state` and
+    /// the `warnings` storage are shared by all the scopes of the build.
+    ///
+    pub fn new_global(state: Rc<State>, warnings: Rc<RefCell<Vec<Warning>>>) -> Self {
         Self {
             parent: None,
-            items: BuiltInItems::new_map(),
+            items: BuiltInItems::new_map(state.curve()),
+            invalid_items: HashSet::new(),
+            function: None,
+            r#loop: None,
+            state,
+            warnings,
+            errors: Rc::new(RefCell::new(Vec::new())),
         }
     }
 
@@ -93,11 +142,31 @@ impl Scope {
         }
         scope.borrow_mut().items.insert(
             identifier.name,
-            Item::new(ItemVariant::Variable(variable), Some(identifier.location)),
+            Item::new(
+                ItemVariant::Variable(variable),
+                Some(identifier.location),
+                false,
+            ),
         );
         Ok(())
     }
 
+    ///
+    /// Declares the binding of a `let` statement, which has failed to be analyzed.
+    ///
+    /// Resolving the binding fails silently, so the error of the statement is not followed
+    /// by the errors of every expression using the binding.
+    ///
+    pub fn declare_invalid(scope: Rc<RefCell<Scope>>, identifier: &str) {
+        if scope.borrow().is_item_declared(identifier) {
+            return;
+        }
+        scope
+            .borrow_mut()
+            .invalid_items
+            .insert(identifier.to_owned());
+    }
+
     ///
     /// Declares a constant, which is normally a `const` binding.
     ///
@@ -105,6 +174,7 @@ impl Scope {
         scope: Rc<RefCell<Scope>>,
         identifier: Identifier,
         constant: Constant,
+        is_public: bool,
     ) -> Result<(), Error> {
         if let Ok(item) = Self::resolve_item(scope.clone(), &identifier.name) {
             return Err(Error::ItemRedeclared {
@@ -114,11 +184,31 @@ impl Scope {
         }
         scope.borrow_mut().items.insert(
             identifier.name,
-            Item::new(ItemVariant::Constant(constant), Some(identifier.location)),
+            Item::new(
+                ItemVariant::Constant(constant),
+                Some(identifier.location),
+                is_public,
+            ),
         );
         Ok(())
     }
 
+    ///
+    /// Replaces the value of a constant declared in the scope or its parents.
+    ///
+    /// Is used to mutate the local variables of `const fn` items evaluated at compile time.
+    ///
+    pub fn update_constant(scope: Rc<RefCell<Scope>>, identifier: &str, constant: Constant) {
+        let mut current = Some(scope);
+        while let Some(scope) = current.take() {
+            if let Some(item) = scope.borrow_mut().items.get_mut(identifier) {
+                item.variant = ItemVariant::Constant(constant);
+                return;
+            }
+            current = scope.borrow().parent.clone();
+        }
+    }
+
     ///
     /// Declares a type, which is normally a `type`, `struct`, or `enum` binding.
     ///
@@ -126,6 +216,7 @@ impl Scope {
         scope: Rc<RefCell<Scope>>,
         identifier: Identifier,
         r#type: Type,
+        is_public: bool,
     ) -> Result<(), Error> {
         if let Ok(item) = Self::resolve_item(scope.clone(), &identifier.name) {
             return Err(Error::ItemRedeclared {
@@ -135,7 +226,11 @@ impl Scope {
         }
         scope.borrow_mut().items.insert(
             identifier.name,
-            Item::new(ItemVariant::Type(r#type), Some(identifier.location)),
+            Item::new(
+                ItemVariant::Type(r#type),
+                Some(identifier.location),
+                is_public,
+            ),
         );
         Ok(())
     }
@@ -147,6 +242,33 @@ impl Scope {
         scope: Rc<RefCell<Scope>>,
         identifier: Identifier,
         module: Rc<RefCell<Scope>>,
+        is_public: bool,
+    ) -> Result<(), Error> {
+        if let Ok(item) = Self::resolve_item(scope.clone(), &identifier.name) {
+            return Err(Error::ItemRedeclared {
+                name: identifier.name,
+                reference: item.location,
+            });
+        }
+        scope.borrow_mut().items.insert(
+            identifier.name,
+            Item::new(
+                ItemVariant::Module(module),
+                Some(identifier.location),
+                is_public,
+            ),
+        );
+        Ok(())
+    }
+
+    ///
+    /// Declares a trait, which is normally a `trait` binding.
+    ///
+    pub fn declare_trait(
+        scope: Rc<RefCell<Scope>>,
+        identifier: Identifier,
+        r#trait: TraitItem,
+        is_public: bool,
     ) -> Result<(), Error> {
         if let Ok(item) = Self::resolve_item(scope.clone(), &identifier.name) {
             return Err(Error::ItemRedeclared {
@@ -156,7 +278,11 @@ impl Scope {
         }
         scope.borrow_mut().items.insert(
             identifier.name,
-            Item::new(ItemVariant::Module(module), Some(identifier.location)),
+            Item::new(
+                ItemVariant::Trait(r#trait),
+                Some(identifier.location),
+                is_public,
+            ),
         );
         Ok(())
     }
@@ -169,21 +295,135 @@ impl Scope {
     pub fn declare_self(&mut self, r#type: Type) {
         self.items.insert(
             Keyword::SelfUppercase.to_string(),
-            Item::new(ItemVariant::Type(r#type), None),
+            Item::new(ItemVariant::Type(r#type), None, false),
         );
     }
 
+    ///
+    /// Declares the function context within a function body.
+    ///
+    pub fn declare_function(&mut self, context: FunctionContext) {
+        self.function = Some(context);
+    }
+
+    ///
+    /// Registers a `return` statement within the nearest function scope.
+    ///
+    /// Returns the context of the function, or `None` if the scope is outside any function.
+    ///
+    pub fn register_return(scope: Rc<RefCell<Scope>>) -> Option<FunctionContext> {
+        if let Some(ref mut context) = scope.borrow_mut().function {
+            context.return_count += 1;
+            return Some(context.to_owned());
+        }
+
+        match scope.borrow().parent {
+            Some(ref parent) => Self::register_return(parent.to_owned()),
+            None => None,
+        }
+    }
+
+    ///
+    /// Gets the number of `return` statements registered so far within the nearest function scope.
+    ///
+    pub fn get_return_count(scope: Rc<RefCell<Scope>>) -> usize {
+        if let Some(ref context) = scope.borrow().function {
+            return context.return_count;
+        }
+
+        match scope.borrow().parent {
+            Some(ref parent) => Self::get_return_count(parent.to_owned()),
+            None => 0,
+        }
+    }
+
+    ///
+    /// Declares the loop context within a loop body.
+    ///
+    pub fn declare_loop(&mut self) {
+        self.r#loop = Some(LoopContext::new());
+    }
+
+    ///
+    /// Registers a `break` statement within the nearest loop scope.
+    ///
+    /// Returns `false` if the scope is outside any loop of the current function.
+    ///
+    pub fn register_break(scope: Rc<RefCell<Scope>>) -> bool {
+        if let Some(ref mut context) = scope.borrow_mut().r#loop {
+            context.break_count += 1;
+            return true;
+        }
+        if scope.borrow().function.is_some() {
+            return false;
+        }
+
+        match scope.borrow().parent {
+            Some(ref parent) => Self::register_break(parent.to_owned()),
+            None => false,
+        }
+    }
+
+    ///
+    /// Registers a `continue` statement within the nearest loop scope.
+    ///
+    /// Returns `false` if the scope is outside any loop of the current function.
+    ///
+    pub fn register_continue(scope: Rc<RefCell<Scope>>) -> bool {
+        if let Some(ref mut context) = scope.borrow_mut().r#loop {
+            context.continue_count += 1;
+            return true;
+        }
+        if scope.borrow().function.is_some() {
+            return false;
+        }
+
+        match scope.borrow().parent {
+            Some(ref parent) => Self::register_continue(parent.to_owned()),
+            None => false,
+        }
+    }
+
+    ///
+    /// Gets the context of the nearest loop scope within the current function.
+    ///
+    pub fn get_loop_context(scope: Rc<RefCell<Scope>>) -> Option<LoopContext> {
+        if let Some(ref context) = scope.borrow().r#loop {
+            return Some(context.to_owned());
+        }
+        if scope.borrow().function.is_some() {
+            return None;
+        }
+
+        match scope.borrow().parent {
+            Some(ref parent) => Self::get_loop_context(parent.to_owned()),
+            None => None,
+        }
+    }
+
     ///
     /// Gets an item at the specified path by looking through modules, implementations,
     /// and enumerations along the way.
     ///
+    /// The items behind the first path element must be either public, or declared within
+    /// the same module as `scope`.
+    ///
     pub fn resolve_path(scope: Rc<RefCell<Scope>>, path: &Path) -> Result<Item, SemanticError> {
-        let mut current_scope = scope;
+        let mut current_scope = scope.clone();
 
         for (index, identifier) in path.elements.iter().enumerate() {
             let item = Self::resolve_item(current_scope.clone(), &identifier.name)
                 .map_err(|error| SemanticError::Scope(identifier.location, error))?;
 
+            if index > 0 && !item.is_public && !Self::is_accessible(scope.clone(), current_scope) {
+                return Err(SemanticError::Scope(
+                    identifier.location,
+                    Error::ItemIsPrivate {
+                        name: identifier.name.to_owned(),
+                    },
+                ));
+            }
+
             if index == path.elements.len() - 1 {
                 return Ok(item);
             }
@@ -194,6 +434,9 @@ impl Scope {
                     enumeration.scope.to_owned()
                 }
                 ItemVariant::Type(Type::Structure(ref structure)) => structure.scope.to_owned(),
+                ItemVariant::Type(Type::TaggedUnion(ref tagged_union)) => {
+                    tagged_union.scope.to_owned()
+                }
                 _ => {
                     return Err(SemanticError::Scope(
                         identifier.location,
@@ -216,9 +459,17 @@ impl Scope {
     ///
     /// Resolves the item within the current scope hierarchy.
     ///
+    /// The item is marked as used.
+    ///
     pub fn resolve_item(scope: Rc<RefCell<Scope>>, identifier: &str) -> Result<Item, Error> {
         match scope.borrow().items.get(identifier) {
-            Some(item) => Ok(item.to_owned()),
+            Some(item) => {
+                item.is_used.set(true);
+                Ok(item.to_owned())
+            }
+            None if scope.borrow().invalid_items.contains(identifier) => Err(Error::ItemInvalid {
+                name: identifier.to_owned(),
+            }),
             None => match scope.borrow().parent {
                 Some(ref parent) => Self::resolve_item(parent.to_owned(), identifier),
                 None => Err(Error::ItemUndeclared {
@@ -228,6 +479,67 @@ impl Scope {
         }
     }
 
+    ///
+    /// Resolves a method, that is, a function with the `self` receiver, declared in the
+    /// structure or enumeration implementation `scope`.
+    ///
+    /// Unlike the ordinar items, methods are not looked for in the parent scopes.
+    ///
+    /// Returns an error if the method is private and is called outside its module.
+    ///
+    pub fn resolve_method(
+        current: Rc<RefCell<Scope>>,
+        scope: Rc<RefCell<Scope>>,
+        identifier: &str,
+    ) -> Result<Option<UserDefinedFunctionType>, Error> {
+        match scope.borrow().items.get(identifier) {
+            Some(Item {
+                variant: ItemVariant::Type(Type::Function(FunctionType::UserDefined(function))),
+                is_public,
+                ..
+            }) if function.is_method() => {
+                if !is_public && !Self::is_accessible(current, scope.clone()) {
+                    return Err(Error::ItemIsPrivate {
+                        name: identifier.to_owned(),
+                    });
+                }
+                Ok(Some(function.to_owned()))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    ///
+    /// Checks whether the `structure` field may be accessed from the `current` scope, that is,
+    /// the field is public, or the structure is declared within the same module.
+    ///
+    pub fn check_field(
+        current: Rc<RefCell<Scope>>,
+        structure: &StructureType,
+        name: &str,
+    ) -> Result<(), Error> {
+        if structure.private_fields.iter().any(|field| field == name)
+            && !Self::is_accessible(current, structure.scope.clone())
+        {
+            return Err(Error::FieldIsPrivate {
+                name: name.to_owned(),
+                structure: structure.identifier.to_owned(),
+            });
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Checks whether the private items of the `namespace` scope may be accessed from the
+    /// `current` scope, that is, both scopes belong to the same module.
+    ///
+    /// Each module has its own global scope, so the scopes of a module share their root.
+    ///
+    pub fn is_accessible(current: Rc<RefCell<Scope>>, namespace: Rc<RefCell<Scope>>) -> bool {
+        Rc::ptr_eq(&Self::root(current), &Self::root(namespace))
+    }
+
     ///
     /// Checks whether the item is declared within the current scope hierarchy.
     ///
@@ -242,10 +554,143 @@ impl Scope {
         }
     }
 
+    ///
+    /// Marks the variable declared in the scope or its parents as mutated.
+    ///
+    pub fn mark_mutated(scope: Rc<RefCell<Scope>>, identifier: &str) {
+        let mut current = Some(scope);
+        while let Some(scope) = current.take() {
+            if let Some(item) = scope.borrow().items.get(identifier) {
+                if let ItemVariant::Variable(ref variable) = item.variant {
+                    variable.is_mutated.set(true);
+                }
+                return;
+            }
+            current = scope.borrow().parent.clone();
+        }
+    }
+
+    ///
+    /// Emits the warnings about the unused variables and unnecessary `mut` keywords.
+    ///
+    /// Is called when the scope ends, so all the variable usages have already been analyzed.
+    ///
+    pub fn warn_unused_variables(&self) {
+        for (name, item) in self.items.iter() {
+            if Self::is_unused_allowed(name) {
+                continue;
+            }
+
+            let location = match item.location {
+                Some(location) => location,
+                None => continue,
+            };
+
+            if let ItemVariant::Variable(ref variable) = item.variant {
+                if !item.is_used.get() {
+                    self.emit_warning(Warning::UnusedVariable {
+                        location,
+                        name: name.to_owned(),
+                    });
+                } else if variable.is_mutable && !variable.is_mutated.get() {
+                    self.emit_warning(Warning::UnusedMutable {
+                        location,
+                        name: name.to_owned(),
+                    });
+                }
+            }
+        }
+    }
+
+    ///
+    /// Emits the warnings about the unused module level items, that is, functions, constants,
+    /// types, modules, traits, and imports.
+    ///
+    /// The built-in items and the entry point are skipped. The public items of a non-entry
+    /// `module` are skipped as well, since they may be used by the other modules.
+    ///
+    pub fn warn_unused_items(&self, is_module: bool) {
+        for (name, item) in self.items.iter() {
+            if item.is_used.get()
+                || (is_module && item.is_public)
+                || Self::is_unused_allowed(name)
+                || name
+                    == crate::semantic::element::r#type::function::user::FUNCTION_MAIN_IDENTIFIER
+            {
+                continue;
+            }
+
+            let location = match item.location {
+                Some(location) => location,
+                None => continue,
+            };
+            let name = name.to_owned();
+
+            let warning = match item.variant {
+                _ if item.is_import => Warning::UnusedImport { location, name },
+                ItemVariant::Variable(_) => Warning::UnusedVariable { location, name },
+                ItemVariant::Constant(_) => Warning::UnusedConstant { location, name },
+                ItemVariant::Type(Type::Function(_)) => Warning::UnusedFunction { location, name },
+                ItemVariant::Type(_) => Warning::UnusedType { location, name },
+                ItemVariant::Module(_) => Warning::UnusedModule { location, name },
+                ItemVariant::Trait(_) => Warning::UnusedTrait { location, name },
+            };
+            self.emit_warning(warning);
+        }
+    }
+
+    ///
+    /// The semantic analyzer state shared by the scopes of the build.
+    ///
+    pub fn state(&self) -> Rc<State> {
+        self.state.clone()
+    }
+
+    ///
+    /// Stores the warning until the end of the file analysis.
+    ///
+    pub fn emit_warning(&self, warning: Warning) {
+        self.warnings.borrow_mut().push(warning);
+    }
+
+    ///
+    /// Stores the error of a block statement, which is not the one the block analysis has
+    /// failed with, until the module statement containing the block fails.
+    ///
+    pub fn store_error(&self, error: SemanticError) {
+        self.errors.borrow_mut().push(error);
+    }
+
+    ///
+    /// Takes the errors stored by the blocks analyzed within the scope hierarchy.
+    ///
+    pub fn take_errors(&self) -> Vec<SemanticError> {
+        self.errors.borrow_mut().drain(..).collect()
+    }
+
     ///
     /// Creates a child scope with the current one as its parent.
     ///
     pub fn new_child(parent: Rc<RefCell<Scope>>) -> Rc<RefCell<Scope>> {
         Rc::new(RefCell::new(Scope::new(Some(parent))))
     }
+
+    ///
+    /// Returns the global scope of the module the `scope` belongs to.
+    ///
+    fn root(scope: Rc<RefCell<Scope>>) -> Rc<RefCell<Scope>> {
+        let parent = scope.borrow().parent.clone();
+        match parent {
+            Some(parent) => Self::root(parent),
+            None => scope,
+        }
+    }
+
+    ///
+    /// Checks whether the item is not warned about being unused, that is, if its name starts
+    /// with an underscore, or it is the `self` argument.
+    ///
+    fn is_unused_allowed(name: &str) -> bool {
+        name.starts_with('_') || name == Keyword::SelfLowercase.to_string()
+    }
 }
@@ -5,7 +5,9 @@
 use std::cell::RefCell;
 use std::rc::Rc;
 
+use crate::semantic::scope::state::State;
 use crate::semantic::scope::Scope;
+use crate::warning::Warning;
 
 ///
 /// The scope stack is a linked list, where a child has access to its parent.
@@ -33,11 +35,12 @@ impl Stack {
     }
 
     ///
-    /// Initializes a scope stack starting from the global scope.
+    /// Initializes a scope stack starting from the global scope with the build `state`,
+    /// whose warnings are stored in `warnings`.
     ///
-    pub fn new_global() -> Self {
+    pub fn new_global(state: Rc<State>, warnings: Rc<RefCell<Vec<Warning>>>) -> Self {
         let mut elements = Vec::with_capacity(Self::STACK_SCOPE_INITIAL_CAPACITY);
-        elements.push(Rc::new(RefCell::new(Scope::new_global())));
+        elements.push(Rc::new(RefCell::new(Scope::new_global(state, warnings))));
         Self { elements }
     }
 
@@ -68,9 +71,13 @@ impl Stack {
     ///
     /// Removes the deepest scope from the current hierarchy.
     ///
+    /// Since the scope variables cannot be used anymore, the unused ones are warned about.
+    ///
     pub fn pop(&mut self) {
         self.elements
             .pop()
-            .expect(PANIC_THERE_MUST_ALWAYS_BE_A_SCOPE);
+            .expect(PANIC_THERE_MUST_ALWAYS_BE_A_SCOPE)
+            .borrow()
+            .warn_unused_variables();
     }
 }
@@ -0,0 +1,97 @@
+//!
+//! The semantic analyzer state shared by the scopes of a build.
+//!
+
+use std::cell::Cell;
+
+use zinc_bytecode::Curve;
+
+use crate::semantic::scope::builtin::BuiltInItems;
+
+///
+/// The state of a single build, which is shared by the global scopes of all its files,
+/// so the builds running at the same time do not affect each other.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct State {
+    /// The curve, whose scalar field the program is compiled for.
+    curve: Curve,
+    /// The unique ID of the next user-defined type.
+    next_type_id: Cell<usize>,
+    /// The number of `const fn` calls being evaluated at the moment.
+    evaluation_depth: Cell<usize>,
+    /// The number of steps made since the outermost `const fn` call.
+    evaluation_steps: Cell<usize>,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self::new(Curve::default())
+    }
+}
+
+impl State {
+    ///
+    /// Initializes the state of a build for the `curve`.
+    ///
+    /// The type IDs below `BuiltInItems::TYPE_ID_FIRST_AVAILABLE` are reserved by the standard
+    /// library types.
+    ///
+    pub fn new(curve: Curve) -> Self {
+        Self {
+            curve,
+            next_type_id: Cell::new(BuiltInItems::TYPE_ID_FIRST_AVAILABLE),
+            evaluation_depth: Cell::new(0),
+            evaluation_steps: Cell::new(0),
+        }
+    }
+
+    ///
+    /// The curve, whose scalar field the program is compiled for.
+    ///
+    pub fn curve(&self) -> Curve {
+        self.curve
+    }
+
+    ///
+    /// Allocates the unique ID of a user-defined type, which distinguishes the types with
+    /// the same name declared in different scopes.
+    ///
+    pub fn next_type_id(&self) -> usize {
+        let unique_id = self.next_type_id.get();
+        self.next_type_id.set(unique_id + 1);
+        unique_id
+    }
+
+    ///
+    /// The number of `const fn` calls being evaluated at the moment.
+    ///
+    pub fn evaluation_depth(&self) -> usize {
+        self.evaluation_depth.get()
+    }
+
+    ///
+    /// Sets the number of `const fn` calls being evaluated, which is incremented before
+    /// a call body is evaluated and restored afterwards.
+    ///
+    pub fn set_evaluation_depth(&self, depth: usize) {
+        self.evaluation_depth.set(depth);
+    }
+
+    ///
+    /// Counts an evaluation step and returns the number of steps made since the outermost
+    /// `const fn` call.
+    ///
+    pub fn evaluation_step(&self) -> usize {
+        let steps = self.evaluation_steps.get() + 1;
+        self.evaluation_steps.set(steps);
+        steps
+    }
+
+    ///
+    /// Resets the evaluation step counter before an outermost `const fn` call.
+    ///
+    pub fn reset_evaluation_steps(&self) {
+        self.evaluation_steps.set(0);
+    }
+}
@@ -4,10 +4,174 @@
 
 #![cfg(test)]
 
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
 use crate::error::Error;
 use crate::lexical::token::location::Location;
 use crate::semantic::error::Error as SemanticError;
 use crate::semantic::scope::error::Error as ScopeError;
+use crate::semantic::scope::Scope;
+use crate::warning::Warning;
+
+static PANIC_COMPILE_DEPENDENCY: &str = "Dependencies must be successfully compiled";
+
+static MODULE_DATA: &str = r#"
+pub struct Data {
+    pub value: u8,
+    secret: u8,
+}
+
+impl Data {
+    pub fn new(value: u8) -> Self {
+        Data {
+            value: value,
+            secret: 42,
+        }
+    }
+
+    pub fn sum(self) -> u8 {
+        self.value + self.secret
+    }
+
+    fn double(self) -> u8 {
+        self.value * 2
+    }
+}
+
+fn helper() -> u8 {
+    42
+}
+"#;
+
+fn compile_entry_with_module(input: &str) -> Result<(), Error> {
+    let module =
+        crate::semantic::tests::compile_module(MODULE_DATA).expect(PANIC_COMPILE_DEPENDENCY);
+
+    let dependencies: HashMap<String, Rc<RefCell<Scope>>> =
+        vec![("data".to_owned(), module)].into_iter().collect();
+
+    crate::semantic::tests::compile_entry_with_dependencies(input, dependencies)
+}
+
+#[test]
+fn ok_item_public() {
+    let input = r#"
+mod data;
+
+use data::Data;
+
+fn main() -> u8 {
+    let instance = Data::new(5);
+    instance.value + instance.sum()
+}
+"#;
+
+    let expected = Ok(());
+
+    let result = compile_entry_with_module(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_item_is_private() {
+    let input = r#"
+mod data;
+
+fn main() -> u8 {
+    data::helper()
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Scope(
+        Location::new(5, 11),
+        ScopeError::ItemIsPrivate {
+            name: "helper".to_owned(),
+        },
+    )));
+
+    let result = compile_entry_with_module(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_item_is_private_method() {
+    let input = r#"
+mod data;
+
+fn main() -> u8 {
+    let instance = data::Data::new(5);
+    instance.double()
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Scope(
+        Location::new(6, 14),
+        ScopeError::ItemIsPrivate {
+            name: "double".to_owned(),
+        },
+    )));
+
+    let result = compile_entry_with_module(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_field_is_private() {
+    let input = r#"
+mod data;
+
+fn main() -> u8 {
+    let instance = data::Data::new(5);
+    instance.secret
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Scope(
+        Location::new(6, 14),
+        ScopeError::FieldIsPrivate {
+            name: "secret".to_owned(),
+            structure: "Data".to_owned(),
+        },
+    )));
+
+    let result = compile_entry_with_module(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_field_is_private_literal() {
+    let input = r#"
+mod data;
+
+use data::Data;
+
+fn main() -> u8 {
+    let instance = Data {
+        value: 5,
+        secret: 0,
+    };
+    instance.value
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Scope(
+        Location::new(9, 9),
+        ScopeError::FieldIsPrivate {
+            name: "secret".to_owned(),
+            structure: "Data".to_owned(),
+        },
+    )));
+
+    let result = compile_entry_with_module(input);
+
+    assert_eq!(result, expected);
+}
 
 #[test]
 fn error_item_is_not_namespace() {
@@ -98,3 +262,97 @@ fn main() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn warning_unused_variable() {
+    let input = r#"
+fn main(witness: u8, unused: u8) -> u8 {
+    let mut used = witness;
+    let mut immutable = witness;
+    let _ignored = 5;
+    used += 1;
+    let local = immutable;
+    for i in 0..4 {}
+    used
+}
+"#;
+
+    let expected = Ok(vec![
+        Warning::UnusedVariable {
+            location: Location::new(2, 22),
+            name: "unused".to_owned(),
+        },
+        Warning::UnusedMutable {
+            location: Location::new(4, 13),
+            name: "immutable".to_owned(),
+        },
+        Warning::UnusedVariable {
+            location: Location::new(7, 9),
+            name: "local".to_owned(),
+        },
+        Warning::UnusedVariable {
+            location: Location::new(8, 9),
+            name: "i".to_owned(),
+        },
+    ]);
+
+    let result = crate::semantic::tests::compile_entry_warnings(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn warning_unused_item() {
+    let input = r#"
+use std::crypto::sha256;
+
+const UNUSED: u8 = 1;
+
+type Alias = u8;
+
+struct Data {
+    value: u8,
+}
+
+trait Zero {
+    fn zero() -> Self;
+}
+
+fn helper() -> u8 {
+    42
+}
+
+fn _ignored() {}
+
+fn main(data: Data) -> u8 {
+    data.value
+}
+"#;
+
+    let expected = Ok(vec![
+        Warning::UnusedImport {
+            location: Location::new(2, 18),
+            name: "sha256".to_owned(),
+        },
+        Warning::UnusedConstant {
+            location: Location::new(4, 7),
+            name: "UNUSED".to_owned(),
+        },
+        Warning::UnusedType {
+            location: Location::new(6, 6),
+            name: "Alias".to_owned(),
+        },
+        Warning::UnusedTrait {
+            location: Location::new(12, 7),
+            name: "Zero".to_owned(),
+        },
+        Warning::UnusedFunction {
+            location: Location::new(16, 4),
+            name: "helper".to_owned(),
+        },
+    ]);
+
+    let result = crate::semantic::tests::compile_entry_warnings(input);
+
+    assert_eq!(result, expected);
+}
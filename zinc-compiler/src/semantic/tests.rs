@@ -13,11 +13,19 @@ use std::rc::Rc;
 use crate::error::Error;
 use crate::semantic::analyzer::entry::Analyzer as EntryAnalyzer;
 use crate::semantic::analyzer::module::Analyzer as ModuleAnalyzer;
+use crate::semantic::scope::state::State as SemanticState;
 use crate::semantic::scope::Scope;
+use crate::warning::Warning;
 use crate::Parser;
 
 static PANIC_SYNTAX_ERROR: &str = "Syntax errors must be eliminated at this point";
 
+thread_local! {
+    /// The build state shared by the modules and the entry compiled by a test, so their types
+    /// get distinct IDs.
+    static STATE: Rc<SemanticState> = Rc::new(SemanticState::default());
+}
+
 pub(crate) fn compile_entry(input: &str) -> Result<(), Error> {
     compile_entry_with_dependencies(input, HashMap::new())
 }
@@ -26,22 +34,48 @@ pub(crate) fn compile_entry_with_dependencies(
     input: &str,
     dependencies: HashMap<String, Rc<RefCell<Scope>>>,
 ) -> Result<(), Error> {
-    let _intermediate = EntryAnalyzer::default().compile(
-        Parser::default()
-            .parse(input, None)
-            .expect(PANIC_SYNTAX_ERROR),
-        dependencies,
-    )?;
-
-    Ok(())
+    compile_entry_all_errors(input, dependencies)
+        .map(|_warnings| ())
+        .map_err(|mut errors| errors.remove(0))
+}
+
+pub(crate) fn compile_entry_warnings(input: &str) -> Result<Vec<Warning>, Error> {
+    compile_entry_all_errors(input, HashMap::new()).map_err(|mut errors| errors.remove(0))
+}
+
+pub(crate) fn compile_entry_all_errors(
+    input: &str,
+    dependencies: HashMap<String, Rc<RefCell<Scope>>>,
+) -> Result<Vec<Warning>, Vec<Error>> {
+    let (_intermediate, warnings) =
+        EntryAnalyzer::new_in_build(STATE.with(Rc::clone), Rc::new(RefCell::new(Vec::new())))
+            .compile(
+                Parser::default()
+                    .parse(input, None)
+                    .expect(PANIC_SYNTAX_ERROR),
+                dependencies,
+            )?;
+
+    Ok(warnings)
 }
 
 pub(crate) fn compile_module(input: &str) -> Result<Rc<RefCell<Scope>>, Error> {
-    let (scope, _intermediate) = ModuleAnalyzer::new().compile(
-        Parser::default()
-            .parse(input, None)
-            .expect(PANIC_SYNTAX_ERROR),
-    )?;
+    compile_module_with_dependencies(input, HashMap::new())
+}
+
+pub(crate) fn compile_module_with_dependencies(
+    input: &str,
+    dependencies: HashMap<String, Rc<RefCell<Scope>>>,
+) -> Result<Rc<RefCell<Scope>>, Error> {
+    let (scope, _intermediate, _warnings) =
+        ModuleAnalyzer::new_in_build(STATE.with(Rc::clone), Rc::new(RefCell::new(Vec::new())))
+            .compile(
+                Parser::default()
+                    .parse(input, None)
+                    .expect(PANIC_SYNTAX_ERROR),
+                dependencies,
+            )
+            .map_err(|mut errors| errors.remove(0))?;
 
     Ok(scope)
 }
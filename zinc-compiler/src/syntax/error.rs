@@ -176,6 +176,26 @@ impl Error {
         Self::ExpectedMatchPattern { location, found }
     }
 
+    ///
+    /// The error location, which is used to sort the errors before reporting them.
+    ///
+    pub fn location(&self) -> Location {
+        match self {
+            Self::ExpectedOneOf { location, .. }
+            | Self::ExpectedOneOfOrOperator { location, .. }
+            | Self::ExpectedIdentifier { location, .. }
+            | Self::ExpectedMutOrIdentifier { location, .. }
+            | Self::ExpectedFieldIdentifier { location, .. }
+            | Self::ExpectedType { location, .. }
+            | Self::ExpectedExpressionOrOperand { location, .. }
+            | Self::ExpectedTypeOrValue { location, .. }
+            | Self::ExpectedValue { location, .. }
+            | Self::ExpectedIntegerLiteral { location, .. }
+            | Self::ExpectedBindingPattern { location, .. }
+            | Self::ExpectedMatchPattern { location, .. } => *location,
+        }
+    }
+
     pub fn format_one_of(lexemes: &[&'static str]) -> String {
         lexemes
             .iter()
@@ -34,6 +34,7 @@ pub struct Parser {
     state: State,
     builder: BlockExpressionBuilder,
     next: Option<Token>,
+    depth: usize,
 }
 
 impl Parser {
@@ -48,6 +49,9 @@ impl Parser {
     /// }
     /// '
     ///
+    /// If a statement is erroneous, the rest of it is skipped and its error is stored in the
+    /// stream, so the following statements are parsed and their errors are reported as well.
+    ///
     pub fn parse(
         mut self,
         stream: Rc<RefCell<TokenStream>>,
@@ -62,6 +66,7 @@ impl Parser {
                             location,
                         } => {
                             self.builder.set_location(location);
+                            self.depth = stream.borrow().depth();
                             self.state = State::StatementOrBracketCurlyRight;
                         }
                         Token { lexeme, location } => {
@@ -82,8 +87,18 @@ impl Parser {
                         } => return Ok((self.builder.finish(), self.next.take())),
                         token => {
                             let (statement, next, is_unterminated) =
-                                FunctionLocalStatementParser::default()
-                                    .parse(stream.clone(), Some(token))?;
+                                match FunctionLocalStatementParser::default()
+                                    .parse(stream.clone(), Some(token))
+                                {
+                                    Ok(result) => result,
+                                    Err(Error::Syntax(error)) => {
+                                        if self.recover(stream.clone(), error)? {
+                                            return Ok((self.builder.finish(), None));
+                                        }
+                                        continue;
+                                    }
+                                    Err(error) => return Err(error),
+                                };
                             self.next = next;
                             match statement {
                                 FunctionLocalStatement::Expression(expression) => {
@@ -102,24 +117,45 @@ impl Parser {
                     }
                 }
                 State::BracketCurlyRight => {
-                    return match crate::syntax::parser::take_or_next(self.next.take(), stream)? {
+                    match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())? {
                         Token {
                             lexeme: Lexeme::Symbol(Symbol::BracketCurlyRight),
                             ..
-                        } => Ok((self.builder.finish(), self.next.take())),
+                        } => return Ok((self.builder.finish(), self.next.take())),
                         Token { lexeme, location } => {
-                            Err(Error::Syntax(SyntaxError::expected_one_of_or_operator(
+                            let error = SyntaxError::expected_one_of_or_operator(
                                 location,
                                 vec!["}"],
                                 lexeme,
                                 None,
-                            )))
+                            );
+                            if self.recover(stream.clone(), error)? {
+                                return Ok((self.builder.finish(), None));
+                            }
+                            self.state = State::StatementOrBracketCurlyRight;
                         }
                     }
                 }
             }
         }
     }
+
+    ///
+    /// Skips the rest of the erroneous statement and stores its error in the stream.
+    ///
+    /// Returns whether the block has been closed while skipping. If the input has ended,
+    /// the error is returned instead, since there is nothing left to recover.
+    ///
+    fn recover(&self, stream: Rc<RefCell<TokenStream>>, error: SyntaxError) -> Result<bool, Error> {
+        let mut stream = stream.borrow_mut();
+        match stream.skip_block_statement(self.depth) {
+            Ok(is_closed) => {
+                stream.push_recovered_error(error);
+                Ok(is_closed)
+            }
+            Err(_) => Err(Error::Syntax(error)),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -161,6 +161,10 @@ impl Parser {
                 Token {
                     lexeme: Lexeme::Keyword(keyword @ Keyword::SelfUppercase),
                     location,
+                }
+                | Token {
+                    lexeme: Lexeme::Keyword(keyword @ Keyword::SelfLowercase),
+                    location,
                 } => {
                     let mut builder = IdentifierBuilder::default();
                     builder.set_location(location);
@@ -309,6 +313,26 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn ok_self() {
+        let input = r#"self"#;
+
+        let expected = Ok((
+            ExpressionTree::new(
+                Location::new(1, 1),
+                ExpressionTreeNode::Operand(ExpressionOperand::Identifier(Identifier::new(
+                    Location::new(1, 1),
+                    "self".to_owned(),
+                ))),
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn ok_parenthesized() {
         let input = r#"(2 + 2)"#;
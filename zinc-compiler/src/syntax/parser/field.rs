@@ -7,6 +7,7 @@ use std::rc::Rc;
 
 use crate::error::Error;
 use crate::lexical::stream::TokenStream;
+use crate::lexical::token::lexeme::keyword::Keyword;
 use crate::lexical::token::lexeme::symbol::Symbol;
 use crate::lexical::token::lexeme::Lexeme;
 use crate::lexical::token::Token;
@@ -30,13 +31,24 @@ impl Parser {
     /// Parses a structure field.
     ///
     /// 'a: u8'
+    /// 'pub a: u8'
     ///
     pub fn parse(
         mut self,
         stream: Rc<RefCell<TokenStream>>,
         mut initial: Option<Token>,
     ) -> Result<(Field, Option<Token>), Error> {
-        match crate::syntax::parser::take_or_next(initial.take(), stream.clone())? {
+        let mut token = crate::syntax::parser::take_or_next(initial.take(), stream.clone())?;
+        if let Token {
+            lexeme: Lexeme::Keyword(Keyword::Pub),
+            ..
+        } = token
+        {
+            self.builder.set_public();
+            token = crate::syntax::parser::take_or_next(None, stream.clone())?;
+        }
+
+        match token {
             Token {
                 lexeme: Lexeme::Identifier(identifier),
                 location,
@@ -109,6 +121,23 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn ok_public() {
+        let input = "pub id: u232";
+
+        let mut field = Field::new(
+            Location::new(1, 5),
+            Identifier::new(Location::new(1, 5), "id".to_owned()),
+            Type::new(Location::new(1, 9), TypeVariant::integer_unsigned(232)),
+        );
+        field.is_public = true;
+        let expected = Ok((field, None));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn error_expected_type() {
         let input = "id";
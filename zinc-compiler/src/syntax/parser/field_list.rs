@@ -7,6 +7,7 @@ use std::rc::Rc;
 
 use crate::error::Error;
 use crate::lexical::stream::TokenStream;
+use crate::lexical::token::lexeme::keyword::Keyword;
 use crate::lexical::token::lexeme::symbol::Symbol;
 use crate::lexical::token::lexeme::Lexeme;
 use crate::lexical::token::Token;
@@ -23,7 +24,7 @@ impl Parser {
     ///
     /// Parses a structure field list.
     ///
-    /// 'a: u8, b: field, c: (bool, u8)'
+    /// 'a: u8, pub b: field, c: (bool, u8)'
     ///
     pub fn parse(
         mut self,
@@ -37,6 +38,12 @@ impl Parser {
                 Token {
                     lexeme: Lexeme::Identifier(_),
                     ..
+                }
+                | token
+                @
+                Token {
+                    lexeme: Lexeme::Keyword(Keyword::Pub),
+                    ..
                 } => {
                     let (field, next) =
                         FieldParser::default().parse(stream.clone(), Some(token))?;
@@ -0,0 +1,273 @@
+//!
+//! The generic parameter parser.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::error::Error;
+use crate::lexical::stream::TokenStream;
+use crate::lexical::token::lexeme::keyword::Keyword;
+use crate::lexical::token::lexeme::symbol::Symbol;
+use crate::lexical::token::lexeme::Lexeme;
+use crate::lexical::token::Token;
+use crate::syntax::error::Error as SyntaxError;
+use crate::syntax::parser::r#type::Parser as TypeParser;
+use crate::syntax::tree::generic::builder::Builder as GenericBuilder;
+use crate::syntax::tree::generic::Generic;
+use crate::syntax::tree::identifier::Identifier;
+
+static HINT_EXPECTED_IDENTIFIER: &str =
+    "generic parameter must be an identifier, e.g. `T` or `const N: u64`";
+static HINT_EXPECTED_BOUND: &str = "generic parameter bound must be a trait, e.g. `T: Zero`";
+static HINT_EXPECTED_TYPE: &str =
+    "constant generic parameter must have a type, e.g. `const N: u64`";
+
+#[derive(Default)]
+pub struct Parser {
+    builder: GenericBuilder,
+    is_constant: bool,
+}
+
+impl Parser {
+    ///
+    /// Parses a generic parameter.
+    ///
+    /// 'T'
+    /// 'T: Zero + Hash'
+    /// 'const N: u64'
+    ///
+    pub fn parse(
+        mut self,
+        stream: Rc<RefCell<TokenStream>>,
+        mut initial: Option<Token>,
+    ) -> Result<(Generic, Option<Token>), Error> {
+        let mut next = match crate::syntax::parser::take_or_next(initial.take(), stream.clone())? {
+            Token {
+                lexeme: Lexeme::Keyword(Keyword::Const),
+                location,
+            } => {
+                self.builder.set_location(location);
+                self.is_constant = true;
+                None
+            }
+            token => Some(token),
+        };
+
+        match crate::syntax::parser::take_or_next(next.take(), stream.clone())? {
+            Token {
+                lexeme: Lexeme::Identifier(identifier),
+                location,
+            } => {
+                if !self.is_constant {
+                    self.builder.set_location(location);
+                }
+                self.builder
+                    .set_identifier(Identifier::new(location, identifier.inner));
+            }
+            Token { lexeme, location } => {
+                return Err(Error::Syntax(SyntaxError::expected_identifier(
+                    location,
+                    lexeme,
+                    Some(HINT_EXPECTED_IDENTIFIER),
+                )));
+            }
+        }
+
+        if !self.is_constant {
+            return self.bounds(stream);
+        }
+
+        match crate::syntax::parser::take_or_next(None, stream.clone())? {
+            Token {
+                lexeme: Lexeme::Symbol(Symbol::Colon),
+                ..
+            } => {}
+            Token { lexeme, location } => {
+                return Err(Error::Syntax(SyntaxError::expected_type(
+                    location,
+                    lexeme,
+                    Some(HINT_EXPECTED_TYPE),
+                )));
+            }
+        }
+
+        let (r#type, next) = TypeParser::default().parse(stream, None)?;
+        self.builder.set_type(r#type);
+        Ok((self.builder.finish(), next))
+    }
+
+    ///
+    /// Parses the optional type parameter trait bounds.
+    ///
+    /// ': Zero + Hash'
+    ///
+    fn bounds(
+        mut self,
+        stream: Rc<RefCell<TokenStream>>,
+    ) -> Result<(Generic, Option<Token>), Error> {
+        match crate::syntax::parser::take_or_next(None, stream.clone())? {
+            Token {
+                lexeme: Lexeme::Symbol(Symbol::Colon),
+                ..
+            } => {}
+            token => return Ok((self.builder.finish(), Some(token))),
+        }
+
+        loop {
+            match crate::syntax::parser::take_or_next(None, stream.clone())? {
+                Token {
+                    lexeme: Lexeme::Identifier(identifier),
+                    location,
+                } => self
+                    .builder
+                    .push_bound(Identifier::new(location, identifier.inner)),
+                Token { lexeme, location } => {
+                    return Err(Error::Syntax(SyntaxError::expected_identifier(
+                        location,
+                        lexeme,
+                        Some(HINT_EXPECTED_BOUND),
+                    )));
+                }
+            }
+
+            match crate::syntax::parser::take_or_next(None, stream.clone())? {
+                Token {
+                    lexeme: Lexeme::Symbol(Symbol::Plus),
+                    ..
+                } => {}
+                token => return Ok((self.builder.finish(), Some(token))),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use super::Parser;
+    use crate::error::Error;
+    use crate::lexical::stream::TokenStream;
+    use crate::lexical::token::lexeme::symbol::Symbol;
+    use crate::lexical::token::lexeme::Lexeme;
+    use crate::lexical::token::location::Location;
+    use crate::lexical::token::Token;
+    use crate::syntax::error::Error as SyntaxError;
+    use crate::syntax::parser::generic::HINT_EXPECTED_BOUND;
+    use crate::syntax::parser::generic::HINT_EXPECTED_IDENTIFIER;
+    use crate::syntax::parser::generic::HINT_EXPECTED_TYPE;
+    use crate::syntax::tree::generic::Generic;
+    use crate::syntax::tree::identifier::Identifier;
+    use crate::syntax::tree::r#type::variant::Variant as TypeVariant;
+    use crate::syntax::tree::r#type::Type;
+
+    #[test]
+    fn ok_type() {
+        let input = "T";
+
+        let expected = Ok((
+            Generic::new(
+                Location::new(1, 1),
+                Identifier::new(Location::new(1, 1), "T".to_owned()),
+                None,
+                vec![],
+            ),
+            Some(Token::new(Lexeme::Eof, Location::new(1, 2))),
+        ));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_type_bounds() {
+        let input = "T: Zero + Hash";
+
+        let expected = Ok((
+            Generic::new(
+                Location::new(1, 1),
+                Identifier::new(Location::new(1, 1), "T".to_owned()),
+                None,
+                vec![
+                    Identifier::new(Location::new(1, 4), "Zero".to_owned()),
+                    Identifier::new(Location::new(1, 11), "Hash".to_owned()),
+                ],
+            ),
+            Some(Token::new(Lexeme::Eof, Location::new(1, 15))),
+        ));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_constant() {
+        let input = "const N: u64";
+
+        let expected = Ok((
+            Generic::new(
+                Location::new(1, 1),
+                Identifier::new(Location::new(1, 7), "N".to_owned()),
+                Some(Type::new(
+                    Location::new(1, 10),
+                    TypeVariant::integer_unsigned(64),
+                )),
+                vec![],
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_identifier() {
+        let input = "const >";
+
+        let expected = Err(Error::Syntax(SyntaxError::expected_identifier(
+            Location::new(1, 7),
+            Lexeme::Symbol(Symbol::Greater),
+            Some(HINT_EXPECTED_IDENTIFIER),
+        )));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_bound() {
+        let input = "T: >";
+
+        let expected = Err(Error::Syntax(SyntaxError::expected_identifier(
+            Location::new(1, 4),
+            Lexeme::Symbol(Symbol::Greater),
+            Some(HINT_EXPECTED_BOUND),
+        )));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_type() {
+        let input = "const N>";
+
+        let expected = Err(Error::Syntax(SyntaxError::expected_type(
+            Location::new(1, 8),
+            Lexeme::Symbol(Symbol::Greater),
+            Some(HINT_EXPECTED_TYPE),
+        )));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,178 @@
+//!
+//! The generic parameter list parser.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::error::Error;
+use crate::lexical::stream::TokenStream;
+use crate::lexical::token::lexeme::symbol::Symbol;
+use crate::lexical::token::lexeme::Lexeme;
+use crate::lexical::token::Token;
+use crate::syntax::error::Error as SyntaxError;
+use crate::syntax::parser::generic::Parser as GenericParser;
+use crate::syntax::tree::generic::Generic;
+
+#[derive(Debug, Clone, Copy)]
+pub enum State {
+    Lesser,
+    Generic,
+    CommaOrGreater,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        State::Lesser
+    }
+}
+
+#[derive(Default)]
+pub struct Parser {
+    state: State,
+    generics: Vec<Generic>,
+    next: Option<Token>,
+}
+
+impl Parser {
+    ///
+    /// Parses a generic parameter list in angle brackets.
+    ///
+    /// '<T, U, const N: u64>'
+    ///
+    pub fn parse(
+        mut self,
+        stream: Rc<RefCell<TokenStream>>,
+        mut initial: Option<Token>,
+    ) -> Result<(Vec<Generic>, Option<Token>), Error> {
+        loop {
+            match self.state {
+                State::Lesser => {
+                    match crate::syntax::parser::take_or_next(initial.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Lesser),
+                            ..
+                        } => self.state = State::Generic,
+                        Token { lexeme, location } => {
+                            return Err(Error::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec!["<"],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
+                }
+                State::Generic => {
+                    let (generic, next) = GenericParser::default().parse(stream.clone(), None)?;
+                    self.generics.push(generic);
+                    self.next = next;
+                    self.state = State::CommaOrGreater;
+                }
+                State::CommaOrGreater => {
+                    match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Comma),
+                            ..
+                        } => self.state = State::Generic,
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Greater),
+                            ..
+                        } => return Ok((self.generics, None)),
+                        Token { lexeme, location } => {
+                            return Err(Error::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec![",", ">"],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use super::Parser;
+    use crate::error::Error;
+    use crate::lexical::stream::TokenStream;
+    use crate::lexical::token::lexeme::identifier::Identifier as LexicalIdentifier;
+    use crate::lexical::token::lexeme::Lexeme;
+    use crate::lexical::token::location::Location;
+    use crate::syntax::error::Error as SyntaxError;
+    use crate::syntax::tree::generic::Generic;
+    use crate::syntax::tree::identifier::Identifier;
+    use crate::syntax::tree::r#type::variant::Variant as TypeVariant;
+    use crate::syntax::tree::r#type::Type;
+
+    #[test]
+    fn ok_single() {
+        let input = "<T>";
+
+        let expected = Ok((
+            vec![Generic::new(
+                Location::new(1, 2),
+                Identifier::new(Location::new(1, 2), "T".to_owned()),
+                None,
+                vec![],
+            )],
+            None,
+        ));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_multiple() {
+        let input = "<T, const N: u64>";
+
+        let expected = Ok((
+            vec![
+                Generic::new(
+                    Location::new(1, 2),
+                    Identifier::new(Location::new(1, 2), "T".to_owned()),
+                    None,
+                    vec![],
+                ),
+                Generic::new(
+                    Location::new(1, 5),
+                    Identifier::new(Location::new(1, 11), "N".to_owned()),
+                    Some(Type::new(
+                        Location::new(1, 14),
+                        TypeVariant::integer_unsigned(64),
+                    )),
+                    vec![],
+                ),
+            ],
+            None,
+        ));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_comma_or_greater() {
+        let input = "<T U>";
+
+        let expected = Err(Error::Syntax(SyntaxError::expected_one_of(
+            Location::new(1, 4),
+            vec![",", ">"],
+            Lexeme::Identifier(LexicalIdentifier::new("U".to_owned())),
+            None,
+        )));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+}
@@ -5,6 +5,8 @@
 pub mod expression;
 pub mod field;
 pub mod field_list;
+pub mod generic;
+pub mod generic_list;
 pub mod pattern_binding;
 pub mod pattern_binding_list;
 pub mod pattern_match;
@@ -32,7 +34,12 @@ impl Parser {
     ///
     /// The top-level parser. Parses a list of module level statements.
     ///
-    pub fn parse(mut self, input: &str, file: Option<usize>) -> Result<Tree, Error> {
+    /// If a statement is erroneous, the parser skips the rest of it and goes on with the next
+    /// one, so all the syntax errors are reported at once. The statements inside blocks are
+    /// recovered from by the block parser, which stores their errors in the token stream.
+    /// The lexical errors cannot be recovered from, so the parsing stops at the first one.
+    ///
+    pub fn parse(mut self, input: &str, file: Option<usize>) -> Result<Tree, Vec<Error>> {
         let stream = match file {
             Some(file) => TokenStream::new_with_file(input, file),
             None => TokenStream::new(input),
@@ -40,22 +47,54 @@ impl Parser {
         let stream = Rc::new(RefCell::new(stream));
 
         let mut statements = Vec::new();
+        let mut errors = Vec::new();
         loop {
-            match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())? {
-                Token {
+            let token = match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())
+            {
+                Ok(Token {
                     lexeme: Lexeme::Eof,
                     ..
-                } => break,
-                token => {
-                    let (statement, next) =
-                        ModuleLocalStatementParser::default().parse(stream.clone(), Some(token))?;
+                }) => break,
+                Ok(token) => token,
+                Err(error) => {
+                    errors.push(error);
+                    break;
+                }
+            };
+
+            match ModuleLocalStatementParser::default().parse(stream.clone(), Some(token)) {
+                Ok((statement, next)) => {
                     self.next = next;
                     statements.push(statement);
                 }
+                Err(error @ Error::Lexical(_)) => {
+                    errors.push(error);
+                    break;
+                }
+                Err(error) => {
+                    errors.push(error);
+                    if let Err(error) = stream.borrow_mut().skip_statement() {
+                        errors.push(Error::Lexical(error));
+                        break;
+                    }
+                }
             }
         }
 
-        Ok(Tree { statements })
+        errors.extend(
+            stream
+                .borrow_mut()
+                .take_recovered_errors()
+                .into_iter()
+                .map(Error::Syntax),
+        );
+        errors.sort_by_key(Error::location);
+
+        if errors.is_empty() {
+            Ok(Tree { statements })
+        } else {
+            Err(errors)
+        }
     }
 }
 
@@ -68,3 +107,88 @@ pub fn take_or_next(
         None => Ok(stream.borrow_mut().next()?),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Parser;
+    use crate::error::Error;
+    use crate::lexical::token::lexeme::keyword::Keyword;
+    use crate::lexical::token::lexeme::symbol::Symbol;
+    use crate::lexical::token::lexeme::Lexeme;
+    use crate::lexical::token::location::Location;
+    use crate::syntax::error::Error as SyntaxError;
+
+    #[test]
+    fn error_multiple() {
+        let input = r#"
+const A: u8 = ;
+
+fn main() {
+    let value = 5
+}
+
+struct Data {
+    value: u8,
+}
+
+const B: u8 = 1 +;
+"#;
+
+        let expected = Err(vec![
+            Error::Syntax(SyntaxError::ExpectedExpressionOrOperand {
+                location: Location::new(2, 15),
+                found: Lexeme::Symbol(Symbol::Semicolon),
+            }),
+            Error::Syntax(SyntaxError::ExpectedOneOf {
+                location: Location::new(6, 1),
+                expected: "`;`".to_owned(),
+                found: Lexeme::Symbol(Symbol::BracketCurlyRight),
+                help: None,
+            }),
+            Error::Syntax(SyntaxError::ExpectedExpressionOrOperand {
+                location: Location::new(12, 18),
+                found: Lexeme::Symbol(Symbol::Semicolon),
+            }),
+        ]);
+
+        let result = Parser::default().parse(input, None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_multiple_in_function() {
+        let input = r#"
+fn main() -> u8 {
+    let a = ;
+    let b = 5
+    let c: u8 = 10;
+    if a {
+        let d = 1 +;
+    };
+    a + b
+}
+"#;
+
+        let expected = Err(vec![
+            Error::Syntax(SyntaxError::ExpectedExpressionOrOperand {
+                location: Location::new(3, 13),
+                found: Lexeme::Symbol(Symbol::Semicolon),
+            }),
+            Error::Syntax(SyntaxError::ExpectedOneOf {
+                location: Location::new(5, 5),
+                expected: "`;`".to_owned(),
+                found: Lexeme::Keyword(Keyword::Let),
+                help: None,
+            }),
+            Error::Syntax(SyntaxError::ExpectedExpressionOrOperand {
+                location: Location::new(7, 20),
+                found: Lexeme::Symbol(Symbol::Semicolon),
+            }),
+        ]);
+
+        let result = Parser::default().parse(input, None);
+
+        assert_eq!(result, expected);
+    }
+}
@@ -13,15 +13,21 @@ use crate::lexical::token::lexeme::Lexeme;
 use crate::lexical::token::Token;
 use crate::syntax::error::Error as SyntaxError;
 use crate::syntax::parser::r#type::Parser as TypeParser;
+use crate::syntax::tree::expression::tree::node::operand::Operand as ExpressionOperand;
+use crate::syntax::tree::expression::tree::node::Node as ExpressionTreeNode;
+use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
 use crate::syntax::tree::identifier::Identifier;
 use crate::syntax::tree::pattern_binding::builder::Builder as BindingPatternBuilder;
 use crate::syntax::tree::pattern_binding::Pattern as BindingPattern;
+use crate::syntax::tree::r#type::variant::Variant as TypeVariant;
+use crate::syntax::tree::r#type::Type;
 
 static HINT_EXPECTED_TYPE: &str =
     "function argument must have a type, e.g. `fn sum(a: u8, b: u8) {}`";
 
 #[derive(Debug, Clone, Copy)]
 pub enum State {
+    KeywordPubOrKeywordMutOrIdentifierOrWildcard,
     KeywordMutOrIdentifierOrWildcard,
     IdentifierOrWildcard,
     Colon,
@@ -30,7 +36,7 @@ pub enum State {
 
 impl Default for State {
     fn default() -> Self {
-        State::KeywordMutOrIdentifierOrWildcard
+        State::KeywordPubOrKeywordMutOrIdentifierOrWildcard
     }
 }
 
@@ -46,6 +52,8 @@ impl Parser {
     /// Parses a binding pattern.
     ///
     /// 'mut a: u8'
+    /// 'pub a: u8'
+    /// 'mut self'
     ///
     pub fn parse(
         mut self,
@@ -54,18 +62,33 @@ impl Parser {
     ) -> Result<(BindingPattern, Option<Token>), Error> {
         loop {
             match self.state {
-                State::KeywordMutOrIdentifierOrWildcard => {
+                State::KeywordPubOrKeywordMutOrIdentifierOrWildcard => {
                     match crate::syntax::parser::take_or_next(initial.take(), stream.clone())? {
                         Token {
-                            lexeme: Lexeme::Keyword(Keyword::Mut),
+                            lexeme: Lexeme::Keyword(Keyword::Pub),
                             location,
                         } => {
                             self.builder.set_location(location);
+                            self.builder.set_is_public();
+                            self.state = State::KeywordMutOrIdentifierOrWildcard;
+                        }
+                        token => {
+                            self.builder.set_location(token.location);
+                            self.next = Some(token);
+                            self.state = State::KeywordMutOrIdentifierOrWildcard;
+                        }
+                    }
+                }
+                State::KeywordMutOrIdentifierOrWildcard => {
+                    match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Keyword(Keyword::Mut),
+                            ..
+                        } => {
                             self.builder.set_is_binding_mutable();
                             self.state = State::IdentifierOrWildcard;
                         }
                         token => {
-                            self.builder.set_location(token.location);
                             self.next = Some(token);
                             self.state = State::IdentifierOrWildcard;
                         }
@@ -81,6 +104,24 @@ impl Parser {
                                 .set_binding(Identifier::new(location, identifier.inner));
                             self.state = State::Colon;
                         }
+                        Token {
+                            lexeme: Lexeme::Keyword(Keyword::SelfLowercase),
+                            location,
+                        } => {
+                            let self_type = TypeVariant::alias(ExpressionTree::new(
+                                location,
+                                ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                                    Identifier::new(location, Keyword::SelfUppercase.to_string()),
+                                )),
+                            ));
+
+                            self.builder.set_binding(Identifier::new(
+                                location,
+                                Keyword::SelfLowercase.to_string(),
+                            ));
+                            self.builder.set_type(Type::new(location, self_type));
+                            return Ok((self.builder.finish(), None));
+                        }
                         Token {
                             lexeme: Lexeme::Symbol(Symbol::Underscore),
                             ..
@@ -132,6 +173,9 @@ mod tests {
     use crate::lexical::token::lexeme::Lexeme;
     use crate::lexical::token::location::Location;
     use crate::syntax::error::Error as SyntaxError;
+    use crate::syntax::tree::expression::tree::node::operand::Operand as ExpressionOperand;
+    use crate::syntax::tree::expression::tree::node::Node as ExpressionTreeNode;
+    use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
     use crate::syntax::tree::identifier::Identifier;
     use crate::syntax::tree::pattern_binding::variant::Variant as BindingPatternVariant;
     use crate::syntax::tree::pattern_binding::Pattern as BindingPattern;
@@ -180,6 +224,56 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn ok_mutable_self() {
+        let input = "mut self";
+
+        let expected = Ok((
+            BindingPattern::new(
+                Location::new(1, 1),
+                BindingPatternVariant::MutableBinding(Identifier::new(
+                    Location::new(1, 5),
+                    "self".to_owned(),
+                )),
+                Type::new(
+                    Location::new(1, 5),
+                    TypeVariant::alias(ExpressionTree::new(
+                        Location::new(1, 5),
+                        ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                            Identifier::new(Location::new(1, 5), "Self".to_owned()),
+                        )),
+                    )),
+                ),
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_public_binding() {
+        let input = "pub value: u8";
+
+        let expected = Ok((
+            BindingPattern::new_public(
+                Location::new(1, 1),
+                BindingPatternVariant::Binding(Identifier::new(
+                    Location::new(1, 5),
+                    "value".to_owned(),
+                )),
+                Type::new(Location::new(1, 12), TypeVariant::integer_unsigned(8)),
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn ok_wildcard() {
         let input = "_: u8";
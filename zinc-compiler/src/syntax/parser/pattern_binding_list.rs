@@ -38,6 +38,7 @@ impl Parser {
     /// Parses a binding pattern list.
     ///
     /// 'mut a: u8, b: field, c: (bool, bool)'
+    /// 'self, a: u8'
     ///
     pub fn parse(
         mut self,
@@ -48,6 +49,17 @@ impl Parser {
             match self.state {
                 State::BindingPattern => {
                     match crate::syntax::parser::take_or_next(initial.take(), stream.clone())? {
+                        token
+                        @
+                        Token {
+                            lexeme: Lexeme::Keyword(Keyword::Pub),
+                            ..
+                        } => {
+                            let (pattern, next) = BindingPatternParser::default()
+                                .parse(stream.clone(), Some(token))?;
+                            self.next = next;
+                            self.patterns.push(pattern);
+                        }
                         token
                         @
                         Token {
@@ -72,6 +84,17 @@ impl Parser {
                         }
                         token
                         @
+                        Token {
+                            lexeme: Lexeme::Keyword(Keyword::SelfLowercase),
+                            ..
+                        } => {
+                            let (pattern, next) = BindingPatternParser::default()
+                                .parse(stream.clone(), Some(token))?;
+                            self.next = next;
+                            self.patterns.push(pattern);
+                        }
+                        token
+                        @
                         Token {
                             lexeme: Lexeme::Symbol(Symbol::Underscore),
                             ..
@@ -12,12 +12,15 @@ use crate::lexical::token::lexeme::symbol::Symbol;
 use crate::lexical::token::lexeme::Lexeme;
 use crate::lexical::token::Token;
 use crate::syntax::error::Error as SyntaxError;
-use crate::syntax::parser::expression::terminal::Parser as TerminalOperandParser;
+use crate::syntax::tree::expression::tree::node::operand::Operand as ExpressionOperand;
 use crate::syntax::tree::expression::tree::node::operator::Operator as ExpressionOperator;
+use crate::syntax::tree::expression::tree::node::Node as ExpressionTreeNode;
+use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
 use crate::syntax::tree::identifier::Identifier;
 use crate::syntax::tree::literal::boolean::Literal as BooleanLiteral;
 use crate::syntax::tree::literal::integer::Literal as IntegerLiteral;
 use crate::syntax::tree::pattern_match::builder::Builder as MatchPatternBuilder;
+use crate::syntax::tree::pattern_match::variant::Variant as MatchPatternVariant;
 use crate::syntax::tree::pattern_match::Pattern as MatchPattern;
 
 #[derive(Debug, Clone, Copy)]
@@ -25,6 +28,12 @@ pub enum State {
     Start,
     PathOperatorOrEnd,
     PathOperand,
+    TupleElementOrParenthesisRight,
+    TupleCommaOrParenthesisRight,
+    StructureFieldOrBracketRight,
+    StructureColonOrCommaOrBracketRight,
+    StructureCommaOrBracketRight,
+    StructureBracketRight,
 }
 
 impl Default for State {
@@ -37,6 +46,7 @@ impl Default for State {
 pub struct Parser {
     state: State,
     builder: MatchPatternBuilder,
+    field: Option<Identifier>,
     next: Option<Token>,
 }
 
@@ -49,6 +59,9 @@ impl Parser {
     /// 'variable'
     /// 'Path::To::Item'
     /// '_'
+    /// '(State::Idle, 1, value)'
+    /// 'Operation::Burn(amount)'
+    /// 'Data { a: 42, b: (true, _), c, .. }'
     ///
     pub fn parse(
         mut self,
@@ -94,6 +107,14 @@ impl Parser {
                             self.builder.set_is_wildcard();
                             return Ok((self.builder.finish(), None));
                         }
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::ParenthesisLeft),
+                            location,
+                        } => {
+                            self.builder.set_location(location);
+                            self.builder.set_is_tuple();
+                            self.state = State::TupleElementOrParenthesisRight;
+                        }
                         Token { lexeme, location } => {
                             return Err(Error::Syntax(SyntaxError::expected_match_pattern(
                                 location, lexeme,
@@ -111,15 +132,195 @@ impl Parser {
                                 .push_path_operator(ExpressionOperator::Path, location);
                             self.state = State::PathOperand;
                         }
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::ParenthesisLeft),
+                            ..
+                        } => {
+                            self.builder.set_is_tuple_variant();
+                            self.state = State::TupleElementOrParenthesisRight;
+                        }
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::BracketCurlyLeft),
+                            ..
+                        } => {
+                            self.builder.set_is_structure();
+                            self.state = State::StructureFieldOrBracketRight;
+                        }
                         token => return Ok((self.builder.finish(), Some(token))),
                     }
                 }
                 State::PathOperand => {
-                    let (expression, next) =
-                        TerminalOperandParser::default().parse(stream.clone(), self.next.take())?;
-                    self.next = next;
-                    self.builder.push_path_element(expression);
-                    self.state = State::PathOperatorOrEnd;
+                    match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Identifier(identifier),
+                            location,
+                        } => {
+                            self.builder.push_path_element(ExpressionTree::new(
+                                location,
+                                ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                                    Identifier::new(location, identifier.inner),
+                                )),
+                            ));
+                            self.state = State::PathOperatorOrEnd;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(Error::Syntax(SyntaxError::expected_identifier(
+                                location, lexeme, None,
+                            )));
+                        }
+                    }
+                }
+                State::TupleElementOrParenthesisRight => {
+                    match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::ParenthesisRight),
+                            ..
+                        } => return Ok((self.builder.finish(), None)),
+                        token => {
+                            let (pattern, next) =
+                                Self::default().parse(stream.clone(), Some(token))?;
+                            self.next = next;
+                            self.builder.push_tuple_element(pattern);
+                            self.state = State::TupleCommaOrParenthesisRight;
+                        }
+                    }
+                }
+                State::TupleCommaOrParenthesisRight => {
+                    match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Comma),
+                            ..
+                        } => {
+                            self.builder.set_tuple_comma();
+                            self.state = State::TupleElementOrParenthesisRight;
+                        }
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::ParenthesisRight),
+                            ..
+                        } => return Ok((self.builder.finish(), None)),
+                        Token { lexeme, location } => {
+                            return Err(Error::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec![",", ")"],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
+                }
+                State::StructureFieldOrBracketRight => {
+                    match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::BracketCurlyRight),
+                            ..
+                        } => return Ok((self.builder.finish(), None)),
+                        Token {
+                            lexeme: Lexeme::Identifier(identifier),
+                            location,
+                        } => {
+                            self.field = Some(Identifier::new(location, identifier.inner));
+                            self.state = State::StructureColonOrCommaOrBracketRight;
+                        }
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::DoubleDot),
+                            ..
+                        } => {
+                            self.builder.set_is_structure_rest();
+                            self.state = State::StructureBracketRight;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(Error::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec!["{identifier}", "..", "}"],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
+                }
+                State::StructureColonOrCommaOrBracketRight => {
+                    let field = self
+                        .field
+                        .take()
+                        .expect(crate::PANIC_VALIDATED_DURING_SYNTAX_ANALYSIS);
+
+                    match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Colon),
+                            ..
+                        } => {
+                            let (pattern, next) = Self::default().parse(stream.clone(), None)?;
+                            self.next = next;
+                            self.builder.push_structure_field(field, pattern);
+                            self.state = State::StructureCommaOrBracketRight;
+                        }
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Comma),
+                            ..
+                        } => {
+                            let pattern = MatchPattern::new(
+                                field.location,
+                                MatchPatternVariant::Binding(field.clone()),
+                            );
+                            self.builder.push_structure_field(field, pattern);
+                            self.state = State::StructureFieldOrBracketRight;
+                        }
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::BracketCurlyRight),
+                            ..
+                        } => {
+                            let pattern = MatchPattern::new(
+                                field.location,
+                                MatchPatternVariant::Binding(field.clone()),
+                            );
+                            self.builder.push_structure_field(field, pattern);
+                            return Ok((self.builder.finish(), None));
+                        }
+                        Token { lexeme, location } => {
+                            return Err(Error::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec![":", ",", "}"],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
+                }
+                State::StructureCommaOrBracketRight => {
+                    match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Comma),
+                            ..
+                        } => self.state = State::StructureFieldOrBracketRight,
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::BracketCurlyRight),
+                            ..
+                        } => return Ok((self.builder.finish(), None)),
+                        Token { lexeme, location } => {
+                            return Err(Error::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec![",", "}"],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
+                }
+                State::StructureBracketRight => {
+                    match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::BracketCurlyRight),
+                            ..
+                        } => return Ok((self.builder.finish(), None)),
+                        Token { lexeme, location } => {
+                            return Err(Error::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec!["}"],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
                 }
             }
         }
@@ -131,13 +332,16 @@ mod tests {
     use std::cell::RefCell;
     use std::rc::Rc;
 
+    use super::Error;
     use super::Parser;
     use crate::lexical::stream::TokenStream;
     use crate::lexical::token::lexeme::literal::boolean::Boolean as LexicalBooleanLiteral;
     use crate::lexical::token::lexeme::literal::integer::Integer as LexicalIntegerLiteral;
+    use crate::lexical::token::lexeme::literal::Literal as LexicalLiteral;
     use crate::lexical::token::lexeme::Lexeme;
     use crate::lexical::token::location::Location;
     use crate::lexical::token::Token;
+    use crate::syntax::error::Error as SyntaxError;
     use crate::syntax::tree::expression::tree::node::operand::Operand as ExpressionOperand;
     use crate::syntax::tree::expression::tree::node::operator::Operator as ExpressionOperator;
     use crate::syntax::tree::expression::tree::node::Node as ExpressionTreeNode;
@@ -263,4 +467,120 @@ mod tests {
 
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn ok_tuple() {
+        let input = "(true, value, _)";
+
+        let expected = Ok((
+            MatchPattern::new(
+                Location::new(1, 1),
+                MatchPatternVariant::Tuple(vec![
+                    MatchPattern::new(
+                        Location::new(1, 2),
+                        MatchPatternVariant::BooleanLiteral(BooleanLiteral::new(
+                            Location::new(1, 2),
+                            LexicalBooleanLiteral::r#true(),
+                        )),
+                    ),
+                    MatchPattern::new(
+                        Location::new(1, 8),
+                        MatchPatternVariant::Binding(Identifier::new(
+                            Location::new(1, 8),
+                            "value".to_owned(),
+                        )),
+                    ),
+                    MatchPattern::new(Location::new(1, 15), MatchPatternVariant::Wildcard),
+                ]),
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_parenthesized() {
+        let input = "(value)";
+
+        let expected = Ok((
+            MatchPattern::new(
+                Location::new(1, 2),
+                MatchPatternVariant::Binding(Identifier::new(
+                    Location::new(1, 2),
+                    "value".to_owned(),
+                )),
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_structure() {
+        let input = "Data { a: 42, b, .. }";
+
+        let expected = Ok((
+            MatchPattern::new(
+                Location::new(1, 1),
+                MatchPatternVariant::Structure {
+                    path: ExpressionTree::new(
+                        Location::new(1, 1),
+                        ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                            Identifier::new(Location::new(1, 1), "Data".to_owned()),
+                        )),
+                    ),
+                    fields: vec![
+                        (
+                            Identifier::new(Location::new(1, 8), "a".to_owned()),
+                            MatchPattern::new(
+                                Location::new(1, 11),
+                                MatchPatternVariant::IntegerLiteral(IntegerLiteral::new(
+                                    Location::new(1, 11),
+                                    LexicalIntegerLiteral::new_decimal("42".to_owned()),
+                                )),
+                            ),
+                        ),
+                        (
+                            Identifier::new(Location::new(1, 15), "b".to_owned()),
+                            MatchPattern::new(
+                                Location::new(1, 15),
+                                MatchPatternVariant::Binding(Identifier::new(
+                                    Location::new(1, 15),
+                                    "b".to_owned(),
+                                )),
+                            ),
+                        ),
+                    ],
+                    is_rest: true,
+                },
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_comma_or_parenthesis_right() {
+        let input = "(true false)";
+
+        let expected: Result<_, Error> = Err(Error::Syntax(SyntaxError::expected_one_of(
+            Location::new(1, 7),
+            vec![",", ")"],
+            Lexeme::Literal(LexicalLiteral::Boolean(LexicalBooleanLiteral::r#false())),
+            None,
+        )));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
 }
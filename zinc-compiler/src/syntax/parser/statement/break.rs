@@ -0,0 +1,127 @@
+//!
+//! The break statement parser.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::error::Error;
+use crate::lexical::stream::TokenStream;
+use crate::lexical::token::lexeme::keyword::Keyword;
+use crate::lexical::token::lexeme::symbol::Symbol;
+use crate::lexical::token::lexeme::Lexeme;
+use crate::lexical::token::Token;
+use crate::syntax::error::Error as SyntaxError;
+use crate::syntax::tree::statement::r#break::builder::Builder as BreakStatementBuilder;
+use crate::syntax::tree::statement::r#break::Statement as BreakStatement;
+
+#[derive(Debug, Clone, Copy)]
+pub enum State {
+    KeywordBreak,
+    Semicolon,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        State::KeywordBreak
+    }
+}
+
+#[derive(Default)]
+pub struct Parser {
+    state: State,
+    builder: BreakStatementBuilder,
+}
+
+impl Parser {
+    ///
+    /// Parses a 'break' statement.
+    ///
+    /// 'break;'
+    ///
+    pub fn parse(
+        mut self,
+        stream: Rc<RefCell<TokenStream>>,
+        mut initial: Option<Token>,
+    ) -> Result<(BreakStatement, Option<Token>), Error> {
+        loop {
+            match self.state {
+                State::KeywordBreak => {
+                    match crate::syntax::parser::take_or_next(initial.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Keyword(Keyword::Break),
+                            location,
+                        } => {
+                            self.builder.set_location(location);
+                            self.state = State::Semicolon;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(Error::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec!["break"],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
+                }
+                State::Semicolon => {
+                    return match crate::syntax::parser::take_or_next(None, stream)? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Semicolon),
+                            ..
+                        } => Ok((self.builder.finish(), None)),
+                        Token { lexeme, location } => Err(Error::Syntax(
+                            SyntaxError::expected_one_of(location, vec![";"], lexeme, None),
+                        )),
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use super::Parser;
+    use crate::error::Error;
+    use crate::lexical::stream::TokenStream;
+    use crate::lexical::token::lexeme::literal::integer::Integer as LexicalIntegerLiteral;
+    use crate::lexical::token::lexeme::literal::Literal as LexicalLiteral;
+    use crate::lexical::token::lexeme::Lexeme;
+    use crate::lexical::token::location::Location;
+    use crate::syntax::error::Error as SyntaxError;
+    use crate::syntax::tree::statement::r#break::Statement as BreakStatement;
+
+    #[test]
+    fn ok() {
+        let input = r#"break;"#;
+
+        let expected = Ok((BreakStatement::new(Location::new(1, 1)), None));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_semicolon() {
+        let input = "break 42;";
+
+        let expected = Err(Error::Syntax(SyntaxError::expected_one_of(
+            Location::new(1, 7),
+            vec![";"],
+            Lexeme::Literal(LexicalLiteral::Integer(LexicalIntegerLiteral::new_decimal(
+                "42".to_owned(),
+            ))),
+            None,
+        )));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,127 @@
+//!
+//! The continue statement parser.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::error::Error;
+use crate::lexical::stream::TokenStream;
+use crate::lexical::token::lexeme::keyword::Keyword;
+use crate::lexical::token::lexeme::symbol::Symbol;
+use crate::lexical::token::lexeme::Lexeme;
+use crate::lexical::token::Token;
+use crate::syntax::error::Error as SyntaxError;
+use crate::syntax::tree::statement::r#continue::builder::Builder as ContinueStatementBuilder;
+use crate::syntax::tree::statement::r#continue::Statement as ContinueStatement;
+
+#[derive(Debug, Clone, Copy)]
+pub enum State {
+    KeywordContinue,
+    Semicolon,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        State::KeywordContinue
+    }
+}
+
+#[derive(Default)]
+pub struct Parser {
+    state: State,
+    builder: ContinueStatementBuilder,
+}
+
+impl Parser {
+    ///
+    /// Parses a 'continue' statement.
+    ///
+    /// 'continue;'
+    ///
+    pub fn parse(
+        mut self,
+        stream: Rc<RefCell<TokenStream>>,
+        mut initial: Option<Token>,
+    ) -> Result<(ContinueStatement, Option<Token>), Error> {
+        loop {
+            match self.state {
+                State::KeywordContinue => {
+                    match crate::syntax::parser::take_or_next(initial.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Keyword(Keyword::Continue),
+                            location,
+                        } => {
+                            self.builder.set_location(location);
+                            self.state = State::Semicolon;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(Error::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec!["continue"],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
+                }
+                State::Semicolon => {
+                    return match crate::syntax::parser::take_or_next(None, stream)? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Semicolon),
+                            ..
+                        } => Ok((self.builder.finish(), None)),
+                        Token { lexeme, location } => Err(Error::Syntax(
+                            SyntaxError::expected_one_of(location, vec![";"], lexeme, None),
+                        )),
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use super::Parser;
+    use crate::error::Error;
+    use crate::lexical::stream::TokenStream;
+    use crate::lexical::token::lexeme::literal::integer::Integer as LexicalIntegerLiteral;
+    use crate::lexical::token::lexeme::literal::Literal as LexicalLiteral;
+    use crate::lexical::token::lexeme::Lexeme;
+    use crate::lexical::token::location::Location;
+    use crate::syntax::error::Error as SyntaxError;
+    use crate::syntax::tree::statement::r#continue::Statement as ContinueStatement;
+
+    #[test]
+    fn ok() {
+        let input = r#"continue;"#;
+
+        let expected = Ok((ContinueStatement::new(Location::new(1, 1)), None));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_semicolon() {
+        let input = "continue 42;";
+
+        let expected = Err(Error::Syntax(SyntaxError::expected_one_of(
+            Location::new(1, 10),
+            vec![";"],
+            Lexeme::Literal(LexicalLiteral::Integer(LexicalIntegerLiteral::new_decimal(
+                "42".to_owned(),
+            ))),
+            None,
+        )));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+}
@@ -13,6 +13,7 @@ use crate::lexical::token::lexeme::Lexeme;
 use crate::lexical::token::Token;
 use crate::syntax::error::Error as SyntaxError;
 use crate::syntax::parser::expression::terminal::block::Parser as BlockExpressionParser;
+use crate::syntax::parser::generic_list::Parser as GenericListParser;
 use crate::syntax::parser::pattern_binding_list::Parser as BindingPatternListParser;
 use crate::syntax::parser::r#type::Parser as TypeParser;
 use crate::syntax::tree::identifier::Identifier;
@@ -26,8 +27,10 @@ static HINT_EXPECTED_ARGUMENT_LIST: &str =
 
 #[derive(Debug, Clone, Copy)]
 pub enum State {
+    KeywordConstOrFn,
     KeywordFn,
     Identifier,
+    GenericListOrParenthesisLeft,
     ParenthesisLeft,
     ArgumentBindingList,
     ParenthesisRight,
@@ -38,7 +41,7 @@ pub enum State {
 
 impl Default for State {
     fn default() -> Self {
-        State::KeywordFn
+        State::KeywordConstOrFn
     }
 }
 
@@ -59,6 +62,20 @@ impl Parser {
     /// }
     /// '
     ///
+    /// '
+    /// fn max<T>(a: T, b: T) -> T {
+    ///     if a > b { a } else { b }
+    /// }
+    /// '
+    ///
+    /// '
+    /// fn zero() -> Self;
+    /// '
+    ///
+    /// '
+    /// const fn depth(leaves: u64) -> u8 { ... }
+    /// '
+    ///
     pub fn parse(
         mut self,
         stream: Rc<RefCell<TokenStream>>,
@@ -66,8 +83,16 @@ impl Parser {
     ) -> Result<(FnStatement, Option<Token>), Error> {
         loop {
             match self.state {
-                State::KeywordFn => {
+                State::KeywordConstOrFn => {
                     match crate::syntax::parser::take_or_next(initial.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Keyword(Keyword::Const),
+                            location,
+                        } => {
+                            self.builder.set_location(location);
+                            self.builder.set_constant();
+                            self.state = State::KeywordFn;
+                        }
                         Token {
                             lexeme: Lexeme::Keyword(Keyword::Fn),
                             location,
@@ -75,6 +100,24 @@ impl Parser {
                             self.builder.set_location(location);
                             self.state = State::Identifier;
                         }
+                        Token { lexeme, location } => {
+                            return Err(Error::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec!["const", "fn"],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
+                }
+                State::KeywordFn => {
+                    match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Keyword(Keyword::Fn),
+                            ..
+                        } => {
+                            self.state = State::Identifier;
+                        }
                         Token { lexeme, location } => {
                             return Err(Error::Syntax(SyntaxError::expected_one_of(
                                 location,
@@ -93,7 +136,7 @@ impl Parser {
                         } => {
                             let identifier = Identifier::new(location, identifier.inner);
                             self.builder.set_identifier(identifier);
-                            self.state = State::ParenthesisLeft;
+                            self.state = State::GenericListOrParenthesisLeft;
                         }
                         Token { lexeme, location } => {
                             return Err(Error::Syntax(SyntaxError::expected_identifier(
@@ -104,6 +147,21 @@ impl Parser {
                         }
                     }
                 }
+                State::GenericListOrParenthesisLeft => {
+                    match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())? {
+                        token @ Token {
+                            lexeme: Lexeme::Symbol(Symbol::Lesser),
+                            ..
+                        } => {
+                            let (generics, next) =
+                                GenericListParser::default().parse(stream.clone(), Some(token))?;
+                            self.builder.set_generics(generics);
+                            self.next = next;
+                        }
+                        token => self.next = Some(token),
+                    }
+                    self.state = State::ParenthesisLeft;
+                }
                 State::ParenthesisLeft => {
                     match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())? {
                         Token {
@@ -162,11 +220,19 @@ impl Parser {
                     self.state = State::Body;
                 }
                 State::Body => {
-                    let (expression, next) =
-                        BlockExpressionParser::default().parse(stream, self.next.take())?;
+                    match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Semicolon),
+                            ..
+                        } => return Ok((self.builder.finish(), None)),
+                        token => {
+                            let (expression, next) =
+                                BlockExpressionParser::default().parse(stream, Some(token))?;
 
-                    self.builder.set_body(expression);
-                    return Ok((self.builder.finish(), next));
+                            self.builder.set_body(expression);
+                            return Ok((self.builder.finish(), next));
+                        }
+                    }
                 }
             }
         }
@@ -181,11 +247,16 @@ mod tests {
     use super::Parser;
     use crate::error::Error;
     use crate::lexical::stream::TokenStream;
+    use crate::lexical::token::lexeme::identifier::Identifier as LexicalIdentifier;
     use crate::lexical::token::lexeme::symbol::Symbol;
     use crate::lexical::token::lexeme::Lexeme;
     use crate::lexical::token::location::Location;
     use crate::syntax::error::Error as SyntaxError;
     use crate::syntax::tree::expression::block::Expression as BlockExpression;
+    use crate::syntax::tree::expression::tree::node::operand::Operand as ExpressionOperand;
+    use crate::syntax::tree::expression::tree::node::Node as ExpressionTreeNode;
+    use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
+    use crate::syntax::tree::generic::Generic;
     use crate::syntax::tree::identifier::Identifier;
     use crate::syntax::tree::pattern_binding::variant::Variant as BindingPatternVariant;
     use crate::syntax::tree::pattern_binding::Pattern as BindingPattern;
@@ -200,7 +271,9 @@ mod tests {
         let expected = Ok((
             FnStatement::new(
                 Location::new(1, 1),
+                false,
                 Identifier::new(Location::new(1, 4), "f".to_owned()),
+                vec![],
                 vec![BindingPattern::new(
                     Location::new(1, 6),
                     BindingPatternVariant::Binding(Identifier::new(
@@ -210,7 +283,7 @@ mod tests {
                     Type::new(Location::new(1, 9), TypeVariant::field()),
                 )],
                 None,
-                BlockExpression::new(Location::new(1, 16), vec![], None),
+                Some(BlockExpression::new(Location::new(1, 16), vec![], None)),
             ),
             None,
         ));
@@ -227,7 +300,38 @@ mod tests {
         let expected = Ok((
             FnStatement::new(
                 Location::new(1, 1),
+                false,
+                Identifier::new(Location::new(1, 4), "f".to_owned()),
+                vec![],
+                vec![BindingPattern::new(
+                    Location::new(1, 6),
+                    BindingPatternVariant::Binding(Identifier::new(
+                        Location::new(1, 6),
+                        "a".to_owned(),
+                    )),
+                    Type::new(Location::new(1, 9), TypeVariant::field()),
+                )],
+                Some(Type::new(Location::new(1, 19), TypeVariant::field())),
+                Some(BlockExpression::new(Location::new(1, 25), vec![], None)),
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_prototype() {
+        let input = r#"fn f(a: field) -> field;"#;
+
+        let expected = Ok((
+            FnStatement::new(
+                Location::new(1, 1),
+                false,
                 Identifier::new(Location::new(1, 4), "f".to_owned()),
+                vec![],
                 vec![BindingPattern::new(
                     Location::new(1, 6),
                     BindingPatternVariant::Binding(Identifier::new(
@@ -237,7 +341,94 @@ mod tests {
                     Type::new(Location::new(1, 9), TypeVariant::field()),
                 )],
                 Some(Type::new(Location::new(1, 19), TypeVariant::field())),
-                BlockExpression::new(Location::new(1, 25), vec![], None),
+                None,
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_constant() {
+        let input = r#"const fn f(a: field) -> field {}"#;
+
+        let expected = Ok((
+            FnStatement::new(
+                Location::new(1, 1),
+                true,
+                Identifier::new(Location::new(1, 10), "f".to_owned()),
+                vec![],
+                vec![BindingPattern::new(
+                    Location::new(1, 12),
+                    BindingPatternVariant::Binding(Identifier::new(
+                        Location::new(1, 12),
+                        "a".to_owned(),
+                    )),
+                    Type::new(Location::new(1, 15), TypeVariant::field()),
+                )],
+                Some(Type::new(Location::new(1, 25), TypeVariant::field())),
+                Some(BlockExpression::new(Location::new(1, 31), vec![], None)),
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_generics() {
+        let input = r#"fn f<T, U>(a: T) -> U {}"#;
+
+        let expected = Ok((
+            FnStatement::new(
+                Location::new(1, 1),
+                false,
+                Identifier::new(Location::new(1, 4), "f".to_owned()),
+                vec![
+                    Generic::new(
+                        Location::new(1, 6),
+                        Identifier::new(Location::new(1, 6), "T".to_owned()),
+                        None,
+                        vec![],
+                    ),
+                    Generic::new(
+                        Location::new(1, 9),
+                        Identifier::new(Location::new(1, 9), "U".to_owned()),
+                        None,
+                        vec![],
+                    ),
+                ],
+                vec![BindingPattern::new(
+                    Location::new(1, 12),
+                    BindingPatternVariant::Binding(Identifier::new(
+                        Location::new(1, 12),
+                        "a".to_owned(),
+                    )),
+                    Type::new(
+                        Location::new(1, 15),
+                        TypeVariant::alias(ExpressionTree::new(
+                            Location::new(1, 15),
+                            ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                                Identifier::new(Location::new(1, 15), "T".to_owned()),
+                            )),
+                        )),
+                    ),
+                )],
+                Some(Type::new(
+                    Location::new(1, 21),
+                    TypeVariant::alias(ExpressionTree::new(
+                        Location::new(1, 21),
+                        ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                            Identifier::new(Location::new(1, 21), "U".to_owned()),
+                        )),
+                    )),
+                )),
+                Some(BlockExpression::new(Location::new(1, 23), vec![], None)),
             ),
             None,
         ));
@@ -262,6 +453,22 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn error_expected_comma_or_greater() {
+        let input = r#"fn f<T U>(a: T) {}"#;
+
+        let expected = Err(Error::Syntax(SyntaxError::expected_one_of(
+            Location::new(1, 8),
+            vec![",", ">"],
+            Lexeme::Identifier(LexicalIdentifier::new("U".to_owned())),
+            None,
+        )));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn error_expected_parenthesis_left() {
         let input = r#"fn sort -> field {}"#;
@@ -19,11 +19,15 @@ use crate::syntax::tree::statement::r#impl::Statement as ImplStatement;
 
 static HINT_EXPECTED_IDENTIFIER: &str =
     "type implementation must have an identifier, e.g. `impl Data { ... }`";
+static HINT_EXPECTED_TYPE_IDENTIFIER: &str =
+    "trait implementation must have a type identifier, e.g. `impl Zero for Data { ... }`";
 
 #[derive(Debug, Clone, Copy)]
 pub enum State {
     KeywordImpl,
     Identifier,
+    KeywordForOrBracketCurlyLeft,
+    TypeIdentifier,
     BracketCurlyLeft,
     StatementOrBracketCurlyRight,
 }
@@ -53,6 +57,14 @@ impl Parser {
     /// }
     /// '
     ///
+    /// '
+    /// impl Zero for Data {
+    ///     fn zero() -> Self {
+    ///         Self { value: 0 }
+    ///     }
+    /// }
+    /// '
+    ///
     pub fn parse(
         mut self,
         stream: Rc<RefCell<TokenStream>>,
@@ -87,7 +99,7 @@ impl Parser {
                         } => {
                             let identifier = Identifier::new(location, identifier.inner);
                             self.builder.set_identifier(identifier);
-                            self.state = State::BracketCurlyLeft;
+                            self.state = State::KeywordForOrBracketCurlyLeft;
                         }
                         Token { lexeme, location } => {
                             return Err(Error::Syntax(SyntaxError::expected_identifier(
@@ -98,6 +110,37 @@ impl Parser {
                         }
                     }
                 }
+                State::KeywordForOrBracketCurlyLeft => {
+                    match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Keyword(Keyword::For),
+                            ..
+                        } => self.state = State::TypeIdentifier,
+                        token => {
+                            self.next = Some(token);
+                            self.state = State::BracketCurlyLeft;
+                        }
+                    }
+                }
+                State::TypeIdentifier => {
+                    match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Identifier(identifier),
+                            location,
+                        } => {
+                            let identifier = Identifier::new(location, identifier.inner);
+                            self.builder.set_trait_and_identifier(identifier);
+                            self.state = State::BracketCurlyLeft;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(Error::Syntax(SyntaxError::expected_identifier(
+                                location,
+                                lexeme,
+                                Some(HINT_EXPECTED_TYPE_IDENTIFIER),
+                            )));
+                        }
+                    }
+                }
                 State::BracketCurlyLeft => {
                     match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())? {
                         Token {
@@ -162,6 +205,7 @@ mod tests {
             ImplStatement::new(
                 Location::new(2, 5),
                 Identifier::new(Location::new(2, 10), "Test".to_owned()),
+                None,
                 vec![],
             ),
             None,
@@ -184,6 +228,7 @@ mod tests {
             ImplStatement::new(
                 Location::new(2, 5),
                 Identifier::new(Location::new(2, 10), "Test".to_owned()),
+                None,
                 vec![ImplementationLocalStatement::Const(ConstStatement::new(
                     Location::new(3, 9),
                     Identifier::new(Location::new(3, 15), "VALUE".to_owned()),
@@ -223,6 +268,7 @@ mod tests {
             ImplStatement::new(
                 Location::new(2, 5),
                 Identifier::new(Location::new(2, 10), "Test".to_owned()),
+                None,
                 vec![
                     ImplementationLocalStatement::Const(ConstStatement::new(
                         Location::new(3, 9),
@@ -276,6 +322,27 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn ok_trait() {
+        let input = r#"
+    impl Zero for Test {}
+"#;
+
+        let expected = Ok((
+            ImplStatement::new(
+                Location::new(2, 5),
+                Identifier::new(Location::new(2, 19), "Test".to_owned()),
+                Some(Identifier::new(Location::new(2, 10), "Zero".to_owned())),
+                vec![],
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn error_expected_identifier() {
         let input = r#"impl { const VALUE: u64 = 42; }"#;
@@ -290,4 +357,19 @@ mod tests {
 
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn error_expected_type_identifier() {
+        let input = r#"impl Zero for { fn zero() -> Self { 0 } }"#;
+
+        let expected = Err(Error::Syntax(SyntaxError::expected_identifier(
+            Location::new(1, 15),
+            Lexeme::Symbol(Symbol::BracketCurlyLeft),
+            Some(super::HINT_EXPECTED_TYPE_IDENTIFIER),
+        )));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
 }
@@ -12,9 +12,12 @@ use crate::lexical::token::lexeme::symbol::Symbol;
 use crate::lexical::token::lexeme::Lexeme;
 use crate::lexical::token::Token;
 use crate::syntax::parser::expression::Parser as ExpressionParser;
+use crate::syntax::parser::statement::r#break::Parser as BreakStatementParser;
 use crate::syntax::parser::statement::r#const::Parser as ConstStatementParser;
+use crate::syntax::parser::statement::r#continue::Parser as ContinueStatementParser;
 use crate::syntax::parser::statement::r#for::Parser as ForStatementParser;
 use crate::syntax::parser::statement::r#let::Parser as LetStatementParser;
+use crate::syntax::parser::statement::r#return::Parser as ReturnStatementParser;
 use crate::syntax::tree::statement::local_fn::Statement as FunctionLocalStatement;
 
 #[derive(Default)]
@@ -65,6 +68,39 @@ impl Parser {
                 self.next = next;
                 FunctionLocalStatement::For(statement)
             }
+            token
+            @
+            Token {
+                lexeme: Lexeme::Keyword(Keyword::Return),
+                ..
+            } => {
+                let (statement, next) =
+                    ReturnStatementParser::default().parse(stream.clone(), Some(token))?;
+                self.next = next;
+                FunctionLocalStatement::Return(statement)
+            }
+            token
+            @
+            Token {
+                lexeme: Lexeme::Keyword(Keyword::Break),
+                ..
+            } => {
+                let (statement, next) =
+                    BreakStatementParser::default().parse(stream.clone(), Some(token))?;
+                self.next = next;
+                FunctionLocalStatement::Break(statement)
+            }
+            token
+            @
+            Token {
+                lexeme: Lexeme::Keyword(Keyword::Continue),
+                ..
+            } => {
+                let (statement, next) =
+                    ContinueStatementParser::default().parse(stream.clone(), Some(token))?;
+                self.next = next;
+                FunctionLocalStatement::Continue(statement)
+            }
             Token {
                 lexeme: Lexeme::Symbol(Symbol::Semicolon),
                 location,
@@ -18,6 +18,8 @@ use crate::syntax::tree::statement::local_impl::Statement as ImplementationLocal
 
 static HINT_ONLY_SOME_STATEMENTS: &str =
     "only constants and functions may be declared within a type implementation";
+static HINT_ONLY_SOME_PUBLIC_STATEMENTS: &str =
+    "only constants and functions may be declared public within a type implementation";
 
 #[derive(Default)]
 pub struct Parser {}
@@ -37,9 +39,27 @@ impl Parser {
             Token {
                 lexeme: Lexeme::Keyword(Keyword::Const),
                 ..
-            } => ConstStatementParser::default()
-                .parse(stream, Some(token))
-                .map(|(statement, next)| (ImplementationLocalStatement::Const(statement), next)),
+            } => {
+                let is_function = match stream.borrow_mut().look_ahead(1)? {
+                    Token {
+                        lexeme: Lexeme::Keyword(Keyword::Fn),
+                        ..
+                    } => true,
+                    _ => false,
+                };
+
+                if is_function {
+                    FnStatementParser::default().parse(stream, Some(token)).map(
+                        |(statement, next)| (ImplementationLocalStatement::Fn(statement), next),
+                    )
+                } else {
+                    ConstStatementParser::default()
+                        .parse(stream, Some(token))
+                        .map(|(statement, next)| {
+                            (ImplementationLocalStatement::Const(statement), next)
+                        })
+                }
+            }
             token
             @
             Token {
@@ -48,13 +68,32 @@ impl Parser {
             } => FnStatementParser::default()
                 .parse(stream, Some(token))
                 .map(|(statement, next)| (ImplementationLocalStatement::Fn(statement), next)),
+            Token {
+                lexeme: Lexeme::Keyword(Keyword::Pub),
+                ..
+            } => {
+                let token = crate::syntax::parser::take_or_next(None, stream.clone())?;
+                match token.lexeme {
+                    Lexeme::Keyword(Keyword::Const) | Lexeme::Keyword(Keyword::Fn) => {
+                        let (mut statement, next) = Self::default().parse(stream, Some(token))?;
+                        statement.set_public();
+                        Ok((statement, next))
+                    }
+                    lexeme => Err(Error::Syntax(SyntaxError::expected_one_of(
+                        token.location,
+                        vec!["pub", "const", "fn"],
+                        lexeme,
+                        Some(HINT_ONLY_SOME_PUBLIC_STATEMENTS),
+                    ))),
+                }
+            }
             Token {
                 lexeme: Lexeme::Symbol(Symbol::Semicolon),
                 location,
             } => Ok((ImplementationLocalStatement::Empty(location), None)),
             Token { lexeme, location } => Err(Error::Syntax(SyntaxError::expected_one_of(
                 location,
-                vec!["const", "fn"],
+                vec!["pub", "const", "fn"],
                 lexeme,
                 Some(HINT_ONLY_SOME_STATEMENTS),
             ))),
@@ -18,12 +18,15 @@ use crate::syntax::parser::statement::r#enum::Parser as EnumStatementParser;
 use crate::syntax::parser::statement::r#fn::Parser as FnStatementParser;
 use crate::syntax::parser::statement::r#impl::Parser as ImplStatementParser;
 use crate::syntax::parser::statement::r#struct::Parser as StructStatementParser;
+use crate::syntax::parser::statement::r#trait::Parser as TraitStatementParser;
 use crate::syntax::parser::statement::r#type::Parser as TypeStatementParser;
 use crate::syntax::parser::statement::r#use::Parser as UseStatementParser;
 use crate::syntax::tree::statement::local_mod::Statement as ModuleLocalStatement;
 
 static HINT_ONLY_SOME_STATEMENTS: &str =
-    "only constants, types, functions, and type implementations may be declared at the module root";
+    "only constants, types, functions, traits, and type implementations may be declared at the module root";
+static HINT_ONLY_SOME_PUBLIC_STATEMENTS: &str =
+    "only constants, types, functions, modules, imports, and traits may be declared public";
 
 #[derive(Default)]
 pub struct Parser {}
@@ -43,9 +46,25 @@ impl Parser {
             Token {
                 lexeme: Lexeme::Keyword(Keyword::Const),
                 ..
-            } => ConstStatementParser::default()
-                .parse(stream, Some(token))
-                .map(|(statement, next)| (ModuleLocalStatement::Const(statement), next)),
+            } => {
+                let is_function = match stream.borrow_mut().look_ahead(1)? {
+                    Token {
+                        lexeme: Lexeme::Keyword(Keyword::Fn),
+                        ..
+                    } => true,
+                    _ => false,
+                };
+
+                if is_function {
+                    FnStatementParser::default()
+                        .parse(stream, Some(token))
+                        .map(|(statement, next)| (ModuleLocalStatement::Fn(statement), next))
+                } else {
+                    ConstStatementParser::default()
+                        .parse(stream, Some(token))
+                        .map(|(statement, next)| (ModuleLocalStatement::Const(statement), next))
+                }
+            }
             token
             @
             Token {
@@ -102,6 +121,42 @@ impl Parser {
             } => ImplStatementParser::default()
                 .parse(stream, Some(token))
                 .map(|(statement, next)| (ModuleLocalStatement::Impl(statement), next)),
+            token
+            @
+            Token {
+                lexeme: Lexeme::Keyword(Keyword::Trait),
+                ..
+            } => TraitStatementParser::default()
+                .parse(stream, Some(token))
+                .map(|(statement, next)| (ModuleLocalStatement::Trait(statement), next)),
+            Token {
+                lexeme: Lexeme::Keyword(Keyword::Pub),
+                ..
+            } => {
+                let token = crate::syntax::parser::take_or_next(None, stream.clone())?;
+                match token.lexeme {
+                    Lexeme::Keyword(Keyword::Const)
+                    | Lexeme::Keyword(Keyword::Type)
+                    | Lexeme::Keyword(Keyword::Struct)
+                    | Lexeme::Keyword(Keyword::Enum)
+                    | Lexeme::Keyword(Keyword::Fn)
+                    | Lexeme::Keyword(Keyword::Mod)
+                    | Lexeme::Keyword(Keyword::Use)
+                    | Lexeme::Keyword(Keyword::Trait) => {
+                        let (mut statement, next) = Self::default().parse(stream, Some(token))?;
+                        statement.set_public();
+                        Ok((statement, next))
+                    }
+                    lexeme => Err(Error::Syntax(SyntaxError::expected_one_of(
+                        token.location,
+                        vec![
+                            "type", "struct", "enum", "fn", "mod", "use", "trait", "const",
+                        ],
+                        lexeme,
+                        Some(HINT_ONLY_SOME_PUBLIC_STATEMENTS),
+                    ))),
+                }
+            }
             Token {
                 lexeme: Lexeme::Symbol(Symbol::Semicolon),
                 location,
@@ -109,7 +164,8 @@ impl Parser {
             Token { lexeme, location } => Err(Error::Syntax(SyntaxError::expected_one_of(
                 location,
                 vec![
-                    "type", "struct", "enum", "fn", "mod", "use", "impl", "const",
+                    "pub", "type", "struct", "enum", "fn", "mod", "use", "impl", "trait",
+                    "const",
                 ],
                 lexeme,
                 Some(HINT_ONLY_SOME_STATEMENTS),
@@ -117,3 +173,52 @@ impl Parser {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use super::Parser;
+    use crate::error::Error;
+    use crate::lexical::stream::TokenStream;
+    use crate::lexical::token::lexeme::keyword::Keyword;
+    use crate::lexical::token::lexeme::Lexeme;
+    use crate::lexical::token::location::Location;
+    use crate::syntax::error::Error as SyntaxError;
+    use crate::syntax::tree::identifier::Identifier;
+    use crate::syntax::tree::statement::local_mod::Statement as ModuleLocalStatement;
+    use crate::syntax::tree::statement::module::Statement as ModStatement;
+
+    #[test]
+    fn ok_public() {
+        let input = r#"pub mod jabberwocky;"#;
+
+        let mut statement = ModStatement::new(
+            Location::new(1, 5),
+            Identifier::new(Location::new(1, 9), "jabberwocky".to_owned()),
+        );
+        statement.is_public = true;
+        let expected = Ok((ModuleLocalStatement::Mod(statement), None));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_public_impl() {
+        let input = r#"pub impl Jabberwocky {}"#;
+
+        let expected = Err(Error::Syntax(SyntaxError::expected_one_of(
+            Location::new(1, 5),
+            vec!["type", "struct", "enum", "fn", "mod", "use", "trait", "const"],
+            Lexeme::Keyword(Keyword::Impl),
+            Some(super::HINT_ONLY_SOME_PUBLIC_STATEMENTS),
+        )));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,176 @@
+//!
+//! The return statement parser.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::error::Error;
+use crate::lexical::stream::TokenStream;
+use crate::lexical::token::lexeme::keyword::Keyword;
+use crate::lexical::token::lexeme::symbol::Symbol;
+use crate::lexical::token::lexeme::Lexeme;
+use crate::lexical::token::Token;
+use crate::syntax::error::Error as SyntaxError;
+use crate::syntax::parser::expression::Parser as ExpressionParser;
+use crate::syntax::tree::statement::r#return::builder::Builder as ReturnStatementBuilder;
+use crate::syntax::tree::statement::r#return::Statement as ReturnStatement;
+
+#[derive(Debug, Clone, Copy)]
+pub enum State {
+    KeywordReturn,
+    ExpressionOrSemicolon,
+    Semicolon,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        State::KeywordReturn
+    }
+}
+
+#[derive(Default)]
+pub struct Parser {
+    state: State,
+    builder: ReturnStatementBuilder,
+    next: Option<Token>,
+}
+
+impl Parser {
+    ///
+    /// Parses a 'return' statement.
+    ///
+    /// 'return 42;'
+    ///
+    pub fn parse(
+        mut self,
+        stream: Rc<RefCell<TokenStream>>,
+        mut initial: Option<Token>,
+    ) -> Result<(ReturnStatement, Option<Token>), Error> {
+        loop {
+            match self.state {
+                State::KeywordReturn => {
+                    match crate::syntax::parser::take_or_next(initial.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Keyword(Keyword::Return),
+                            location,
+                        } => {
+                            self.builder.set_location(location);
+                            self.state = State::ExpressionOrSemicolon;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(Error::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec!["return"],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
+                }
+                State::ExpressionOrSemicolon => {
+                    match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Semicolon),
+                            ..
+                        } => return Ok((self.builder.finish(), None)),
+                        token => {
+                            let (expression, next) =
+                                ExpressionParser::default().parse(stream.clone(), Some(token))?;
+                            self.builder.set_expression(expression);
+                            self.next = next;
+                            self.state = State::Semicolon;
+                        }
+                    }
+                }
+                State::Semicolon => {
+                    return match crate::syntax::parser::take_or_next(self.next.take(), stream)? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Semicolon),
+                            ..
+                        } => Ok((self.builder.finish(), None)),
+                        Token { lexeme, location } => {
+                            Err(Error::Syntax(SyntaxError::expected_one_of_or_operator(
+                                location,
+                                vec![";"],
+                                lexeme,
+                                None,
+                            )))
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use super::Parser;
+    use crate::error::Error;
+    use crate::lexical::stream::TokenStream;
+    use crate::lexical::token::lexeme::literal::integer::Integer as LexicalIntegerLiteral;
+    use crate::lexical::token::lexeme::Lexeme;
+    use crate::lexical::token::location::Location;
+    use crate::syntax::error::Error as SyntaxError;
+    use crate::syntax::tree::expression::tree::node::operand::Operand as ExpressionOperand;
+    use crate::syntax::tree::expression::tree::node::Node as ExpressionTreeNode;
+    use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
+    use crate::syntax::tree::literal::integer::Literal as IntegerLiteral;
+    use crate::syntax::tree::statement::r#return::Statement as ReturnStatement;
+
+    #[test]
+    fn ok_value() {
+        let input = r#"return 42;"#;
+
+        let expected = Ok((
+            ReturnStatement::new(
+                Location::new(1, 1),
+                Some(ExpressionTree::new(
+                    Location::new(1, 8),
+                    ExpressionTreeNode::operand(ExpressionOperand::LiteralInteger(
+                        IntegerLiteral::new(
+                            Location::new(1, 8),
+                            LexicalIntegerLiteral::new_decimal("42".to_owned()),
+                        ),
+                    )),
+                )),
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_unit() {
+        let input = r#"return;"#;
+
+        let expected = Ok((ReturnStatement::new(Location::new(1, 1), None), None));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_semicolon() {
+        let input = "return 42";
+
+        let expected = Err(Error::Syntax(SyntaxError::expected_one_of(
+            Location::new(1, 10),
+            vec![";"],
+            Lexeme::Eof,
+            None,
+        )));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+}
@@ -13,6 +13,7 @@ use crate::lexical::token::lexeme::Lexeme;
 use crate::lexical::token::Token;
 use crate::syntax::error::Error as SyntaxError;
 use crate::syntax::parser::field_list::Parser as FieldListParser;
+use crate::syntax::parser::generic_list::Parser as GenericListParser;
 use crate::syntax::tree::identifier::Identifier;
 use crate::syntax::tree::statement::r#struct::builder::Builder as StructStatementBuilder;
 use crate::syntax::tree::statement::r#struct::Statement as StructStatement;
@@ -24,6 +25,7 @@ static HINT_EXPECTED_IDENTIFIER: &str =
 pub enum State {
     KeywordStruct,
     Identifier,
+    GenericListOrBracketCurlyLeftOrEnd,
     BracketCurlyLeftOrEnd,
     FieldList,
     BracketCurlyRight,
@@ -54,6 +56,12 @@ impl Parser {
     /// }
     /// '
     ///
+    /// '
+    /// struct Path<const N: u64> {
+    ///     nodes: [u248; N],
+    /// }
+    /// '
+    ///
     pub fn parse(
         mut self,
         stream: Rc<RefCell<TokenStream>>,
@@ -88,7 +96,7 @@ impl Parser {
                         } => {
                             let identifier = Identifier::new(location, identifier.inner);
                             self.builder.set_identifier(identifier);
-                            self.state = State::BracketCurlyLeftOrEnd;
+                            self.state = State::GenericListOrBracketCurlyLeftOrEnd;
                         }
                         Token { lexeme, location } => {
                             return Err(Error::Syntax(SyntaxError::expected_identifier(
@@ -99,6 +107,21 @@ impl Parser {
                         }
                     }
                 }
+                State::GenericListOrBracketCurlyLeftOrEnd => {
+                    match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())? {
+                        token @ Token {
+                            lexeme: Lexeme::Symbol(Symbol::Lesser),
+                            ..
+                        } => {
+                            let (generics, next) =
+                                GenericListParser::default().parse(stream.clone(), Some(token))?;
+                            self.builder.set_generics(generics);
+                            self.next = next;
+                        }
+                        token => self.next = Some(token),
+                    }
+                    self.state = State::BracketCurlyLeftOrEnd;
+                }
                 State::BracketCurlyLeftOrEnd => {
                     match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())? {
                         Token {
@@ -145,7 +168,11 @@ mod tests {
     use crate::lexical::token::location::Location;
     use crate::lexical::token::Token;
     use crate::syntax::error::Error as SyntaxError;
+    use crate::syntax::tree::expression::tree::node::operand::Operand as ExpressionOperand;
+    use crate::syntax::tree::expression::tree::node::Node as ExpressionTreeNode;
+    use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
     use crate::syntax::tree::field::Field;
+    use crate::syntax::tree::generic::Generic;
     use crate::syntax::tree::identifier::Identifier;
     use crate::syntax::tree::r#type::variant::Variant as TypeVariant;
     use crate::syntax::tree::r#type::Type;
@@ -162,6 +189,7 @@ mod tests {
                 Location::new(2, 5),
                 Identifier::new(Location::new(2, 12), "Test".to_owned()),
                 vec![],
+                vec![],
             ),
             None,
         ));
@@ -182,6 +210,7 @@ mod tests {
                 Location::new(2, 5),
                 Identifier::new(Location::new(2, 12), "Test".to_owned()),
                 vec![],
+                vec![],
             ),
             Some(Token::new(
                 Lexeme::Symbol(Symbol::Semicolon),
@@ -206,6 +235,7 @@ mod tests {
             StructStatement::new(
                 Location::new(2, 5),
                 Identifier::new(Location::new(2, 12), "Test".to_owned()),
+                vec![],
                 vec![Field::new(
                     Location::new(3, 9),
                     Identifier::new(Location::new(3, 9), "a".to_owned()),
@@ -234,6 +264,7 @@ mod tests {
             StructStatement::new(
                 Location::new(2, 5),
                 Identifier::new(Location::new(2, 12), "Test".to_owned()),
+                vec![],
                 vec![
                     Field::new(
                         Location::new(3, 9),
@@ -260,6 +291,52 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn ok_generic() {
+        let input = r#"
+    struct Test<const N: u64> {
+        a: [u8; N],
+    }
+"#;
+
+        let expected = Ok((
+            StructStatement::new(
+                Location::new(2, 5),
+                Identifier::new(Location::new(2, 12), "Test".to_owned()),
+                vec![Generic::new(
+                    Location::new(2, 17),
+                    Identifier::new(Location::new(2, 23), "N".to_owned()),
+                    Some(Type::new(
+                        Location::new(2, 26),
+                        TypeVariant::integer_unsigned(64),
+                    )),
+                    vec![],
+                )],
+                vec![Field::new(
+                    Location::new(3, 9),
+                    Identifier::new(Location::new(3, 9), "a".to_owned()),
+                    Type::new(
+                        Location::new(3, 12),
+                        TypeVariant::array(
+                            TypeVariant::integer_unsigned(8),
+                            ExpressionTree::new(
+                                Location::new(3, 17),
+                                ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                                    Identifier::new(Location::new(3, 17), "N".to_owned()),
+                                )),
+                            ),
+                        ),
+                    ),
+                )],
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn error_expected_identifier() {
         let input = r#"struct { a: u8 };"#;
@@ -16,6 +16,11 @@ use crate::lexical::token::lexeme::symbol::Symbol;
 use crate::lexical::token::lexeme::Lexeme;
 use crate::lexical::token::Token;
 use crate::syntax::error::Error as SyntaxError;
+use crate::syntax::parser::expression::bitwise_or::Parser as BitwiseOrOperandParser;
+use crate::syntax::tree::expression::tree::node::operand::Operand as ExpressionOperand;
+use crate::syntax::tree::expression::tree::node::Node as ExpressionTreeNode;
+use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
+use crate::syntax::tree::identifier::Identifier;
 use crate::syntax::tree::r#type::builder::Builder as TypeBuilder;
 use crate::syntax::tree::r#type::Type;
 
@@ -36,6 +41,8 @@ impl Parser {
     /// '[u8; 16]'
     /// '(u8, field, bool)'
     /// 'Path::To::Type`
+    /// 'Path::To::Type<10, N>`
+    /// 'Self'
     ///
     pub fn parse(
         mut self,
@@ -55,33 +62,69 @@ impl Parser {
                     self.builder.set_keyword(keyword);
                     Ok((self.builder.finish(), None))
                 }
+                keyword @ Keyword::SelfUppercase => {
+                    self.builder.set_location(location);
+                    self.builder.set_path_expression(ExpressionTree::new(
+                        location,
+                        ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                            Identifier::new(location, keyword.to_string()),
+                        )),
+                    ));
+                    Ok((self.builder.finish(), None))
+                }
                 _ => Err(Error::Syntax(SyntaxError::expected_type(
                     location,
                     Lexeme::Keyword(keyword),
                     None,
                 ))),
             },
-            token
-            @
-            Token {
+            token @ Token {
                 lexeme: Lexeme::Identifier(_),
                 ..
             } => {
                 let location = token.location;
-                let (expression, next) = PathParser::default().parse(stream, Some(token))?;
+                let (expression, next) =
+                    PathParser::default().parse(stream.clone(), Some(token))?;
                 self.builder.set_location(location);
                 self.builder.set_path_expression(expression);
-                Ok((self.builder.finish(), next))
+
+                match crate::syntax::parser::take_or_next(next, stream.clone())? {
+                    Token {
+                        lexeme: Lexeme::Symbol(Symbol::Lesser),
+                        ..
+                    } => {}
+                    token => return Ok((self.builder.finish(), Some(token))),
+                }
+                loop {
+                    let (expression, next) =
+                        BitwiseOrOperandParser::default().parse(stream.clone(), None)?;
+                    self.builder.push_generic_argument(expression);
+
+                    match crate::syntax::parser::take_or_next(next, stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Comma),
+                            ..
+                        } => continue,
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Greater),
+                            ..
+                        } => return Ok((self.builder.finish(), None)),
+                        Token { lexeme, location } => {
+                            return Err(Error::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec![",", ">"],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
+                }
             }
-            token
-            @
-            Token {
+            token @ Token {
                 lexeme: Lexeme::Symbol(Symbol::BracketSquareLeft),
                 ..
             } => ArrayParser::default().parse(stream, Some(token)),
-            token
-            @
-            Token {
+            token @ Token {
                 lexeme: Lexeme::Symbol(Symbol::ParenthesisLeft),
                 ..
             } => TupleParser::default().parse(stream, Some(token)),
@@ -103,9 +146,15 @@ mod tests {
     use crate::lexical::token::lexeme::keyword::Keyword;
     use crate::lexical::token::lexeme::literal::integer::Integer as LexicalIntegerLiteral;
     use crate::lexical::token::lexeme::literal::Literal as LexicalLiteral;
+    use crate::lexical::token::lexeme::symbol::Symbol;
     use crate::lexical::token::lexeme::Lexeme;
     use crate::lexical::token::location::Location;
     use crate::syntax::error::Error as SyntaxError;
+    use crate::syntax::tree::expression::tree::node::operand::Operand as ExpressionOperand;
+    use crate::syntax::tree::expression::tree::node::Node as ExpressionTreeNode;
+    use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
+    use crate::syntax::tree::identifier::Identifier;
+    use crate::syntax::tree::literal::integer::Literal as IntegerLiteral;
     use crate::syntax::tree::r#type::variant::Variant as TypeVariant;
     use crate::syntax::tree::r#type::Type;
 
@@ -145,6 +194,86 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn ok_alias_self() {
+        let input = "Self";
+
+        let expected = Ok((
+            Type::new(
+                Location::new(1, 1),
+                TypeVariant::alias(ExpressionTree::new(
+                    Location::new(1, 1),
+                    ExpressionTreeNode::operand(ExpressionOperand::Identifier(Identifier::new(
+                        Location::new(1, 1),
+                        "Self".to_owned(),
+                    ))),
+                )),
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_alias_generic() {
+        let input = "Data<10, N>";
+
+        let expected = Ok((
+            Type::new(
+                Location::new(1, 1),
+                TypeVariant::generic_alias(
+                    ExpressionTree::new(
+                        Location::new(1, 1),
+                        ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                            Identifier::new(Location::new(1, 1), "Data".to_owned()),
+                        )),
+                    ),
+                    vec![
+                        ExpressionTree::new(
+                            Location::new(1, 6),
+                            ExpressionTreeNode::operand(ExpressionOperand::LiteralInteger(
+                                IntegerLiteral::new(
+                                    Location::new(1, 6),
+                                    LexicalIntegerLiteral::new_decimal("10".to_owned()),
+                                ),
+                            )),
+                        ),
+                        ExpressionTree::new(
+                            Location::new(1, 10),
+                            ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                                Identifier::new(Location::new(1, 10), "N".to_owned()),
+                            )),
+                        ),
+                    ],
+                ),
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_comma_or_greater() {
+        let input = "Data<10;";
+
+        let expected = Err(Error::Syntax(SyntaxError::expected_one_of(
+            Location::new(1, 8),
+            vec![",", ">"],
+            Lexeme::Symbol(Symbol::Semicolon),
+            None,
+        )));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn error_expected_type_keyword() {
         let input = "while";
@@ -12,18 +12,39 @@ use crate::lexical::token::lexeme::symbol::Symbol;
 use crate::lexical::token::lexeme::Lexeme;
 use crate::lexical::token::Token;
 use crate::syntax::error::Error as SyntaxError;
+use crate::syntax::parser::field_list::Parser as FieldListParser;
+use crate::syntax::parser::r#type::Parser as TypeParser;
 use crate::syntax::tree::identifier::Identifier;
 use crate::syntax::tree::literal::integer::Literal as IntegerLiteral;
+use crate::syntax::tree::r#type::Type;
 use crate::syntax::tree::variant::builder::Builder as VariantBuilder;
 use crate::syntax::tree::variant::Variant;
 
 static HINT_EXPECTED_IDENTIFIER: &str =
     "enumeration variant must have an identifier, e.g. `Value = 42`";
-static HINT_EXPECTED_VALUE: &str = "enumeration variant must be initialized, e.g. `Value = 42`";
+
+#[derive(Debug, Clone, Copy)]
+pub enum State {
+    Identifier,
+    EqualsOrDataOrEnd,
+    IntegerLiteral,
+    TupleTypeOrParenthesisRight,
+    TupleCommaOrParenthesisRight,
+    StructureFieldList,
+    StructureBracketRight,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        State::Identifier
+    }
+}
 
 #[derive(Default)]
 pub struct Parser {
+    state: State,
     builder: VariantBuilder,
+    tuple_types: Vec<Type>,
     next: Option<Token>,
 }
 
@@ -32,57 +53,137 @@ impl Parser {
     /// Parses an enum variant.
     ///
     /// 'A = 1'
+    /// 'Noop'
+    /// 'Burn(u64)'
+    /// 'Transfer { to: field, amount: u64 }'
     ///
     pub fn parse(
         mut self,
         stream: Rc<RefCell<TokenStream>>,
         mut initial: Option<Token>,
     ) -> Result<(Variant, Option<Token>), Error> {
-        match crate::syntax::parser::take_or_next(initial.take(), stream.clone())? {
-            Token {
-                lexeme: Lexeme::Identifier(identifier),
-                location,
-            } => {
-                let identifier = Identifier::new(location, identifier.inner);
-                self.builder.set_location(location);
-                self.builder.set_identifier(identifier);
-            }
-            Token { lexeme, location } => {
-                return Err(Error::Syntax(SyntaxError::expected_identifier(
-                    location,
-                    lexeme,
-                    Some(HINT_EXPECTED_IDENTIFIER),
-                )));
-            }
-        }
-
-        match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())? {
-            Token {
-                lexeme: Lexeme::Symbol(Symbol::Equals),
-                ..
-            } => {}
-            Token { lexeme, location } => {
-                return Err(Error::Syntax(SyntaxError::expected_value(
-                    location,
-                    lexeme,
-                    Some(HINT_EXPECTED_VALUE),
-                )));
+        loop {
+            match self.state {
+                State::Identifier => {
+                    match crate::syntax::parser::take_or_next(initial.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Identifier(identifier),
+                            location,
+                        } => {
+                            let identifier = Identifier::new(location, identifier.inner);
+                            self.builder.set_location(location);
+                            self.builder.set_identifier(identifier);
+                            self.state = State::EqualsOrDataOrEnd;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(Error::Syntax(SyntaxError::expected_identifier(
+                                location,
+                                lexeme,
+                                Some(HINT_EXPECTED_IDENTIFIER),
+                            )));
+                        }
+                    }
+                }
+                State::EqualsOrDataOrEnd => {
+                    match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Equals),
+                            ..
+                        } => self.state = State::IntegerLiteral,
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::ParenthesisLeft),
+                            ..
+                        } => self.state = State::TupleTypeOrParenthesisRight,
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::BracketCurlyLeft),
+                            ..
+                        } => self.state = State::StructureFieldList,
+                        token => return Ok((self.builder.finish(), Some(token))),
+                    }
+                }
+                State::IntegerLiteral => {
+                    match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Literal(LexicalLiteral::Integer(literal)),
+                            location,
+                        } => {
+                            self.builder
+                                .set_literal(IntegerLiteral::new(location, literal));
+                            return Ok((self.builder.finish(), None));
+                        }
+                        Token { lexeme, location } => {
+                            return Err(Error::Syntax(SyntaxError::expected_integer_literal(
+                                location, lexeme,
+                            )));
+                        }
+                    }
+                }
+                State::TupleTypeOrParenthesisRight => {
+                    match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::ParenthesisRight),
+                            ..
+                        } => {
+                            self.builder.set_tuple_data(self.tuple_types);
+                            return Ok((self.builder.finish(), None));
+                        }
+                        token => {
+                            let (r#type, next) =
+                                TypeParser::default().parse(stream.clone(), Some(token))?;
+                            self.next = next;
+                            self.tuple_types.push(r#type);
+                            self.state = State::TupleCommaOrParenthesisRight;
+                        }
+                    }
+                }
+                State::TupleCommaOrParenthesisRight => {
+                    match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Comma),
+                            ..
+                        } => self.state = State::TupleTypeOrParenthesisRight,
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::ParenthesisRight),
+                            ..
+                        } => {
+                            self.builder.set_tuple_data(self.tuple_types);
+                            return Ok((self.builder.finish(), None));
+                        }
+                        Token { lexeme, location } => {
+                            return Err(Error::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec![",", ")"],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
+                }
+                State::StructureFieldList => {
+                    let (fields, next) =
+                        FieldListParser::default().parse(stream.clone(), self.next.take())?;
+                    self.next = next;
+                    self.builder.set_structure_data(fields);
+                    self.state = State::StructureBracketRight;
+                }
+                State::StructureBracketRight => {
+                    match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::BracketCurlyRight),
+                            ..
+                        } => return Ok((self.builder.finish(), None)),
+                        Token { lexeme, location } => {
+                            return Err(Error::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec!["}"],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
+                }
             }
         }
-
-        match crate::syntax::parser::take_or_next(self.next.take(), stream)? {
-            Token {
-                lexeme: Lexeme::Literal(LexicalLiteral::Integer(literal)),
-                location,
-            } => {
-                self.builder
-                    .set_literal(IntegerLiteral::new(location, literal));
-                Ok((self.builder.finish(), self.next.take()))
-            }
-            Token { lexeme, location } => Err(Error::Syntax(
-                SyntaxError::expected_integer_literal(location, lexeme),
-            )),
-        }
     }
 }
 
@@ -96,11 +197,17 @@ mod tests {
     use crate::lexical::stream::TokenStream;
     use crate::lexical::token::lexeme::identifier::Identifier as LexicalIdentifier;
     use crate::lexical::token::lexeme::literal::integer::Integer as LexicalIntegerLiteral;
+    use crate::lexical::token::lexeme::symbol::Symbol;
     use crate::lexical::token::lexeme::Lexeme;
     use crate::lexical::token::location::Location;
+    use crate::lexical::token::Token;
     use crate::syntax::error::Error as SyntaxError;
+    use crate::syntax::tree::field::Field;
     use crate::syntax::tree::identifier::Identifier;
     use crate::syntax::tree::literal::integer::Literal as IntegerLiteral;
+    use crate::syntax::tree::r#type::variant::Variant as TypeVariant;
+    use crate::syntax::tree::r#type::Type;
+    use crate::syntax::tree::variant::data::Data as VariantData;
     use crate::syntax::tree::variant::Variant;
 
     #[test]
@@ -125,13 +232,82 @@ mod tests {
     }
 
     #[test]
-    fn error_expected_value() {
+    fn ok_unit() {
         let input = "A";
 
-        let expected = Err(Error::Syntax(SyntaxError::expected_value(
-            Location::new(1, 2),
-            Lexeme::Eof,
-            Some(super::HINT_EXPECTED_VALUE),
+        let expected = Ok((
+            Variant::new_with_data(
+                Location::new(1, 1),
+                Identifier::new(Location::new(1, 1), "A".to_owned()),
+                VariantData::Unit,
+            ),
+            Some(Token::new(Lexeme::Eof, Location::new(1, 2))),
+        ));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_tuple() {
+        let input = "A(u8, field)";
+
+        let expected = Ok((
+            Variant::new_with_data(
+                Location::new(1, 1),
+                Identifier::new(Location::new(1, 1), "A".to_owned()),
+                VariantData::Tuple(vec![
+                    Type::new(Location::new(1, 3), TypeVariant::integer_unsigned(8)),
+                    Type::new(Location::new(1, 7), TypeVariant::field()),
+                ]),
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_structure() {
+        let input = "A { a: u8, b: field }";
+
+        let expected = Ok((
+            Variant::new_with_data(
+                Location::new(1, 1),
+                Identifier::new(Location::new(1, 1), "A".to_owned()),
+                VariantData::Structure(vec![
+                    Field::new(
+                        Location::new(1, 5),
+                        Identifier::new(Location::new(1, 5), "a".to_owned()),
+                        Type::new(Location::new(1, 8), TypeVariant::integer_unsigned(8)),
+                    ),
+                    Field::new(
+                        Location::new(1, 12),
+                        Identifier::new(Location::new(1, 12), "b".to_owned()),
+                        Type::new(Location::new(1, 15), TypeVariant::field()),
+                    ),
+                ]),
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_comma_or_parenthesis_right() {
+        let input = "A(u8;";
+
+        let expected = Err(Error::Syntax(SyntaxError::expected_one_of(
+            Location::new(1, 5),
+            vec![",", ")"],
+            Lexeme::Symbol(Symbol::Semicolon),
+            None,
         )));
 
         let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
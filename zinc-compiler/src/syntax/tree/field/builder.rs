@@ -10,6 +10,7 @@ use crate::syntax::tree::r#type::Type;
 #[derive(Default)]
 pub struct Builder {
     location: Option<Location>,
+    is_public: bool,
     identifier: Option<Identifier>,
     r#type: Option<Type>,
 }
@@ -19,6 +20,10 @@ impl Builder {
         self.location = Some(value);
     }
 
+    pub fn set_public(&mut self) {
+        self.is_public = true;
+    }
+
     pub fn set_identifier(&mut self, value: Identifier) {
         self.identifier = Some(value);
     }
@@ -28,7 +33,7 @@ impl Builder {
     }
 
     pub fn finish(&mut self) -> Field {
-        Field::new(
+        let mut field = Field::new(
             self.location
                 .take()
                 .unwrap_or_else(|| panic!("{}{}", crate::PANIC_BUILDER_REQUIRES_VALUE, "location")),
@@ -38,6 +43,8 @@ impl Builder {
             self.r#type
                 .take()
                 .unwrap_or_else(|| panic!("{}{}", crate::PANIC_BUILDER_REQUIRES_VALUE, "type")),
-        )
+        );
+        field.is_public = self.is_public;
+        field
     }
 }
@@ -11,6 +11,7 @@ use crate::syntax::tree::r#type::Type;
 #[derive(Debug, Clone, PartialEq)]
 pub struct Field {
     pub location: Location,
+    pub is_public: bool,
     pub identifier: Identifier,
     pub r#type: Type,
 }
@@ -19,6 +20,7 @@ impl Field {
     pub fn new(location: Location, identifier: Identifier, r#type: Type) -> Self {
         Self {
             location,
+            is_public: false,
             identifier,
             r#type,
         }
@@ -0,0 +1,47 @@
+//!
+//! The generic parameter builder.
+//!
+
+use crate::lexical::token::location::Location;
+use crate::syntax::tree::generic::Generic;
+use crate::syntax::tree::identifier::Identifier;
+use crate::syntax::tree::r#type::Type;
+
+#[derive(Default)]
+pub struct Builder {
+    location: Option<Location>,
+    identifier: Option<Identifier>,
+    r#type: Option<Type>,
+    bounds: Vec<Identifier>,
+}
+
+impl Builder {
+    pub fn set_location(&mut self, value: Location) {
+        self.location = Some(value);
+    }
+
+    pub fn set_identifier(&mut self, value: Identifier) {
+        self.identifier = Some(value);
+    }
+
+    pub fn set_type(&mut self, value: Type) {
+        self.r#type = Some(value);
+    }
+
+    pub fn push_bound(&mut self, value: Identifier) {
+        self.bounds.push(value);
+    }
+
+    pub fn finish(&mut self) -> Generic {
+        Generic::new(
+            self.location
+                .take()
+                .unwrap_or_else(|| panic!("{}{}", crate::PANIC_BUILDER_REQUIRES_VALUE, "location")),
+            self.identifier.take().unwrap_or_else(|| {
+                panic!("{}{}", crate::PANIC_BUILDER_REQUIRES_VALUE, "identifier")
+            }),
+            self.r#type.take(),
+            std::mem::take(&mut self.bounds),
+        )
+    }
+}
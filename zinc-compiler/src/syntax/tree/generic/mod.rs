@@ -0,0 +1,43 @@
+//!
+//! The generic parameter.
+//!
+
+pub mod builder;
+
+use crate::lexical::token::location::Location;
+use crate::syntax::tree::identifier::Identifier;
+use crate::syntax::tree::r#type::Type;
+
+///
+/// The generic parameter, which is either a type parameter like `T: Zero`, or a constant parameter
+/// like `const N: u64`, whose `type` is the constant type.
+///
+/// The `bounds` are the traits the type parameter argument must implement.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Generic {
+    pub location: Location,
+    pub identifier: Identifier,
+    pub r#type: Option<Type>,
+    pub bounds: Vec<Identifier>,
+}
+
+impl Generic {
+    pub fn new(
+        location: Location,
+        identifier: Identifier,
+        r#type: Option<Type>,
+        bounds: Vec<Identifier>,
+    ) -> Self {
+        Self {
+            location,
+            identifier,
+            r#type,
+            bounds,
+        }
+    }
+
+    pub fn is_constant(&self) -> bool {
+        self.r#type.is_some()
+    }
+}
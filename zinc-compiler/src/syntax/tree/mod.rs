@@ -4,6 +4,7 @@
 
 pub mod expression;
 pub mod field;
+pub mod generic;
 pub mod identifier;
 pub mod literal;
 pub mod pattern_binding;
@@ -13,6 +13,7 @@ pub struct Builder {
     location: Option<Location>,
     binding: Option<Identifier>,
     is_binding_mutable: bool,
+    is_public: bool,
     wildcard: bool,
     r#type: Option<Type>,
 }
@@ -30,6 +31,10 @@ impl Builder {
         self.is_binding_mutable = true;
     }
 
+    pub fn set_is_public(&mut self) {
+        self.is_public = true;
+    }
+
     pub fn set_is_wildcard(&mut self) {
         self.wildcard = true;
     }
@@ -65,6 +70,10 @@ impl Builder {
             .take()
             .unwrap_or_else(|| panic!("{}{}", crate::PANIC_BUILDER_REQUIRES_VALUE, "type"));
 
-        BindingPattern::new(location, variant, r#type)
+        if self.is_public {
+            BindingPattern::new_public(location, variant, r#type)
+        } else {
+            BindingPattern::new(location, variant, r#type)
+        }
     }
 }
@@ -15,6 +15,7 @@ pub struct Pattern {
     pub location: Location,
     pub variant: Variant,
     pub r#type: Type,
+    pub is_public: bool,
 }
 
 impl Pattern {
@@ -23,6 +24,16 @@ impl Pattern {
             location,
             variant,
             r#type,
+            is_public: false,
+        }
+    }
+
+    pub fn new_public(location: Location, variant: Variant, r#type: Type) -> Self {
+        Self {
+            location,
+            variant,
+            r#type,
+            is_public: true,
         }
     }
 }
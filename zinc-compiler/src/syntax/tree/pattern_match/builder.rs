@@ -21,6 +21,13 @@ pub struct Builder {
     binding: Option<Identifier>,
     path_builder: ExpressionTreeBuilder,
     is_wildcard: bool,
+    is_tuple: bool,
+    is_tuple_variant: bool,
+    tuple_elements: Vec<MatchPattern>,
+    has_tuple_comma: bool,
+    is_structure: bool,
+    structure_fields: Vec<(Identifier, MatchPattern)>,
+    is_structure_rest: bool,
 }
 
 impl Builder {
@@ -54,6 +61,37 @@ impl Builder {
         self.is_wildcard = true;
     }
 
+    pub fn set_is_tuple(&mut self) {
+        self.is_tuple = true;
+    }
+
+    pub fn set_is_tuple_variant(&mut self) {
+        self.move_binding_to_path();
+        self.is_tuple = true;
+        self.is_tuple_variant = true;
+    }
+
+    pub fn push_tuple_element(&mut self, pattern: MatchPattern) {
+        self.tuple_elements.push(pattern);
+    }
+
+    pub fn set_tuple_comma(&mut self) {
+        self.has_tuple_comma = true;
+    }
+
+    pub fn set_is_structure(&mut self) {
+        self.move_binding_to_path();
+        self.is_structure = true;
+    }
+
+    pub fn push_structure_field(&mut self, identifier: Identifier, pattern: MatchPattern) {
+        self.structure_fields.push((identifier, pattern));
+    }
+
+    pub fn set_is_structure_rest(&mut self) {
+        self.is_structure_rest = true;
+    }
+
     pub fn finish(mut self) -> MatchPattern {
         let location = self
             .location
@@ -62,6 +100,22 @@ impl Builder {
 
         let variant = if self.is_wildcard {
             MatchPatternVariant::Wildcard
+        } else if self.is_tuple_variant {
+            MatchPatternVariant::TupleVariant {
+                path: self.path_builder.finish(),
+                elements: self.tuple_elements,
+            }
+        } else if self.is_tuple {
+            if self.tuple_elements.len() == 1 && !self.has_tuple_comma {
+                return self.tuple_elements.remove(0);
+            }
+            MatchPatternVariant::Tuple(self.tuple_elements)
+        } else if self.is_structure {
+            MatchPatternVariant::Structure {
+                path: self.path_builder.finish(),
+                fields: self.structure_fields,
+                is_rest: self.is_structure_rest,
+            }
         } else if let Some(boolean_literal) = self.boolean_literal.take() {
             MatchPatternVariant::BooleanLiteral(boolean_literal)
         } else if let Some(integer_literal) = self.integer_literal.take() {
@@ -74,7 +128,7 @@ impl Builder {
             panic!(
                 "{}{}",
                 crate::PANIC_BUILDER_REQUIRES_VALUE,
-                "boolean | integer | binding | path | wildcard"
+                "boolean | integer | binding | path | wildcard | tuple | tuple variant | structure"
             );
         };
 
@@ -6,6 +6,7 @@ use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
 use crate::syntax::tree::identifier::Identifier;
 use crate::syntax::tree::literal::boolean::Literal as BooleanLiteral;
 use crate::syntax::tree::literal::integer::Literal as IntegerLiteral;
+use crate::syntax::tree::pattern_match::Pattern as MatchPattern;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum Variant {
@@ -14,6 +15,16 @@ pub enum Variant {
     Binding(Identifier),
     Path(ExpressionTree),
     Wildcard,
+    Tuple(Vec<MatchPattern>),
+    TupleVariant {
+        path: ExpressionTree,
+        elements: Vec<MatchPattern>,
+    },
+    Structure {
+        path: ExpressionTree,
+        fields: Vec<(Identifier, MatchPattern)>,
+        is_rest: bool,
+    },
 }
 
 impl Variant {
@@ -36,4 +47,24 @@ impl Variant {
     pub fn new_wildcard() -> Self {
         Self::Wildcard
     }
+
+    pub fn new_tuple(elements: Vec<MatchPattern>) -> Self {
+        Self::Tuple(elements)
+    }
+
+    pub fn new_tuple_variant(path: ExpressionTree, elements: Vec<MatchPattern>) -> Self {
+        Self::TupleVariant { path, elements }
+    }
+
+    pub fn new_structure(
+        path: ExpressionTree,
+        fields: Vec<(Identifier, MatchPattern)>,
+        is_rest: bool,
+    ) -> Self {
+        Self::Structure {
+            path,
+            fields,
+            is_rest,
+        }
+    }
 }
@@ -12,6 +12,7 @@ use crate::syntax::tree::r#type::Type;
 #[derive(Debug, Clone, PartialEq)]
 pub struct Statement {
     pub location: Location,
+    pub is_public: bool,
     pub identifier: Identifier,
     pub r#type: Type,
     pub expression: ExpressionTree,
@@ -26,6 +27,7 @@ impl Statement {
     ) -> Self {
         Self {
             location,
+            is_public: false,
             identifier,
             r#type,
             expression,
@@ -0,0 +1,25 @@
+//!
+//! The continue statement builder.
+//!
+
+use crate::lexical::token::location::Location;
+use crate::syntax::tree::statement::r#continue::Statement as ContinueStatement;
+
+#[derive(Default)]
+pub struct Builder {
+    location: Option<Location>,
+}
+
+impl Builder {
+    pub fn set_location(&mut self, value: Location) {
+        self.location = Some(value);
+    }
+
+    pub fn finish(mut self) -> ContinueStatement {
+        ContinueStatement::new(
+            self.location
+                .take()
+                .unwrap_or_else(|| panic!("{}{}", crate::PANIC_BUILDER_REQUIRES_VALUE, "location")),
+        )
+    }
+}
@@ -0,0 +1,18 @@
+//!
+//! The continue statement.
+//!
+
+pub mod builder;
+
+use crate::lexical::token::location::Location;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Statement {
+    pub location: Location,
+}
+
+impl Statement {
+    pub fn new(location: Location) -> Self {
+        Self { location }
+    }
+}
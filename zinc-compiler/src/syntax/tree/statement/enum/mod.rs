@@ -11,6 +11,7 @@ use crate::syntax::tree::variant::Variant;
 #[derive(Debug, Clone, PartialEq)]
 pub struct Statement {
     pub location: Location,
+    pub is_public: bool,
     pub identifier: Identifier,
     pub variants: Vec<Variant>,
 }
@@ -19,6 +20,7 @@ impl Statement {
     pub fn new(location: Location, identifier: Identifier, variants: Vec<Variant>) -> Self {
         Self {
             location,
+            is_public: false,
             identifier,
             variants,
         }
@@ -4,6 +4,7 @@
 
 use crate::lexical::token::location::Location;
 use crate::syntax::tree::expression::block::Expression as BlockExpression;
+use crate::syntax::tree::generic::Generic;
 use crate::syntax::tree::identifier::Identifier;
 use crate::syntax::tree::pattern_binding::Pattern as BindingPattern;
 use crate::syntax::tree::r#type::Type;
@@ -12,7 +13,9 @@ use crate::syntax::tree::statement::r#fn::Statement as FnStatement;
 #[derive(Default)]
 pub struct Builder {
     location: Option<Location>,
+    is_constant: bool,
     identifier: Option<Identifier>,
+    generics: Vec<Generic>,
     argument_bindings: Vec<BindingPattern>,
     return_type: Option<Type>,
     body: Option<BlockExpression>,
@@ -23,10 +26,18 @@ impl Builder {
         self.location = Some(value);
     }
 
+    pub fn set_constant(&mut self) {
+        self.is_constant = true;
+    }
+
     pub fn set_identifier(&mut self, value: Identifier) {
         self.identifier = Some(value);
     }
 
+    pub fn set_generics(&mut self, value: Vec<Generic>) {
+        self.generics = value;
+    }
+
     pub fn set_argument_bindings(&mut self, value: Vec<BindingPattern>) {
         self.argument_bindings = value;
     }
@@ -46,14 +57,14 @@ impl Builder {
             .unwrap_or_else(|| panic!("{}{}", crate::PANIC_BUILDER_REQUIRES_VALUE, "location"));
         FnStatement::new(
             location,
+            self.is_constant,
             self.identifier.take().unwrap_or_else(|| {
                 panic!("{}{}", crate::PANIC_BUILDER_REQUIRES_VALUE, "identifier")
             }),
+            self.generics,
             self.argument_bindings,
             self.return_type.take(),
-            self.body
-                .take()
-                .unwrap_or_else(|| panic!("{}{}", crate::PANIC_BUILDER_REQUIRES_VALUE, "body")),
+            self.body.take(),
         )
     }
 }
@@ -6,30 +6,48 @@ pub mod builder;
 
 use crate::lexical::token::location::Location;
 use crate::syntax::tree::expression::block::Expression as BlockExpression;
+use crate::syntax::tree::generic::Generic;
 use crate::syntax::tree::identifier::Identifier;
 use crate::syntax::tree::pattern_binding::Pattern as BindingPattern;
 use crate::syntax::tree::r#type::Type;
 
+///
+/// The function statement.
+///
+/// The `body` is only omitted in trait method prototypes, e.g. `fn zero() -> Self;`.
+///
+/// The `is_constant` flag is set for `const fn` items, which may be evaluated at compile time.
+///
+/// The `is_public` flag is set for `pub fn` items, which may be called from other modules.
+///
 #[derive(Debug, Clone, PartialEq)]
 pub struct Statement {
     pub location: Location,
+    pub is_public: bool,
+    pub is_constant: bool,
     pub identifier: Identifier,
+    pub generics: Vec<Generic>,
     pub argument_bindings: Vec<BindingPattern>,
     pub return_type: Option<Type>,
-    pub body: BlockExpression,
+    pub body: Option<BlockExpression>,
 }
 
 impl Statement {
     pub fn new(
         location: Location,
+        is_constant: bool,
         identifier: Identifier,
+        generics: Vec<Generic>,
         argument_bindings: Vec<BindingPattern>,
         return_type: Option<Type>,
-        body: BlockExpression,
+        body: Option<BlockExpression>,
     ) -> Self {
         Self {
             location,
+            is_public: false,
+            is_constant,
             identifier,
+            generics,
             argument_bindings,
             return_type,
             body,
@@ -11,6 +11,7 @@ use crate::syntax::tree::statement::r#impl::Statement as ImplStatement;
 pub struct Builder {
     location: Option<Location>,
     identifier: Option<Identifier>,
+    r#trait: Option<Identifier>,
     statements: Vec<ImplementationLocalStatement>,
 }
 
@@ -23,6 +24,15 @@ impl Builder {
         self.identifier = Some(value);
     }
 
+    ///
+    /// Sets the type identifier of a trait implementation, moving the previously set
+    /// identifier to the trait one.
+    ///
+    pub fn set_trait_and_identifier(&mut self, value: Identifier) {
+        self.r#trait = self.identifier.take();
+        self.identifier = Some(value);
+    }
+
     pub fn push_statement(&mut self, statement: ImplementationLocalStatement) {
         self.statements.push(statement);
     }
@@ -35,6 +45,7 @@ impl Builder {
             self.identifier.take().unwrap_or_else(|| {
                 panic!("{}{}", crate::PANIC_BUILDER_REQUIRES_VALUE, "identifier")
             }),
+            self.r#trait.take(),
             self.statements,
         )
     }
@@ -8,10 +8,15 @@ use crate::lexical::token::location::Location;
 use crate::syntax::tree::identifier::Identifier;
 use crate::syntax::tree::statement::local_impl::Statement as ImplementationLocalStatement;
 
+///
+/// The implementation statement, whose `trait` is set for trait implementations like
+/// `impl Zero for Data { ... }`.
+///
 #[derive(Debug, Clone, PartialEq)]
 pub struct Statement {
     pub location: Location,
     pub identifier: Identifier,
+    pub r#trait: Option<Identifier>,
     pub statements: Vec<ImplementationLocalStatement>,
 }
 
@@ -19,11 +24,13 @@ impl Statement {
     pub fn new(
         location: Location,
         identifier: Identifier,
+        r#trait: Option<Identifier>,
         statements: Vec<ImplementationLocalStatement>,
     ) -> Self {
         Self {
             location,
             identifier,
+            r#trait,
             statements,
         }
     }
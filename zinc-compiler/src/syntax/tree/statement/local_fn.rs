@@ -4,15 +4,21 @@
 
 use crate::lexical::token::location::Location;
 use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
+use crate::syntax::tree::statement::r#break::Statement as BreakStatement;
 use crate::syntax::tree::statement::r#const::Statement as ConstStatement;
+use crate::syntax::tree::statement::r#continue::Statement as ContinueStatement;
 use crate::syntax::tree::statement::r#for::Statement as ForStatement;
 use crate::syntax::tree::statement::r#let::Statement as LetStatement;
+use crate::syntax::tree::statement::r#return::Statement as ReturnStatement;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum Statement {
     Let(LetStatement),
     Const(ConstStatement),
     For(ForStatement),
+    Return(ReturnStatement),
+    Break(BreakStatement),
+    Continue(ContinueStatement),
     Empty(Location),
     Expression(ExpressionTree),
 }
@@ -23,6 +29,9 @@ impl Statement {
             Self::Let(inner) => inner.location,
             Self::Const(inner) => inner.location,
             Self::For(inner) => inner.location,
+            Self::Return(inner) => inner.location,
+            Self::Break(inner) => inner.location,
+            Self::Continue(inner) => inner.location,
             Self::Empty(location) => *location,
             Self::Expression(inner) => inner.location,
         }
@@ -21,4 +21,16 @@ impl Statement {
             Self::Empty(location) => *location,
         }
     }
+    ///
+    /// Marks the item as declared with the `pub` keyword.
+    ///
+    /// The empty statements are left intact.
+    ///
+    pub fn set_public(&mut self) {
+        match self {
+            Self::Const(inner) => inner.is_public = true,
+            Self::Fn(inner) => inner.is_public = true,
+            Self::Empty(_) => {}
+        }
+    }
 }
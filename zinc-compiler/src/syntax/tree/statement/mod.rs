@@ -2,7 +2,9 @@
 //! The statement.
 //!
 
+pub mod r#break;
 pub mod r#const;
+pub mod r#continue;
 pub mod r#enum;
 pub mod r#fn;
 pub mod r#for;
@@ -11,7 +13,10 @@ pub mod r#let;
 pub mod local_fn;
 pub mod local_impl;
 pub mod local_mod;
+pub mod local_trait;
 pub mod module;
+pub mod r#return;
 pub mod r#struct;
+pub mod r#trait;
 pub mod r#type;
 pub mod r#use;
@@ -10,6 +10,7 @@ use crate::syntax::tree::identifier::Identifier;
 #[derive(Debug, Clone, PartialEq)]
 pub struct Statement {
     pub location: Location,
+    pub is_public: bool,
     pub identifier: Identifier,
 }
 
@@ -17,6 +18,7 @@ impl Statement {
     pub fn new(location: Location, identifier: Identifier) -> Self {
         Self {
             location,
+            is_public: false,
             identifier,
         }
     }
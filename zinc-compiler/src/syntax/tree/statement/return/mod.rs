@@ -0,0 +1,23 @@
+//!
+//! The return statement.
+//!
+
+pub mod builder;
+
+use crate::lexical::token::location::Location;
+use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Statement {
+    pub location: Location,
+    pub expression: Option<ExpressionTree>,
+}
+
+impl Statement {
+    pub fn new(location: Location, expression: Option<ExpressionTree>) -> Self {
+        Self {
+            location,
+            expression,
+        }
+    }
+}
@@ -4,6 +4,7 @@
 
 use crate::lexical::token::location::Location;
 use crate::syntax::tree::field::Field;
+use crate::syntax::tree::generic::Generic;
 use crate::syntax::tree::identifier::Identifier;
 use crate::syntax::tree::statement::r#struct::Statement as StructStatement;
 
@@ -11,6 +12,7 @@ use crate::syntax::tree::statement::r#struct::Statement as StructStatement;
 pub struct Builder {
     location: Option<Location>,
     identifier: Option<Identifier>,
+    generics: Vec<Generic>,
     fields: Vec<Field>,
 }
 
@@ -23,6 +25,10 @@ impl Builder {
         self.identifier = Some(value);
     }
 
+    pub fn set_generics(&mut self, value: Vec<Generic>) {
+        self.generics = value;
+    }
+
     pub fn set_fields(&mut self, value: Vec<Field>) {
         self.fields = value;
     }
@@ -35,6 +41,7 @@ impl Builder {
             self.identifier.take().unwrap_or_else(|| {
                 panic!("{}{}", crate::PANIC_BUILDER_REQUIRES_VALUE, "identifier")
             }),
+            self.generics,
             self.fields,
         )
     }
@@ -6,20 +6,30 @@ pub mod builder;
 
 use crate::lexical::token::location::Location;
 use crate::syntax::tree::field::Field;
+use crate::syntax::tree::generic::Generic;
 use crate::syntax::tree::identifier::Identifier;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Statement {
     pub location: Location,
+    pub is_public: bool,
     pub identifier: Identifier,
+    pub generics: Vec<Generic>,
     pub fields: Vec<Field>,
 }
 
 impl Statement {
-    pub fn new(location: Location, identifier: Identifier, fields: Vec<Field>) -> Self {
+    pub fn new(
+        location: Location,
+        identifier: Identifier,
+        generics: Vec<Generic>,
+        fields: Vec<Field>,
+    ) -> Self {
         Self {
             location,
+            is_public: false,
             identifier,
+            generics,
             fields,
         }
     }
@@ -0,0 +1,32 @@
+//!
+//! The trait statement.
+//!
+
+pub mod builder;
+
+use crate::lexical::token::location::Location;
+use crate::syntax::tree::identifier::Identifier;
+use crate::syntax::tree::statement::local_trait::Statement as TraitLocalStatement;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Statement {
+    pub location: Location,
+    pub is_public: bool,
+    pub identifier: Identifier,
+    pub statements: Vec<TraitLocalStatement>,
+}
+
+impl Statement {
+    pub fn new(
+        location: Location,
+        identifier: Identifier,
+        statements: Vec<TraitLocalStatement>,
+    ) -> Self {
+        Self {
+            location,
+            is_public: false,
+            identifier,
+            statements,
+        }
+    }
+}
@@ -10,11 +10,16 @@ use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
 #[derive(Debug, Clone, PartialEq)]
 pub struct Statement {
     pub location: Location,
+    pub is_public: bool,
     pub path: ExpressionTree,
 }
 
 impl Statement {
     pub fn new(location: Location, path: ExpressionTree) -> Self {
-        Self { location, path }
+        Self {
+            location,
+            is_public: false,
+            path,
+        }
     }
 }
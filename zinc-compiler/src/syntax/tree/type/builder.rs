@@ -17,6 +17,7 @@ pub struct Builder {
     array_size: Option<ExpressionTree>,
     tuple_element_types: Vec<TypeVariant>,
     path_expression: Option<ExpressionTree>,
+    generic_arguments: Vec<ExpressionTree>,
 }
 
 impl Builder {
@@ -50,6 +51,10 @@ impl Builder {
         self.path_expression = Some(value);
     }
 
+    pub fn push_generic_argument(&mut self, value: ExpressionTree) {
+        self.generic_arguments.push(value);
+    }
+
     pub fn finish(mut self) -> Type {
         static PANIC_BUILDER_TYPE_INVALID_KEYWORD: &str =
             "The type builder has got an unexpected non-type keyword: ";
@@ -62,7 +67,7 @@ impl Builder {
             .unwrap_or_else(|| panic!("{}{}", crate::PANIC_BUILDER_REQUIRES_VALUE, "location"));
 
         let variant = if let Some(path) = self.path_expression.take() {
-            TypeVariant::alias(path)
+            TypeVariant::generic_alias(path, self.generic_arguments)
         } else if let Some(keyword) = self.keyword.take() {
             match keyword {
                 Keyword::Bool => TypeVariant::boolean(),
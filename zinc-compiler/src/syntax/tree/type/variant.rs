@@ -24,6 +24,7 @@ pub enum Variant {
     },
     Alias {
         path: ExpressionTree,
+        arguments: Vec<ExpressionTree>,
     },
 }
 
@@ -68,6 +69,13 @@ impl Variant {
     }
 
     pub fn alias(path: ExpressionTree) -> Self {
-        Self::Alias { path }
+        Self::Alias {
+            path,
+            arguments: vec![],
+        }
+    }
+
+    pub fn generic_alias(path: ExpressionTree, arguments: Vec<ExpressionTree>) -> Self {
+        Self::Alias { path, arguments }
     }
 }
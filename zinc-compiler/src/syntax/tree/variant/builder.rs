@@ -3,8 +3,11 @@
 //!
 
 use crate::lexical::token::location::Location;
+use crate::syntax::tree::field::Field;
 use crate::syntax::tree::identifier::Identifier;
 use crate::syntax::tree::literal::integer::Literal as IntegerLiteral;
+use crate::syntax::tree::r#type::Type;
+use crate::syntax::tree::variant::data::Data;
 use crate::syntax::tree::variant::Variant;
 
 #[derive(Default)]
@@ -12,6 +15,7 @@ pub struct Builder {
     location: Option<Location>,
     identifier: Option<Identifier>,
     literal: Option<IntegerLiteral>,
+    data: Data,
 }
 
 impl Builder {
@@ -27,17 +31,31 @@ impl Builder {
         self.literal = Some(value);
     }
 
+    pub fn set_tuple_data(&mut self, value: Vec<Type>) {
+        self.data = Data::Tuple(value);
+    }
+
+    pub fn set_structure_data(&mut self, value: Vec<Field>) {
+        self.data = Data::Structure(value);
+    }
+
     pub fn finish(&mut self) -> Variant {
-        Variant::new(
-            self.location
-                .take()
-                .unwrap_or_else(|| panic!("{}{}", crate::PANIC_BUILDER_REQUIRES_VALUE, "location")),
-            self.identifier.take().unwrap_or_else(|| {
-                panic!("{}{}", crate::PANIC_BUILDER_REQUIRES_VALUE, "identifier")
-            }),
-            self.literal
-                .take()
-                .unwrap_or_else(|| panic!("{}{}", crate::PANIC_BUILDER_REQUIRES_VALUE, "literal")),
-        )
+        let location = self
+            .location
+            .take()
+            .unwrap_or_else(|| panic!("{}{}", crate::PANIC_BUILDER_REQUIRES_VALUE, "location"));
+        let identifier = self
+            .identifier
+            .take()
+            .unwrap_or_else(|| panic!("{}{}", crate::PANIC_BUILDER_REQUIRES_VALUE, "identifier"));
+
+        match self.literal.take() {
+            Some(literal) => Variant::new(location, identifier, literal),
+            None => Variant::new_with_data(
+                location,
+                identifier,
+                std::mem::replace(&mut self.data, Data::Unit),
+            ),
+        }
     }
 }
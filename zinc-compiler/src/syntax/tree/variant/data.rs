@@ -0,0 +1,25 @@
+//!
+//! The variant data.
+//!
+
+use crate::syntax::tree::field::Field;
+use crate::syntax::tree::r#type::Type;
+
+///
+/// The data carried by an enumeration variant.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub enum Data {
+    /// the variant without data, e.g. `Noop`
+    Unit,
+    /// the variant with unnamed fields, e.g. `Burn(u64)`
+    Tuple(Vec<Type>),
+    /// the variant with named fields, e.g. `Transfer { to: field, amount: u64 }`
+    Structure(Vec<Field>),
+}
+
+impl Default for Data {
+    fn default() -> Self {
+        Self::Unit
+    }
+}
@@ -3,16 +3,26 @@
 //!
 
 pub mod builder;
+pub mod data;
 
 use crate::lexical::token::location::Location;
 use crate::syntax::tree::identifier::Identifier;
 use crate::syntax::tree::literal::integer::Literal as IntegerLiteral;
 
+use self::data::Data;
+
+///
+/// The enumeration variant.
+///
+/// The variant either has an explicit integer value, e.g. `A = 1`, or carries some
+/// (possibly empty) data, e.g. `Burn(u64)`.
+///
 #[derive(Debug, Clone, PartialEq)]
 pub struct Variant {
     pub location: Location,
     pub identifier: Identifier,
-    pub literal: IntegerLiteral,
+    pub literal: Option<IntegerLiteral>,
+    pub data: Data,
 }
 
 impl Variant {
@@ -20,7 +30,17 @@ impl Variant {
         Self {
             location,
             identifier,
-            literal,
+            literal: Some(literal),
+            data: Data::Unit,
+        }
+    }
+
+    pub fn new_with_data(location: Location, identifier: Identifier, data: Data) -> Self {
+        Self {
+            location,
+            identifier,
+            literal: None,
+            data,
         }
     }
 }
@@ -0,0 +1,104 @@
+//!
+//! The Zinc compiler warning.
+//!
+
+use std::cell::RefCell;
+
+use crate::diagnostic::Diagnostic;
+use crate::diagnostic::Severity;
+use crate::lexical::token::location::Location;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Warning {
+    UnusedVariable { location: Location, name: String },
+    UnusedMutable { location: Location, name: String },
+    UnusedFunction { location: Location, name: String },
+    UnusedConstant { location: Location, name: String },
+    UnusedType { location: Location, name: String },
+    UnusedModule { location: Location, name: String },
+    UnusedTrait { location: Location, name: String },
+    UnusedImport { location: Location, name: String },
+    MatchBranchUnreachable { location: Location },
+}
+
+impl Warning {
+    ///
+    /// Takes the warnings emitted so far, sorted by location and without duplicates, which
+    /// appear when a generic function is analyzed for each of its instances.
+    ///
+    pub fn take_all(warnings: &RefCell<Vec<Self>>) -> Vec<Self> {
+        let mut warnings = warnings.replace(Vec::new());
+        warnings.sort_by_key(|warning| warning.location());
+        warnings.dedup();
+        warnings
+    }
+
+    pub fn location(&self) -> Location {
+        match self {
+            Self::UnusedVariable { location, .. }
+            | Self::UnusedMutable { location, .. }
+            | Self::UnusedFunction { location, .. }
+            | Self::UnusedConstant { location, .. }
+            | Self::UnusedType { location, .. }
+            | Self::UnusedModule { location, .. }
+            | Self::UnusedTrait { location, .. }
+            | Self::UnusedImport { location, .. }
+            | Self::MatchBranchUnreachable { location } => *location,
+        }
+    }
+
+    ///
+    /// Converts the warning into a diagnostic, which is formatted the same way as the errors.
+    ///
+    pub fn diagnostic(self) -> Diagnostic {
+        let diagnostic = match self {
+            Self::UnusedVariable { location, name } => Diagnostic::line(
+                format!("unused variable `{}`", name).as_str(),
+                location,
+                Some(format!("if this is intentional, prefix it with an underscore: `_{}`", name).as_str()),
+            ),
+            Self::UnusedMutable { location, name } => Diagnostic::line(
+                format!("variable `{}` does not need to be mutable", name).as_str(),
+                location,
+                Some("remove the `mut` keyword"),
+            ),
+            Self::UnusedFunction { location, name } => Diagnostic::line(
+                format!("function `{}` is never used", name).as_str(),
+                location,
+                Some(format!("if this is intentional, prefix it with an underscore: `_{}`", name).as_str()),
+            ),
+            Self::UnusedConstant { location, name } => Diagnostic::line(
+                format!("constant `{}` is never used", name).as_str(),
+                location,
+                Some(format!("if this is intentional, prefix it with an underscore: `_{}`", name).as_str()),
+            ),
+            Self::UnusedType { location, name } => Diagnostic::line(
+                format!("type `{}` is never used", name).as_str(),
+                location,
+                Some(format!("if this is intentional, prefix it with an underscore: `_{}`", name).as_str()),
+            ),
+            Self::UnusedModule { location, name } => Diagnostic::line(
+                format!("module `{}` is never used", name).as_str(),
+                location,
+                Some("remove the `mod` statement"),
+            ),
+            Self::UnusedTrait { location, name } => Diagnostic::line(
+                format!("trait `{}` is never used", name).as_str(),
+                location,
+                Some(format!("if this is intentional, prefix it with an underscore: `_{}`", name).as_str()),
+            ),
+            Self::UnusedImport { location, name } => Diagnostic::line(
+                format!("unused import `{}`", name).as_str(),
+                location,
+                Some("remove the `use` statement"),
+            ),
+            Self::MatchBranchUnreachable { location } => Diagnostic::line(
+                "match expression branch is unreachable",
+                location,
+                Some("consider removing the branch or moving it above the branch with a wildcard or irrefutable binding"),
+            ),
+        };
+
+        diagnostic.with_severity(Severity::Warning)
+    }
+}
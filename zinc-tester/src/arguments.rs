@@ -20,6 +20,13 @@ pub struct Arguments {
     )]
     pub proof_check: bool,
 
+    #[structopt(
+        short = "c",
+        long = "constraint-check",
+        help = "Checks whether the witness satisfies the constraints for every test case"
+    )]
+    pub constraint_check: bool,
+
     #[structopt(short = "q", long = "quiet", help = "Doesn't show successful tests.")]
     pub quiet: bool,
 }
@@ -25,6 +25,7 @@ use structopt::StructOpt;
 use self::data::TestData;
 use self::directory::TestDirectory;
 use self::file::TestFile;
+use self::runners::ConstraintCheckRunner;
 use self::runners::EvaluationTestRunner;
 use self::runners::ProofCheckRunner;
 use self::runners::TestRunner;
@@ -45,6 +46,11 @@ fn main() {
             verbosity: args.verbosity,
         };
         main_inner(runner)
+    } else if args.constraint_check {
+        let runner = ConstraintCheckRunner {
+            verbosity: args.verbosity,
+        };
+        main_inner(runner)
     } else {
         let runner = EvaluationTestRunner {
             verbosity: args.verbosity,
@@ -12,8 +12,10 @@ use serde_json::Value as JsonValue;
 use zinc_bytecode::data::values::JsonValueError;
 use zinc_bytecode::data::values::Value;
 use zinc_bytecode::program::Program;
+use zinc_bytecode::Curve;
 use zinc_compiler::Bytecode;
 use zinc_compiler::EntryAnalyzer;
+use zinc_compiler::Error as CompilerError;
 use zinc_compiler::Parser;
 
 pub struct ProgramData {
@@ -45,15 +47,15 @@ impl ProgramData {
 
         let syntax_tree = Parser::default()
             .parse(code, None)
-            .map_err(|error| error.format(lines.as_slice()))
+            .map_err(|errors| CompilerError::format_all(errors, lines.as_slice()))
             .map_err(Error::Compiler)?;
 
-        let intermediate = EntryAnalyzer::new()
+        let (intermediate, _warnings) = EntryAnalyzer::new()
             .compile(syntax_tree, HashMap::new())
-            .map_err(|error| error.format(lines.as_slice()))
+            .map_err(|errors| CompilerError::format_all(errors, lines.as_slice()))
             .map_err(Error::Compiler)?;
 
-        let bytecode = Rc::new(RefCell::new(Bytecode::new()));
+        let bytecode = Rc::new(RefCell::new(Bytecode::new(Curve::Bn256)));
         intermediate.write_all_to_bytecode(bytecode.clone());
         let bytecode = Rc::try_unwrap(bytecode)
             .expect(crate::PANIC_LAST_SHARED_REFERENCE)
@@ -0,0 +1,123 @@
+//!
+//! The constraint-check test runner.
+//!
+
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use colored::Colorize;
+
+use pairing::bn256::Bn256;
+
+use crate::data::TestData;
+use crate::file::TestFile;
+use crate::program::ProgramData;
+use crate::runners::TestRunner;
+use crate::Summary;
+
+pub struct ConstraintCheckRunner {
+    pub verbosity: usize,
+}
+
+impl TestRunner for ConstraintCheckRunner {
+    fn run(
+        &self,
+        test_file_path: &PathBuf,
+        test_file: &TestFile,
+        test_data: &TestData,
+        summary: Arc<Mutex<Summary>>,
+    ) {
+        let test_file_path = match test_file_path.strip_prefix(crate::TESTS_DIRECTORY) {
+            Ok(path) => path,
+            Err(_error) => test_file_path,
+        };
+
+        for test_case in test_data.cases.iter() {
+            let case_name = format!("{}::{}", test_file_path.to_string_lossy(), test_case.case);
+
+            let program_data = match ProgramData::new(&test_case.input, test_file.code.as_str()) {
+                Ok(program_data) => program_data,
+                Err(error) => {
+                    summary.lock().expect(crate::PANIC_MUTEX_SYNC).invalid += 1;
+                    println!(
+                        "[INTEGRATION] {} {} ({})",
+                        "INVALID".red(),
+                        case_name,
+                        error
+                    );
+                    continue;
+                }
+            };
+
+            if test_data.ignore || test_case.ignore {
+                summary.lock().expect(crate::PANIC_MUTEX_SYNC).ignored += 1;
+                println!("[INTEGRATION] {} {}", "IGNORE".yellow(), case_name);
+                continue;
+            }
+
+            let cs = match zinc_vm::synthesize::<Bn256>(
+                &program_data.program,
+                Some(&program_data.input),
+            ) {
+                Ok(cs) => cs,
+                Err(error) => {
+                    if test_case.should_panic {
+                        summary.lock().expect(crate::PANIC_MUTEX_SYNC).passed += 1;
+                        if self.verbosity > 0 {
+                            println!(
+                                "[INTEGRATION] {} {} (panicked)",
+                                "PASSED".green(),
+                                case_name
+                            );
+                        }
+                    } else {
+                        summary.lock().expect(crate::PANIC_MUTEX_SYNC).failed += 1;
+                        println!(
+                            "[INTEGRATION] {} {} ({})",
+                            "FAILED".bright_red(),
+                            case_name,
+                            error
+                        );
+                    }
+                    continue;
+                }
+            };
+
+            match cs.which_is_unsatisfied() {
+                Ok(None) if !test_case.should_panic => {
+                    summary.lock().expect(crate::PANIC_MUTEX_SYNC).passed += 1;
+                    if self.verbosity > 0 {
+                        println!("[INTEGRATION] {} {}", "PASSED".green(), case_name);
+                    }
+                }
+                Ok(None) => {
+                    summary.lock().expect(crate::PANIC_MUTEX_SYNC).failed += 1;
+                    println!(
+                        "[INTEGRATION] {} {} (should have panicked)",
+                        "FAILED".bright_red(),
+                        case_name
+                    );
+                }
+                Ok(Some(annotation)) => {
+                    summary.lock().expect(crate::PANIC_MUTEX_SYNC).failed += 1;
+                    println!(
+                        "[INTEGRATION] {} {} (constraint `{}` is not satisfied)",
+                        "FAILED".bright_red(),
+                        case_name,
+                        annotation
+                    );
+                }
+                Err(error) => {
+                    summary.lock().expect(crate::PANIC_MUTEX_SYNC).failed += 1;
+                    println!(
+                        "[INTEGRATION] {} {} (synthesis: {})",
+                        "FAILED".bright_red(),
+                        case_name,
+                        error
+                    );
+                }
+            }
+        }
+    }
+}
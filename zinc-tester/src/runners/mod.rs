@@ -2,9 +2,11 @@
 //! The test runners.
 //!
 
+mod constraint_check;
 mod evaluation;
 mod proof_check;
 
+pub use self::constraint_check::ConstraintCheckRunner;
 pub use self::evaluation::EvaluationTestRunner;
 pub use self::proof_check::ProofCheckRunner;
 pub use crate::Summary;
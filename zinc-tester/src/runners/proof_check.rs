@@ -41,7 +41,7 @@ impl TestRunner for ProofCheckRunner {
             }
         };
 
-        let params = match zinc_vm::setup::<Bn256>(&program) {
+        let params = match zinc_vm::setup::<Bn256, _>(&program, &mut rand::thread_rng()) {
             Ok(params) => params,
             Err(error) => {
                 summary.lock().expect(crate::PANIC_MUTEX_SYNC).invalid += 1;
@@ -83,10 +83,11 @@ impl TestRunner for ProofCheckRunner {
                 continue;
             }
 
-            let (output, proof) = match zinc_vm::prove::<Bn256>(
+            let (output, proof) = match zinc_vm::prove::<Bn256, _>(
                 &program_data.program,
                 &params,
                 &program_data.input,
+                &mut rand::thread_rng(),
             ) {
                 Ok((output, proof)) => {
                     let output_json = output.to_json();
@@ -125,7 +126,11 @@ impl TestRunner for ProofCheckRunner {
                 }
             };
 
-            match zinc_vm::verify(&params.vk, &proof, &output) {
+            let public_data = program_data
+                .program
+                .public_data(&program_data.input, output);
+
+            match zinc_vm::verify(&params.vk, &proof, &public_data) {
                 Ok(success) => {
                     if success {
                     } else {
@@ -1,10 +1,12 @@
 use crate::{Error, IoToError};
+use pairing::bls12_381::Bls12;
 use pairing::bn256::Bn256;
 use std::fs;
 use std::path::PathBuf;
 use structopt::StructOpt;
 use zinc_bytecode::data::values::Value;
 use zinc_bytecode::program::Program;
+use zinc_bytecode::Curve;
 
 #[derive(Debug, StructOpt)]
 #[structopt(name = "debug", about = "Executes circuit with additional checks")]
@@ -30,7 +32,10 @@ impl DebugCommand {
         let json = serde_json::from_str(&input_text)?;
         let input = Value::from_typed_json(&json, &program.input)?;
 
-        let output = zinc_vm::debug::<Bn256>(&program, &input)?;
+        let output = match program.curve {
+            Curve::Bn256 => zinc_vm::debug::<Bn256>(&program, &input)?,
+            Curve::Bls12_381 => zinc_vm::debug::<Bls12>(&program, &input)?,
+        };
 
         let output_json = serde_json::to_string_pretty(&output.to_json())? + "\n";
         fs::write(&self.output_path, &output_json)
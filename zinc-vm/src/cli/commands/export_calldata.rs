@@ -0,0 +1,52 @@
+use super::verify::read_hex;
+use crate::{Error, IoToError};
+use franklin_crypto::bellman::groth16::Proof;
+use pairing::bn256::Bn256;
+use std::fs;
+use std::path::PathBuf;
+use structopt::StructOpt;
+use zinc_bytecode::data::values::Value;
+use zinc_bytecode::Program;
+
+#[derive(Debug, StructOpt)]
+#[structopt(
+    name = "export-calldata",
+    about = "Prints Solidity verifier's `verifyProof` arguments for the proof"
+)]
+pub struct ExportCalldataCommand {
+    #[structopt(short = "c", long = "circuit", help = "Compiled circuit program file")]
+    pub circuit_path: PathBuf,
+
+    #[structopt(
+        short = "d",
+        long = "public-data",
+        help = "Path to public data JSON file"
+    )]
+    pub public_data_path: PathBuf,
+}
+
+impl ExportCalldataCommand {
+    pub fn execute(&self) -> Result<(), Error> {
+        // Read proof
+        let proof_bytes = read_hex(std::io::stdin(), "<stdin>", "proof")?;
+        let proof =
+            Proof::<Bn256>::read(proof_bytes.as_slice()).error_with_path(|| "<proof data>")?;
+
+        // Read program
+        let bytes =
+            fs::read(&self.circuit_path).error_with_path(|| self.circuit_path.to_string_lossy())?;
+        let program = Program::from_bytes(bytes.as_slice()).map_err(Error::ProgramDecoding)?;
+
+        // Read public data
+        let public_data_text = fs::read_to_string(&self.public_data_path)
+            .error_with_path(|| self.public_data_path.to_string_lossy())?;
+        let public_data_value = serde_json::from_str(public_data_text.as_str())?;
+        let public_data_type = Program::public_data_type(&program.public_input, &program.output);
+        let public_data_struct = Value::from_typed_json(&public_data_value, &public_data_type)?;
+
+        let calldata = zinc_vm::solidity::calldata(&proof, &public_data_struct)?;
+        println!("{}", serde_json::to_string_pretty(&calldata)?);
+
+        Ok(())
+    }
+}
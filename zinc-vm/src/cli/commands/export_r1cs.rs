@@ -0,0 +1,54 @@
+use crate::{Error, IoToError};
+use pairing::bls12_381::Bls12;
+use pairing::bn256::Bn256;
+use std::fs;
+use std::path::PathBuf;
+use structopt::StructOpt;
+use zinc_bytecode::program::Program;
+use zinc_bytecode::Curve;
+use zinc_vm::constraint_systems::RecordingConstraintSystem;
+use zinc_vm::Engine;
+
+#[derive(Debug, StructOpt)]
+#[structopt(
+    name = "export-r1cs",
+    about = "Exports circuit's constraint system in circom's .r1cs format"
+)]
+pub struct ExportR1csCommand {
+    #[structopt(short = "c", long = "circuit", help = "Circuit's bytecode file")]
+    pub circuit_path: PathBuf,
+
+    #[structopt(short = "o", long = "output", help = "Constraint system file to write")]
+    pub output_path: PathBuf,
+
+    #[structopt(long = "json", help = "Writes the readable JSON form instead")]
+    pub json: bool,
+}
+
+impl ExportR1csCommand {
+    pub fn execute(&self) -> Result<(), Error> {
+        let bytes =
+            fs::read(&self.circuit_path).error_with_path(|| self.circuit_path.to_string_lossy())?;
+        let program = Program::from_bytes(bytes.as_slice()).map_err(Error::ProgramDecoding)?;
+
+        match program.curve {
+            Curve::Bn256 => self.write(zinc_vm::synthesize::<Bn256>(&program, None)?),
+            Curve::Bls12_381 => self.write(zinc_vm::synthesize::<Bls12>(&program, None)?),
+        }
+    }
+
+    fn write<E: Engine>(&self, cs: RecordingConstraintSystem<E>) -> Result<(), Error> {
+        if self.json {
+            let r1cs_json = serde_json::to_string_pretty(&cs.r1cs_json())? + "\n";
+            fs::write(&self.output_path, r1cs_json)
+                .error_with_path(|| self.output_path.to_string_lossy())?;
+        } else {
+            let file = fs::File::create(&self.output_path)
+                .error_with_path(|| self.output_path.to_string_lossy())?;
+            cs.write_r1cs(file)
+                .error_with_path(|| self.output_path.to_string_lossy())?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,54 @@
+use super::verify::read_hex;
+use crate::key_file;
+use crate::{Error, IoToError};
+use franklin_crypto::bellman::groth16::VerifyingKey;
+use pairing::bn256::Bn256;
+use std::fs;
+use std::path::PathBuf;
+use structopt::StructOpt;
+use zinc_bytecode::Curve;
+
+#[derive(Debug, StructOpt)]
+#[structopt(
+    name = "export-verifier",
+    about = "Generates Solidity verifier contract from verifying key"
+)]
+pub struct ExportVerifierCommand {
+    #[structopt(
+        short = "k",
+        long = "verifying-key",
+        help = "Path to verifying key file"
+    )]
+    pub key_path: PathBuf,
+
+    #[structopt(short = "o", long = "output", help = "Solidity contract file to write")]
+    pub output_path: PathBuf,
+}
+
+impl ExportVerifierCommand {
+    pub fn execute(&self) -> Result<(), Error> {
+        let key_data =
+            fs::read(&self.key_path).error_with_path(|| self.key_path.to_string_lossy())?;
+        let (curve, key_hex) = key_file::split_header(key_data.as_slice())?;
+        if curve != Curve::Bn256 {
+            return Err(Error::Curve(format!(
+                "the Solidity verifier supports only {}, but the key is generated for {}",
+                Curve::Bn256,
+                curve
+            )));
+        }
+        let key_bytes = read_hex(
+            key_hex,
+            &self.key_path.to_string_lossy(),
+            "verification key",
+        )?;
+        let key = VerifyingKey::<Bn256>::read(key_bytes.as_slice())
+            .error_with_path(|| self.key_path.to_string_lossy())?;
+
+        let contract = zinc_vm::solidity::verifier_contract(&key);
+        fs::write(&self.output_path, contract)
+            .error_with_path(|| self.output_path.to_string_lossy())?;
+
+        Ok(())
+    }
+}
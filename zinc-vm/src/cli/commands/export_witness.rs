@@ -0,0 +1,63 @@
+use crate::{Error, IoToError};
+use pairing::bls12_381::Bls12;
+use pairing::bn256::Bn256;
+use std::fs;
+use std::path::PathBuf;
+use structopt::StructOpt;
+use zinc_bytecode::data::values::Value;
+use zinc_bytecode::program::Program;
+use zinc_bytecode::Curve;
+use zinc_vm::constraint_systems::RecordingConstraintSystem;
+use zinc_vm::Engine;
+
+#[derive(Debug, StructOpt)]
+#[structopt(
+    name = "export-witness",
+    about = "Exports circuit's variable values in circom's .wtns format"
+)]
+pub struct ExportWitnessCommand {
+    #[structopt(short = "c", long = "circuit", help = "Circuit's bytecode file")]
+    pub circuit_path: PathBuf,
+
+    #[structopt(short = "w", long = "witness", help = "Witness JSON file")]
+    pub witness_path: PathBuf,
+
+    #[structopt(short = "o", long = "output", help = "Witness file to write")]
+    pub output_path: PathBuf,
+
+    #[structopt(long = "json", help = "Writes the readable JSON form instead")]
+    pub json: bool,
+}
+
+impl ExportWitnessCommand {
+    pub fn execute(&self) -> Result<(), Error> {
+        let bytes =
+            fs::read(&self.circuit_path).error_with_path(|| self.circuit_path.to_string_lossy())?;
+        let program = Program::from_bytes(bytes.as_slice()).map_err(Error::ProgramDecoding)?;
+
+        let witness_text = fs::read_to_string(&self.witness_path)
+            .error_with_path(|| self.witness_path.to_string_lossy())?;
+        let witness_json = serde_json::from_str(&witness_text)?;
+        let witness = Value::from_typed_json(&witness_json, &program.input)?;
+
+        match program.curve {
+            Curve::Bn256 => self.write(zinc_vm::synthesize::<Bn256>(&program, Some(&witness))?),
+            Curve::Bls12_381 => self.write(zinc_vm::synthesize::<Bls12>(&program, Some(&witness))?),
+        }
+    }
+
+    fn write<E: Engine>(&self, cs: RecordingConstraintSystem<E>) -> Result<(), Error> {
+        if self.json {
+            let values_json = serde_json::to_string_pretty(&cs.witness_json())? + "\n";
+            fs::write(&self.output_path, values_json)
+                .error_with_path(|| self.output_path.to_string_lossy())?;
+        } else {
+            let file = fs::File::create(&self.output_path)
+                .error_with_path(|| self.output_path.to_string_lossy())?;
+            cs.write_wtns(file)
+                .error_with_path(|| self.output_path.to_string_lossy())?;
+        }
+
+        Ok(())
+    }
+}
@@ -1,14 +1,23 @@
 mod debug;
+mod export_calldata;
+mod export_r1cs;
+mod export_verifier;
+mod export_witness;
 mod prove;
 mod run;
 mod setup;
 mod verify;
 
 use self::debug::DebugCommand;
+use self::export_calldata::ExportCalldataCommand;
+use self::export_r1cs::ExportR1csCommand;
+use self::export_verifier::ExportVerifierCommand;
+use self::export_witness::ExportWitnessCommand;
 use self::prove::ProveCommand;
 use self::run::RunCommand;
 use self::setup::SetupCommand;
 use self::verify::VerifyCommand;
+use rand::{ChaChaRng, SeedableRng};
 use structopt::StructOpt;
 
 #[derive(Debug, StructOpt)]
@@ -32,4 +41,19 @@ pub enum Command {
     Setup(SetupCommand),
     Prove(ProveCommand),
     Verify(VerifyCommand),
+    ExportR1cs(ExportR1csCommand),
+    ExportWitness(ExportWitnessCommand),
+    ExportVerifier(ExportVerifierCommand),
+    ExportCalldata(ExportCalldataCommand),
+}
+
+/// Creates the deterministic random generator for the reproducible keys and proofs.
+/// The generated data is insecure, so a warning is printed every time.
+fn seeded_rng(seed: u64) -> ChaChaRng {
+    log::warn!(
+        "using the random seed {}: the output is reproducible and must not be used in production",
+        seed
+    );
+
+    ChaChaRng::from_seed(&[seed as u32, (seed >> 32) as u32][..])
 }
@@ -1,11 +1,15 @@
+use crate::key_file;
 use crate::{Error, IoToError};
 use franklin_crypto::bellman::groth16::Parameters;
+use pairing::bls12_381::Bls12;
 use pairing::bn256::Bn256;
 use std::fs;
 use std::path::PathBuf;
 use structopt::StructOpt;
 use zinc_bytecode::data::values::Value;
 use zinc_bytecode::program::Program;
+use zinc_bytecode::Curve;
+use zinc_vm::Engine;
 
 #[derive(Debug, StructOpt)]
 #[structopt(name = "prove", about = "Executes circuit and prints program's output")]
@@ -19,8 +23,14 @@ pub struct ProveCommand {
     #[structopt(short = "w", long = "witness", help = "File with witness values")]
     pub witness_path: PathBuf,
 
-    #[structopt(short = "p", long = "public-data", help = "File with witness values")]
+    #[structopt(short = "p", long = "public-data", help = "Public data file to write")]
     pub pubdata_path: PathBuf,
+
+    #[structopt(
+        long = "seed",
+        help = "Random seed for reproducible proof, insecure, for testing only"
+    )]
+    pub seed: Option<u64>,
 }
 
 impl ProveCommand {
@@ -30,11 +40,11 @@ impl ProveCommand {
             fs::read(&self.circuit_path).error_with_path(|| self.circuit_path.to_string_lossy())?;
         let program = Program::from_bytes(bytes.as_slice()).map_err(Error::ProgramDecoding)?;
 
-        // Read verifying key
-        let file = fs::File::open(&self.proving_key_path)
-            .error_with_path(|| self.proving_key_path.to_string_lossy())?;
-        let params = Parameters::<Bn256>::read(file, true)
+        // Read proving key
+        let key_bytes = fs::read(&self.proving_key_path)
             .error_with_path(|| self.proving_key_path.to_string_lossy())?;
+        let (curve, key_bytes) = key_file::split_header(key_bytes.as_slice())?;
+        key_file::check_curve(curve, program.curve)?;
 
         // Read witness
         let witness_json = fs::read_to_string(&self.witness_path)
@@ -42,7 +52,11 @@ impl ProveCommand {
         let witness_value = serde_json::from_str(&witness_json)?;
         let witness_struct = Value::from_typed_json(&witness_value, &program.input)?;
 
-        let (pubdata, proof) = zinc_vm::prove::<Bn256>(&program, &params, &witness_struct)?;
+        let (output, proof_bytes) = match curve {
+            Curve::Bn256 => self.prove::<Bn256>(&program, key_bytes, &witness_struct)?,
+            Curve::Bls12_381 => self.prove::<Bls12>(&program, key_bytes, &witness_struct)?,
+        };
+        let pubdata = program.public_data(&witness_struct, output);
 
         // Write pubdata
         let pubdata_json = serde_json::to_string_pretty(&pubdata.to_json())? + "\n";
@@ -50,12 +64,31 @@ impl ProveCommand {
             .error_with_path(|| self.pubdata_path.to_string_lossy())?;
 
         // Write proof to stdout
-        let mut proof_bytes = Vec::new();
-        proof.write(&mut proof_bytes).expect("writing to vec");
-
         let proof_hex = hex::encode(proof_bytes);
         println!("{}", proof_hex);
 
         Ok(())
     }
+
+    fn prove<E: Engine>(
+        &self,
+        program: &Program,
+        key_bytes: &[u8],
+        witness: &Value,
+    ) -> Result<(Value, Vec<u8>), Error> {
+        let params = Parameters::<E>::read(key_bytes, true)
+            .error_with_path(|| self.proving_key_path.to_string_lossy())?;
+
+        let (output, proof) = match self.seed {
+            Some(seed) => {
+                zinc_vm::prove::<E, _>(program, &params, witness, &mut super::seeded_rng(seed))?
+            }
+            None => zinc_vm::prove::<E, _>(program, &params, witness, &mut rand::thread_rng())?,
+        };
+
+        let mut proof_bytes = Vec::new();
+        proof.write(&mut proof_bytes).expect("writing to vec");
+
+        Ok((output, proof_bytes))
+    }
 }
@@ -1,10 +1,12 @@
 use crate::{Error, IoToError};
+use pairing::bls12_381::Bls12;
 use pairing::bn256::Bn256;
 use std::fs;
 use std::path::PathBuf;
 use structopt::StructOpt;
 use zinc_bytecode::data::values::Value;
 use zinc_bytecode::program::Program;
+use zinc_bytecode::Curve;
 
 #[derive(Debug, StructOpt)]
 #[structopt(name = "run", about = "Executes circuit and prints program's output")]
@@ -17,6 +19,12 @@ pub struct RunCommand {
 
     #[structopt(short = "o", long = "output", help = "Program's output file")]
     pub output_path: PathBuf,
+
+    #[structopt(
+        long = "witness-only",
+        help = "Computes only the output without building constraints"
+    )]
+    pub witness_only: bool,
 }
 
 impl RunCommand {
@@ -30,7 +38,12 @@ impl RunCommand {
         let json = serde_json::from_str(&input_text)?;
         let input = Value::from_typed_json(&json, &program.input)?;
 
-        let output = zinc_vm::run::<Bn256>(&program, &input)?;
+        let output = match (program.curve, self.witness_only) {
+            (Curve::Bn256, false) => zinc_vm::run::<Bn256>(&program, &input)?,
+            (Curve::Bn256, true) => zinc_vm::evaluate::<Bn256>(&program, &input)?,
+            (Curve::Bls12_381, false) => zinc_vm::run::<Bls12>(&program, &input)?,
+            (Curve::Bls12_381, true) => zinc_vm::evaluate::<Bls12>(&program, &input)?,
+        };
 
         let output_json = serde_json::to_string_pretty(&output.to_json())? + "\n";
         fs::write(&self.output_path, &output_json)
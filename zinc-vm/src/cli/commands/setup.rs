@@ -1,9 +1,13 @@
+use crate::key_file;
 use crate::{Error, IoToError};
+use pairing::bls12_381::Bls12;
 use pairing::bn256::Bn256;
 use std::fs;
 use std::path::PathBuf;
 use structopt::StructOpt;
 use zinc_bytecode::program::Program;
+use zinc_bytecode::Curve;
+use zinc_vm::Engine;
 
 #[derive(Debug, StructOpt)]
 #[structopt(
@@ -19,6 +23,12 @@ pub struct SetupCommand {
 
     #[structopt(short = "v", long = "verifying-key", help = "Params file to write")]
     pub verifying_key_path: PathBuf,
+
+    #[structopt(
+        long = "seed",
+        help = "Random seed for reproducible keys, insecure, for testing only"
+    )]
+    pub seed: Option<u64>,
 }
 
 impl SetupCommand {
@@ -27,9 +37,21 @@ impl SetupCommand {
             fs::read(&self.circuit_path).error_with_path(|| self.circuit_path.to_string_lossy())?;
         let program = Program::from_bytes(bytes.as_slice()).map_err(Error::ProgramDecoding)?;
 
-        let params = zinc_vm::setup::<Bn256>(&program)?;
+        match program.curve {
+            Curve::Bn256 => self.setup::<Bn256>(&program),
+            Curve::Bls12_381 => self.setup::<Bls12>(&program),
+        }
+    }
+
+    fn setup<E: Engine>(&self, program: &Program) -> Result<(), Error> {
+        let params = match self.seed {
+            Some(seed) => zinc_vm::setup::<E, _>(program, &mut super::seeded_rng(seed))?,
+            None => zinc_vm::setup::<E, _>(program, &mut rand::thread_rng())?,
+        };
 
-        let pkey_file = fs::File::create(&self.proving_key_path)
+        let mut pkey_file = fs::File::create(&self.proving_key_path)
+            .error_with_path(|| self.proving_key_path.to_string_lossy())?;
+        key_file::write_header(&mut pkey_file, E::CURVE)
             .error_with_path(|| self.proving_key_path.to_string_lossy())?;
         params
             .write(pkey_file)
@@ -41,7 +63,11 @@ impl SetupCommand {
             hex::encode(vk_bytes) + "\n"
         };
 
-        fs::write(&self.verifying_key_path, vk_hex)
+        let mut vk_file = Vec::new();
+        key_file::write_header(&mut vk_file, E::CURVE).expect("writing to vec");
+        vk_file.extend(vk_hex.into_bytes());
+
+        fs::write(&self.verifying_key_path, vk_file)
             .error_with_path(|| self.verifying_key_path.to_string_lossy())?;
 
         Ok(())
@@ -1,13 +1,16 @@
+use crate::key_file;
 use crate::{Error, IoToError};
 use colored::Colorize;
 use franklin_crypto::bellman::groth16::{Proof, VerifyingKey};
+use pairing::bls12_381::Bls12;
 use pairing::bn256::Bn256;
 use std::fs;
 use std::path::PathBuf;
 use std::process::exit;
 use structopt::StructOpt;
 use zinc_bytecode::data::values::Value;
-use zinc_bytecode::Program;
+use zinc_bytecode::{Curve, Program};
+use zinc_vm::Engine;
 
 #[derive(Debug, StructOpt)]
 #[structopt(name = "verify", about = "Verifies the proof using verifying key")]
@@ -34,8 +37,6 @@ impl VerifyCommand {
     pub fn execute(&self) -> Result<(), Error> {
         // Read proof
         let proof_bytes = read_hex(std::io::stdin(), "<stdin>", "proof")?;
-        let proof =
-            Proof::<Bn256>::read(proof_bytes.as_slice()).error_with_path(|| "<proof data>")?;
 
         // Read program
         let bytes =
@@ -43,24 +44,30 @@ impl VerifyCommand {
         let program = Program::from_bytes(bytes.as_slice()).map_err(Error::ProgramDecoding)?;
 
         // Read verification key
-        let key_file =
-            fs::File::open(&self.key_path).error_with_path(|| self.key_path.to_string_lossy())?;
+        let key_data =
+            fs::read(&self.key_path).error_with_path(|| self.key_path.to_string_lossy())?;
+        let (curve, key_hex) = key_file::split_header(key_data.as_slice())?;
+        key_file::check_curve(curve, program.curve)?;
         let key_bytes = read_hex(
-            key_file,
+            key_hex,
             &self.key_path.to_string_lossy(),
             "verification key",
         )?;
-        let key = VerifyingKey::<Bn256>::read(key_bytes.as_slice())
-            .error_with_path(|| self.key_path.to_string_lossy())?;
 
-        // Read public input
-        let output_text = fs::read_to_string(&self.public_data_path)
+        // Read public data
+        let public_data_text = fs::read_to_string(&self.public_data_path)
             .error_with_path(|| self.public_data_path.to_string_lossy())?;
-        let output_value = serde_json::from_str(output_text.as_str())?;
-        let output_struct = Value::from_typed_json(&output_value, &program.output)?;
+        let public_data_value = serde_json::from_str(public_data_text.as_str())?;
+        let public_data_type = Program::public_data_type(&program.public_input, &program.output);
+        let public_data_struct = Value::from_typed_json(&public_data_value, &public_data_type)?;
 
         // Verify
-        let verified = zinc_vm::verify(&key, &proof, &output_struct)?;
+        let verified = match curve {
+            Curve::Bn256 => self.verify::<Bn256>(&key_bytes, &proof_bytes, &public_data_struct)?,
+            Curve::Bls12_381 => {
+                self.verify::<Bls12>(&key_bytes, &proof_bytes, &public_data_struct)?
+            }
+        };
 
         if verified {
             println!("{}", "✔  Verified".bold().green());
@@ -71,9 +78,22 @@ impl VerifyCommand {
 
         Ok(())
     }
+
+    fn verify<E: Engine>(
+        &self,
+        key_bytes: &[u8],
+        proof_bytes: &[u8],
+        public_data: &Value,
+    ) -> Result<bool, Error> {
+        let proof = Proof::<E>::read(proof_bytes).error_with_path(|| "<proof data>")?;
+        let key = VerifyingKey::<E>::read(key_bytes)
+            .error_with_path(|| self.key_path.to_string_lossy())?;
+
+        Ok(zinc_vm::verify(&key, &proof, public_data)?)
+    }
 }
 
-fn read_hex<R: std::io::Read>(
+pub fn read_hex<R: std::io::Read>(
     mut reader: R,
     path_hint: &str,
     context_hint: &str,
@@ -28,6 +28,9 @@ pub enum Error {
     #[fail(display = "failed to decode program: {}", _0)]
     ProgramDecoding(String),
 
+    #[fail(display = "curve: {}", _0)]
+    Curve(String),
+
     #[fail(display = "failed to decode {} hex-code: {}", context, error)]
     HexDecoding {
         context: String,
@@ -0,0 +1,75 @@
+use crate::Error;
+use std::io::{self, Write};
+use zinc_bytecode::Curve;
+
+static HEADER_PREFIX: &str = "curve: ";
+
+/// Writes the key file header, which records the curve the key has been generated for.
+pub fn write_header<W: Write>(mut writer: W, curve: Curve) -> io::Result<()> {
+    writeln!(writer, "{}{}", HEADER_PREFIX, curve)
+}
+
+/// Splits the header off the key file data. The files without the header have been
+/// generated before the curve was recorded, that is, for BN256.
+pub fn split_header(data: &[u8]) -> Result<(Curve, &[u8]), Error> {
+    if !data.starts_with(HEADER_PREFIX.as_bytes()) {
+        return Ok((Curve::Bn256, data));
+    }
+
+    let end = data
+        .iter()
+        .position(|byte| *byte == b'\n')
+        .ok_or_else(|| Error::Curve("the key file header is not terminated".into()))?;
+    let name = String::from_utf8_lossy(&data[HEADER_PREFIX.len()..end]);
+    let curve = name.trim().parse::<Curve>().map_err(Error::Curve)?;
+
+    Ok((curve, &data[end + 1..]))
+}
+
+/// Checks whether the key has been generated for the curve the program is compiled for.
+pub fn check_curve(key: Curve, program: Curve) -> Result<(), Error> {
+    if key != program {
+        return Err(Error::Curve(format!(
+            "the key is generated for {}, but the program is compiled for {}",
+            key, program
+        )));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn header_roundtrip() {
+        for curve in Curve::ALL.iter() {
+            let mut data = Vec::new();
+            write_header(&mut data, *curve).expect("writing to vec");
+            data.extend_from_slice(b"key");
+
+            let (parsed, rest) = split_header(data.as_slice()).expect("split");
+            assert_eq!(parsed, *curve);
+            assert_eq!(rest, b"key");
+        }
+    }
+
+    #[test]
+    fn header_missing() {
+        let (curve, rest) = split_header(b"key").expect("split");
+        assert_eq!(curve, Curve::Bn256);
+        assert_eq!(rest, b"key");
+    }
+
+    #[test]
+    fn header_not_terminated() {
+        assert!(split_header(b"curve: bn256").is_err());
+    }
+
+    #[test]
+    fn curve_mismatch() {
+        assert!(check_curve(Curve::Bn256, Curve::Bn256).is_ok());
+        assert!(check_curve(Curve::Bls12_381, Curve::Bn256).is_err());
+    }
+}
@@ -1,4 +1,5 @@
 mod commands;
+mod key_file;
 
 use crate::commands::{Arguments, Command};
 use std::process::exit;
@@ -18,6 +19,10 @@ fn main() {
         Command::Setup(command) => command.execute(),
         Command::Prove(command) => command.execute(),
         Command::Verify(command) => command.execute(),
+        Command::ExportR1cs(command) => command.execute(),
+        Command::ExportWitness(command) => command.execute(),
+        Command::ExportVerifier(command) => command.execute(),
+        Command::ExportCalldata(command) => command.execute(),
     };
 
     if let Err(error) = result {
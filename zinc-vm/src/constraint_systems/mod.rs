@@ -2,8 +2,10 @@ mod debug_cs;
 mod duplicate_removing_cs;
 mod logging_cs;
 mod noop_cs;
+mod recording_cs;
 
 pub use debug_cs::*;
 pub use duplicate_removing_cs::*;
 pub use logging_cs::*;
 pub use noop_cs::*;
+pub use recording_cs::*;
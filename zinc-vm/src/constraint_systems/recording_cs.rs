@@ -0,0 +1,478 @@
+use std::io::{self, Write};
+
+use ff::{Field, PrimeField, PrimeFieldRepr};
+use franklin_crypto::bellman::{
+    ConstraintSystem, Index, LinearCombination, SynthesisError, Variable,
+};
+use num_bigint::{BigInt, Sign};
+use serde_json::{json, Map as JsonMap, Value as JsonValue};
+
+use crate::gadgets::utils::{fr_to_bigint_signed, fr_to_bigint_unsigned};
+use crate::Engine;
+
+const ONE_ANNOTATION: &str = "ONE";
+const NAMESPACE_SEPARATOR: &str = "/";
+
+const R1CS_MAGIC: &[u8; 4] = b"r1cs";
+const R1CS_VERSION: u32 = 1;
+const R1CS_SECTION_HEADER: u32 = 1;
+const R1CS_SECTION_CONSTRAINTS: u32 = 2;
+const R1CS_SECTION_WIRE_TO_LABEL: u32 = 3;
+
+const WTNS_MAGIC: &[u8; 4] = b"wtns";
+const WTNS_VERSION: u32 = 2;
+const WTNS_SECTION_HEADER: u32 = 1;
+const WTNS_SECTION_VALUES: u32 = 2;
+
+/// A recorded `A * B = C` constraint, whose linear combinations consist of the variable
+/// indexes with their coefficients.
+pub struct Constraint<E: Engine> {
+    pub annotation: String,
+    pub a: Vec<(Index, E::Fr)>,
+    pub b: Vec<(Index, E::Fr)>,
+    pub c: Vec<(Index, E::Fr)>,
+}
+
+/// Records the constraint matrices and the variables with their annotations, so they may be
+/// exported in the circom `.r1cs` and `.wtns` binary layouts or in JSON.
+///
+/// The variables are numbered as circom wires: the constant `ONE` goes first, then the public
+/// inputs, and the witness variables follow them. The values are only known if the circuit
+/// is synthesized with the witness.
+pub struct RecordingConstraintSystem<E: Engine> {
+    inputs: Vec<(String, Option<E::Fr>)>,
+    witness: Vec<(String, Option<E::Fr>)>,
+    constraints: Vec<Constraint<E>>,
+    namespace: Vec<String>,
+}
+
+impl<E: Engine> Default for RecordingConstraintSystem<E> {
+    fn default() -> Self {
+        Self {
+            inputs: vec![(ONE_ANNOTATION.to_owned(), Some(E::Fr::one()))],
+            witness: Vec::new(),
+            constraints: Vec::new(),
+            namespace: Vec::new(),
+        }
+    }
+}
+
+impl<E: Engine> RecordingConstraintSystem<E> {
+    pub fn num_inputs(&self) -> usize {
+        self.inputs.len()
+    }
+
+    pub fn num_witness(&self) -> usize {
+        self.witness.len()
+    }
+
+    pub fn constraints(&self) -> &[Constraint<E>] {
+        self.constraints.as_slice()
+    }
+
+    /// Returns the circom wire number of the variable.
+    pub fn wire(&self, index: Index) -> usize {
+        match index {
+            Index::Input(index) => index,
+            Index::Aux(index) => self.inputs.len() + index,
+        }
+    }
+
+    /// Writes the constraint system in the circom `.r1cs` binary layout.
+    ///
+    /// The public data is written as the public inputs, and the rest of the variables as
+    /// the internal wires, which are labeled with their own numbers.
+    pub fn write_r1cs<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        let wires = self.inputs.len() + self.witness.len();
+
+        let mut header = Vec::new();
+        Self::write_field_header(&mut header)?;
+        header.write_all(&(wires as u32).to_le_bytes())?;
+        header.write_all(&0u32.to_le_bytes())?;
+        header.write_all(&((self.inputs.len() - 1) as u32).to_le_bytes())?;
+        header.write_all(&0u32.to_le_bytes())?;
+        header.write_all(&(wires as u64).to_le_bytes())?;
+        header.write_all(&(self.constraints.len() as u32).to_le_bytes())?;
+
+        let mut constraints = Vec::new();
+        for constraint in self.constraints.iter() {
+            for terms in [&constraint.a, &constraint.b, &constraint.c].iter() {
+                constraints.write_all(&(terms.len() as u32).to_le_bytes())?;
+                for (index, coefficient) in terms.iter() {
+                    constraints.write_all(&(self.wire(*index) as u32).to_le_bytes())?;
+                    coefficient.into_repr().write_le(&mut constraints)?;
+                }
+            }
+        }
+
+        let mut labels = Vec::new();
+        for wire in 0..wires {
+            labels.write_all(&(wire as u64).to_le_bytes())?;
+        }
+
+        writer.write_all(R1CS_MAGIC)?;
+        writer.write_all(&R1CS_VERSION.to_le_bytes())?;
+        writer.write_all(&3u32.to_le_bytes())?;
+        write_section(&mut writer, R1CS_SECTION_HEADER, &header)?;
+        write_section(&mut writer, R1CS_SECTION_CONSTRAINTS, &constraints)?;
+        write_section(&mut writer, R1CS_SECTION_WIRE_TO_LABEL, &labels)?;
+
+        Ok(())
+    }
+
+    /// Writes the variable values in the circom `.wtns` binary layout.
+    ///
+    /// Fails if the circuit has been synthesized without the witness.
+    pub fn write_wtns<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        let values = self.values().ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidData, "the witness values are unknown")
+        })?;
+
+        let mut header = Vec::new();
+        Self::write_field_header(&mut header)?;
+        header.write_all(&(values.len() as u32).to_le_bytes())?;
+
+        let mut body = Vec::new();
+        for value in values.iter() {
+            value.into_repr().write_le(&mut body)?;
+        }
+
+        writer.write_all(WTNS_MAGIC)?;
+        writer.write_all(&WTNS_VERSION.to_le_bytes())?;
+        writer.write_all(&2u32.to_le_bytes())?;
+        write_section(&mut writer, WTNS_SECTION_HEADER, &header)?;
+        write_section(&mut writer, WTNS_SECTION_VALUES, &body)?;
+
+        Ok(())
+    }
+
+    /// Returns the constraint system in the readable JSON form, where the linear combinations
+    /// map the wire numbers to the signed coefficients.
+    pub fn r1cs_json(&self) -> JsonValue {
+        let constraints: Vec<JsonValue> = self
+            .constraints
+            .iter()
+            .map(|constraint| {
+                json!({
+                    "annotation": constraint.annotation,
+                    "a": self.terms_json(&constraint.a),
+                    "b": self.terms_json(&constraint.b),
+                    "c": self.terms_json(&constraint.c),
+                })
+            })
+            .collect();
+
+        let wires: Vec<&str> = self
+            .wires()
+            .map(|(annotation, _value)| annotation.as_str())
+            .collect();
+
+        json!({
+            "prime": modulus::<E>().to_string(),
+            "public_inputs": self.inputs.len() - 1,
+            "wires": wires,
+            "constraints": constraints,
+        })
+    }
+
+    /// Returns the wire values in the readable JSON form, where the unknown values are `null`.
+    pub fn witness_json(&self) -> JsonValue {
+        let wires: Vec<JsonValue> = self
+            .wires()
+            .enumerate()
+            .map(|(wire, (annotation, value))| {
+                json!({
+                    "wire": wire,
+                    "annotation": annotation,
+                    "value": value.map(|value| fr_to_bigint_unsigned(&value).to_string()),
+                })
+            })
+            .collect();
+
+        JsonValue::Array(wires)
+    }
+
+    /// Returns the annotation of the first constraint which does not hold for the recorded
+    /// values, or `None` if all of them hold.
+    ///
+    /// Fails if the circuit has been synthesized without the witness.
+    pub fn which_is_unsatisfied(&self) -> Result<Option<&str>, SynthesisError> {
+        let values = self.values().ok_or(SynthesisError::AssignmentMissing)?;
+
+        for constraint in self.constraints.iter() {
+            let mut product = self.evaluate(&constraint.a, &values);
+            product.mul_assign(&self.evaluate(&constraint.b, &values));
+            if product != self.evaluate(&constraint.c, &values) {
+                return Ok(Some(constraint.annotation.as_str()));
+            }
+        }
+
+        Ok(None)
+    }
+
+    fn wires(&self) -> impl Iterator<Item = &(String, Option<E::Fr>)> {
+        self.inputs.iter().chain(self.witness.iter())
+    }
+
+    fn values(&self) -> Option<Vec<E::Fr>> {
+        self.wires().map(|(_annotation, value)| *value).collect()
+    }
+
+    fn terms_json(&self, terms: &[(Index, E::Fr)]) -> JsonValue {
+        let mut object = JsonMap::with_capacity(terms.len());
+        for (index, coefficient) in terms.iter() {
+            object.insert(
+                self.wire(*index).to_string(),
+                JsonValue::String(fr_to_bigint_signed(coefficient).to_string()),
+            );
+        }
+        JsonValue::Object(object)
+    }
+
+    fn evaluate(&self, terms: &[(Index, E::Fr)], values: &[E::Fr]) -> E::Fr {
+        let mut sum = E::Fr::zero();
+        for (index, coefficient) in terms.iter() {
+            let mut term = values[self.wire(*index)];
+            term.mul_assign(coefficient);
+            sum.add_assign(&term);
+        }
+        sum
+    }
+
+    fn write_field_header<W: Write>(mut writer: W) -> io::Result<()> {
+        let mut prime = Vec::new();
+        E::Fr::char().write_le(&mut prime)?;
+        writer.write_all(&(prime.len() as u32).to_le_bytes())?;
+        writer.write_all(&prime)
+    }
+
+    fn annotate(&self, annotation: String) -> String {
+        if self.namespace.is_empty() {
+            annotation
+        } else {
+            self.namespace.join(NAMESPACE_SEPARATOR) + NAMESPACE_SEPARATOR + &annotation
+        }
+    }
+
+    fn record(&self, lc: LinearCombination<E>) -> Vec<(Index, E::Fr)> {
+        lc.as_ref()
+            .iter()
+            .map(|(variable, coefficient)| (variable.get_unchecked(), *coefficient))
+            .collect()
+    }
+}
+
+impl<E: Engine> ConstraintSystem<E> for RecordingConstraintSystem<E> {
+    type Root = Self;
+
+    fn alloc<F, A, AR>(&mut self, annotation: A, f: F) -> Result<Variable, SynthesisError>
+    where
+        F: FnOnce() -> Result<E::Fr, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+    {
+        let annotation = self.annotate(annotation().into());
+        let value = assignment(f)?;
+        self.witness.push((annotation, value));
+        Ok(Variable::new_unchecked(Index::Aux(self.witness.len() - 1)))
+    }
+
+    fn alloc_input<F, A, AR>(&mut self, annotation: A, f: F) -> Result<Variable, SynthesisError>
+    where
+        F: FnOnce() -> Result<E::Fr, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+    {
+        let annotation = self.annotate(annotation().into());
+        let value = assignment(f)?;
+        self.inputs.push((annotation, value));
+        Ok(Variable::new_unchecked(Index::Input(self.inputs.len() - 1)))
+    }
+
+    fn enforce<A, AR, LA, LB, LC>(&mut self, annotation: A, a: LA, b: LB, c: LC)
+    where
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+        LA: FnOnce(LinearCombination<E>) -> LinearCombination<E>,
+        LB: FnOnce(LinearCombination<E>) -> LinearCombination<E>,
+        LC: FnOnce(LinearCombination<E>) -> LinearCombination<E>,
+    {
+        let constraint = Constraint {
+            annotation: self.annotate(annotation().into()),
+            a: self.record(a(LinearCombination::zero())),
+            b: self.record(b(LinearCombination::zero())),
+            c: self.record(c(LinearCombination::zero())),
+        };
+        self.constraints.push(constraint);
+    }
+
+    fn push_namespace<NR, N>(&mut self, name_fn: N)
+    where
+        NR: Into<String>,
+        N: FnOnce() -> NR,
+    {
+        self.namespace.push(name_fn().into());
+    }
+
+    fn pop_namespace(&mut self) {
+        self.namespace.pop();
+    }
+
+    fn get_root(&mut self) -> &mut Self::Root {
+        self
+    }
+}
+
+/// Computes the variable value, which is missing if the circuit is synthesized without
+/// the witness.
+fn assignment<T, F>(f: F) -> Result<Option<T>, SynthesisError>
+where
+    F: FnOnce() -> Result<T, SynthesisError>,
+{
+    match f() {
+        Ok(value) => Ok(Some(value)),
+        Err(SynthesisError::AssignmentMissing) => Ok(None),
+        Err(error) => Err(error),
+    }
+}
+
+fn modulus<E: Engine>() -> BigInt {
+    let mut buffer = Vec::new();
+    E::Fr::char()
+        .write_be(&mut buffer)
+        .expect("failed to write into Vec<u8>");
+    BigInt::from_bytes_be(Sign::Plus, &buffer)
+}
+
+fn write_section<W: Write>(mut writer: W, r#type: u32, data: &[u8]) -> io::Result<()> {
+    writer.write_all(&r#type.to_le_bytes())?;
+    writer.write_all(&(data.len() as u64).to_le_bytes())?;
+    writer.write_all(data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use pairing::bn256::{Bn256, Fr};
+
+    fn square() -> RecordingConstraintSystem<Bn256> {
+        let mut cs = RecordingConstraintSystem::<Bn256>::default();
+
+        let x = cs
+            .alloc(
+                || "x",
+                || Fr::from_str("3").ok_or(SynthesisError::Unsatisfiable),
+            )
+            .unwrap();
+        let y = cs
+            .namespace(|| "square")
+            .alloc_input(
+                || "y",
+                || Fr::from_str("9").ok_or(SynthesisError::Unsatisfiable),
+            )
+            .unwrap();
+        cs.enforce(|| "x * x = y", |lc| lc + x, |lc| lc + x, |lc| lc + y);
+
+        cs
+    }
+
+    fn read_u32(bytes: &[u8], offset: usize) -> u32 {
+        let mut buffer = [0u8; 4];
+        buffer.copy_from_slice(&bytes[offset..offset + 4]);
+        u32::from_le_bytes(buffer)
+    }
+
+    fn read_u64(bytes: &[u8], offset: usize) -> u64 {
+        let mut buffer = [0u8; 8];
+        buffer.copy_from_slice(&bytes[offset..offset + 8]);
+        u64::from_le_bytes(buffer)
+    }
+
+    #[test]
+    fn test_json() {
+        let cs = square();
+
+        assert_eq!(
+            cs.r1cs_json(),
+            json!({
+                "prime": modulus::<Bn256>().to_string(),
+                "public_inputs": 1,
+                "wires": ["ONE", "square/y", "x"],
+                "constraints": [{
+                    "annotation": "x * x = y",
+                    "a": { "2": "1" },
+                    "b": { "2": "1" },
+                    "c": { "1": "1" },
+                }],
+            })
+        );
+        assert_eq!(cs.witness_json()[2]["value"], json!("3"));
+    }
+
+    #[test]
+    fn test_binary() {
+        let cs = square();
+
+        let mut r1cs = Vec::new();
+        cs.write_r1cs(&mut r1cs).unwrap();
+        assert_eq!(&r1cs[0..4], b"r1cs");
+        assert_eq!(read_u32(&r1cs, 4), R1CS_VERSION);
+        assert_eq!(read_u32(&r1cs, 8), 3);
+        assert_eq!(
+            r1cs.len(),
+            12 + (12 + 64) + (12 + 3 * (4 + 36)) + (12 + 3 * 8)
+        );
+
+        assert_eq!(read_u32(&r1cs, 12), R1CS_SECTION_HEADER);
+        assert_eq!(read_u64(&r1cs, 16), 64);
+        assert_eq!(read_u32(&r1cs, 24), 32, "n8");
+        assert_eq!(
+            BigInt::from_bytes_le(Sign::Plus, &r1cs[28..60]),
+            modulus::<Bn256>(),
+            "prime"
+        );
+        assert_eq!(read_u32(&r1cs, 60), 3, "nWires");
+        assert_eq!(read_u32(&r1cs, 64), 0, "nPubOut");
+        assert_eq!(read_u32(&r1cs, 68), 1, "nPubIn");
+        assert_eq!(read_u32(&r1cs, 72), 0, "nPrvIn");
+        assert_eq!(read_u64(&r1cs, 76), 3, "nLabels");
+        assert_eq!(read_u32(&r1cs, 84), 1, "mConstraints");
+
+        assert_eq!(read_u32(&r1cs, 88), R1CS_SECTION_CONSTRAINTS);
+        assert_eq!(read_u64(&r1cs, 92), 3 * (4 + 36));
+        assert_eq!(read_u32(&r1cs, 100), 1, "the number of terms of A");
+        assert_eq!(read_u32(&r1cs, 104), 2, "the wire of x");
+        assert_eq!(
+            BigInt::from_bytes_le(Sign::Plus, &r1cs[108..140]),
+            BigInt::from(1),
+            "the coefficient of x"
+        );
+
+        let mut wtns = Vec::new();
+        cs.write_wtns(&mut wtns).unwrap();
+        assert_eq!(&wtns[0..4], b"wtns");
+        assert_eq!(wtns.len(), 12 + (12 + 40) + (12 + 3 * 32));
+    }
+
+    #[test]
+    fn test_satisfied() {
+        let mut cs = square();
+        assert_eq!(cs.which_is_unsatisfied().unwrap(), None);
+
+        let x = Variable::new_unchecked(Index::Aux(0));
+        cs.enforce(|| "x * x = x", |lc| lc + x, |lc| lc + x, |lc| lc + x);
+        assert_eq!(cs.which_is_unsatisfied().unwrap(), Some("x * x = x"));
+    }
+
+    #[test]
+    fn test_binary_without_witness() {
+        let mut cs = RecordingConstraintSystem::<Bn256>::default();
+        cs.alloc(|| "x", || Err(SynthesisError::AssignmentMissing))
+            .unwrap();
+
+        assert!(cs.write_wtns(Vec::new()).is_err());
+        assert!(cs.which_is_unsatisfied().is_err());
+        assert_eq!(cs.witness_json()[1]["value"], JsonValue::Null);
+    }
+}
@@ -8,7 +8,7 @@ pub use state::*;
 
 use crate::core::location::CodeLocation;
 use crate::errors::MalformedBytecode;
-use crate::gadgets::{Gadgets, Scalar, ScalarType};
+use crate::gadgets::{self, Gadgets, Scalar, ScalarType};
 use crate::Engine;
 use colored::Colorize;
 use franklin_crypto::bellman::ConstraintSystem;
@@ -88,6 +88,13 @@ impl<E: Engine, CS: ConstraintSystem<E>> VirtualMachine<E, CS> {
         CB: FnMut(&CS) -> (),
         F: FnMut(&CS) -> Result<(), RuntimeError>,
     {
+        if program.curve != E::CURVE {
+            return Err(RuntimeError::CurveMismatch {
+                program: program.curve,
+                engine: E::CURVE,
+            });
+        }
+
         self.cs.cs.enforce(
             || "ONE * ONE = ONE (do this to avoid `unconstrained` error)",
             |zero| zero + CS::one(),
@@ -99,7 +106,7 @@ impl<E: Engine, CS: ConstraintSystem<E>> VirtualMachine<E, CS> {
             .constant_bigint(&1.into(), ScalarType::Boolean)?;
         self.condition_push(one)?;
 
-        self.init_root_frame(&program.input, inputs)?;
+        self.init_root_frame(&program.input, &program.public_input, inputs)?;
 
         let mut step = 0;
         while self.state.instruction_counter < program.bytecode.len() {
@@ -131,13 +138,14 @@ impl<E: Engine, CS: ConstraintSystem<E>> VirtualMachine<E, CS> {
     fn init_root_frame(
         &mut self,
         input_type: &object_types::DataType,
+        public_input_type: &object_types::DataType,
         inputs: Option<&[BigInt]>,
     ) -> Result<(), RuntimeError> {
         self.state
             .frames_stack
             .push(FunctionFrame::new(0, std::usize::MAX));
 
-        let types = data_type_into_scalar_types(&input_type);
+        let types = input_into_scalar_types(&input_type, &public_input_type);
 
         // Convert Option<&[BigInt]> to iterator of Option<&BigInt> and zip with types.
         let value_type_pairs: Vec<_> = match inputs {
@@ -145,14 +153,103 @@ impl<E: Engine, CS: ConstraintSystem<E>> VirtualMachine<E, CS> {
             None => std::iter::repeat(None).zip(types).collect(),
         };
 
-        for (value, dtype) in value_type_pairs {
-            let variable = self.operations().allocate_witness(value, dtype)?;
+        let mut variables = Vec::with_capacity(value_type_pairs.len());
+        for (value, (dtype, is_public)) in value_type_pairs {
+            let variable = if is_public {
+                self.operations().allocate_input(value, dtype)?
+            } else {
+                self.operations().allocate_witness(value, dtype)?
+            };
+            variables.push(variable);
+        }
+
+        self.enforce_tagged_unions(input_type, &variables, &mut 0)?;
+
+        for variable in variables.into_iter() {
             self.push(Cell::Value(variable))?;
         }
 
         Ok(())
     }
 
+    /// Finds the tagged unions among the `main` arguments, which are passed by the prover
+    /// as bare fields, and enforces them to be valid.
+    fn enforce_tagged_unions(
+        &mut self,
+        dtype: &object_types::DataType,
+        variables: &[Scalar<E>],
+        offset: &mut usize,
+    ) -> Result<(), RuntimeError> {
+        match dtype {
+            object_types::DataType::Unit => {}
+            object_types::DataType::Scalar(_) | object_types::DataType::Enum => {
+                *offset += 1;
+            }
+            object_types::DataType::Struct(fields) => {
+                for (_, t) in fields {
+                    self.enforce_tagged_unions(t, variables, offset)?;
+                }
+            }
+            object_types::DataType::Tuple(fields) => {
+                for t in fields {
+                    self.enforce_tagged_unions(t, variables, offset)?;
+                }
+            }
+            object_types::DataType::Array(t, size) => {
+                for _ in 0..*size {
+                    self.enforce_tagged_unions(t.as_ref(), variables, offset)?;
+                }
+            }
+            object_types::DataType::TaggedUnion(variants) => {
+                let size = dtype.size();
+                self.enforce_tagged_union(
+                    variants,
+                    &variables[*offset],
+                    &variables[*offset + 1..*offset + size],
+                )?;
+                *offset += size;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Enforces the tag to select one of the `variants`, and the payload slots past the
+    /// selected variant to be zero.
+    ///
+    /// The `match` falls back to the last variant, so an unchecked tag would let the prover
+    /// take its branch with an arbitrary value.
+    fn enforce_tagged_union(
+        &mut self,
+        variants: &[(String, object_types::DataType)],
+        tag: &Scalar<E>,
+        payload: &[Scalar<E>],
+    ) -> Result<(), RuntimeError> {
+        let variants_count = Scalar::new_constant_int(variants.len(), ScalarType::Field);
+        let is_in_range = gadgets::lt(self.cs.namespace(), tag, &variants_count)?;
+        self.operations()
+            .assert(is_in_range, Some("enumeration variant tag is out of range"))?;
+
+        let zero = Scalar::new_constant_int(0, ScalarType::Field);
+        for (index, (_, variant)) in variants.iter().enumerate() {
+            if variant.size() == payload.len() {
+                continue;
+            }
+
+            let variant_tag = Scalar::new_constant_int(index, ScalarType::Field);
+            let is_selected = self.operations().eq(tag.clone(), variant_tag)?;
+            let is_not_selected = gadgets::not(self.cs.namespace(), &is_selected)?;
+            for slot in payload.iter().skip(variant.size()) {
+                let is_zero = self.operations().eq(slot.clone(), zero.clone())?;
+                let is_valid = self.operations().or(is_not_selected.clone(), is_zero)?;
+                self.operations()
+                    .assert(is_valid, Some("enumeration variant padding is not zero"))?;
+            }
+        }
+
+        Ok(())
+    }
+
     fn get_outputs(&mut self) -> Result<Vec<Option<BigInt>>, RuntimeError> {
         let outputs_fr: Vec<_> = self.outputs.iter().map(|f| (*f).clone()).collect();
 
@@ -222,6 +319,11 @@ fn data_type_into_scalar_types(dtype: &object_types::DataType) -> Vec<ScalarType
                     internal(types, t.as_ref());
                 }
             }
+            object_types::DataType::TaggedUnion(_) => {
+                for _ in 0..dtype.size() {
+                    types.push(ScalarType::Field);
+                }
+            }
         }
     }
 
@@ -229,3 +331,32 @@ fn data_type_into_scalar_types(dtype: &object_types::DataType) -> Vec<ScalarType
     internal(&mut types, dtype);
     types
 }
+
+/// Flattens the `main` arguments into scalar types, marking those which are public inputs.
+fn input_into_scalar_types(
+    input_type: &object_types::DataType,
+    public_input_type: &object_types::DataType,
+) -> Vec<(ScalarType, bool)> {
+    let public_names: Vec<&str> = match public_input_type {
+        object_types::DataType::Struct(fields) => {
+            fields.iter().map(|(name, _)| name.as_str()).collect()
+        }
+        _ => vec![],
+    };
+
+    match input_type {
+        object_types::DataType::Struct(fields) => fields
+            .iter()
+            .flat_map(|(name, dtype)| {
+                let is_public = public_names.contains(&name.as_str());
+                data_type_into_scalar_types(dtype)
+                    .into_iter()
+                    .map(move |scalar_type| (scalar_type, is_public))
+            })
+            .collect(),
+        dtype => data_type_into_scalar_types(dtype)
+            .into_iter()
+            .map(|scalar_type| (scalar_type, false))
+            .collect(),
+    }
+}
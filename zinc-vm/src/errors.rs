@@ -2,6 +2,7 @@ use crate::gadgets::ScalarType;
 use failure::Fail;
 use franklin_crypto::bellman::SynthesisError;
 use num_bigint::BigInt;
+use zinc_bytecode::Curve;
 
 pub type Result<T = ()> = std::result::Result<T, RuntimeError>;
 
@@ -97,6 +98,12 @@ pub enum RuntimeError {
 
     #[fail(display = "using witness as array index is not yet supported")]
     WitnessArrayIndex,
+
+    #[fail(
+        display = "curve mismatch: the program is compiled for {}, but run over {}",
+        program, engine
+    )]
+    CurveMismatch { program: Curve, engine: Curve },
 }
 
 impl From<SynthesisError> for RuntimeError {
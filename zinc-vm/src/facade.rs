@@ -1,15 +1,16 @@
 use std::fmt::Debug;
 
 use bellman::groth16;
-use bellman::pairing::bn256::Bn256;
 use franklin_crypto::bellman::groth16::{Parameters, Proof, VerifyingKey};
 use franklin_crypto::bellman::{Circuit, ConstraintSystem, SynthesisError};
 use num_bigint::BigInt;
-use rand::ThreadRng;
+use rand::Rng;
 
 use zinc_bytecode::program::Program;
 
-use crate::constraint_systems::{DebugConstraintSystem, DuplicateRemovingCS};
+use crate::constraint_systems::{
+    ConstantCS, DebugConstraintSystem, DuplicateRemovingCS, RecordingConstraintSystem,
+};
 use crate::core::VirtualMachine;
 pub use crate::errors::{MalformedBytecode, Result, RuntimeError, TypeSizeError};
 use crate::gadgets::utils::bigint_to_fr;
@@ -38,7 +39,7 @@ impl<E: Engine> Circuit<E> for VMCircuit<'_> {
 }
 
 pub fn run<E: Engine>(program: &Program, inputs: &Value) -> Result<Value> {
-    let cs = DebugConstraintSystem::<Bn256>::default();
+    let cs = DebugConstraintSystem::<E>::default();
     let mut vm = VirtualMachine::new(cs, true);
 
     let inputs_flat = inputs.to_flat_values();
@@ -81,8 +82,33 @@ pub fn run<E: Engine>(program: &Program, inputs: &Value) -> Result<Value> {
     Ok(value)
 }
 
+/// Runs the program computing only the values, so no constraints are kept, which is
+/// much faster than `run`. The assertions and the integer overflows are still checked,
+/// since they are detected using the values.
+pub fn evaluate<E: Engine>(program: &Program, inputs: &Value) -> Result<Value> {
+    let mut vm = VirtualMachine::<E, ConstantCS>::new(ConstantCS, true);
+
+    let inputs_flat = inputs.to_flat_values();
+
+    let result = vm.run(program, Some(&inputs_flat), |_| {}, |_| Ok(()))?;
+
+    let output_flat = result
+        .into_iter()
+        .map(|v| v.expect("`evaluate` always computes witness"))
+        .collect::<Vec<_>>();
+
+    let value = Value::from_flat_values(&program.output, &output_flat).ok_or_else(|| {
+        TypeSizeError::Output {
+            expected: 0,
+            actual: 0,
+        }
+    })?;
+
+    Ok(value)
+}
+
 pub fn debug<E: Engine>(program: &Program, inputs: &Value) -> Result<Value> {
-    let cs = TestConstraintSystem::<Bn256>::new();
+    let cs = TestConstraintSystem::<E>::new();
     let mut vm = VirtualMachine::new(cs, true);
 
     let inputs_flat = inputs.to_flat_values();
@@ -137,8 +163,9 @@ pub fn debug<E: Engine>(program: &Program, inputs: &Value) -> Result<Value> {
     Ok(value)
 }
 
-pub fn setup<E: Engine>(program: &Program) -> Result<Parameters<E>> {
-    let rng = &mut rand::thread_rng();
+/// Generates the proving and verifying keys using `rng`, which must be a secure random
+/// generator, e.g. `rand::thread_rng()`, unless the keys are generated for testing.
+pub fn setup<E: Engine, R: Rng>(program: &Program, rng: &mut R) -> Result<Parameters<E>> {
     let mut result = None;
     let circuit = VMCircuit {
         program,
@@ -146,7 +173,7 @@ pub fn setup<E: Engine>(program: &Program) -> Result<Parameters<E>> {
         result: &mut result,
     };
 
-    let params = groth16::generate_random_parameters::<E, VMCircuit, ThreadRng>(circuit, rng)?;
+    let params = groth16::generate_random_parameters::<E, VMCircuit, R>(circuit, rng)?;
 
     match result.expect("vm should return either output or error") {
         Ok(_) => Ok(params),
@@ -154,13 +181,14 @@ pub fn setup<E: Engine>(program: &Program) -> Result<Parameters<E>> {
     }
 }
 
-pub fn prove<E: Engine>(
+/// Generates the proof using `rng`, which must be a secure random generator,
+/// e.g. `rand::thread_rng()`, unless the proof is generated for testing.
+pub fn prove<E: Engine, R: Rng>(
     program: &Program,
     params: &Parameters<E>,
     witness: &Value,
+    rng: &mut R,
 ) -> Result<(Value, Proof<E>)> {
-    let rng = &mut rand::thread_rng();
-
     let witness_flat = witness.to_flat_values();
 
     let (result, proof) = {
@@ -203,6 +231,31 @@ pub fn prove<E: Engine>(
     }
 }
 
+/// Synthesizes the circuit into the recording constraint system, which may be exported
+/// afterwards. The variable values are recorded only if the witness is passed.
+pub fn synthesize<E: Engine>(
+    program: &Program,
+    witness: Option<&Value>,
+) -> Result<RecordingConstraintSystem<E>> {
+    let witness_flat = witness.map(Value::to_flat_values);
+
+    let mut cs = RecordingConstraintSystem::<E>::default();
+    let mut result = None;
+    let circuit = VMCircuit {
+        program,
+        inputs: witness_flat.as_ref().map(Vec::as_slice),
+        result: &mut result,
+    };
+    circuit
+        .synthesize(&mut cs)
+        .map_err(RuntimeError::SynthesisError)?;
+
+    match result.expect("vm should return either output or error") {
+        Ok(_) => Ok(cs),
+        Err(error) => Err(error),
+    }
+}
+
 #[derive(Debug, Fail)]
 pub enum VerificationError {
     #[fail(display = "value overflow: value {} is not in the field", _0)]
@@ -212,12 +265,14 @@ pub enum VerificationError {
     SynthesisError(SynthesisError),
 }
 
+/// Verifies the proof against the public data, which consists of the public inputs
+/// followed by the program output (see `Program::public_data`).
 pub fn verify<E: Engine>(
     key: &VerifyingKey<E>,
     proof: &Proof<E>,
-    public_input: &Value,
+    public_data: &Value,
 ) -> std::result::Result<bool, VerificationError> {
-    let public_input_flat = public_input
+    let public_input_flat = public_data
         .to_flat_values()
         .into_iter()
         .map(|value| {
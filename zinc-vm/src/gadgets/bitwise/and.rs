@@ -0,0 +1,39 @@
+use crate::auto_const;
+use crate::gadgets::auto_const::prelude::*;
+use crate::gadgets::bitwise::bits;
+use crate::gadgets::{Scalar, ScalarType, ScalarTypeExpectation};
+use crate::{Engine, Result};
+use franklin_crypto::bellman::ConstraintSystem;
+use franklin_crypto::circuit::boolean::Boolean;
+
+pub fn bit_and<E, CS>(cs: CS, left: &Scalar<E>, right: &Scalar<E>) -> Result<Scalar<E>>
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+{
+    fn inner<E, CS>(mut cs: CS, left: &Scalar<E>, right: &Scalar<E>) -> Result<Scalar<E>>
+    where
+        E: Engine,
+        CS: ConstraintSystem<E>,
+    {
+        let scalar_type = ScalarType::expect_same(left.get_type(), right.get_type())?;
+        let int_type = bits::integer_type(scalar_type)?;
+
+        let left_bits = bits::into_bits_le(cs.namespace(|| "left bits"), left)?;
+        let right_bits = bits::into_bits_le(cs.namespace(|| "right bits"), right)?;
+
+        let mut result_bits = Vec::with_capacity(int_type.bitlength);
+        for (index, (left_bit, right_bit)) in left_bits.iter().zip(right_bits.iter()).enumerate() {
+            let bit = Boolean::and(
+                cs.namespace(|| format!("bit {}", index)),
+                left_bit,
+                right_bit,
+            )?;
+            result_bits.push(bit);
+        }
+
+        bits::from_bits_le(cs.namespace(|| "result"), &result_bits, int_type)
+    }
+
+    auto_const!(inner, cs, left, right)
+}
@@ -0,0 +1,177 @@
+use crate::gadgets::{utils, IntegerType, Scalar, ScalarType};
+use crate::{Engine, Result, RuntimeError};
+use franklin_crypto::bellman::{ConstraintSystem, SynthesisError};
+use franklin_crypto::circuit::boolean::Boolean;
+use franklin_crypto::circuit::expression::Expression;
+use franklin_crypto::circuit::num::AllocatedNum;
+use num_bigint::BigInt;
+use num_traits::ToPrimitive;
+
+pub fn integer_type(scalar_type: ScalarType) -> Result<IntegerType> {
+    match scalar_type {
+        ScalarType::Integer(int_type) => Ok(int_type),
+        scalar_type => Err(RuntimeError::TypeError {
+            expected: "integer type".into(),
+            actual: scalar_type.to_string(),
+        }),
+    }
+}
+
+/// Decomposes the integer into the little-endian bits of its two's complement representation.
+pub fn into_bits_le<E, CS>(mut cs: CS, scalar: &Scalar<E>) -> Result<Vec<Boolean>>
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+{
+    let int_type = integer_type(scalar.get_type())?;
+    let expr = scalar.to_expression::<CS>();
+
+    if int_type.is_signed {
+        let base_value = BigInt::from(1) << int_type.bitlength;
+        let base = Scalar::<E>::new_constant_bigint(&base_value, ScalarType::Field)?;
+
+        let complement = expr + base.to_expression::<CS>();
+        let bits =
+            complement.into_bits_le_fixed(cs.namespace(|| "bits"), int_type.bitlength + 1)?;
+
+        Ok(Vec::from(&bits[..int_type.bitlength]))
+    } else {
+        Ok(expr.into_bits_le_fixed(cs.namespace(|| "bits"), int_type.bitlength)?)
+    }
+}
+
+/// Packs the little-endian two's complement bits back into an integer of type `int_type`.
+pub fn from_bits_le<E, CS>(mut cs: CS, bits: &[Boolean], int_type: IntegerType) -> Result<Scalar<E>>
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+{
+    let mut bits = Vec::from(bits);
+
+    if int_type.is_signed {
+        let sign_bit = bits[int_type.bitlength - 1].clone();
+        bits.push(sign_bit.not());
+    }
+
+    let mut num =
+        AllocatedNum::pack_bits_to_element(cs.namespace(|| "pack_bits_to_element"), &bits)?;
+
+    if int_type.is_signed {
+        let base_value = BigInt::from(1) << int_type.bitlength;
+        let base = Scalar::<E>::new_constant_bigint(&base_value, ScalarType::Field)?;
+
+        let expr = Expression::from(&num) - base.to_expression::<CS>();
+        num = expr.into_number(cs.namespace(|| "complement"))?;
+    }
+
+    Ok(Scalar::new_unchecked_variable(
+        num.get_value(),
+        num.get_variable(),
+        int_type.into(),
+    ))
+}
+
+/// Shifts `bits` by the amount stored in the unsigned integer `shift`.
+///
+/// `shift_by` moves the bits by a known amount, filling the vacated positions with `fill`.
+/// A constant `shift` is applied directly. A witness `shift` is decomposed into bits and
+/// applied as a sequence of conditional shifts by powers of two. Any amount not less than the
+/// number of bits yields `fill` in every position.
+pub fn shift_bits_le<E, CS, F>(
+    mut cs: CS,
+    bits: &[Boolean],
+    shift: &Scalar<E>,
+    fill: &Boolean,
+    shift_by: F,
+) -> Result<Vec<Boolean>>
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+    F: Fn(&[Boolean], usize, &Boolean) -> Vec<Boolean>,
+{
+    let length = bits.len();
+
+    let shift_type = integer_type(shift.get_type())?;
+    if shift_type.is_signed {
+        return Err(RuntimeError::TypeError {
+            expected: "unsigned integer type".into(),
+            actual: shift.get_type().to_string(),
+        });
+    }
+
+    if shift.is_constant() {
+        let amount = utils::fr_to_bigint(&shift.get_constant()?, false)
+            .to_usize()
+            .unwrap_or(length)
+            .min(length);
+        return Ok(shift_by(bits, amount, fill));
+    }
+
+    let shift_bits = shift
+        .to_expression::<CS>()
+        .into_bits_le_fixed(cs.namespace(|| "shift bits"), shift_type.bitlength)?;
+
+    let mut result = Vec::from(bits);
+    let mut overflow = Boolean::constant(false);
+    for (index, bit) in shift_bits.iter().enumerate() {
+        match 1usize
+            .checked_shl(index as u32)
+            .filter(|amount| *amount < length)
+        {
+            Some(amount) => {
+                let shifted = shift_by(&result, amount, fill);
+                result = select(
+                    cs.namespace(|| format!("stage {}", index)),
+                    bit,
+                    &shifted,
+                    &result,
+                )?;
+            }
+            None => {
+                overflow = Boolean::and(
+                    cs.namespace(|| format!("overflow {}", index)),
+                    &overflow.not(),
+                    &bit.not(),
+                )?
+                .not();
+            }
+        }
+    }
+
+    let filled = vec![fill.clone(); length];
+    select(cs.namespace(|| "overflow"), &overflow, &filled, &result)
+}
+
+/// Selects `then` bits if `condition` is set, and `otherwise` bits if it is not.
+fn select<E, CS>(
+    mut cs: CS,
+    condition: &Boolean,
+    then: &[Boolean],
+    otherwise: &[Boolean],
+) -> Result<Vec<Boolean>>
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+{
+    match condition {
+        Boolean::Constant(true) => return Ok(Vec::from(then)),
+        Boolean::Constant(false) => return Ok(Vec::from(otherwise)),
+        _ => {}
+    }
+
+    let result = then
+        .iter()
+        .zip(otherwise.iter())
+        .enumerate()
+        .map(|(index, (then, otherwise))| {
+            Boolean::sha256_ch(
+                cs.namespace(|| format!("bit {}", index)),
+                condition,
+                then,
+                otherwise,
+            )
+        })
+        .collect::<std::result::Result<Vec<Boolean>, SynthesisError>>()?;
+
+    Ok(result)
+}
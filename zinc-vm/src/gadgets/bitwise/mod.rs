@@ -0,0 +1,77 @@
+mod and;
+mod bits;
+mod not;
+mod or;
+mod shift_left;
+mod shift_right;
+mod xor;
+
+pub use and::*;
+pub use not::*;
+pub use or::*;
+pub use shift_left::*;
+pub use shift_right::*;
+pub use xor::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use bellman::ConstraintSystem;
+    use franklin_crypto::circuit::num::AllocatedNum;
+    use franklin_crypto::circuit::test::TestConstraintSystem;
+    use num_bigint::{BigInt, ToBigInt};
+    use pairing::bn256::Bn256;
+
+    use crate::gadgets::{utils, Scalar};
+    use zinc_bytecode::scalar::{IntegerType, ScalarType};
+
+    fn witness<CS>(mut cs: CS, value: i64, scalar_type: ScalarType) -> Scalar<Bn256>
+    where
+        CS: ConstraintSystem<Bn256>,
+    {
+        let fr = utils::bigint_to_fr::<Bn256>(&BigInt::from(value)).unwrap();
+        let num = AllocatedNum::alloc(cs.namespace(|| "witness"), || Ok(fr)).unwrap();
+        Scalar::new_unchecked_variable(num.get_value(), num.get_variable(), scalar_type)
+    }
+
+    #[test]
+    fn test_binary_witness() {
+        let mut cs = TestConstraintSystem::<Bn256>::new();
+
+        let a = witness(cs.namespace(|| "a"), -84, IntegerType::I8.into());
+        let b = witness(cs.namespace(|| "b"), 42, IntegerType::I8.into());
+
+        let and = bit_and(cs.namespace(|| "and"), &a, &b).unwrap();
+        let or = bit_or(cs.namespace(|| "or"), &a, &b).unwrap();
+        let xor = bit_xor(cs.namespace(|| "xor"), &a, &b).unwrap();
+        let not = bit_not(cs.namespace(|| "not"), &a).unwrap();
+
+        assert_eq!(and.to_bigint(), Some(BigInt::from(-84 & 42)));
+        assert_eq!(or.to_bigint(), Some(BigInt::from(-84 | 42)));
+        assert_eq!(xor.to_bigint(), Some(BigInt::from(-84 ^ 42)));
+        assert_eq!(not.to_bigint(), Some(BigInt::from(83)));
+        assert!(cs.is_satisfied());
+    }
+
+    #[test]
+    fn test_shift_witness() {
+        let mut cs = TestConstraintSystem::<Bn256>::new();
+
+        let unsigned = witness(cs.namespace(|| "unsigned"), 255, IntegerType::U8.into());
+        let signed = witness(cs.namespace(|| "signed"), -84, IntegerType::I8.into());
+        let four = witness(cs.namespace(|| "four"), 4, IntegerType::U8.into());
+        let large = witness(cs.namespace(|| "large"), 200, IntegerType::U8.into());
+
+        let left = shift_left(cs.namespace(|| "left"), &unsigned, &four).unwrap();
+        let left_large = shift_left(cs.namespace(|| "left large"), &unsigned, &large).unwrap();
+        let right = shift_right(cs.namespace(|| "right"), &signed, &four).unwrap();
+        let right_large = shift_right(cs.namespace(|| "right large"), &signed, &large).unwrap();
+
+        assert_eq!(left.to_bigint(), Some(BigInt::from(240)));
+        assert_eq!(left_large.to_bigint(), Some(BigInt::from(0)));
+        assert_eq!(right.to_bigint(), Some(BigInt::from(-6)));
+        assert_eq!(right_large.to_bigint(), Some(BigInt::from(-1)));
+        assert!(cs.is_satisfied());
+    }
+}
@@ -0,0 +1,41 @@
+use crate::auto_const;
+use crate::gadgets::auto_const::prelude::*;
+use crate::gadgets::bitwise::bits;
+use crate::gadgets::{Scalar, ScalarType};
+use crate::{Engine, Result};
+use franklin_crypto::bellman::ConstraintSystem;
+use num_bigint::BigInt;
+
+pub fn bit_not<E, CS>(cs: CS, scalar: &Scalar<E>) -> Result<Scalar<E>>
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+{
+    fn inner<E, CS>(mut cs: CS, scalar: &Scalar<E>) -> Result<Scalar<E>>
+    where
+        E: Engine,
+        CS: ConstraintSystem<E>,
+    {
+        let int_type = bits::integer_type(scalar.get_type())?;
+
+        // Inverting every bit of an n-bit number equals subtracting it from the number with
+        // all bits set, which is `-1` in two's complement and `2^n - 1` otherwise.
+        let all_ones_value = if int_type.is_signed {
+            BigInt::from(-1)
+        } else {
+            (BigInt::from(1) << int_type.bitlength) - BigInt::from(1)
+        };
+        let all_ones = Scalar::<E>::new_constant_bigint(&all_ones_value, ScalarType::Field)?;
+
+        let expr = all_ones.to_expression::<CS>() - scalar.to_expression::<CS>();
+        let num = expr.into_number(cs.namespace(|| "into_number"))?;
+
+        Ok(Scalar::new_unchecked_variable(
+            num.get_value(),
+            num.get_variable(),
+            int_type.into(),
+        ))
+    }
+
+    auto_const!(inner, cs, scalar)
+}
@@ -0,0 +1,45 @@
+use crate::auto_const;
+use crate::gadgets::auto_const::prelude::*;
+use crate::gadgets::bitwise::bits;
+use crate::gadgets::Scalar;
+use crate::{Engine, Result};
+use franklin_crypto::bellman::ConstraintSystem;
+use franklin_crypto::circuit::boolean::Boolean;
+
+pub fn shift_left<E, CS>(cs: CS, left: &Scalar<E>, right: &Scalar<E>) -> Result<Scalar<E>>
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+{
+    fn inner<E, CS>(mut cs: CS, left: &Scalar<E>, right: &Scalar<E>) -> Result<Scalar<E>>
+    where
+        E: Engine,
+        CS: ConstraintSystem<E>,
+    {
+        let int_type = bits::integer_type(left.get_type())?;
+
+        let left_bits = bits::into_bits_le(cs.namespace(|| "left bits"), left)?;
+
+        let result_bits = bits::shift_bits_le(
+            cs.namespace(|| "shift"),
+            &left_bits,
+            right,
+            &Boolean::constant(false),
+            |bits, amount, fill| {
+                (0..bits.len())
+                    .map(|index| {
+                        if index >= amount {
+                            bits[index - amount].clone()
+                        } else {
+                            fill.clone()
+                        }
+                    })
+                    .collect()
+            },
+        )?;
+
+        bits::from_bits_le(cs.namespace(|| "result"), &result_bits, int_type)
+    }
+
+    auto_const!(inner, cs, left, right)
+}
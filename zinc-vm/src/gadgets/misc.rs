@@ -1,9 +1,10 @@
 use std::marker::PhantomData;
 use std::mem;
 
-use bellman::{ConstraintSystem, Namespace};
+use bellman::{ConstraintSystem, Namespace, Variable};
 use ff::Field;
 use num_bigint::BigInt;
+use num_traits::ToPrimitive;
 
 use crate::core::RuntimeError;
 use crate::gadgets::{utils, Gadget, Scalar, ScalarType, ScalarTypeExpectation, ScalarVariant};
@@ -59,6 +60,28 @@ where
         let mut cs = self.cs_namespace();
 
         let variable = cs.alloc(|| "variable", || value.grab())?;
+
+        Self::type_check_allocated(cs, value, variable, scalar_type)
+    }
+
+    fn input_fr(
+        &mut self,
+        value: Option<E::Fr>,
+        scalar_type: ScalarType,
+    ) -> Result<Scalar<E>, RuntimeError> {
+        let mut cs = self.cs_namespace();
+
+        let variable = cs.alloc_input(|| "input value", || value.grab())?;
+
+        Self::type_check_allocated(cs, value, variable, scalar_type)
+    }
+
+    fn type_check_allocated(
+        mut cs: Namespace<E, CS::Root>,
+        value: Option<E::Fr>,
+        variable: Variable,
+        scalar_type: ScalarType,
+    ) -> Result<Scalar<E>, RuntimeError> {
         let scalar = Scalar::new_unchecked_variable(value, variable, scalar_type);
 
         match scalar_type {
@@ -99,6 +122,26 @@ where
         self.witness_fr(fr, scalar_type)
     }
 
+    /// Allocates a public input, which is passed to the verifier along with the outputs.
+    pub fn allocate_input(
+        &mut self,
+        value: Option<&BigInt>,
+        scalar_type: ScalarType,
+    ) -> Result<Scalar<E>, RuntimeError> {
+        let fr = if let Some(bigint) = value {
+            Some(
+                utils::bigint_to_fr::<E>(bigint).ok_or(RuntimeError::ValueOverflow {
+                    value: bigint.clone(),
+                    scalar_type,
+                })?,
+            )
+        } else {
+            None
+        };
+
+        self.input_fr(fr, scalar_type)
+    }
+
     pub fn constant_bigint(
         &self,
         value: &BigInt,
@@ -351,8 +394,13 @@ where
         }
     }
 
+    /// Writes the value into the array at the specified index.
+    ///
+    /// If the index is a witness, every element is rewritten with a conditional select,
+    /// and 0 <= index < array.len() is only enforced if condition is true.
     pub fn array_set(
         &mut self,
+        condition: &Scalar<E>,
         array: &[Scalar<E>],
         index: Scalar<E>,
         value: Scalar<E>,
@@ -372,16 +420,36 @@ where
                 new_array[i] = value;
             }
             _ => {
-                return Err(RuntimeError::WitnessArrayIndex);
-                // let mut new_array = Vec::new();
-
-                // for (i, p) in array.iter().enumerate() {
-                //     let curr_index = Scalar::new_constant_int(i, ScalarType::Field);
-                //     let is_current_index = self.eq(curr_index, index.clone())?;
-                //     let cs = self.cs_namespace();
-                //     let value = gadgets::conditional_select(cs, &is_current_index, &value, p)?;
-                //     new_array.push(value);
-                // }
+                if array.is_empty() {
+                    return Err(RuntimeError::IndexOutOfBounds {
+                        lower_bound: 0,
+                        upper_bound: 0,
+                        actual: index
+                            .get_value()
+                            .and_then(|fr| utils::fr_to_bigint(&fr, false).to_usize())
+                            .unwrap_or_default(),
+                    });
+                }
+
+                let mut cs = self.cs_namespace();
+                let length = Scalar::new_constant_bigint(&array.len().into(), index.get_type())?;
+                let lt = gadgets::comparison::lt(cs.namespace(|| "lt"), &index, &length)?;
+                let in_bounds = gadgets::conditional_select(
+                    cs.namespace(|| "in bounds"),
+                    condition,
+                    &lt,
+                    &Scalar::new_constant_bool(true),
+                )?;
+                mem::drop(cs);
+                self.assert(in_bounds, Some("index out of bounds"))?;
+
+                for (i, element) in array.iter().enumerate() {
+                    let current_index = Scalar::new_constant_int(i, index.get_type());
+                    let is_current_index = self.eq(current_index, index.clone())?;
+                    let cs = self.cs_namespace();
+                    new_array[i] =
+                        gadgets::conditional_select(cs, &is_current_index, &value, element)?;
+                }
             }
         };
 
@@ -1,11 +1,10 @@
-use crate::core::{InternalVM, VMInstruction, VirtualMachine};
-use crate::gadgets::utils::{bigint_to_fr, fr_to_bigint};
-use crate::gadgets::{Scalar, ScalarTypeExpectation};
-use crate::{Engine, Result, RuntimeError};
+extern crate franklin_crypto;
 
-use franklin_crypto::bellman::ConstraintSystem;
-use num_bigint::BigInt;
-use num_bigint::Sign;
+use self::franklin_crypto::bellman::ConstraintSystem;
+use crate::core::{Cell, InternalVM, VMInstruction};
+use crate::core::{RuntimeError, VirtualMachine};
+use crate::gadgets;
+use crate::Engine;
 use zinc_bytecode::instructions::BitNot;
 
 impl<E, CS> VMInstruction<E, CS> for BitNot
@@ -13,25 +12,31 @@ where
     E: Engine,
     CS: ConstraintSystem<E>,
 {
-    fn execute(&self, vm: &mut VirtualMachine<E, CS>) -> Result {
-        let scalar = vm.pop()?.value()?;
+    fn execute(&self, vm: &mut VirtualMachine<E, CS>) -> Result<(), RuntimeError> {
+        let value = vm.pop()?.value()?;
 
-        let scalar_type = scalar.get_type();
-        let value = fr_to_bigint(&scalar.get_constant()?, scalar_type.is_signed());
+        let cs = vm.constraint_system();
+        let result = gadgets::bit_not(cs.namespace(|| "bit_not"), &value)?;
 
-        let mut mask = vec![0xFF; scalar_type.bit_length::<E>() / 8];
-        if scalar_type.is_signed() {
-            mask[0] = 0x7F;
-        }
+        vm.push(Cell::Value(result))
+    }
+}
 
-        let mut result_value = !value;
-        result_value &= &BigInt::from_bytes_le(Sign::Plus, mask.as_slice());
+#[cfg(test)]
+mod tests {
+    use crate::instructions::testing_utils::{TestingError, VMTestRunner};
+    use zinc_bytecode::scalar::IntegerType;
+    use zinc_bytecode::*;
 
-        let result_fr = bigint_to_fr::<E>(&result_value).ok_or(RuntimeError::ValueOverflow {
-            value: result_value,
-            scalar_type,
-        })?;
-        let result = Scalar::new_constant_fr(result_fr, scalar_type);
-        vm.push(result.into())
+    #[test]
+    fn test_bit_not() -> Result<(), TestingError> {
+        VMTestRunner::new()
+            .add(PushConst::new(42.into(), IntegerType::U8.into()))
+            .add(BitNot)
+            .add(PushConst::new(42.into(), IntegerType::I8.into()))
+            .add(BitNot)
+            .add(PushConst::new((-128).into(), IntegerType::I8.into()))
+            .add(BitNot)
+            .test(&[127, -43, 213])
     }
 }
@@ -1,12 +1,10 @@
-use crate::core::{InternalVM, VMInstruction, VirtualMachine};
-use crate::gadgets::utils::{bigint_to_fr, fr_to_bigint};
-use crate::gadgets::{Scalar, ScalarTypeExpectation};
-use crate::{Engine, Result, RuntimeError};
+extern crate franklin_crypto;
 
-use franklin_crypto::bellman::ConstraintSystem;
-use num_bigint::BigInt;
-use num_bigint::Sign;
-use num_traits::ToPrimitive;
+use self::franklin_crypto::bellman::ConstraintSystem;
+use crate::core::{Cell, InternalVM, VMInstruction};
+use crate::core::{RuntimeError, VirtualMachine};
+use crate::gadgets;
+use crate::Engine;
 use zinc_bytecode::instructions::BitShiftLeft;
 
 impl<E, CS> VMInstruction<E, CS> for BitShiftLeft
@@ -14,28 +12,38 @@ where
     E: Engine,
     CS: ConstraintSystem<E>,
 {
-    fn execute(&self, vm: &mut VirtualMachine<E, CS>) -> Result {
+    fn execute(&self, vm: &mut VirtualMachine<E, CS>) -> Result<(), RuntimeError> {
         let right = vm.pop()?.value()?;
         let left = vm.pop()?.value()?;
 
-        let scalar_type = left.get_type();
+        let cs = vm.constraint_system();
+        let result = gadgets::shift_left(cs.namespace(|| "shift_left"), &left, &right)?;
 
-        let left_value = fr_to_bigint(&left.get_constant()?, scalar_type.is_signed());
-        let right_value = right.get_constant_usize()?;
-
-        let mut mask = vec![0xFF; scalar_type.bit_length::<E>() / 8];
-        if scalar_type.is_signed() {
-            mask[0] = 0x7F;
-        }
+        vm.push(Cell::Value(result))
+    }
+}
 
-        let mut result_value = &left_value << right_value.to_usize().unwrap();
-        result_value &= &BigInt::from_bytes_le(Sign::Plus, mask.as_slice());
+#[cfg(test)]
+mod tests {
+    use crate::instructions::testing_utils::{TestingError, VMTestRunner};
+    use zinc_bytecode::scalar::IntegerType;
+    use zinc_bytecode::*;
 
-        let result_fr = bigint_to_fr::<E>(&result_value).ok_or(RuntimeError::ValueOverflow {
-            value: result_value,
-            scalar_type,
-        })?;
-        let result = Scalar::new_constant_fr(result_fr, scalar_type);
-        vm.push(result.into())
+    #[test]
+    fn test_bit_shift_left() -> Result<(), TestingError> {
+        VMTestRunner::new()
+            .add(PushConst::new(42.into(), IntegerType::U8.into()))
+            .add(PushConst::new(2.into(), IntegerType::U8.into()))
+            .add(BitShiftLeft)
+            .add(PushConst::new(255.into(), IntegerType::U8.into()))
+            .add(PushConst::new(4.into(), IntegerType::U8.into()))
+            .add(BitShiftLeft)
+            .add(PushConst::new((-1).into(), IntegerType::I8.into()))
+            .add(PushConst::new(7.into(), IntegerType::U8.into()))
+            .add(BitShiftLeft)
+            .add(PushConst::new(1.into(), IntegerType::U8.into()))
+            .add(PushConst::new(8.into(), IntegerType::U8.into()))
+            .add(BitShiftLeft)
+            .test(&[0, -128, 240, 168])
     }
 }
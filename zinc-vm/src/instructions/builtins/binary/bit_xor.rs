@@ -1,9 +1,10 @@
-use crate::core::{InternalVM, VMInstruction, VirtualMachine};
-use crate::{Engine, Result, RuntimeError};
+extern crate franklin_crypto;
 
-use crate::gadgets::utils::{bigint_to_fr, fr_to_bigint};
-use crate::gadgets::{Scalar, ScalarType, ScalarTypeExpectation};
-use franklin_crypto::bellman::ConstraintSystem;
+use self::franklin_crypto::bellman::ConstraintSystem;
+use crate::core::{Cell, InternalVM, VMInstruction};
+use crate::core::{RuntimeError, VirtualMachine};
+use crate::gadgets;
+use crate::Engine;
 use zinc_bytecode::instructions::BitXor;
 
 impl<E, CS> VMInstruction<E, CS> for BitXor
@@ -11,22 +12,35 @@ where
     E: Engine,
     CS: ConstraintSystem<E>,
 {
-    fn execute(&self, vm: &mut VirtualMachine<E, CS>) -> Result {
+    fn execute(&self, vm: &mut VirtualMachine<E, CS>) -> Result<(), RuntimeError> {
         let right = vm.pop()?.value()?;
         let left = vm.pop()?.value()?;
 
-        let scalar_type = ScalarType::expect_same(left.get_type(), right.get_type())?;
+        let cs = vm.constraint_system();
+        let result = gadgets::bit_xor(cs.namespace(|| "bit_xor"), &left, &right)?;
 
-        let left_value = fr_to_bigint(&left.get_constant()?, scalar_type.is_signed());
-        let right_value = fr_to_bigint(&right.get_constant()?, scalar_type.is_signed());
+        vm.push(Cell::Value(result))
+    }
+}
 
-        let result_value = &left_value ^ &right_value;
+#[cfg(test)]
+mod tests {
+    use crate::instructions::testing_utils::{TestingError, VMTestRunner};
+    use zinc_bytecode::scalar::IntegerType;
+    use zinc_bytecode::*;
 
-        let result_fr = bigint_to_fr::<E>(&result_value).ok_or(RuntimeError::ValueOverflow {
-            value: result_value,
-            scalar_type,
-        })?;
-        let result = Scalar::new_constant_fr(result_fr, scalar_type);
-        vm.push(result.into())
+    #[test]
+    fn test_bit_xor() -> Result<(), TestingError> {
+        VMTestRunner::new()
+            .add(PushConst::new(42.into(), IntegerType::U8.into()))
+            .add(PushConst::new(21.into(), IntegerType::U8.into()))
+            .add(BitXor)
+            .add(PushConst::new((-1).into(), IntegerType::I8.into()))
+            .add(PushConst::new(42.into(), IntegerType::I8.into()))
+            .add(BitXor)
+            .add(PushConst::new(255.into(), IntegerType::U8.into()))
+            .add(PushConst::new(255.into(), IntegerType::U8.into()))
+            .add(BitXor)
+            .test(&[0, -43, 63])
     }
 }
@@ -25,6 +25,7 @@ where
         values.reverse();
 
         let index = vm.pop()?.value()?;
+        let condition = vm.condition_top()?;
 
         for (i, value) in values.into_iter().enumerate() {
             let cs = vm.constraint_system();
@@ -32,7 +33,7 @@ where
             let address = gadgets::add(cs.namespace(|| format!("address {}", i)), &index, &offset)?;
             array = vm
                 .operations()
-                .array_set(array.as_slice(), address, value)?;
+                .array_set(&condition, array.as_slice(), address, value)?;
         }
 
         for (i, value) in array.into_iter().enumerate() {
@@ -42,3 +43,33 @@ where
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::instructions::testing_utils::{TestingError, VMTestRunner};
+    use zinc_bytecode::instructions::*;
+    use zinc_bytecode::scalar::ScalarType;
+
+    #[test]
+    fn test_store_sequence_by_witness_index() -> Result<(), TestingError> {
+        VMTestRunner::new()
+            .add(PushConst::new(1.into(), ScalarType::Field))
+            .add(Store::new(0))
+            .add(PushConst::new(2.into(), ScalarType::Field))
+            .add(Store::new(1))
+            .add(PushConst::new(3.into(), ScalarType::Field))
+            .add(Store::new(2))
+            .add(PushConst::new(4.into(), ScalarType::Field))
+            .add(Store::new(3))
+            // `1 == 1` is allocated as a witness, so the index is not a constant
+            .add(PushConst::new(1.into(), ScalarType::Field))
+            .add(PushConst::new(1.into(), ScalarType::Field))
+            .add(Eq)
+            .add(Cast::new(ScalarType::Field))
+            .add(PushConst::new(7.into(), ScalarType::Field))
+            .add(PushConst::new(8.into(), ScalarType::Field))
+            .add(StoreSequenceByIndex::new(0, 4, 2))
+            .add(LoadSequence::new(0, 4))
+            .test(&[4, 8, 7, 1])
+    }
+}
@@ -18,7 +18,10 @@ where
             array.push(vm.load(self.address + i)?.value()?);
         }
 
-        let new_array = vm.operations().array_set(array.as_slice(), index, value)?;
+        let condition = vm.condition_top()?;
+        let new_array = vm
+            .operations()
+            .array_set(&condition, array.as_slice(), index, value)?;
 
         for (i, value) in new_array.into_iter().enumerate() {
             vm.store(self.address + i, Cell::Value(value))?;
@@ -27,3 +30,71 @@ where
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::core::RuntimeError;
+    use crate::instructions::testing_utils::{TestingError, VMTestRunner};
+    use zinc_bytecode::instructions::*;
+    use zinc_bytecode::scalar::ScalarType;
+
+    #[test]
+    fn test_store_by_index() -> Result<(), TestingError> {
+        VMTestRunner::new()
+            .add(PushConst::new(1.into(), ScalarType::Field))
+            .add(Store::new(0))
+            .add(PushConst::new(2.into(), ScalarType::Field))
+            .add(Store::new(1))
+            .add(PushConst::new(3.into(), ScalarType::Field))
+            .add(Store::new(2))
+            .add(PushConst::new(1.into(), ScalarType::Field))
+            .add(PushConst::new(5.into(), ScalarType::Field))
+            .add(StoreByIndex::new(0, 3))
+            .add(LoadSequence::new(0, 3))
+            .test(&[3, 5, 1])
+    }
+
+    #[test]
+    fn test_store_by_witness_index() -> Result<(), TestingError> {
+        VMTestRunner::new()
+            .add(PushConst::new(1.into(), ScalarType::Field))
+            .add(Store::new(0))
+            .add(PushConst::new(2.into(), ScalarType::Field))
+            .add(Store::new(1))
+            .add(PushConst::new(3.into(), ScalarType::Field))
+            .add(Store::new(2))
+            // `1 == 1` is allocated as a witness, so the index is not a constant
+            .add(PushConst::new(1.into(), ScalarType::Field))
+            .add(PushConst::new(1.into(), ScalarType::Field))
+            .add(Eq)
+            .add(Cast::new(ScalarType::Field))
+            .add(PushConst::new(1.into(), ScalarType::Field))
+            .add(Add)
+            .add(PushConst::new(5.into(), ScalarType::Field))
+            .add(StoreByIndex::new(0, 3))
+            .add(LoadSequence::new(0, 3))
+            .test(&[5, 2, 1])
+    }
+
+    #[test]
+    fn test_store_by_witness_index_empty() {
+        let res = VMTestRunner::new()
+            // `1 == 1` is allocated as a witness, so the index is not a constant
+            .add(PushConst::new(1.into(), ScalarType::Field))
+            .add(PushConst::new(1.into(), ScalarType::Field))
+            .add(Eq)
+            .add(Cast::new(ScalarType::Field))
+            .add(PushConst::new(5.into(), ScalarType::Field))
+            .add(StoreByIndex::new(0, 0))
+            .test::<i32>(&[]);
+
+        match res {
+            Err(TestingError::RuntimeError(RuntimeError::IndexOutOfBounds {
+                upper_bound: 0,
+                actual: 1,
+                ..
+            })) => {}
+            res => panic!("expected index out of bounds error, got {:?} instead", res),
+        }
+    }
+}
@@ -7,7 +7,7 @@ use franklin_crypto::bellman::ConstraintSystem;
 use franklin_crypto::circuit::test::TestConstraintSystem;
 use num_bigint::{BigInt, ToBigInt};
 use zinc_bytecode::data::types::DataType;
-use zinc_bytecode::{Call, Instruction, InstructionInfo, Program};
+use zinc_bytecode::{Call, Curve, Instruction, InstructionInfo, Program};
 
 type TestVirtualMachine = VirtualMachine<Bn256, TestConstraintSystem<Bn256>>;
 
@@ -85,7 +85,13 @@ impl VMTestRunner {
     ) -> Result<(), TestingError> {
         let mut vm = new_test_constrained_vm();
 
-        let program = Program::new(DataType::Unit, DataType::Unit, self.instructions.clone());
+        let program = Program::new(
+            Curve::Bn256,
+            DataType::Unit,
+            DataType::Unit,
+            DataType::Unit,
+            self.instructions.clone(),
+        );
 
         vm.run(&program, Some(&[]), |_| {}, |_| Ok(()))
             .map_err(TestingError::RuntimeError)?;
@@ -3,6 +3,7 @@ mod core;
 mod errors;
 pub mod gadgets;
 mod instructions;
+pub mod solidity;
 pub mod stdlib;
 
 #[cfg(test)]
@@ -12,20 +13,36 @@ mod facade;
 pub use facade::*;
 
 use franklin_crypto::alt_babyjubjub::{AltJubjubBn256, JubjubEngine};
+use franklin_crypto::jubjub::JubjubBls12;
 use lazy_static::lazy_static;
+use pairing::bls12_381::Bls12;
 use pairing::bn256::Bn256;
 use std::fmt::Debug;
+use zinc_bytecode::Curve;
 
 pub trait Engine: JubjubEngine + Debug {
+    const CURVE: Curve;
+
     fn jubjub_params<'a>() -> &'a Self::Params;
 }
 
 lazy_static! {
     static ref JUBJUB_BN256_PARAMS: AltJubjubBn256 = AltJubjubBn256::new();
+    static ref JUBJUB_BLS12_381_PARAMS: JubjubBls12 = JubjubBls12::new();
 }
 
 impl Engine for Bn256 {
+    const CURVE: Curve = Curve::Bn256;
+
     fn jubjub_params<'a>() -> &'a Self::Params {
         &JUBJUB_BN256_PARAMS
     }
 }
+
+impl Engine for Bls12 {
+    const CURVE: Curve = Curve::Bls12_381;
+
+    fn jubjub_params<'a>() -> &'a Self::Params {
+        &JUBJUB_BLS12_381_PARAMS
+    }
+}
@@ -0,0 +1,94 @@
+use franklin_crypto::bellman::groth16::{Proof, VerifyingKey};
+use num_bigint::{BigInt, Sign};
+use pairing::bn256::Bn256;
+use pairing::{CurveAffine, EncodedPoint};
+use serde_json::{json, Value as JsonValue};
+
+use zinc_bytecode::data::values::Value;
+
+use crate::gadgets::utils::{bigint_to_fr, fr_to_bigint_unsigned};
+use crate::VerificationError;
+
+static VERIFIER_TEMPLATE: &str = include_str!("verifier.sol");
+
+const COORDINATE_SIZE: usize = 32;
+
+/// Renders the Solidity contract, which verifies the proofs against the embedded key.
+///
+/// Only the BN256 curve is supported, since the contract relies on the Ethereum
+/// precompiled pairing contracts.
+pub fn verifier_contract(key: &VerifyingKey<Bn256>) -> String {
+    let ic = key
+        .ic
+        .iter()
+        .enumerate()
+        .map(|(index, point)| {
+            format!(
+                "        vk.ic[{}] = Pairing.G1Point({});",
+                index,
+                g1_arguments(point)
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+
+    VERIFIER_TEMPLATE
+        .replace("{{alpha}}", g1_arguments(&key.alpha_g1).as_str())
+        .replace("{{beta}}", g2_arguments(&key.beta_g2).as_str())
+        .replace("{{gamma}}", g2_arguments(&key.gamma_g2).as_str())
+        .replace("{{delta}}", g2_arguments(&key.delta_g2).as_str())
+        .replace("{{ic_length}}", key.ic.len().to_string().as_str())
+        .replace("{{ic}}", ic.as_str())
+}
+
+/// Returns the `verifyProof` arguments of the contract rendered by `verifier_contract`.
+///
+/// The public data is flattened the same way as in `verify`, and the values are written
+/// as decimal strings.
+pub fn calldata(proof: &Proof<Bn256>, public_data: &Value) -> Result<JsonValue, VerificationError> {
+    let input = public_data
+        .to_flat_values()
+        .into_iter()
+        .map(|value| {
+            bigint_to_fr::<Bn256>(&value)
+                .map(|fr| fr_to_bigint_unsigned(&fr).to_string())
+                .ok_or_else(|| VerificationError::ValueOverflow(value))
+        })
+        .collect::<Result<Vec<String>, VerificationError>>()?;
+
+    let b = coordinates(&proof.b);
+
+    Ok(json!({
+        "a": coordinates(&proof.a),
+        "b": [&b[0..2], &b[2..4]],
+        "c": coordinates(&proof.c),
+        "input": input,
+    }))
+}
+
+/// Returns the point coordinates in the Ethereum precompile order, that is, the imaginary
+/// parts of the `G2` coordinates go first. The point at infinity is encoded as zeros.
+fn coordinates<P: CurveAffine>(point: &P) -> Vec<String> {
+    let encoded = point.into_uncompressed();
+    let bytes = encoded.as_ref();
+    if point.is_zero() {
+        return vec!["0".to_owned(); bytes.len() / COORDINATE_SIZE];
+    }
+
+    bytes
+        .chunks(COORDINATE_SIZE)
+        .map(|chunk| BigInt::from_bytes_be(Sign::Plus, chunk).to_string())
+        .collect()
+}
+
+fn g1_arguments<P: CurveAffine>(point: &P) -> String {
+    coordinates(point).join(", ")
+}
+
+fn g2_arguments<P: CurveAffine>(point: &P) -> String {
+    let coordinates = coordinates(point);
+    format!(
+        "[uint256({}), uint256({})], [uint256({}), uint256({})]",
+        coordinates[0], coordinates[1], coordinates[2], coordinates[3]
+    )
+}
@@ -0,0 +1,124 @@
+// The Groth16 verifier generated by the Zinc virtual machine.
+// The verifying key of the circuit is embedded into the `verifyingKey` function.
+
+pragma solidity ^0.6.0;
+
+library Pairing {
+    uint256 constant PRIME_Q = 21888242871839275222246405745257275088696311157297823662689037894645226208583;
+
+    struct G1Point {
+        uint256 X;
+        uint256 Y;
+    }
+
+    // The coordinates are encoded as `X[0] * i + X[1]`.
+    struct G2Point {
+        uint256[2] X;
+        uint256[2] Y;
+    }
+
+    function negate(G1Point memory p) internal pure returns (G1Point memory) {
+        if (p.X == 0 && p.Y == 0) {
+            return G1Point(0, 0);
+        }
+        return G1Point(p.X, PRIME_Q - (p.Y % PRIME_Q));
+    }
+
+    function addition(G1Point memory p1, G1Point memory p2) internal view returns (G1Point memory r) {
+        uint256[4] memory input;
+        input[0] = p1.X;
+        input[1] = p1.Y;
+        input[2] = p2.X;
+        input[3] = p2.Y;
+        bool success;
+        assembly {
+            success := staticcall(sub(gas(), 2000), 6, input, 0x80, r, 0x40)
+        }
+        require(success, "pairing-add-failed");
+    }
+
+    function scalarMul(G1Point memory p, uint256 s) internal view returns (G1Point memory r) {
+        uint256[3] memory input;
+        input[0] = p.X;
+        input[1] = p.Y;
+        input[2] = s;
+        bool success;
+        assembly {
+            success := staticcall(sub(gas(), 2000), 7, input, 0x60, r, 0x40)
+        }
+        require(success, "pairing-mul-failed");
+    }
+
+    function pairing(G1Point[4] memory p1, G2Point[4] memory p2) internal view returns (bool) {
+        uint256[24] memory input;
+        for (uint256 i = 0; i < 4; i++) {
+            uint256 j = i * 6;
+            input[j + 0] = p1[i].X;
+            input[j + 1] = p1[i].Y;
+            input[j + 2] = p2[i].X[0];
+            input[j + 3] = p2[i].X[1];
+            input[j + 4] = p2[i].Y[0];
+            input[j + 5] = p2[i].Y[1];
+        }
+        uint256[1] memory out;
+        bool success;
+        assembly {
+            success := staticcall(sub(gas(), 2000), 8, input, 0x300, out, 0x20)
+        }
+        require(success, "pairing-opcode-failed");
+        return out[0] != 0;
+    }
+}
+
+contract Verifier {
+    uint256 constant SNARK_SCALAR_FIELD = 21888242871839275222246405745257275088548364400416034343698204186575808495617;
+
+    struct VerifyingKey {
+        Pairing.G1Point alpha;
+        Pairing.G2Point beta;
+        Pairing.G2Point gamma;
+        Pairing.G2Point delta;
+        Pairing.G1Point[] ic;
+    }
+
+    function verifyingKey() internal pure returns (VerifyingKey memory vk) {
+        vk.alpha = Pairing.G1Point({{alpha}});
+        vk.beta = Pairing.G2Point({{beta}});
+        vk.gamma = Pairing.G2Point({{gamma}});
+        vk.delta = Pairing.G2Point({{delta}});
+        vk.ic = new Pairing.G1Point[]({{ic_length}});
+{{ic}}
+    }
+
+    // The input is the public data of the circuit, that is, its public arguments followed
+    // by its result, which are flattened into the scalar field elements.
+    function verifyProof(
+        uint256[2] memory a,
+        uint256[2][2] memory b,
+        uint256[2] memory c,
+        uint256[] memory input
+    ) public view returns (bool) {
+        VerifyingKey memory vk = verifyingKey();
+        require(input.length + 1 == vk.ic.length, "verifier-bad-input");
+
+        Pairing.G1Point memory x = vk.ic[0];
+        for (uint256 i = 0; i < input.length; i++) {
+            require(input[i] < SNARK_SCALAR_FIELD, "verifier-gte-snark-scalar-field");
+            x = Pairing.addition(x, Pairing.scalarMul(vk.ic[i + 1], input[i]));
+        }
+
+        Pairing.G1Point[4] memory p1;
+        p1[0] = Pairing.negate(Pairing.G1Point(a[0], a[1]));
+        p1[1] = vk.alpha;
+        p1[2] = x;
+        p1[3] = Pairing.G1Point(c[0], c[1]);
+
+        Pairing.G2Point[4] memory p2;
+        p2[0] = Pairing.G2Point(b[0], b[1]);
+        p2[1] = vk.beta;
+        p2[2] = vk.gamma;
+        p2[3] = vk.delta;
+
+        return Pairing.pairing(p1, p2);
+    }
+}
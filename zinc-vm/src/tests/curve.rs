@@ -0,0 +1,66 @@
+use num_bigint::BigInt;
+use pairing::bls12_381::Bls12;
+use pairing::bn256::Bn256;
+use zinc_bytecode::data::types::DataType;
+use zinc_bytecode::data::values::{ScalarValue, Value};
+use zinc_bytecode::instructions::*;
+use zinc_bytecode::scalar::IntegerType;
+use zinc_bytecode::{Curve, InstructionInfo, Program};
+
+use crate::RuntimeError;
+
+fn increment(curve: Curve) -> Program {
+    let u8_type = DataType::Scalar(IntegerType::U8.into());
+    Program::new(
+        curve,
+        u8_type.clone(),
+        DataType::Unit,
+        u8_type,
+        vec![
+            Call::new(2, 1).wrap(),
+            Exit::new(1).wrap(),
+            Load::new(0).wrap(),
+            PushConst::new(1.into(), IntegerType::U8.into()).wrap(),
+            Add.wrap(),
+            Return::new(1).wrap(),
+        ],
+    )
+}
+
+fn u8_value(value: u32) -> Value {
+    Value::Scalar(ScalarValue::Integer(BigInt::from(value), IntegerType::U8))
+}
+
+#[test]
+fn bls12_381_setup_prove_verify() {
+    let program = increment(Curve::Bls12_381);
+    let rng = &mut rand::thread_rng();
+
+    let params = crate::setup::<Bls12, _>(&program, rng).expect("setup");
+    let (output, proof) =
+        crate::prove::<Bls12, _>(&program, &params, &u8_value(41), rng).expect("proved");
+    assert_eq!(output.to_flat_values(), vec![BigInt::from(42)]);
+
+    let public_data = program.public_data(&u8_value(41), output);
+    let verified = crate::verify(&params.vk, &proof, &public_data).expect("verified");
+    assert!(verified);
+
+    let wrong_data = u8_value(43);
+    let verified = crate::verify(&params.vk, &proof, &wrong_data).expect("verified");
+    assert!(!verified);
+}
+
+#[test]
+fn curve_mismatch() {
+    let program = increment(Curve::Bn256);
+
+    match crate::run::<Bls12>(&program, &u8_value(41)) {
+        Err(RuntimeError::CurveMismatch {
+            program: Curve::Bn256,
+            engine: Curve::Bls12_381,
+        }) => {}
+        res => panic!("expected curve mismatch error, got {:?} instead", res),
+    }
+
+    assert!(crate::run::<Bn256>(&program, &u8_value(41)).is_ok());
+}
@@ -0,0 +1,71 @@
+use num_bigint::BigInt;
+use pairing::bn256::Bn256;
+use zinc_bytecode::data::types::DataType;
+use zinc_bytecode::data::values::{ScalarValue, Value};
+use zinc_bytecode::instructions::*;
+use zinc_bytecode::scalar::{IntegerType, ScalarType};
+use zinc_bytecode::{Curve, InstructionInfo, Program};
+
+use crate::RuntimeError;
+
+fn increment() -> Program {
+    let u8_type = DataType::Scalar(IntegerType::U8.into());
+    Program::new(
+        Curve::Bn256,
+        u8_type.clone(),
+        DataType::Unit,
+        u8_type,
+        vec![
+            Call::new(2, 1).wrap(),
+            Exit::new(1).wrap(),
+            Load::new(0).wrap(),
+            PushConst::new(1.into(), IntegerType::U8.into()).wrap(),
+            Add.wrap(),
+            Return::new(1).wrap(),
+        ],
+    )
+}
+
+fn u8_value(value: u32) -> Value {
+    Value::Scalar(ScalarValue::Integer(BigInt::from(value), IntegerType::U8))
+}
+
+#[test]
+fn evaluate_ok() {
+    let program = increment();
+    let output = crate::evaluate::<Bn256>(&program, &u8_value(41)).expect("evaluated");
+    let expected = crate::run::<Bn256>(&program, &u8_value(41)).expect("ran");
+
+    assert_eq!(output.to_flat_values(), vec![BigInt::from(42)]);
+    assert_eq!(output.to_flat_values(), expected.to_flat_values());
+}
+
+#[test]
+fn evaluate_overflow_fail() {
+    match crate::evaluate::<Bn256>(&increment(), &u8_value(255)) {
+        Err(RuntimeError::ValueOverflow { .. }) => {}
+        res => panic!("expected overflow error, got {:?} instead", res),
+    }
+}
+
+#[test]
+fn evaluate_assertion_fail() {
+    let program = Program::new(
+        Curve::Bn256,
+        DataType::Unit,
+        DataType::Unit,
+        DataType::Unit,
+        vec![
+            Call::new(2, 0).wrap(),
+            Exit::new(0).wrap(),
+            PushConst::new(0.into(), ScalarType::Boolean).wrap(),
+            Assert::new(None).wrap(),
+            Return::new(0).wrap(),
+        ],
+    );
+
+    match crate::evaluate::<Bn256>(&program, &Value::Unit) {
+        Err(RuntimeError::AssertionError(_)) => {}
+        res => panic!("expected assertion error, got {:?} instead", res),
+    }
+}
@@ -1 +1,6 @@
+mod curve;
+mod evaluate;
 mod overflow;
+mod solidity;
+mod synthesize;
+mod tagged_union;
@@ -0,0 +1,82 @@
+use franklin_crypto::bellman::groth16::{Proof, VerifyingKey};
+use num_bigint::BigInt;
+use pairing::bn256::{Bn256, G1Affine, G2Affine};
+use pairing::{CurveAffine, CurveProjective};
+use serde_json::json;
+use zinc_bytecode::data::values::{ScalarValue, Value};
+
+use crate::solidity;
+
+/// The contract for the key built by `verifying_key`, whose point coordinates have been
+/// computed independently of the contract renderer.
+static VERIFIER_GOLDEN: &str = include_str!("data/verifier.sol");
+
+/// Returns the generator multiplied by `factor`.
+fn multiple<G: CurveAffine>(factor: usize) -> G {
+    let mut point = G::zero().into_projective();
+    for _ in 0..factor {
+        point.add_assign_mixed(&G::one());
+    }
+    point.into_affine()
+}
+
+/// Returns a key with distinct points, so the points swapped by the renderer are detected.
+fn verifying_key() -> VerifyingKey<Bn256> {
+    VerifyingKey::<Bn256> {
+        alpha_g1: multiple(2),
+        beta_g1: G1Affine::one(),
+        beta_g2: multiple(1),
+        gamma_g2: multiple(2),
+        delta_g1: G1Affine::one(),
+        delta_g2: multiple(3),
+        ic: vec![multiple(1), multiple(2), multiple(3)],
+    }
+}
+
+#[test]
+fn verifier_contract_golden() {
+    assert_eq!(
+        solidity::verifier_contract(&verifying_key()),
+        VERIFIER_GOLDEN
+    );
+}
+
+#[test]
+fn calldata() {
+    let proof = Proof::<Bn256> {
+        a: G1Affine::one(),
+        b: G2Affine::one(),
+        c: multiple(2),
+    };
+    let public_data = Value::Array(vec![
+        Value::Scalar(ScalarValue::Field(BigInt::from(42))),
+        Value::Scalar(ScalarValue::Field(BigInt::from(-1))),
+    ]);
+
+    let calldata = solidity::calldata(&proof, &public_data).expect("valid public data");
+
+    assert_eq!(
+        calldata,
+        json!({
+            "a": ["1", "2"],
+            "b": [
+                [
+                    "11559732032986387107991004021392285783925812861821192530917403151452391805634",
+                    "10857046999023057135944570762232829481370756359578518086990519993285655852781"
+                ],
+                [
+                    "4082367875863433681332203403145435568316851327593401208105741076214120093531",
+                    "8495653923123431417604973247489272438418190587263600148770280649306958101930"
+                ]
+            ],
+            "c": [
+                "1368015179489954701390400359078579693043519447331113978918064868415326638035",
+                "9918110051302171585080402603319702774565515993150576347155970296011118125764"
+            ],
+            "input": [
+                "42",
+                "21888242871839275222246405745257275088548364400416034343698204186575808495616"
+            ],
+        })
+    );
+}
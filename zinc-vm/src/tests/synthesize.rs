@@ -0,0 +1,68 @@
+use pairing::bn256::Bn256;
+use serde_json::json;
+use zinc_bytecode::data::types::DataType;
+use zinc_bytecode::data::values::Value;
+use zinc_bytecode::instructions::*;
+use zinc_bytecode::scalar::{IntegerType, ScalarType};
+use zinc_bytecode::{Curve, InstructionInfo, Program};
+
+/// The bytecode emitted by the compiler without the column markers for:
+///
+/// ```zinc
+/// fn main(a: u8, b: u8) -> bool {
+///     let c = a * 3 + b;
+///     c > 100
+/// }
+/// ```
+fn compiled() -> Program {
+    let u8_type = IntegerType::U8;
+    Program::new(
+        Curve::Bn256,
+        DataType::Struct(vec![
+            ("a".to_owned(), DataType::Scalar(u8_type.into())),
+            ("b".to_owned(), DataType::Scalar(u8_type.into())),
+        ]),
+        DataType::Struct(vec![]),
+        DataType::Scalar(ScalarType::Boolean),
+        vec![
+            Call::new(2, 2).wrap(),
+            Exit::new(1).wrap(),
+            FileMarker::new("main.zn".to_owned()).wrap(),
+            FunctionMarker::new("main".to_owned()).wrap(),
+            LineMarker::new(2).wrap(),
+            LoadSequence::new(0, 1).wrap(),
+            PushConst::new(3.into(), u8_type.into()).wrap(),
+            Mul.wrap(),
+            LoadSequence::new(1, 1).wrap(),
+            Add.wrap(),
+            Cast::new(u8_type.into()).wrap(),
+            StoreSequence::new(2, 1).wrap(),
+            LineMarker::new(3).wrap(),
+            LoadSequence::new(2, 1).wrap(),
+            PushConst::new(100.into(), u8_type.into()).wrap(),
+            Gt.wrap(),
+            LineMarker::new(1).wrap(),
+            Return::new(1).wrap(),
+        ],
+    )
+}
+
+#[test]
+fn synthesize_satisfied() {
+    let program = compiled();
+    let witness = Value::from_typed_json(&json!({ "a": "20", "b": "50" }), &program.input)
+        .expect("valid witness");
+
+    let cs = crate::synthesize::<Bn256>(&program, Some(&witness)).expect("synthesized");
+
+    assert!(!cs.constraints().is_empty());
+    assert_eq!(cs.which_is_unsatisfied().expect("witness is known"), None);
+}
+
+#[test]
+fn synthesize_without_witness() {
+    let cs = crate::synthesize::<Bn256>(&compiled(), None).expect("synthesized");
+
+    assert!(!cs.constraints().is_empty());
+    assert!(cs.which_is_unsatisfied().is_err());
+}
@@ -0,0 +1,68 @@
+use num_bigint::BigInt;
+use pairing::bn256::Bn256;
+use zinc_bytecode::data::types::DataType;
+use zinc_bytecode::data::values::{ScalarValue, TaggedUnionValue, Value};
+use zinc_bytecode::instructions::*;
+use zinc_bytecode::scalar::IntegerType;
+use zinc_bytecode::{Curve, InstructionInfo, Program};
+
+use crate::RuntimeError;
+
+/// The program taking an `Option`-like enumeration with data and doing nothing with it.
+fn consume() -> Program {
+    Program::new(
+        Curve::Bn256,
+        DataType::TaggedUnion(vec![
+            ("None".to_owned(), DataType::Unit),
+            ("Some".to_owned(), DataType::Scalar(IntegerType::U8.into())),
+        ]),
+        DataType::Unit,
+        DataType::Unit,
+        vec![
+            Call::new(2, 2).wrap(),
+            Exit::new(0).wrap(),
+            Return::new(0).wrap(),
+        ],
+    )
+}
+
+fn tagged_union(variant: &str, tag: usize, value: Value, padding: usize) -> Value {
+    Value::TaggedUnion(Box::new(TaggedUnionValue {
+        variant: variant.to_owned(),
+        tag,
+        value,
+        padding,
+    }))
+}
+
+fn u8_value(value: u32) -> Value {
+    Value::Scalar(ScalarValue::Integer(BigInt::from(value), IntegerType::U8))
+}
+
+#[test]
+fn tagged_union_ok() {
+    let program = consume();
+
+    crate::run::<Bn256>(&program, &tagged_union("None", 0, Value::Unit, 1)).expect("ran");
+    crate::run::<Bn256>(&program, &tagged_union("Some", 1, u8_value(42), 0)).expect("ran");
+}
+
+#[test]
+fn tagged_union_tag_out_of_range() {
+    let program = consume();
+
+    match crate::run::<Bn256>(&program, &tagged_union("Some", 2, u8_value(42), 0)) {
+        Err(RuntimeError::AssertionError(_)) => {}
+        res => panic!("expected assertion error, got {:?} instead", res),
+    }
+}
+
+#[test]
+fn tagged_union_padding_not_zero() {
+    let program = consume();
+
+    match crate::run::<Bn256>(&program, &tagged_union("None", 0, u8_value(42), 0)) {
+        Err(RuntimeError::AssertionError(_)) => {}
+        res => panic!("expected assertion error, got {:?} instead", res),
+    }
+}